@@ -1,6 +1,11 @@
 use arrow_gpu::utils::ScalarArray;
 
+/// Compares with a relative tolerance of 1e-3, values below 1e-3 in magnitude are
+/// compared with an absolute tolerance of 1e-6
 pub fn float_eq_in_error(left: f32, right: f32) -> bool {
+    if left == right {
+        return true;
+    }
     if (left.is_nan() && !right.is_nan()) || (right.is_nan() && !left.is_nan()) {
         return false;
     }
@@ -17,17 +22,15 @@ pub fn float_eq_in_error(left: f32, right: f32) -> bool {
     {
         return false;
     }
-    if (left.abs() - right.abs()).abs() > 0.01 {
-        return false;
-    }
-    true
+    (left - right).abs() <= 1e-3 * left.abs().max(right.abs()).max(1e-3)
 }
 
 pub fn float_slice_eq_in_error(v1: ScalarArray, v2: ScalarArray) {
     match (v1, v2) {
         (ScalarArray::F32Vec(x), ScalarArray::F32Vec(y)) => {
+            assert_eq!(x.len(), y.len(), "left: {:?} \n right: {:?}", x, y);
             for i in 0..x.len() {
-                if !float_eq_in_error(x[i], x[i]) {
+                if !float_eq_in_error(x[i], y[i]) {
                     panic!(
                         "assertion failed: `(left {} == right {}) \n left: `{:?}` \n right: `{:?}`",
                         x[i], y[i], x, y
@@ -0,0 +1,18 @@
+struct ArangeParams {
+    start: f32,
+    step: f32,
+}
+
+@group(0) @binding(0)
+var<storage, read_write> params : ArangeParams;
+
+@group(0) @binding(1)
+var<storage, read_write> values : array<f32>;
+
+@compute
+@workgroup_size(256)
+fn arange(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&values) {
+        values[global_id.x] = params.start + f32(global_id.x) * params.step;
+    }
+}
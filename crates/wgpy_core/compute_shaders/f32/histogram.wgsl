@@ -0,0 +1,58 @@
+const NO_BIN: u32 = 0xffffffffu;
+
+// Samples of `dims` coordinates
+@group(0) @binding(0)
+var<storage, read> input_1 : array<f32>;
+
+// Increasing bin edges of every dimension, one dimension after the other
+@group(0) @binding(1)
+var<storage, read> input_2 : array<f32>;
+
+// len, dims and the number of bins of every dimension
+@group(0) @binding(2)
+var<storage, read> input_3 : array<u32>;
+
+@group(0) @binding(3)
+var<storage, read_write> output : array<u32>;
+
+// Bin of a value within the `bins + 1` edges from `start`, the last bin includes its
+// right edge. Values outside of the edges and nans have no bin.
+fn find_bin(value: f32, start: u32, bins: u32) -> u32 {
+    if !(value >= input_2[start] && value <= input_2[start + bins]) {
+        return NO_BIN;
+    }
+    var low = 0u;
+    var high = bins + 1u;
+    while low < high {
+        let middle = (low + high) / 2u;
+        if input_2[start + middle] <= value {
+            low = middle + 1u;
+        } else {
+            high = middle;
+        }
+    }
+    return min(low - 1u, bins - 1u);
+}
+
+// Flat index of the bin of every sample, in row major order of the dimensions
+@compute
+@workgroup_size(256)
+fn bin_indices_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let i = global_id.x;
+    if i < input_3[0] {
+        let dims = input_3[1];
+        var index = 0u;
+        var start = 0u;
+        for (var d = 0u; d < dims; d++) {
+            let bins = input_3[2u + d];
+            let bin = find_bin(input_1[i * dims + d], start, bins);
+            if bin == NO_BIN {
+                output[i] = NO_BIN;
+                return;
+            }
+            index = index * bins + bin;
+            start += bins + 1u;
+        }
+        output[i] = index;
+    }
+}
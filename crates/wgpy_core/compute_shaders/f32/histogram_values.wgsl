@@ -0,0 +1,56 @@
+const MODE_BITS: u32 = 0u;
+const MODE_VALUE: u32 = 1u;
+
+// Counts or weights of every bin as u32 or f32 bits, followed by their total
+@group(0) @binding(0)
+var<storage, read> input_1 : array<u32>;
+
+// Bin edges of every dimension, one dimension after the other
+@group(0) @binding(1)
+var<storage, read> input_2 : array<f32>;
+
+// weighted, mode, dims and the number of bins of every dimension
+@group(0) @binding(2)
+var<storage, read> input_3 : array<u32>;
+
+@group(0) @binding(3)
+var<storage, read_write> output : array<f32>;
+
+fn bin_value(i: u32) -> f32 {
+    if input_3[0] != 0u {
+        return bitcast<f32>(input_1[i]);
+    }
+    return f32(input_1[i]);
+}
+
+// Values of the bins, as the bits of the sums, the sums as f32 or the density, which
+// divides the sums by their total and by the volume of their bin
+@compute
+@workgroup_size(256)
+fn histogram_values_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let i = global_id.x;
+    let len = arrayLength(&output);
+    if i < len {
+        switch input_3[1] {
+            case MODE_BITS: {
+                output[i] = bitcast<f32>(input_1[i]);
+            }
+            case MODE_VALUE: {
+                output[i] = bin_value(i);
+            }
+            default: {
+                var volume = 1.0;
+                var rest = i;
+                var start = arrayLength(&input_2);
+                for (var d = input_3[2]; d > 0u; d--) {
+                    let bins = input_3[2u + d];
+                    start -= bins + 1u;
+                    let bin = rest % bins;
+                    rest /= bins;
+                    volume *= input_2[start + bin + 1u] - input_2[start + bin];
+                }
+                output[i] = bin_value(i) / bin_value(len) / volume;
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+struct SpaceParams {
+    start: f32,
+    step: f32,
+    // value of the last element when endpoint is set
+    last: f32,
+    endpoint: u32,
+    base: f32,
+    scale: f32,
+}
+
+@group(0) @binding(0)
+var<storage, read_write> params : SpaceParams;
+
+@group(0) @binding(1)
+var<storage, read_write> values : array<f32>;
+
+fn is_last(index: u32) -> bool {
+    return params.endpoint != 0u && index == arrayLength(&values) - 1u;
+}
+
+@compute
+@workgroup_size(256)
+fn linspace(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&values) {
+        if is_last(global_id.x) {
+            values[global_id.x] = params.last;
+        } else {
+            values[global_id.x] = params.start + f32(global_id.x) * params.step;
+        }
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn logspace(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&values) {
+        if is_last(global_id.x) {
+            values[global_id.x] = params.last;
+        } else {
+            let exponent = params.start + f32(global_id.x) * params.step;
+            values[global_id.x] = params.scale * pow(params.base, exponent);
+        }
+    }
+}
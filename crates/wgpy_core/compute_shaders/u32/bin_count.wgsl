@@ -0,0 +1,52 @@
+struct BinCountParams {
+    len: u32,
+    bins: u32,
+    weighted: u32,
+    total: u32,
+}
+
+// Bin of every element, elements outside of the bins are skipped
+@group(0) @binding(0)
+var<storage, read> input_1 : array<u32>;
+
+@group(0) @binding(1)
+var<storage, read> input_2 : array<f32>;
+
+@group(0) @binding(2)
+var<storage, read> params : BinCountParams;
+
+// Counts, or sums of weights stored as f32 bits, of every bin followed by their total
+@group(0) @binding(3)
+var<storage, read_write> output : array<atomic<u32>>;
+
+fn add_weight(index: u32, weight: f32) {
+    var old = atomicLoad(&output[index]);
+    loop {
+        let updated = bitcast<u32>(bitcast<f32>(old) + weight);
+        let result = atomicCompareExchangeWeak(&output[index], old, updated);
+        if result.exchanged {
+            break;
+        }
+        old = result.old_value;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn bin_count_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let i = global_id.x;
+    if i < params.len && input_1[i] < params.bins {
+        let bin = input_1[i];
+        if params.weighted != 0u {
+            add_weight(bin, input_2[i]);
+            if params.total != 0u {
+                add_weight(params.bins, input_2[i]);
+            }
+        } else {
+            atomicAdd(&output[bin], 1u);
+            if params.total != 0u {
+                atomicAdd(&output[params.bins], 1u);
+            }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+// Writes the index of every non zero flag of input_1 to the output, input_2 holds the
+// inclusive scan of the flags which gives their positions
+@group(0) @binding(0)
+var<storage, read> input_1 : array<u32>;
+
+@group(0) @binding(1)
+var<storage, read> input_2 : array<u32>;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<u32>;
+
+@compute
+@workgroup_size(256)
+fn compact_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let i = global_id.x;
+    if i < arrayLength(&input_1) && input_1[i] != 0u {
+        output[input_2[i] - 1u] = i;
+    }
+}
@@ -0,0 +1,21 @@
+struct ConcatenateBlock {
+    block_in: u32,
+    block_out: u32,
+    offset: u32,
+}
+
+@group(0) @binding(0)
+var<storage, read_write> block : ConcatenateBlock;
+
+@group(0) @binding(1)
+var<storage, read_write> indexes : array<u32>;
+
+@compute
+@workgroup_size(256)
+fn concatenate_index(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&indexes) {
+        let outer = global_id.x / block.block_in;
+        let inner = global_id.x % block.block_in;
+        indexes[global_id.x] = outer * block.block_out + block.offset + inner;
+    }
+}
@@ -0,0 +1,89 @@
+// params holds the number of output dimensions followed by
+// [out_dim, in_dim, in_stride, mode, param] for every output dimension
+@group(0) @binding(0)
+var<storage, read_write> params : array<u32>;
+
+@group(0) @binding(1)
+var<storage, read_write> indexes : array<u32>;
+
+const MODE_WRAP: u32 = 0u;
+const MODE_FLIP: u32 = 1u;
+const MODE_CONSTANT: u32 = 2u;
+const MODE_EDGE: u32 = 3u;
+const MODE_REFLECT: u32 = 4u;
+const MODE_SYMMETRIC: u32 = 5u;
+
+// Returns -1 when the coordinate falls outside of the input
+fn map_coordinate(coord: u32, in_dim: u32, mode: u32, param: u32) -> i32 {
+    let n = i32(in_dim);
+    let c = i32(coord) - i32(param);
+    switch mode {
+        case MODE_WRAP: {
+            return i32((coord + param) % in_dim);
+        }
+        case MODE_FLIP: {
+            return i32(in_dim - 1u - coord);
+        }
+        case MODE_CONSTANT: {
+            if c < 0 || c >= n {
+                return -1;
+            }
+            return c;
+        }
+        case MODE_EDGE: {
+            return clamp(c, 0, n - 1);
+        }
+        case MODE_REFLECT: {
+            if n == 1 {
+                return 0;
+            }
+            let period = 2 * (n - 1);
+            var r = c % period;
+            if r < 0 {
+                r += period;
+            }
+            if r >= n {
+                r = period - r;
+            }
+            return r;
+        }
+        case MODE_SYMMETRIC: {
+            let period = 2 * n;
+            var r = c % period;
+            if r < 0 {
+                r += period;
+            }
+            if r >= n {
+                r = period - 1 - r;
+            }
+            return r;
+        }
+        default: {
+            return -1;
+        }
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn gather_index(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&indexes) {
+        let ndim = params[0];
+        var remaining = global_id.x;
+        var index = 0u;
+        for (var i = 0u; i < ndim; i += 1u) {
+            let base = 1u + (ndim - 1u - i) * 5u;
+            let out_dim = params[base];
+            let coord = remaining % out_dim;
+            remaining = remaining / out_dim;
+
+            let mapped = map_coordinate(coord, params[base + 1u], params[base + 3u], params[base + 4u]);
+            if mapped < 0 {
+                index = 0xffffffffu;
+                break;
+            }
+            index += u32(mapped) * params[base + 2u];
+        }
+        indexes[global_id.x] = index;
+    }
+}
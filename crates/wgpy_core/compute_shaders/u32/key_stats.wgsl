@@ -0,0 +1,41 @@
+// Requires sort.wgsl to be concatenated before this file
+
+@group(0) @binding(0)
+var<storage, read> input : array<u32>;
+
+@group(0) @binding(1)
+var<storage, read> params : SortParams;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<atomic<u32>>;
+
+fn key_at(i: u32) -> u32 {
+    return to_key(input[params.offset + i * params.inner], params.key_type);
+}
+
+// Smallest and largest keys of the `len` elements read every `inner` elements from
+// `offset`. The output starts zeroed, so the smallest key is stored inverted.
+@compute
+@workgroup_size(256)
+fn key_range_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        let key = key_at(global_id.x);
+        atomicMax(&output[0], ~key);
+        atomicMax(&output[1], key);
+    }
+}
+
+// Counts the neighbours which decrease and the ones which increase
+@compute
+@workgroup_size(256)
+fn monotonicity_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x + 1u < params.len {
+        let key = key_at(global_id.x);
+        let next = key_at(global_id.x + 1u);
+        if key > next {
+            atomicAdd(&output[0], 1u);
+        } else if key < next {
+            atomicAdd(&output[1], 1u);
+        }
+    }
+}
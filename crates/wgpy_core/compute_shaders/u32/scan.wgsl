@@ -0,0 +1,29 @@
+// A step of an inclusive Hillis Steele scan adding the value `shift` positions before,
+// a shift of 0 moves every value one position up to make the scan exclusive
+@group(0) @binding(0)
+var<storage, read> input : array<u32>;
+
+@group(0) @binding(1)
+var<storage, read> shift : u32;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<u32>;
+
+@compute
+@workgroup_size(256)
+fn scan_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let i = global_id.x;
+    if i < arrayLength(&output) {
+        if shift == 0u {
+            if i > 0u {
+                output[i] = input[i - 1u];
+            } else {
+                output[i] = 0u;
+            }
+        } else if i >= shift {
+            output[i] = input[i] + input[i - shift];
+        } else {
+            output[i] = input[i];
+        }
+    }
+}
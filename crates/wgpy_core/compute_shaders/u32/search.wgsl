@@ -0,0 +1,72 @@
+// Requires sort.wgsl to be concatenated before this file
+
+struct SearchParams {
+    len: u32,
+    sorted_len: u32,
+    key_type: u32,
+    invert: u32,
+    reverse: u32,
+}
+
+@group(0) @binding(0)
+var<storage, read> input_1 : array<u32>;
+
+@group(0) @binding(1)
+var<storage, read> input_2 : array<u32>;
+
+@group(0) @binding(2)
+var<storage, read> params : SearchParams;
+
+@group(0) @binding(3)
+var<storage, read_write> output : array<u32>;
+
+// Binary search of the values of input_1 in the keys of the sorted pairs of input_2,
+// returns the first position whose key is not less, or greater with `right`. Positions
+// are counted from the end with `reverse`.
+fn search(key: u32, right: bool) -> u32 {
+    var low = 0u;
+    var high = params.sorted_len;
+    while low < high {
+        let middle = (low + high) / 2u;
+        let sorted_key = input_2[2u * middle];
+        if sorted_key < key || (right && sorted_key == key) {
+            low = middle + 1u;
+        } else {
+            high = middle;
+        }
+    }
+    if params.reverse != 0u {
+        return params.sorted_len - low;
+    }
+    return low;
+}
+
+@compute
+@workgroup_size(256)
+fn search_left_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        output[global_id.x] = search(to_key(input_1[global_id.x], params.key_type), false);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn search_right_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        output[global_id.x] = search(to_key(input_1[global_id.x], params.key_type), true);
+    }
+}
+
+// Flags the values of input_1 found in the sorted pairs, or the ones which are not found
+// with `invert`. Nans are not equal to any value, like in numpy.
+@compute
+@workgroup_size(256)
+fn contains_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        let key = to_key(input_1[global_id.x], params.key_type);
+        let position = search(key, false);
+        let is_nan = params.key_type == KEY_F32 && key == 0xffffffffu;
+        let found = !is_nan && position < params.sorted_len && input_2[2u * position] == key;
+        output[global_id.x] = u32(found) ^ params.invert;
+    }
+}
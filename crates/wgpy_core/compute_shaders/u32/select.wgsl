@@ -0,0 +1,35 @@
+// Requires sort.wgsl to be concatenated before this file
+
+// Pairs of the input order, the index of a pair is its position
+@group(0) @binding(0)
+var<storage, read> input_1 : array<u32>;
+
+// (prefix, rank) state of every kth of every segment, see select_digit_ in
+// sort_ternary.wgsl
+@group(0) @binding(1)
+var<storage, read> input_2 : array<u32>;
+
+@group(0) @binding(2)
+var<storage, read> params : SortParams;
+
+// Digit counts of every kth of every segment
+@group(0) @binding(3)
+var<storage, read_write> output : array<atomic<u32>>;
+
+// Counts the digits at `shift` of the keys whose higher digits match the prefix of
+// every kth of their segment
+@compute
+@workgroup_size(256)
+fn select_histogram_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let i = global_id.x;
+    if i < params.len {
+        let key = input_1[2u * i];
+        let kth_count = arrayLength(&input_2) / (2u * segments(params));
+        let first = segment_of(i, params) * kth_count;
+        for (var j = first; j < first + kth_count; j++) {
+            if high_digits_match(key, input_2[2u * j], params.shift) {
+                atomicAdd(&output[j * RADIX + digit(key, params.shift)], 1u);
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// Radix sort helpers shared by the sort shaders, which are concatenated after this file.
+// Elements are sorted as (key, index) pairs interleaved in a u32 array, where the index
+// is the flat position of the element in the input.
+
+struct SortParams {
+    len: u32,
+    axis_len: u32,
+    inner: u32,
+    key_type: u32,
+    shift: u32,
+    blocks: u32,
+    offset: u32,
+}
+
+const KEY_U32: u32 = 0u;
+const KEY_I32: u32 = 1u;
+const KEY_F32: u32 = 2u;
+
+const RADIX: u32 = 16u;
+const BLOCK_SIZE: u32 = 256u;
+
+// Maps the bits of a value to an unsigned key with the same ordering, negative zero
+// is equal to zero and nans go last
+fn to_key(bits: u32, key_type: u32) -> u32 {
+    switch key_type {
+        case KEY_I32: {
+            return bits ^ 0x80000000u;
+        }
+        case KEY_F32: {
+            if (bits & 0x7fffffffu) > 0x7f800000u {
+                return 0xffffffffu;
+            }
+            if bits == 0x80000000u {
+                return bits;
+            }
+            if (bits & 0x80000000u) != 0u {
+                return ~bits;
+            }
+            return bits | 0x80000000u;
+        }
+        default: {
+            return bits;
+        }
+    }
+}
+
+fn digit(key: u32, shift: u32) -> u32 {
+    return (key >> shift) & (RADIX - 1u);
+}
+
+// Whether the digits of the keys above `shift` are equal
+fn high_digits_match(key: u32, other: u32, shift: u32) -> bool {
+    let high = shift + 4u;
+    if high >= 32u {
+        return true;
+    }
+    return (key >> high) == (other >> high);
+}
+
+// The input is viewed as [outer, axis_len, inner] and every lane along the axis is a
+// segment sorted on its own
+fn segment_of(index: u32, params: SortParams) -> u32 {
+    return (index / (params.axis_len * params.inner)) * params.inner + index % params.inner;
+}
+
+fn segments(params: SortParams) -> u32 {
+    return params.len / params.axis_len;
+}
+
+fn axis_position(index: u32, params: SortParams) -> u32 {
+    return (index / params.inner) % params.axis_len;
+}
@@ -0,0 +1,96 @@
+// Requires sort.wgsl to be concatenated before this file
+
+@group(0) @binding(0)
+var<storage, read> input : array<u32>;
+
+@group(0) @binding(1)
+var<storage, read> params : SortParams;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<u32>;
+
+// Position of the element at `index` of the output within the sorted pairs
+fn sorted_position(index: u32) -> u32 {
+    return segment_of(index, params) * params.axis_len + axis_position(index, params);
+}
+
+@compute
+@workgroup_size(256)
+fn sort_keys_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        output[2u * global_id.x] = to_key(input[params.offset + global_id.x], params.key_type);
+        output[2u * global_id.x + 1u] = global_id.x;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn segment_keys_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        let index = input[2u * global_id.x + 1u];
+        output[2u * global_id.x] = segment_of(index, params);
+        output[2u * global_id.x + 1u] = index;
+    }
+}
+
+// Counts the digits of every block, the counts are stored digit major so that their
+// exclusive scan gives the offset of every block and digit in the output
+@compute
+@workgroup_size(256)
+fn radix_histogram_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let block = global_id.x;
+    if block < params.blocks {
+        var counts: array<u32, 16>;
+        let end = min((block + 1u) * BLOCK_SIZE, params.len);
+        for (var i = block * BLOCK_SIZE; i < end; i++) {
+            counts[digit(input[2u * i], params.shift)] += 1u;
+        }
+        for (var d = 0u; d < RADIX; d++) {
+            output[d * params.blocks + block] = counts[d];
+        }
+    }
+}
+
+// Flat indices of the sorted elements, used to take the values from the input
+@compute
+@workgroup_size(256)
+fn flat_indices_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        output[global_id.x] = input[2u * sorted_position(global_id.x) + 1u];
+    }
+}
+
+// Indices of the sorted elements along the axis
+@compute
+@workgroup_size(256)
+fn axis_indices_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        let index = input[2u * sorted_position(global_id.x) + 1u];
+        output[global_id.x] = axis_position(index, params);
+    }
+}
+
+// Size of every group given the sorted positions where the groups start
+@compute
+@workgroup_size(256)
+fn group_counts_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let i = global_id.x;
+    let groups = arrayLength(&input);
+    if i < groups {
+        var end = params.len;
+        if i + 1u < groups {
+            end = input[i + 1u];
+        }
+        output[i] = end - input[i];
+    }
+}
+
+// Flat indices of the rows of the input, viewed with `inner` columns
+@compute
+@workgroup_size(256)
+fn take_rows_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let i = global_id.x;
+    if i < arrayLength(&output) {
+        output[i] = input[i / params.inner] * params.inner + i % params.inner;
+    }
+}
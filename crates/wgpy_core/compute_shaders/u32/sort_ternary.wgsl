@@ -0,0 +1,138 @@
+// Requires sort.wgsl to be concatenated before this file
+
+@group(0) @binding(0)
+var<storage, read> input_1 : array<u32>;
+
+@group(0) @binding(1)
+var<storage, read> input_2 : array<u32>;
+
+@group(0) @binding(2)
+var<storage, read> params : SortParams;
+
+@group(0) @binding(3)
+var<storage, read_write> output : array<u32>;
+
+var<workgroup> block_digits : array<u32, 256>;
+
+// Moves the pairs of input_1 to the offsets of input_2, the rank of a pair within its
+// block and digit keeps the sort stable
+@compute
+@workgroup_size(256)
+fn radix_scatter_(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) workgroup_id: vec3<u32>
+) {
+    let i = global_id.x;
+    var d = RADIX;
+    if i < params.len {
+        d = digit(input_1[2u * i], params.shift);
+    }
+    block_digits[local_id.x] = d;
+    workgroupBarrier();
+    if i < params.len {
+        var rank = 0u;
+        for (var j = 0u; j < local_id.x; j++) {
+            if block_digits[j] == d {
+                rank += 1u;
+            }
+        }
+        let position = input_2[d * params.blocks + workgroup_id.x] + rank;
+        output[2u * position] = input_1[2u * i];
+        output[2u * position + 1u] = input_1[2u * i + 1u];
+    }
+}
+
+// Replaces the keys of the pairs in input_2 by the values of input_1 at their index
+@compute
+@workgroup_size(256)
+fn rekey_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        let index = input_2[2u * global_id.x + 1u];
+        output[2u * global_id.x] = to_key(input_1[params.offset + index], params.key_type);
+        output[2u * global_id.x + 1u] = index;
+    }
+}
+
+// Group of every element in the input order, input_1 holds the sorted pairs and input_2
+// the inclusive scan of the flags of the first element of every group
+@compute
+@workgroup_size(256)
+fn inverse_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < params.len {
+        output[input_1[2u * global_id.x + 1u]] = input_2[global_id.x] - 1u;
+    }
+}
+
+// Flags the sorted rows which differ from the one before, input_1 holds the values
+// as `inner` columns of `len` rows and input_2 the pairs of the sorted rows
+@compute
+@workgroup_size(256)
+fn rows_differ_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let i = global_id.x;
+    if i < params.len {
+        var differ = i == 0u;
+        if !differ {
+            let row = input_2[2u * i + 1u];
+            let previous = input_2[2u * (i - 1u) + 1u];
+            for (var column = 0u; column < params.inner; column++) {
+                let base = column * params.len;
+                if to_key(input_1[base + row], params.key_type)
+                    != to_key(input_1[base + previous], params.key_type) {
+                    differ = true;
+                    break;
+                }
+            }
+        }
+        output[i] = u32(differ);
+    }
+}
+
+// Selects the digit at `shift` of every kth, input_1 holds the digit counts of
+// select_histogram_ and input_2 the (prefix, rank) state, where the prefix has the digits
+// of the kth key above `shift` and the rank is its position among the keys matching them
+@compute
+@workgroup_size(256)
+fn select_digit_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let j = global_id.x;
+    if j < arrayLength(&input_2) / 2u {
+        var prefix = input_2[2u * j];
+        var rank = input_2[2u * j + 1u];
+        for (var d = 0u; d < RADIX; d++) {
+            let count = input_1[j * RADIX + d];
+            if rank < count {
+                prefix |= d << params.shift;
+                break;
+            }
+            rank -= count;
+        }
+        output[2u * j] = prefix;
+        output[2u * j + 1u] = rank;
+    }
+}
+
+// Keys the pairs of input_1 by their bucket between the kth keys of their segment, the
+// prefixes of the selection state in input_2. Keys equal to a kth key get their own
+// bucket, so sorting the buckets puts the kth keys at their sorted positions.
+@compute
+@workgroup_size(256)
+fn partition_keys_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let i = global_id.x;
+    if i < params.len {
+        let key = input_1[2u * i];
+        let kth_count = arrayLength(&input_2) / (2u * segments(params));
+        let first = segment_of(i, params) * kth_count;
+        var bucket = 0u;
+        var equal = 0u;
+        for (var j = first; j < first + kth_count; j++) {
+            let kth_key = input_2[2u * j];
+            if kth_key < key {
+                bucket += 2u;
+            } else if kth_key == key {
+                equal = 1u;
+            }
+        }
+        output[2u * i] = bucket + equal;
+        output[2u * i + 1u] = i;
+    }
+}
@@ -0,0 +1,32 @@
+struct TriParams {
+    cols: u32,
+    k: i32,
+}
+
+@group(0) @binding(0)
+var<storage, read_write> params : TriParams;
+
+@group(0) @binding(1)
+var<storage, read_write> values : array<u32>;
+
+// 1 where the column is on or below the k-th diagonal
+@compute
+@workgroup_size(256)
+fn tri(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&values) {
+        let row = i32(global_id.x / params.cols);
+        let col = i32(global_id.x % params.cols);
+        values[global_id.x] = select(0u, 1u, col <= row + params.k);
+    }
+}
+
+// 1 where the column is on the k-th diagonal
+@compute
+@workgroup_size(256)
+fn eye(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&values) {
+        let row = i32(global_id.x / params.cols);
+        let col = i32(global_id.x % params.cols);
+        values[global_id.x] = select(0u, 1u, col == row + params.k);
+    }
+}
@@ -1,49 +1,127 @@
 use std::sync::Arc;
 
-use arrow_gpu::{array::UInt32ArrayGPU, gpu_utils::*};
+use arrow_gpu::{
+    array::{ArrowArrayGPU, Float32ArrayGPU, Int32ArrayGPU, UInt32ArrayGPU},
+    gpu_utils::*,
+};
 use wgpu::Buffer;
 
-use crate::{Dtype, IndexSlice, NdArray, GPU_DEVICE};
+use crate::{Dtype, IndexSlice, NdArray, NdArrayError, NdArrayResult, ScalarValue, GPU_DEVICE};
 
 const ARANGE_SHADER: &str = include_str!("../../compute_shaders/u32/slice_to_index.wgsl");
+const ARANGE_I32_SHADER: &str = include_str!("../../compute_shaders/i32/arange.wgsl");
+const ARANGE_F32_SHADER: &str = include_str!("../../compute_shaders/f32/arange.wgsl");
 
-// TODO handle cases like these np.arange(10, 0, -1)
+/// Number of values in `[start, stop)` spaced by `step`, `ceil((stop - start) / step)`
+fn arange_length(start: f64, stop: f64, step: f64) -> NdArrayResult<u32> {
+    if step == 0.0 {
+        return Err(NdArrayError::ValueError(
+            "step must not be zero".to_string(),
+        ));
+    }
+    let length = ((stop - start) / step).ceil();
+    if length.is_nan() {
+        return Err(NdArrayError::ValueError(
+            "arange length is not a number".to_string(),
+        ));
+    }
+    Ok(if length > 0.0 { length as u32 } else { 0 })
+}
+
+/// Evenly spaced values within `[start, stop)`. Start defaults to zero and step to one,
+/// the dtype defaults to the result type of the given values.
 pub fn arange(
-    start: Option<u32>,
-    stop: u32,
-    step: Option<i32>,
+    start: Option<ScalarValue>,
+    stop: ScalarValue,
+    step: Option<ScalarValue>,
     dtype: Option<Dtype>,
     gpu_device: Option<Arc<GpuDevice>>,
-) -> NdArray {
+) -> NdArrayResult<NdArray> {
     let gpu_device = gpu_device.unwrap_or(GPU_DEVICE.clone());
-    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), None);
-    let start = start.unwrap_or(0);
-    let step = step.unwrap_or(1);
-    let index_slice = IndexSlice { start, stop, step };
-    let data = arange_op(&index_slice, &mut pipeline);
-
-    //TODO handle dtype
-    let dtype = match dtype {
-        Some(Dtype::UInt32) => Dtype::UInt32,
-        None => Dtype::UInt32,
-        Some(_) => todo!(),
+    let dtype = dtype.unwrap_or_else(|| {
+        let dtypes = [Some(stop), start, step]
+            .iter()
+            .flatten()
+            .map(Dtype::from)
+            .collect::<Vec<_>>();
+        Dtype::result_type(&dtypes).unwrap()
+    });
+
+    let start = start.map_or(0.0, |x| x.as_f64());
+    let step = step.map_or(1.0, |x| x.as_f64());
+    let len = arange_length(start, stop.as_f64(), step)?;
+    if len == 0 {
+        return Ok(NdArray::zero_sized(vec![0], dtype, gpu_device));
+    }
+
+    // Integers are generated as i32 and reinterpreted, wrapping gives the same bits for u32
+    let (params, shader, kernel_dtype) = match dtype {
+        Dtype::Float32 => (
+            [(start as f32).to_bits(), (step as f32).to_bits()],
+            ARANGE_F32_SHADER,
+            Dtype::Float32,
+        ),
+        Dtype::UInt32 => (
+            [start as i64 as u32, step as i64 as u32],
+            ARANGE_I32_SHADER,
+            Dtype::UInt32,
+        ),
+        _ => (
+            [start as i64 as u32, step as i64 as u32],
+            ARANGE_I32_SHADER,
+            Dtype::Int32,
+        ),
     };
 
+    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("arange"));
+    let params_buffer = pipeline.device.create_gpu_buffer_with_data(&params);
+    let data = Arc::new(pipeline.apply_unary_function(
+        &params_buffer,
+        len as u64 * 4,
+        shader,
+        "arange",
+        len.div_ceil(256),
+    ));
     pipeline.finish();
 
-    let data = UInt32ArrayGPU {
-        data: Arc::new(data),
-        gpu_device,
-        phantom: std::marker::PhantomData,
-        len: index_slice.element_count() as usize,
-        null_buffer: None,
+    let data: ArrowArrayGPU = match kernel_dtype {
+        Dtype::Float32 => Float32ArrayGPU {
+            data,
+            gpu_device,
+            phantom: std::marker::PhantomData,
+            len: len as usize,
+            null_buffer: None,
+        }
+        .into(),
+        Dtype::UInt32 => UInt32ArrayGPU {
+            data,
+            gpu_device,
+            phantom: std::marker::PhantomData,
+            len: len as usize,
+            null_buffer: None,
+        }
+        .into(),
+        _ => Int32ArrayGPU {
+            data,
+            gpu_device,
+            phantom: std::marker::PhantomData,
+            len: len as usize,
+            null_buffer: None,
+        }
+        .into(),
     };
 
-    NdArray {
-        shape: vec![index_slice.element_count()],
+    let array = NdArray {
+        shape: vec![len],
         dims: 1,
-        data: data.into(),
-        dtype,
+        data,
+        dtype: kernel_dtype,
+    };
+
+    if dtype == kernel_dtype {
+        Ok(array)
+    } else {
+        Ok(array.astype(dtype))
     }
 }
 
@@ -71,16 +149,73 @@ mod tests {
 
     #[test]
     fn test_arange() {
-        let input_1 = arange(None, 10, None, None, None);
+        let input_1 = arange(None, 10u32.into(), None, None, None).unwrap();
         let result = (0..10).collect::<Vec<u32>>();
         assert_eq!(input_1.data.get_raw_values(), result.into());
 
-        let input_1 = arange(Some(2), 10, None, None, None);
+        let input_1 = arange(Some(2u32.into()), 10u32.into(), None, None, None).unwrap();
         let result = (2..10).collect::<Vec<u32>>();
         assert_eq!(input_1.data.get_raw_values(), result.into());
 
-        let input_1 = arange(Some(3), 10, Some(2), None, None);
+        let input_1 = arange(
+            Some(3u32.into()),
+            10u32.into(),
+            Some(2u32.into()),
+            None,
+            None,
+        );
         let result = (3..10).step_by(2).collect::<Vec<u32>>();
-        assert_eq!(input_1.data.get_raw_values(), result.into());
+        assert_eq!(input_1.unwrap().data.get_raw_values(), result.into());
+    }
+
+    #[test]
+    fn test_arange_negative_step() {
+        let result = arange(
+            Some(10i32.into()),
+            0i32.into(),
+            Some((-3i32).into()),
+            None,
+            None,
+        );
+        let result = result.unwrap();
+        assert_eq!(result.dtype, Dtype::Int32);
+        assert_eq!(result.data.get_raw_values(), vec![10, 7, 4, 1].into());
+
+        let result = arange(
+            Some(1i32.into()),
+            5i32.into(),
+            Some((-1i32).into()),
+            None,
+            None,
+        );
+        assert_eq!(&result.unwrap().shape, &[0]);
+        assert!(arange(None, 5i32.into(), Some(0i32.into()), None, None).is_err());
+    }
+
+    #[test]
+    fn test_arange_float_dtype() {
+        let result = arange(
+            Some((-1.0f32).into()),
+            1.0f32.into(),
+            Some(0.5f32.into()),
+            None,
+            None,
+        );
+        let result = result.unwrap();
+        assert_eq!(result.dtype, Dtype::Float32);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![-1.0f32, -0.5, 0.0, 0.5].into()
+        );
+
+        let result = arange(None, 4i32.into(), None, Some(Dtype::Float32), None).unwrap();
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![0.0f32, 1.0, 2.0, 3.0].into()
+        );
+
+        let result = arange(None, 3i32.into(), None, Some(Dtype::Int8), None).unwrap();
+        assert_eq!(result.dtype, Dtype::Int8);
+        assert_eq!(result.data.get_raw_values(), vec![0i8, 1, 2].into());
     }
 }
@@ -105,6 +105,9 @@ pub fn broadcast_if_required(
 /// Broadcast an array to a new shape
 pub fn broadcast_to_op(x: &NdArray, shape: &[u32], pipeline: &mut ArrowComputePipeline) -> NdArray {
     let braodcasted_shape = broadcast_shape(&x.shape, shape).unwrap();
+    if braodcasted_shape.contains(&0) {
+        return NdArray::zero_sized(braodcasted_shape, x.dtype, x.get_gpu_device());
+    }
 
     let buffer = shape_to_indexes_buf(&braodcasted_shape, &x.shape, pipeline);
     let len = (buffer.size() / 4) as usize;
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{ArrowArrayGPU, UInt32ArrayGPU},
+    gpu_utils::ArrowComputePipeline,
+    kernels::take_op_dyn,
+    utils::ScalarArray,
+};
+use wgpu::Buffer;
+
+use crate::utils::into_index_array;
+
+const SCAN_SHADER: &str = include_str!("../../compute_shaders/u32/scan.wgsl");
+const COMPACT_SHADER: &str = include_str!("../../compute_shaders/u32/compact.wgsl");
+
+fn scan_step(values: &Buffer, shift: u32, len: u32, pipeline: &mut ArrowComputePipeline) -> Buffer {
+    let shift = pipeline.device.create_gpu_buffer_with_data(&[shift]);
+    pipeline.apply_binary_function(
+        values,
+        &shift,
+        len as u64 * 4,
+        SCAN_SHADER,
+        "scan_",
+        len.div_ceil(256),
+    )
+}
+
+/// Prefix sums of `len` u32 values, an exclusive scan starts from zero
+pub(crate) fn scan_op(
+    values: &Buffer,
+    len: u32,
+    exclusive: bool,
+    pipeline: &mut ArrowComputePipeline,
+) -> Buffer {
+    let mut scanned = scan_step(values, u32::from(!exclusive), len, pipeline);
+    let mut shift = if exclusive { 1 } else { 2 };
+    while shift < len {
+        scanned = scan_step(&scanned, shift, len, pipeline);
+        shift *= 2;
+    }
+    scanned
+}
+
+/// Non zero flags gathered to the front of an array
+pub(crate) struct Compaction {
+    /// Indices of the non zero flags in increasing order
+    pub indices: UInt32ArrayGPU,
+    /// Inclusive scan of the flags, which numbers the non zero flags from one
+    pub positions: Arc<Buffer>,
+    pub count: u32,
+}
+
+/// Finds the indices of the non zero values among `len` u32 flags. The number of
+/// indices is read back to size the output, so the pipeline is finished and replaced
+/// with a new one. `indices` holds no data if `count` is zero.
+pub(crate) fn compact_op(
+    flags: &Buffer,
+    len: u32,
+    pipeline: &mut ArrowComputePipeline,
+    label: &str,
+) -> Compaction {
+    let gpu_device = pipeline.device.clone();
+    let positions = scan_op(flags, len, false, pipeline);
+    let positions: ArrowArrayGPU = into_index_array(positions, len, gpu_device.clone()).into();
+    let last = gpu_device.create_gpu_buffer_with_data(&[len - 1]);
+    let last = into_index_array(last, 1, gpu_device.clone());
+    let count = take_op_dyn(&positions, &last, pipeline);
+    std::mem::replace(
+        pipeline,
+        ArrowComputePipeline::new(gpu_device.clone(), Some(label)),
+    )
+    .finish();
+    let count = match count.get_raw_values() {
+        ScalarArray::U32Vec(x) => x[0],
+        _ => unreachable!(),
+    };
+
+    let positions = match positions {
+        ArrowArrayGPU::UInt32ArrayGPU(x) => x.data,
+        _ => unreachable!(),
+    };
+    let indices = pipeline.apply_binary_function(
+        flags,
+        &positions,
+        count.max(1) as u64 * 4,
+        COMPACT_SHADER,
+        "compact_",
+        len.div_ceil(256),
+    );
+    Compaction {
+        indices: into_index_array(indices, count, gpu_device),
+        positions,
+        count,
+    }
+}
@@ -0,0 +1,368 @@
+use arrow_gpu::{gpu_utils::*, kernels::put_dyn};
+use wgpu::Buffer;
+
+use crate::{
+    arange_op,
+    array_routines::shape::{atleast_1d_shape, atleast_2d_shape},
+    normalize_axis,
+    utils::{into_index_array, Holder},
+    zeros, Dtype, IndexSlice, NdArray, NdArrayError, NdArrayResult,
+};
+
+const CONCATENATE_SHADER: &str = include_str!("../../compute_shaders/u32/concatenate_index.wgsl");
+
+/// Nested list of arrays used to assemble an array with [`block`]
+#[derive(Debug)]
+pub enum Block<'a> {
+    Array(&'a NdArray),
+    List(Vec<Block<'a>>),
+}
+
+fn concatenate_index_op(
+    block_in: u32,
+    block_out: u32,
+    offset: u32,
+    count: u32,
+    pipeline: &mut ArrowComputePipeline,
+) -> Buffer {
+    let block_buffer = pipeline
+        .device
+        .create_gpu_buffer_with_data(&[block_in, block_out, offset]);
+
+    pipeline.apply_unary_function(
+        &block_buffer,
+        count as u64 * 4,
+        CONCATENATE_SHADER,
+        "concatenate_index",
+        count.div_ceil(256),
+    )
+}
+
+/// Concatenates arrays along an axis, treating each array as if it had the matching shape
+/// in `shapes`. The shapes must have the same number of elements as the arrays.
+pub(crate) fn concatenate_shapes(
+    arrays: &[&NdArray],
+    shapes: &[Vec<u32>],
+    axis: usize,
+) -> NdArrayResult<NdArray> {
+    let first_shape = &shapes[0];
+    for shape in &shapes[1..] {
+        if shape.len() != first_shape.len() {
+            return Err(NdArrayError::ConcatenateError(format!(
+                "all the input arrays must have same number of dimensions, got shapes {:?} and {:?}",
+                first_shape, shape
+            )));
+        }
+        let mismatch = shape
+            .iter()
+            .zip(first_shape)
+            .enumerate()
+            .any(|(i, (x, y))| i != axis && x != y);
+        if mismatch {
+            return Err(NdArrayError::ConcatenateError(format!(
+                "all the input array dimensions except for the concatenation axis must match exactly, got shapes {:?} and {:?}",
+                first_shape, shape
+            )));
+        }
+    }
+
+    let dtypes = arrays.iter().map(|x| x.dtype).collect::<Vec<_>>();
+    let dtype = Dtype::result_type(&dtypes).unwrap();
+    let gpu_device = arrays[0].get_gpu_device();
+
+    let mut new_shape = first_shape.clone();
+    new_shape[axis] = shapes.iter().map(|x| x[axis]).sum();
+    if new_shape.contains(&0) {
+        return Ok(NdArray::zero_sized(new_shape, dtype, gpu_device));
+    }
+
+    let inner_size = new_shape[axis + 1..].iter().product::<u32>();
+    let block_out = new_shape[axis] * inner_size;
+
+    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("concatenate"));
+    let mut index_buffers = Vec::with_capacity(arrays.len());
+    let mut offset = 0;
+    for (array, shape) in arrays.iter().zip(shapes) {
+        let block_in = shape[axis] * inner_size;
+        let count = array.len();
+        if count > 0 {
+            let index_slice = IndexSlice {
+                start: 0,
+                stop: count,
+                step: 1,
+            };
+            let src_indexes = arange_op(&index_slice, &mut pipeline);
+            let dst_indexes =
+                concatenate_index_op(block_in, block_out, offset, count, &mut pipeline);
+            index_buffers.push((*array, src_indexes, dst_indexes, count));
+        }
+        offset += block_in;
+    }
+    pipeline.finish();
+
+    let mut new_array = zeros(new_shape, Some(dtype), Some(gpu_device.clone()));
+    for (array, src_indexes, dst_indexes, count) in index_buffers {
+        let array = if array.dtype != dtype {
+            Holder::Owned(array.astype(dtype))
+        } else {
+            Holder::Borrowed(array)
+        };
+        put_dyn(
+            &array.as_ref().data,
+            &into_index_array(src_indexes, count, gpu_device.clone()),
+            &mut new_array.data,
+            &into_index_array(dst_indexes, count, gpu_device.clone()),
+        );
+    }
+
+    Ok(new_array)
+}
+
+fn validate_not_empty(arrays: &[&NdArray]) -> NdArrayResult<()> {
+    if arrays.is_empty() {
+        Err(NdArrayError::ConcatenateError(
+            "need at least one array to concatenate".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Joins arrays along an existing axis, arrays are flattened if axis is None
+pub fn concatenate(arrays: &[&NdArray], axis: Option<i32>) -> NdArrayResult<NdArray> {
+    validate_not_empty(arrays)?;
+
+    match axis {
+        None => {
+            let shapes = arrays.iter().map(|x| vec![x.len()]).collect::<Vec<_>>();
+            concatenate_shapes(arrays, &shapes, 0)
+        }
+        Some(axis) => {
+            if arrays.iter().any(|x| x.shape.is_empty()) {
+                return Err(NdArrayError::ConcatenateError(
+                    "zero-dimensional arrays cannot be concatenated".to_string(),
+                ));
+            }
+            let axis = normalize_axis(axis, arrays[0].shape.len())?;
+            let shapes = arrays.iter().map(|x| x.shape.clone()).collect::<Vec<_>>();
+            concatenate_shapes(arrays, &shapes, axis)
+        }
+    }
+}
+
+/// Joins arrays of the same shape along a new axis
+pub fn stack(arrays: &[&NdArray], axis: i32) -> NdArrayResult<NdArray> {
+    validate_not_empty(arrays)?;
+
+    let first_shape = &arrays[0].shape;
+    if arrays.iter().any(|x| &x.shape != first_shape) {
+        return Err(NdArrayError::ConcatenateError(
+            "all input arrays must have the same shape".to_string(),
+        ));
+    }
+
+    let axis = normalize_axis(axis, first_shape.len() + 1)?;
+    let mut new_shape = first_shape.clone();
+    new_shape.insert(axis, 1);
+    let shapes = vec![new_shape; arrays.len()];
+    concatenate_shapes(arrays, &shapes, axis)
+}
+
+/// Stacks arrays column wise, 1-D arrays are joined along their only axis
+pub fn hstack(arrays: &[&NdArray]) -> NdArrayResult<NdArray> {
+    validate_not_empty(arrays)?;
+
+    let shapes = arrays
+        .iter()
+        .map(|x| atleast_1d_shape(&x.shape))
+        .collect::<Vec<_>>();
+    let axis = if shapes[0].len() == 1 { 0 } else { 1 };
+    concatenate_shapes(arrays, &shapes, axis)
+}
+
+/// Stacks arrays row wise, 1-D arrays of shape `(N,)` are treated as `(1, N)`
+pub fn vstack(arrays: &[&NdArray]) -> NdArrayResult<NdArray> {
+    validate_not_empty(arrays)?;
+
+    let shapes = arrays
+        .iter()
+        .map(|x| atleast_2d_shape(&x.shape))
+        .collect::<Vec<_>>();
+    concatenate_shapes(arrays, &shapes, 0)
+}
+
+/// Stacks 1-D arrays as columns of a 2-D array, other arrays are stacked like [`hstack`]
+pub fn column_stack(arrays: &[&NdArray]) -> NdArrayResult<NdArray> {
+    validate_not_empty(arrays)?;
+
+    let shapes = arrays
+        .iter()
+        .map(|x| match x.shape.len() {
+            0 => vec![1, 1],
+            1 => vec![x.shape[0], 1],
+            _ => x.shape.clone(),
+        })
+        .collect::<Vec<_>>();
+    concatenate_shapes(arrays, &shapes, 1)
+}
+
+/// Appends values to the end of an array, both are flattened if axis is None
+pub fn append(arr: &NdArray, values: &NdArray, axis: Option<i32>) -> NdArrayResult<NdArray> {
+    concatenate(&[arr, values], axis)
+}
+
+/// Returns the depth of the nested lists and the largest number of dimensions of the arrays
+fn block_depth(block: &Block) -> NdArrayResult<(usize, usize)> {
+    match block {
+        Block::Array(x) => Ok((0, x.shape.len())),
+        Block::List(blocks) => {
+            if blocks.is_empty() {
+                return Err(NdArrayError::ConcatenateError(
+                    "lists cannot be empty".to_string(),
+                ));
+            }
+
+            let (list_depth, mut ndim) = block_depth(&blocks[0])?;
+            for block in &blocks[1..] {
+                let (depth, block_ndim) = block_depth(block)?;
+                if depth != list_depth {
+                    return Err(NdArrayError::ConcatenateError(
+                        "list depths are mismatched".to_string(),
+                    ));
+                }
+                ndim = ndim.max(block_ndim);
+            }
+
+            Ok((list_depth + 1, ndim))
+        }
+    }
+}
+
+fn block_concatenate<'a>(
+    block: &'a Block<'a>,
+    list_depth: usize,
+    ndim: usize,
+) -> NdArrayResult<(Holder<'a, NdArray>, Vec<u32>)> {
+    match block {
+        Block::Array(x) => {
+            let mut shape = vec![1; ndim - x.shape.len()];
+            shape.extend(&x.shape);
+            Ok((Holder::Borrowed(*x), shape))
+        }
+        Block::List(blocks) => {
+            let children = blocks
+                .iter()
+                .map(|x| block_concatenate(x, list_depth - 1, ndim))
+                .collect::<NdArrayResult<Vec<_>>>()?;
+            let arrays = children.iter().map(|x| x.0.as_ref()).collect::<Vec<_>>();
+            let shapes = children.iter().map(|x| x.1.clone()).collect::<Vec<_>>();
+            let result = concatenate_shapes(&arrays, &shapes, ndim - list_depth)?;
+            let shape = result.shape.clone();
+            Ok((Holder::Owned(result), shape))
+        }
+    }
+}
+
+/// Assembles an array from nested lists of blocks. The innermost lists are joined along
+/// the last axis, the next ones along the second last axis and so on.
+pub fn block(blocks: &Block) -> NdArrayResult<NdArray> {
+    let (list_depth, ndim) = block_depth(blocks)?;
+    let ndim = ndim.max(list_depth);
+
+    match block_concatenate(blocks, list_depth, ndim)? {
+        (Holder::Owned(x), _) => Ok(x),
+        (Holder::Borrowed(x), _) => Ok(x.clone_array()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_concatenate() {
+        let input_1 =
+            NdArray::from_slice([1.0f32, 2.0, 3.0, 4.0].as_ref().into(), vec![2, 2], None);
+        let input_2 = NdArray::from_slice([5.0f32, 6.0].as_ref().into(), vec![1, 2], None);
+
+        let result = concatenate(&[&input_1, &input_2], Some(0)).unwrap();
+        assert_eq!(&result.shape, &[3, 2]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0].into()
+        );
+
+        let input_2 = NdArray::from_slice([5.0f32, 6.0].as_ref().into(), vec![2, 1], None);
+        let result = concatenate(&[&input_1, &input_2], Some(-1)).unwrap();
+        assert_eq!(&result.shape, &[2, 3]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![1.0f32, 2.0, 5.0, 3.0, 4.0, 6.0].into()
+        );
+
+        let result = concatenate(&[&input_1, &input_2], None).unwrap();
+        assert_eq!(&result.shape, &[6]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0].into()
+        );
+
+        assert!(concatenate(&[&input_1, &input_2], Some(0)).is_err());
+        assert!(concatenate(&[&input_1, &input_2], Some(2)).is_err());
+    }
+
+    #[test]
+    fn test_concatenate_promotes_dtype() {
+        let input_1 = NdArray::from_slice([1u8, 2].as_ref().into(), vec![2], None);
+        let input_2 = NdArray::from_slice([-3i8].as_ref().into(), vec![1], None);
+
+        let result = concatenate(&[&input_1, &input_2], Some(0)).unwrap();
+        assert_eq!(result.dtype, Dtype::Int16);
+        assert_eq!(result.data.get_raw_values(), vec![1i16, 2, -3].into());
+    }
+
+    #[test]
+    fn test_stack() {
+        let input_1 = NdArray::from_slice([1i32, 2, 3].as_ref().into(), vec![3], None);
+        let input_2 = NdArray::from_slice([4i32, 5, 6].as_ref().into(), vec![3], None);
+
+        let result = stack(&[&input_1, &input_2], 0).unwrap();
+        assert_eq!(&result.shape, &[2, 3]);
+        assert_eq!(result.data.get_raw_values(), vec![1, 2, 3, 4, 5, 6].into());
+
+        let result = stack(&[&input_1, &input_2], -1).unwrap();
+        assert_eq!(&result.shape, &[3, 2]);
+        assert_eq!(result.data.get_raw_values(), vec![1, 4, 2, 5, 3, 6].into());
+
+        let result = column_stack(&[&input_1, &input_2]).unwrap();
+        assert_eq!(&result.shape, &[3, 2]);
+        assert_eq!(result.data.get_raw_values(), vec![1, 4, 2, 5, 3, 6].into());
+
+        let result = hstack(&[&input_1, &input_2]).unwrap();
+        assert_eq!(&result.shape, &[6]);
+
+        let result = vstack(&[&input_1, &input_2]).unwrap();
+        assert_eq!(&result.shape, &[2, 3]);
+    }
+
+    #[test]
+    fn test_block() {
+        let a = NdArray::from_slice([1i32, 2, 3, 4].as_ref().into(), vec![2, 2], None);
+        let b = NdArray::from_slice([5i32, 6].as_ref().into(), vec![2, 1], None);
+        let c = NdArray::from_slice([7i32, 8, 9].as_ref().into(), vec![3], None);
+
+        let blocks = Block::List(vec![
+            Block::List(vec![Block::Array(&a), Block::Array(&b)]),
+            Block::List(vec![Block::Array(&c)]),
+        ]);
+        let result = block(&blocks).unwrap();
+        assert_eq!(&result.shape, &[3, 3]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![1, 2, 5, 3, 4, 6, 7, 8, 9].into()
+        );
+
+        let blocks = Block::List(vec![Block::Array(&a), Block::List(vec![])]);
+        assert!(block(&blocks).is_err());
+    }
+}
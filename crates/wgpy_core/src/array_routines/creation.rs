@@ -0,0 +1,610 @@
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{broadcast_op_dyn, Float32ArrayGPU},
+    gpu_utils::*,
+    kernels::{lt_op_dyn, put_dyn, take_op_dyn},
+};
+use wgpu::Buffer;
+
+use crate::{
+    arange, arange_op, broadcast_to, stack, utils::into_index_array, where_, zeros, Dtype,
+    IndexSlice, NdArray, NdArrayError, NdArrayResult, ScalarValue, GPU_DEVICE,
+};
+
+const SPACE_SHADER: &str = include_str!("../../compute_shaders/f32/space.wgsl");
+const TRI_SHADER: &str = include_str!("../../compute_shaders/u32/tri.wgsl");
+
+/// Order of the output axes of [`meshgrid`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Indexing {
+    /// Cartesian indexing, the first two axes are swapped
+    #[default]
+    Xy,
+    /// Matrix indexing
+    Ij,
+}
+
+/// Parameters of the spacing kernels, the element at `i` is
+/// `scale * base ^ (start + i * step)` for logspace and `start + i * step` for linspace
+#[derive(Debug, Clone, Copy)]
+struct SpaceParams {
+    start: f32,
+    step: f32,
+    last: f32,
+    endpoint: bool,
+    base: f32,
+    scale: f32,
+}
+
+impl SpaceParams {
+    fn into_words(self) -> [u32; 6] {
+        [
+            self.start.to_bits(),
+            self.step.to_bits(),
+            self.last.to_bits(),
+            self.endpoint as u32,
+            self.base.to_bits(),
+            self.scale.to_bits(),
+        ]
+    }
+}
+
+fn space(
+    entry_point: &str,
+    params: SpaceParams,
+    num: u32,
+    dtype: Option<Dtype>,
+    gpu_device: Option<Arc<GpuDevice>>,
+) -> NdArray {
+    let gpu_device = gpu_device.unwrap_or(GPU_DEVICE.clone());
+    let dtype = dtype.unwrap_or(Dtype::Float32);
+    if num == 0 {
+        return NdArray::zero_sized(vec![0], dtype, gpu_device);
+    }
+
+    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some(entry_point));
+    let params_buffer = pipeline
+        .device
+        .create_gpu_buffer_with_data(&params.into_words());
+    let data = pipeline.apply_unary_function(
+        &params_buffer,
+        num as u64 * 4,
+        SPACE_SHADER,
+        entry_point,
+        num.div_ceil(256),
+    );
+    pipeline.finish();
+
+    let data = Float32ArrayGPU {
+        data: Arc::new(data),
+        gpu_device,
+        phantom: std::marker::PhantomData,
+        len: num as usize,
+        null_buffer: None,
+    };
+    let array = NdArray {
+        shape: vec![num],
+        dims: 1,
+        data: data.into(),
+        dtype: Dtype::Float32,
+    };
+
+    if dtype == Dtype::Float32 {
+        array
+    } else {
+        array.astype(dtype)
+    }
+}
+
+/// Number of intervals between `num` evenly spaced samples
+fn space_intervals(num: u32, endpoint: bool) -> u32 {
+    if endpoint {
+        num.saturating_sub(1)
+    } else {
+        num
+    }
+}
+
+/// Evenly spaced numbers over an interval, along with the spacing between
+/// them. The step is NaN when there are no intervals.
+pub fn linspace_retstep(
+    start: f32,
+    stop: f32,
+    num: u32,
+    endpoint: bool,
+    dtype: Option<Dtype>,
+    gpu_device: Option<Arc<GpuDevice>>,
+) -> (NdArray, f32) {
+    let intervals = space_intervals(num, endpoint);
+    let step = if intervals > 0 {
+        ((stop as f64 - start as f64) / intervals as f64) as f32
+    } else {
+        f32::NAN
+    };
+
+    let params = SpaceParams {
+        start,
+        step: if intervals > 0 { step } else { 0.0 },
+        last: stop,
+        endpoint: endpoint && num > 1,
+        base: 1.0,
+        scale: 1.0,
+    };
+    (space("linspace", params, num, dtype, gpu_device), step)
+}
+
+/// Evenly spaced numbers over an interval
+pub fn linspace(
+    start: f32,
+    stop: f32,
+    num: u32,
+    endpoint: bool,
+    dtype: Option<Dtype>,
+    gpu_device: Option<Arc<GpuDevice>>,
+) -> NdArray {
+    linspace_retstep(start, stop, num, endpoint, dtype, gpu_device).0
+}
+
+/// Numbers spaced evenly on a log scale, from `base ^ start` to `base ^ stop`
+pub fn logspace(
+    start: f32,
+    stop: f32,
+    num: u32,
+    endpoint: bool,
+    base: f32,
+    dtype: Option<Dtype>,
+    gpu_device: Option<Arc<GpuDevice>>,
+) -> NdArray {
+    let intervals = space_intervals(num, endpoint);
+    let step = if intervals > 0 {
+        (stop as f64 - start as f64) / intervals as f64
+    } else {
+        0.0
+    };
+
+    let params = SpaceParams {
+        start,
+        step: step as f32,
+        last: (base as f64).powf(stop as f64) as f32,
+        endpoint: endpoint && num > 1,
+        base,
+        scale: 1.0,
+    };
+    space("logspace", params, num, dtype, gpu_device)
+}
+
+/// Numbers spaced evenly on a log scale, with the endpoints given directly
+pub fn geomspace(
+    start: f32,
+    stop: f32,
+    num: u32,
+    endpoint: bool,
+    dtype: Option<Dtype>,
+    gpu_device: Option<Arc<GpuDevice>>,
+) -> NdArrayResult<NdArray> {
+    if start == 0.0 || stop == 0.0 {
+        return Err(NdArrayError::ValueError(
+            "geometric sequence cannot include zero".to_string(),
+        ));
+    }
+    if start.is_sign_negative() != stop.is_sign_negative() {
+        return Err(NdArrayError::ValueError(
+            "geometric sequence cannot change sign".to_string(),
+        ));
+    }
+
+    let intervals = space_intervals(num, endpoint);
+    // Each element is start * ratio ^ i, so the first one is exact
+    let ratio = if intervals > 0 {
+        (stop as f64 / start as f64).powf(1.0 / intervals as f64)
+    } else {
+        1.0
+    };
+
+    let params = SpaceParams {
+        start: 0.0,
+        step: 1.0,
+        last: stop,
+        endpoint: endpoint && num > 1,
+        base: ratio as f32,
+        scale: start,
+    };
+    Ok(space("logspace", params, num, dtype, gpu_device))
+}
+
+fn tri_op(
+    entry_point: &str,
+    rows: u32,
+    cols: u32,
+    k: i32,
+    pipeline: &mut ArrowComputePipeline,
+) -> Buffer {
+    let params_buffer = pipeline
+        .device
+        .create_gpu_buffer_with_data(&[cols, k as u32]);
+    let count = rows * cols;
+
+    pipeline.apply_unary_function(
+        &params_buffer,
+        count as u64 * 4,
+        TRI_SHADER,
+        entry_point,
+        count.div_ceil(256),
+    )
+}
+
+fn tri_array(
+    entry_point: &str,
+    n: u32,
+    m: Option<u32>,
+    k: i32,
+    dtype: Option<Dtype>,
+    gpu_device: Option<Arc<GpuDevice>>,
+) -> NdArray {
+    let gpu_device = gpu_device.unwrap_or(GPU_DEVICE.clone());
+    let dtype = dtype.unwrap_or(Dtype::Float32);
+    let m = m.unwrap_or(n);
+    if n == 0 || m == 0 {
+        return NdArray::zero_sized(vec![n, m], dtype, gpu_device);
+    }
+
+    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some(entry_point));
+    let data = tri_op(entry_point, n, m, k, &mut pipeline);
+    pipeline.finish();
+
+    let array = NdArray {
+        shape: vec![n, m],
+        dims: 2,
+        data: into_index_array(data, n * m, gpu_device).into(),
+        dtype: Dtype::UInt32,
+    };
+
+    if dtype == Dtype::UInt32 {
+        array
+    } else {
+        array.astype(dtype)
+    }
+}
+
+/// 2-D array with ones on the k-th diagonal and zeros elsewhere
+pub fn eye(
+    n: u32,
+    m: Option<u32>,
+    k: i32,
+    dtype: Option<Dtype>,
+    gpu_device: Option<Arc<GpuDevice>>,
+) -> NdArray {
+    tri_array("eye", n, m, k, dtype, gpu_device)
+}
+
+/// Square identity array
+pub fn identity(n: u32, dtype: Option<Dtype>, gpu_device: Option<Arc<GpuDevice>>) -> NdArray {
+    eye(n, None, 0, dtype, gpu_device)
+}
+
+/// 2-D array with ones at and below the k-th diagonal and zeros elsewhere
+pub fn tri(
+    n: u32,
+    m: Option<u32>,
+    k: i32,
+    dtype: Option<Dtype>,
+    gpu_device: Option<Arc<GpuDevice>>,
+) -> NdArray {
+    tri_array("tri", n, m, k, dtype, gpu_device)
+}
+
+/// Boolean mask which is true at and below the k-th diagonal when `lower` is
+/// set and strictly above it otherwise
+fn tri_mask(rows: u32, cols: u32, k: i32, lower: bool, gpu_device: Arc<GpuDevice>) -> NdArray {
+    let count = rows * cols;
+    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("tri_mask"));
+    let k = if lower { k } else { k - 1 };
+    let values = into_index_array(
+        tri_op("tri", rows, cols, k, &mut pipeline),
+        count,
+        gpu_device,
+    );
+    let values = values.into();
+    let mask = if lower {
+        let zero = broadcast_op_dyn(ScalarValue::U32(0).into(), count as usize, &mut pipeline);
+        lt_op_dyn(&zero, &values, &mut pipeline)
+    } else {
+        let one = broadcast_op_dyn(ScalarValue::U32(1).into(), count as usize, &mut pipeline);
+        lt_op_dyn(&values, &one, &mut pipeline)
+    };
+    pipeline.finish();
+
+    NdArray {
+        shape: vec![rows, cols],
+        dims: 2,
+        data: mask.into(),
+        dtype: Dtype::Bool,
+    }
+}
+
+fn triangle(m: &NdArray, k: i32, lower: bool) -> NdArrayResult<NdArray> {
+    let (rows, cols, shape) = match m.shape.as_slice() {
+        [] => {
+            return Err(NdArrayError::ShapeError(
+                "input must be at least 1-d".to_string(),
+            ))
+        }
+        [n] => (*n, *n, vec![*n, *n]),
+        [.., rows, cols] => (*rows, *cols, m.shape.clone()),
+    };
+    let gpu_device = m.get_gpu_device();
+    if shape.contains(&0) {
+        return Ok(NdArray::zero_sized(shape, m.dtype, gpu_device));
+    }
+
+    let mask = tri_mask(rows, cols, k, lower, gpu_device.clone());
+    let zero = zeros(vec![], Some(m.dtype), Some(gpu_device));
+    Ok(where_(&mask, m, &zero))
+}
+
+/// Lower triangle of an array, elements above the k-th diagonal are zeroed.
+/// 1-D inputs are treated as rows of a square matrix.
+pub fn tril(m: &NdArray, k: i32) -> NdArrayResult<NdArray> {
+    triangle(m, k, true)
+}
+
+/// Upper triangle of an array, elements below the k-th diagonal are zeroed.
+/// 1-D inputs are treated as rows of a square matrix.
+pub fn triu(m: &NdArray, k: i32) -> NdArrayResult<NdArray> {
+    triangle(m, k, false)
+}
+
+/// Extracts the k-th diagonal of a 2-D array, or builds a 2-D array with a
+/// 1-D array on the k-th diagonal
+pub fn diag(v: &NdArray, k: i32) -> NdArrayResult<NdArray> {
+    let gpu_device = v.get_gpu_device();
+    let offset = k.unsigned_abs();
+    match v.shape.as_slice() {
+        [len] => {
+            let len = *len;
+            let n = len + offset;
+            let mut new_array = zeros(vec![n, n], Some(v.dtype), Some(gpu_device.clone()));
+            if len == 0 {
+                return Ok(new_array);
+            }
+
+            let start = if k >= 0 { offset } else { offset * n };
+            let dst_slice = IndexSlice {
+                start,
+                stop: start + len * (n + 1),
+                step: (n + 1) as i32,
+            };
+            let src_slice = IndexSlice {
+                start: 0,
+                stop: len,
+                step: 1,
+            };
+            let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("diag"));
+            let dst_indexes = arange_op(&dst_slice, &mut pipeline);
+            let src_indexes = arange_op(&src_slice, &mut pipeline);
+            pipeline.finish();
+
+            put_dyn(
+                &v.data,
+                &into_index_array(src_indexes, len, gpu_device.clone()),
+                &mut new_array.data,
+                &into_index_array(dst_indexes, len, gpu_device),
+            );
+            Ok(new_array)
+        }
+        [rows, cols] => {
+            let (start, count) = if k >= 0 {
+                (offset, (*rows).min(cols.saturating_sub(offset)))
+            } else {
+                (offset * cols, rows.saturating_sub(offset).min(*cols))
+            };
+            if count == 0 {
+                return Ok(NdArray::zero_sized(vec![0], v.dtype, gpu_device));
+            }
+
+            let slice = IndexSlice {
+                start,
+                stop: start + count * (cols + 1),
+                step: (cols + 1) as i32,
+            };
+            let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("diag"));
+            let indexes = into_index_array(arange_op(&slice, &mut pipeline), count, gpu_device);
+            let data = take_op_dyn(&v.data, &indexes, &mut pipeline);
+            pipeline.finish();
+
+            Ok(NdArray {
+                shape: vec![count],
+                dims: 1,
+                data,
+                dtype: v.dtype,
+            })
+        }
+        _ => Err(NdArrayError::ShapeError(
+            "input must be 1-d or 2-d".to_string(),
+        )),
+    }
+}
+
+/// Coordinate arrays from 1-D coordinate vectors, inputs of other shapes are
+/// flattened. Sparse grids keep a length of one along every other axis.
+pub fn meshgrid(xi: &[&NdArray], indexing: Indexing, sparse: bool) -> Vec<NdArray> {
+    let ndim = xi.len();
+    let mut shape = xi.iter().map(|x| x.len()).collect::<Vec<_>>();
+    let mut axes = (0..ndim).collect::<Vec<_>>();
+    if indexing == Indexing::Xy && ndim > 1 {
+        shape.swap(0, 1);
+        axes.swap(0, 1);
+    }
+
+    xi.iter()
+        .zip(axes)
+        .map(|(x, axis)| {
+            let mut x_shape = vec![1; ndim];
+            x_shape[axis] = x.len();
+            let x = x.with_shape(x_shape);
+            if sparse {
+                x
+            } else {
+                broadcast_to(&x, &shape)
+            }
+        })
+        .collect()
+}
+
+/// Array of shape `[dimensions.len(), ...dimensions]` holding the index of
+/// every element of a grid along each axis
+pub fn indices(
+    dimensions: &[u32],
+    dtype: Option<Dtype>,
+    gpu_device: Option<Arc<GpuDevice>>,
+) -> NdArray {
+    let gpu_device = gpu_device.unwrap_or(GPU_DEVICE.clone());
+    let dtype = dtype.unwrap_or(Dtype::Int32);
+    let mut shape = vec![dimensions.len() as u32];
+    shape.extend_from_slice(dimensions);
+    if shape.contains(&0) {
+        return NdArray::zero_sized(shape, dtype, gpu_device);
+    }
+
+    let ranges = dimensions
+        .iter()
+        .map(|x| {
+            arange(
+                None,
+                (*x).into(),
+                None,
+                Some(dtype),
+                Some(gpu_device.clone()),
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let ranges = ranges.iter().collect::<Vec<_>>();
+    let grids = meshgrid(&ranges, Indexing::Ij, false);
+    let grids = grids.iter().collect::<Vec<_>>();
+    stack(&grids, 0).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_linspace() {
+        let (result, step) = linspace_retstep(0.0, 1.0, 5, true, None, None);
+        assert_eq!(step, 0.25);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![0.0f32, 0.25, 0.5, 0.75, 1.0].into()
+        );
+
+        let result = linspace(0.0, 1.0, 4, false, None, None);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![0.0f32, 0.25, 0.5, 0.75].into()
+        );
+
+        let (result, step) = linspace_retstep(2.0, 3.0, 1, true, None, None);
+        assert!(step.is_nan());
+        assert_eq!(result.data.get_raw_values(), vec![2.0f32].into());
+        assert_eq!(&linspace(0.0, 1.0, 0, true, None, None).shape, &[0]);
+    }
+
+    #[test]
+    fn test_geomspace() {
+        let result = geomspace(1.0, 1000.0, 4, true, None, None).unwrap();
+        let values = result.data.get_raw_values();
+        let expected = [1.0f32, 10.0, 100.0, 1000.0];
+        match values {
+            arrow_gpu::utils::ScalarArray::F32Vec(values) => {
+                for (x, y) in values.iter().zip(expected) {
+                    assert!((x - y).abs() / y < 1e-5);
+                }
+            }
+            _ => unreachable!(),
+        }
+        assert!(geomspace(0.0, 1.0, 4, true, None, None).is_err());
+        assert!(geomspace(-1.0, 1.0, 4, true, None, None).is_err());
+    }
+
+    #[test]
+    fn test_eye_tri() {
+        let result = eye(2, Some(3), 1, Some(Dtype::UInt32), None);
+        assert_eq!(&result.shape, &[2, 3]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![0u32, 1, 0, 0, 0, 1].into()
+        );
+
+        let result = tri(3, None, -1, Some(Dtype::UInt32), None);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![0u32, 0, 0, 1, 0, 0, 1, 1, 0].into()
+        );
+    }
+
+    #[test]
+    fn test_tril_triu() {
+        let values = (1..7).collect::<Vec<i32>>();
+        let array = NdArray::from_slice(values.as_slice().into(), vec![2, 3], None);
+
+        let result = tril(&array, 0).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![1, 0, 0, 4, 5, 0].into());
+        let result = triu(&array, 1).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![0, 2, 3, 0, 0, 6].into());
+    }
+
+    #[test]
+    fn test_diag() {
+        let values = (1..4).collect::<Vec<i32>>();
+        let array = NdArray::from_slice(values.as_slice().into(), vec![3], None);
+
+        let result = diag(&array, -1).unwrap();
+        assert_eq!(&result.shape, &[4, 4]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![0, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 0, 3, 0].into()
+        );
+
+        let values = (0..6).collect::<Vec<i32>>();
+        let array = NdArray::from_slice(values.as_slice().into(), vec![2, 3], None);
+        assert_eq!(
+            diag(&array, 0).unwrap().data.get_raw_values(),
+            vec![0, 4].into()
+        );
+        assert_eq!(
+            diag(&array, 2).unwrap().data.get_raw_values(),
+            vec![2].into()
+        );
+        assert_eq!(&diag(&array, 3).unwrap().shape, &[0]);
+    }
+
+    #[test]
+    fn test_meshgrid_indices() {
+        let x = NdArray::from_slice([1, 2, 3].as_slice().into(), vec![3], None);
+        let y = NdArray::from_slice([4, 5].as_slice().into(), vec![2], None);
+
+        let result = meshgrid(&[&x, &y], Indexing::Xy, false);
+        assert_eq!(&result[0].shape, &[2, 3]);
+        assert_eq!(
+            result[0].data.get_raw_values(),
+            vec![1, 2, 3, 1, 2, 3].into()
+        );
+        assert_eq!(
+            result[1].data.get_raw_values(),
+            vec![4, 4, 4, 5, 5, 5].into()
+        );
+
+        let result = meshgrid(&[&x, &y], Indexing::Ij, true);
+        assert_eq!(&result[0].shape, &[3, 1]);
+        assert_eq!(&result[1].shape, &[1, 2]);
+
+        let result = indices(&[2, 2], None, None);
+        assert_eq!(&result.shape, &[2, 2, 2]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![0, 0, 1, 1, 0, 1, 0, 1].into()
+        );
+    }
+}
@@ -1,74 +1,22 @@
-use arrow_gpu::{array::UInt32ArrayGPU, kernels::put_dyn};
+use crate::{
+    array_routines::{concatenate::concatenate_shapes, shape::atleast_3d_shape},
+    NdArray, NdArrayError, NdArrayResult,
+};
 
-use crate::{zeros, NdArray};
-
-fn recast_shape(shape: &Vec<u32>) -> Vec<u32> {
-    if shape.len() == 2 {
-        let mut new_shape = shape.clone();
-        new_shape.push(1);
-        new_shape
-    } else {
-        shape.clone()
-    }
-}
-
-fn validate_and_genereate_new_shapes(tup: &[&NdArray]) -> Vec<Vec<u32>> {
-    let mut new_shapes = vec![];
-    new_shapes.push(recast_shape(&tup[0].shape));
-
-    for i in 1..tup.len() {
-        if tup[0].shape != tup[i].shape {
-            panic!(
-                "Cant dstack shapes {:?} and {:?}",
-                tup[0].shape, tup[i].shape
-            )
-        }
-        if tup[0].dtype != tup[i].dtype {
-            panic!(
-                "Cant dstack dtypes {:?} and {:?}",
-                tup[0].dtype, tup[i].dtype
-            )
-        }
-        new_shapes.push(recast_shape(&tup[i].shape));
+/// Stacks arrays along the third axis, 1-D arrays of shape `(N,)` are treated as `(1, N, 1)`
+/// and 2-D arrays of shape `(M, N)` as `(M, N, 1)`
+pub fn dstack(tup: &[&NdArray]) -> NdArrayResult<NdArray> {
+    if tup.is_empty() {
+        return Err(NdArrayError::ConcatenateError(
+            "need at least one array to concatenate".to_string(),
+        ));
     }
 
-    new_shapes
-}
-
-// TODO broadcasting of shapes and validating
-pub fn dstack(tup: &[&NdArray]) -> NdArray {
-    if tup.len() == 1 {
-        tup[0].clone_array()
-    } else {
-        let new_shapes = validate_and_genereate_new_shapes(tup);
-        let mut new_shape = new_shapes[0].clone();
-        let shape_len = new_shape.len();
-
-        let last_dimension_size = new_shapes.iter().map(|x| x[x.len() - 1]).sum();
-        new_shape[shape_len - 1] = last_dimension_size;
-        let device = tup[0].data.get_gpu_device().clone();
-        let mut new_array = zeros(new_shape, Some(tup[0].dtype), Some(device.clone()));
-
-        let mut last_dimension = 0;
-        for array in tup {
-            let count = array.shape.iter().product();
-            let src_indexes = (0..count).collect::<Vec<u32>>();
-            let dst_indexes = (0..count)
-                .map(|x| last_dimension + (x * last_dimension_size))
-                .collect::<Vec<u32>>();
-            let src_indexes_gpu = UInt32ArrayGPU::from_slice(&src_indexes, device.clone());
-            let dst_indexes_gpu = UInt32ArrayGPU::from_slice(&dst_indexes, device.clone());
-            put_dyn(
-                &array.data,
-                &src_indexes_gpu,
-                &mut new_array.data,
-                &dst_indexes_gpu,
-            );
-            last_dimension += array.shape[array.shape.len() - 1];
-        }
-
-        new_array
-    }
+    let shapes = tup
+        .iter()
+        .map(|x| atleast_3d_shape(&x.shape))
+        .collect::<Vec<_>>();
+    concatenate_shapes(tup, &shapes, 2)
 }
 
 #[cfg(test)]
@@ -81,7 +29,7 @@ mod tests {
             NdArray::from_slice([1.0f32, 2.0, 3.0, 4.0].as_ref().into(), vec![2, 2, 1], None);
         let input_2 =
             NdArray::from_slice([5.0f32, 6.0, 7.0, 8.0].as_ref().into(), vec![2, 2, 1], None);
-        let new_gpu_array = dstack(&[&input_1, &input_2]);
+        let new_gpu_array = dstack(&[&input_1, &input_2]).unwrap();
         assert_eq!(
             new_gpu_array.data.get_raw_values(),
             vec![1.0f32, 5.0, 2.0, 6.0, 3.0, 7.0, 4.0, 8.0].into()
@@ -99,10 +47,25 @@ mod tests {
             vec![2, 2, 1],
             None,
         );
-        let new_gpu_array = dstack(&[&input_1, &input_2, &input_3]);
+        let new_gpu_array = dstack(&[&input_1, &input_2, &input_3]).unwrap();
         assert_eq!(
             new_gpu_array.data.get_raw_values(),
             vec![1.0f32, 5.0, 9.0, 2.0, 6.0, 10.0, 3.0, 7.0, 11.0, 4.0, 8.0, 12.0].into()
         );
     }
+
+    #[test]
+    fn test_dstack_1d() {
+        let input_1 = NdArray::from_slice([1i32, 2, 3].as_ref().into(), vec![3], None);
+        let input_2 = NdArray::from_slice([4i32, 5, 6].as_ref().into(), vec![3], None);
+        let new_gpu_array = dstack(&[&input_1, &input_2]).unwrap();
+        assert_eq!(&new_gpu_array.shape, &[1, 3, 2]);
+        assert_eq!(
+            new_gpu_array.data.get_raw_values(),
+            vec![1, 4, 2, 5, 3, 6].into()
+        );
+
+        let input_2 = NdArray::from_slice([4i32, 5].as_ref().into(), vec![2], None);
+        assert!(dstack(&[&input_1, &input_2]).is_err());
+    }
 }
@@ -0,0 +1,129 @@
+use crate::{
+    array_routines::layout::{gather, GatherAxis, GatherMode},
+    normalize_axis, NdArray, NdArrayError, NdArrayResult,
+};
+
+/// Reverses the order of elements along the given axes, all axes are reversed if
+/// axis is None
+pub fn flip(arr: &NdArray, axis: Option<&[i32]>) -> NdArrayResult<NdArray> {
+    let ndim = arr.shape.len();
+    let mut flipped = vec![false; ndim];
+    match axis {
+        None => flipped.iter_mut().for_each(|x| *x = true),
+        Some(axis) => {
+            for x in axis {
+                flipped[normalize_axis(*x, ndim)?] = true;
+            }
+        }
+    }
+
+    let axes = flipped
+        .iter()
+        .enumerate()
+        .map(|(i, flip)| {
+            let mode = if *flip {
+                GatherMode::Flip
+            } else {
+                GatherMode::Wrap(0)
+            };
+            GatherAxis::new(arr.shape[i], i, mode)
+        })
+        .collect::<Vec<_>>();
+
+    gather(arr, &arr.shape, &axes, None)
+}
+
+/// Reverses the order of elements along axis 1
+pub fn fliplr(arr: &NdArray) -> NdArrayResult<NdArray> {
+    if arr.shape.len() < 2 {
+        return Err(NdArrayError::ShapeError("input must be >= 2-d".to_string()));
+    }
+    flip(arr, Some(&[1]))
+}
+
+/// Reverses the order of elements along axis 0
+pub fn flipud(arr: &NdArray) -> NdArrayResult<NdArray> {
+    if arr.shape.is_empty() {
+        return Err(NdArrayError::ShapeError("input must be >= 1-d".to_string()));
+    }
+    flip(arr, Some(&[0]))
+}
+
+/// Rotates an array by 90 degrees `k` times in the plane given by axes, rotating from
+/// the first axis towards the second
+pub fn rot90(arr: &NdArray, k: i32, axes: (i32, i32)) -> NdArrayResult<NdArray> {
+    let ndim = arr.shape.len();
+    if ndim < 2 {
+        return Err(NdArrayError::ShapeError(
+            "rot90 requires an array of at least 2 dimensions".to_string(),
+        ));
+    }
+    let axis_0 = normalize_axis(axes.0, ndim)?;
+    let axis_1 = normalize_axis(axes.1, ndim)?;
+    if axis_0 == axis_1 {
+        return Err(NdArrayError::AxisError(
+            "axes must be different".to_string(),
+        ));
+    }
+
+    let mut gather_axes = (0..ndim)
+        .map(|i| GatherAxis::identity(&arr.shape, i))
+        .collect::<Vec<_>>();
+    match k.rem_euclid(4) {
+        0 => return Ok(arr.clone_array()),
+        1 => {
+            gather_axes[axis_0] = GatherAxis::new(arr.shape[axis_1], axis_1, GatherMode::Flip);
+            gather_axes[axis_1] = GatherAxis::identity(&arr.shape, axis_0);
+        }
+        2 => {
+            gather_axes[axis_0].mode = GatherMode::Flip;
+            gather_axes[axis_1].mode = GatherMode::Flip;
+        }
+        _ => {
+            gather_axes[axis_0] = GatherAxis::identity(&arr.shape, axis_1);
+            gather_axes[axis_1] = GatherAxis::new(arr.shape[axis_0], axis_0, GatherMode::Flip);
+        }
+    }
+
+    gather(arr, &arr.shape, &gather_axes, None)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_flip() {
+        let values = (0..6).collect::<Vec<i32>>();
+        let input = NdArray::from_slice(values.as_slice().into(), vec![2, 3], None);
+
+        let result = flip(&input, None).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![5, 4, 3, 2, 1, 0].into());
+
+        let result = fliplr(&input).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![2, 1, 0, 5, 4, 3].into());
+
+        let result = flipud(&input).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![3, 4, 5, 0, 1, 2].into());
+
+        assert!(flip(&input, Some(&[2])).is_err());
+    }
+
+    #[test]
+    fn test_rot90() {
+        let values = (0..6).collect::<Vec<i32>>();
+        let input = NdArray::from_slice(values.as_slice().into(), vec![2, 3], None);
+
+        let result = rot90(&input, 1, (0, 1)).unwrap();
+        assert_eq!(&result.shape, &[3, 2]);
+        assert_eq!(result.data.get_raw_values(), vec![2, 5, 1, 4, 0, 3].into());
+
+        let result = rot90(&input, 2, (0, 1)).unwrap();
+        assert_eq!(&result.shape, &[2, 3]);
+        assert_eq!(result.data.get_raw_values(), vec![5, 4, 3, 2, 1, 0].into());
+
+        let result = rot90(&input, -1, (0, 1)).unwrap();
+        assert_eq!(&result.shape, &[3, 2]);
+        assert_eq!(result.data.get_raw_values(), vec![3, 0, 4, 1, 5, 2].into());
+    }
+}
@@ -0,0 +1,569 @@
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{ArrowType, Float32ArrayGPU, Int32ArrayGPU},
+    gpu_utils::{ArrowComputePipeline, GpuDevice},
+};
+use wgpu::Buffer;
+
+use crate::{
+    array_routines::search::{check_1d, f32_from_key, i32_from_key, key_stats_op},
+    cast_if_needed, concatenate, full, key_type, linspace, raw_buffer, stack,
+    utils::read_u32_buffer,
+    Dtype, NdArray, NdArrayError, NdArrayResult, ScalarValue,
+};
+
+const HISTOGRAM_SHADER: &str = include_str!("../../compute_shaders/f32/histogram.wgsl");
+const HISTOGRAM_VALUES_SHADER: &str =
+    include_str!("../../compute_shaders/f32/histogram_values.wgsl");
+const BIN_COUNT_SHADER: &str = include_str!("../../compute_shaders/u32/bin_count.wgsl");
+
+/// How `histogram_values_` turns the sums of the bins into the output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BinValues {
+    /// Bits of the sums, u32 counts or f32 weights
+    Bits = 0,
+    /// Sums converted to f32
+    Value = 1,
+    Density = 2,
+}
+
+/// Bins of one dimension of a histogram
+#[derive(Debug, Clone, Copy)]
+pub enum HistogramBins<'a> {
+    /// Number of equal width bins over the range of the data
+    Count(u32),
+    /// Monotonically increasing bin edges, including the rightmost edge
+    Edges(&'a NdArray),
+}
+
+fn into_array(
+    buffer: Buffer,
+    shape: Vec<u32>,
+    dtype: Dtype,
+    gpu_device: Arc<GpuDevice>,
+) -> NdArray {
+    let len = shape.iter().product::<u32>() as usize;
+    let data = match dtype {
+        Dtype::Int32 => Int32ArrayGPU {
+            data: Arc::new(buffer),
+            gpu_device,
+            phantom: std::marker::PhantomData,
+            len,
+            null_buffer: None,
+        }
+        .into(),
+        _ => Float32ArrayGPU {
+            data: Arc::new(buffer),
+            gpu_device,
+            phantom: std::marker::PhantomData,
+            len,
+            null_buffer: None,
+        }
+        .into(),
+    };
+    NdArray {
+        dims: shape.len() as u16,
+        shape,
+        data,
+        dtype,
+    }
+}
+
+/// Accumulates the elements into `bins` bins, `indices` holds the bin of every element.
+/// The sums of the bins are followed by their total if `total`.
+fn bin_count_op(
+    indices: &Buffer,
+    weights: Option<&Buffer>,
+    [len, bins]: [u32; 2],
+    total: bool,
+    pipeline: &mut ArrowComputePipeline,
+) -> Buffer {
+    let params = [len, bins, u32::from(weights.is_some()), u32::from(total)];
+    let params = pipeline.device.create_gpu_buffer_with_data(&params);
+    pipeline.apply_ternary_function(
+        indices,
+        weights.unwrap_or(indices),
+        &params,
+        (bins as u64 + 1) * 4,
+        BIN_COUNT_SHADER,
+        "bin_count_",
+        len.div_ceil(256),
+    )
+}
+
+/// Runs `histogram_values_` on the sums of the bins of every dimension
+fn bin_values_op(
+    sums: &Buffer,
+    edges: &Buffer,
+    bins: &[u32],
+    weighted: bool,
+    values: BinValues,
+    pipeline: &mut ArrowComputePipeline,
+) -> Buffer {
+    let mut params = vec![u32::from(weighted), values as u32, bins.len() as u32];
+    params.extend(bins);
+    let params = pipeline.device.create_gpu_buffer_with_data(&params);
+    let len = bins.iter().product::<u32>();
+    pipeline.apply_ternary_function(
+        sums,
+        edges,
+        &params,
+        len as u64 * 4,
+        HISTOGRAM_VALUES_SHADER,
+        "histogram_values_",
+        len.div_ceil(256),
+    )
+}
+
+/// Counts the occurrences of every non negative integer of the 1-D array, or sums their
+/// weights. The result has at least `minlength` bins and is int32, or f32 if weighted.
+pub fn bincount(x: &NdArray, weights: Option<&NdArray>, minlength: u32) -> NdArrayResult<NdArray> {
+    check_1d(x)?;
+    if x.dtype == Dtype::Float32 {
+        return Err(NdArrayError::DtypeError(
+            "Cannot cast array data from dtype('float32') to dtype('int64') according to the \
+             rule 'safe'"
+                .to_string(),
+        ));
+    }
+    if weights.is_some_and(|w| w.len() != x.len()) {
+        return Err(NdArrayError::ValueError(
+            "The weights and list don't have the same length.".to_string(),
+        ));
+    }
+    let dtype = match weights {
+        Some(_) => Dtype::Float32,
+        None => Dtype::Int32,
+    };
+    let gpu_device = x.get_gpu_device();
+    if x.is_empty() {
+        let zero = ScalarValue::F32(0.0).into();
+        return full(vec![minlength], zero, Some(dtype), Some(gpu_device));
+    }
+
+    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("bincount"));
+    let (arrow_type, key_type) = key_type(Dtype::Int32);
+    let mut casted = None;
+    let data = cast_if_needed(&x.data, &arrow_type, &mut casted, &mut pipeline);
+    let range = key_stats_op(data, key_type, [x.len(), 1, 0], "key_range_", &mut pipeline);
+    std::mem::replace(
+        &mut pipeline,
+        ArrowComputePipeline::new(gpu_device.clone(), Some("bincount")),
+    )
+    .finish();
+    let range = read_u32_buffer(&range, &gpu_device);
+    let (min, max) = (i32_from_key(!range[0]), i32_from_key(range[1]));
+    if min < 0 {
+        return Err(NdArrayError::ValueError(
+            "'list' argument must have no negative elements".to_string(),
+        ));
+    }
+
+    let bins = (max as u32 + 1).max(minlength);
+    let mut casted_weights = None;
+    let weights = weights.map(|w| {
+        cast_if_needed(
+            &w.data,
+            &ArrowType::Float32Type,
+            &mut casted_weights,
+            &mut pipeline,
+        )
+    });
+    let weights = weights.map(raw_buffer);
+    let sums = bin_count_op(
+        raw_buffer(data),
+        weights,
+        [x.len(), bins],
+        false,
+        &mut pipeline,
+    );
+    let values = bin_values_op(
+        &sums,
+        &sums,
+        &[bins],
+        weights.is_some(),
+        BinValues::Bits,
+        &mut pipeline,
+    );
+    pipeline.finish();
+    Ok(into_array(values, vec![bins], dtype, gpu_device))
+}
+
+/// Outer edges of bins over the data, numpy widens empty ranges by 0.5 on both sides
+fn outer_edges(
+    range: Option<(f32, f32)>,
+    data_range: Option<(f32, f32)>,
+) -> NdArrayResult<(f32, f32)> {
+    let (first, last) = match (range, data_range) {
+        (Some((first, last)), _) => {
+            if first > last {
+                return Err(NdArrayError::ValueError(
+                    "max must be larger than min in range parameter.".to_string(),
+                ));
+            }
+            if !first.is_finite() || !last.is_finite() {
+                return Err(NdArrayError::ValueError(format!(
+                    "supplied range of [{}, {}] is not finite",
+                    first, last
+                )));
+            }
+            (first, last)
+        }
+        (None, None) => (0.0, 1.0),
+        (None, Some((first, last))) => {
+            if !first.is_finite() || !last.is_finite() {
+                return Err(NdArrayError::ValueError(format!(
+                    "autodetected range of [{}, {}] is not finite",
+                    first, last
+                )));
+            }
+            (first, last)
+        }
+    };
+    if first == last {
+        Ok((first - 0.5, last + 0.5))
+    } else {
+        Ok((first, last))
+    }
+}
+
+/// Histogram of `len` samples of `dims` coordinates, stored row major in `sample`.
+/// Returns the flat histogram and the edges of every dimension.
+fn histogram_op(
+    sample: &NdArray,
+    [len, dims]: [u32; 2],
+    bins: &[HistogramBins],
+    range: Option<&[(f32, f32)]>,
+    weights: Option<&NdArray>,
+    density: bool,
+    float_counts: bool,
+) -> NdArrayResult<(NdArray, Vec<NdArray>)> {
+    if bins.len() != dims as usize {
+        return Err(NdArrayError::ValueError(
+            "The dimension of bins must be equal to the dimension of the sample x.".to_string(),
+        ));
+    }
+    if range.is_some_and(|x| x.len() != dims as usize) {
+        return Err(NdArrayError::ValueError(
+            "range argument must have one entry per dimension".to_string(),
+        ));
+    }
+    if weights.is_some_and(|w| w.len() != len) {
+        return Err(NdArrayError::ValueError(
+            "weights should have the same shape as a.".to_string(),
+        ));
+    }
+    for bin in bins {
+        match bin {
+            HistogramBins::Count(0) => {
+                return Err(NdArrayError::ValueError(
+                    "`bins` must be positive, when an integer".to_string(),
+                ))
+            }
+            HistogramBins::Edges(edges) if edges.shape.len() != 1 || edges.len() < 2 => {
+                return Err(NdArrayError::ValueError(
+                    "`bins` must be 1d with at least two edges, when an array".to_string(),
+                ))
+            }
+            _ => (),
+        }
+    }
+
+    // The range of the data and the monotonicity of the edges are read back first
+    let gpu_device = sample.get_gpu_device();
+    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("histogram"));
+    let mut casted = None;
+    let data = cast_if_needed(
+        &sample.data,
+        &ArrowType::Float32Type,
+        &mut casted,
+        &mut pipeline,
+    );
+    let (_, f32_key) = key_type(Dtype::Float32);
+    let mut kernel_edges = bins
+        .iter()
+        .map(|bin| match bin {
+            HistogramBins::Count(_) => None,
+            HistogramBins::Edges(edges) => Some(edges.astype(Dtype::Float32)),
+        })
+        .collect::<Vec<_>>();
+    let stats = bins
+        .iter()
+        .zip(&kernel_edges)
+        .enumerate()
+        .map(|(d, (bin, edges))| match (bin, edges) {
+            (_, Some(edges)) => {
+                let stats = [edges.len(), 1, 0];
+                let data = &edges.data;
+                Some(key_stats_op(
+                    data,
+                    f32_key,
+                    stats,
+                    "monotonicity_",
+                    &mut pipeline,
+                ))
+            }
+            _ if range.is_some() || len == 0 => None,
+            _ => {
+                let stats = [len, dims, d as u32];
+                Some(key_stats_op(
+                    data,
+                    f32_key,
+                    stats,
+                    "key_range_",
+                    &mut pipeline,
+                ))
+            }
+        })
+        .collect::<Vec<_>>();
+    std::mem::replace(
+        &mut pipeline,
+        ArrowComputePipeline::new(gpu_device.clone(), Some("histogram")),
+    )
+    .finish();
+
+    let mut bin_edges = vec![];
+    for (d, (bin, stats)) in bins.iter().zip(stats).enumerate() {
+        let stats = stats.map(|x| read_u32_buffer(&x, &gpu_device));
+        match bin {
+            HistogramBins::Count(count) => {
+                let data_range = stats.map(|x| (f32_from_key(!x[0]), f32_from_key(x[1])));
+                let (first, last) = outer_edges(range.map(|x| x[d]), data_range)?;
+                let edges = linspace(
+                    first,
+                    last,
+                    count + 1,
+                    true,
+                    Some(Dtype::Float32),
+                    Some(gpu_device.clone()),
+                );
+                kernel_edges[d] = Some(edges.clone_array());
+                bin_edges.push(edges);
+            }
+            HistogramBins::Edges(edges) => {
+                if stats.unwrap()[0] > 0 {
+                    return Err(NdArrayError::ValueError(
+                        "`bins` must increase monotonically, when an array".to_string(),
+                    ));
+                }
+                bin_edges.push(edges.clone_array());
+            }
+        }
+    }
+    let counts = bins
+        .iter()
+        .zip(&bin_edges)
+        .map(|(_, x)| x.len() - 1)
+        .collect::<Vec<_>>();
+    let bins_len = counts.iter().product::<u32>();
+    let weighted = weights.is_some();
+    let values = match (density, weighted || !float_counts) {
+        (true, _) => BinValues::Density,
+        (false, true) => BinValues::Bits,
+        (false, false) => BinValues::Value,
+    };
+    let dtype = match (values, weighted) {
+        (BinValues::Bits, false) => Dtype::Int32,
+        _ => Dtype::Float32,
+    };
+    if len == 0 {
+        let fill = if density { f32::NAN } else { 0.0 };
+        let hist = full(
+            counts,
+            ScalarValue::F32(fill).into(),
+            Some(dtype),
+            Some(gpu_device),
+        )?;
+        return Ok((hist, bin_edges));
+    }
+
+    let kernel_edges = kernel_edges.into_iter().flatten().collect::<Vec<_>>();
+    let edges = concatenate(&kernel_edges.iter().collect::<Vec<_>>(), None)?;
+    let mut params = vec![len, dims];
+    params.extend(&counts);
+    let params = pipeline.device.create_gpu_buffer_with_data(&params);
+    let indices = pipeline.apply_ternary_function(
+        raw_buffer(data),
+        raw_buffer(&edges.data),
+        &params,
+        len as u64 * 4,
+        HISTOGRAM_SHADER,
+        "bin_indices_",
+        len.div_ceil(256),
+    );
+    let mut casted_weights = None;
+    let weights = weights.map(|w| {
+        cast_if_needed(
+            &w.data,
+            &ArrowType::Float32Type,
+            &mut casted_weights,
+            &mut pipeline,
+        )
+    });
+    let sums = bin_count_op(
+        &indices,
+        weights.map(raw_buffer),
+        [len, bins_len],
+        density,
+        &mut pipeline,
+    );
+    let hist = bin_values_op(
+        &sums,
+        raw_buffer(&edges.data),
+        &counts,
+        weighted,
+        values,
+        &mut pipeline,
+    );
+    pipeline.finish();
+    Ok((into_array(hist, counts, dtype, gpu_device), bin_edges))
+}
+
+/// Computes the histogram of the flattened array, returning the histogram and the bin
+/// edges. The histogram holds int32 counts, or f32 values if weighted or a `density`.
+/// The last bin includes its right edge.
+pub fn histogram(
+    a: &NdArray,
+    bins: HistogramBins,
+    range: Option<(f32, f32)>,
+    weights: Option<&NdArray>,
+    density: bool,
+) -> NdArrayResult<(NdArray, NdArray)> {
+    let range = range.map(|x| [x]);
+    let (hist, mut edges) = histogram_op(
+        a,
+        [a.len(), 1],
+        &[bins],
+        range.as_ref().map(|x| x.as_slice()),
+        weights,
+        density,
+        false,
+    )?;
+    Ok((hist, edges.pop().unwrap()))
+}
+
+/// Computes the multidimensional histogram of `(N, D)` samples, a 1-D sample has a
+/// single dimension. A single entry of `bins` applies to every dimension. The histogram
+/// is f32, like the float64 histogram of numpy.
+pub fn histogramdd(
+    sample: &NdArray,
+    bins: &[HistogramBins],
+    range: Option<&[(f32, f32)]>,
+    weights: Option<&NdArray>,
+    density: bool,
+) -> NdArrayResult<(NdArray, Vec<NdArray>)> {
+    let (len, dims) = match sample.shape.as_slice() {
+        [len] => (*len, 1),
+        [len, dims] => (*len, *dims),
+        _ => {
+            return Err(NdArrayError::ValueError(
+                "sample must be a 1-D or 2-D array".to_string(),
+            ))
+        }
+    };
+    let bins = match bins {
+        [bin] => vec![*bin; dims as usize],
+        _ => bins.to_vec(),
+    };
+    histogram_op(sample, [len, dims], &bins, range, weights, density, true)
+}
+
+/// Computes the two dimensional histogram of the samples `x` and `y`, returning the
+/// histogram and the bin edges along both dimensions. See [`histogramdd`].
+pub fn histogram2d(
+    x: &NdArray,
+    y: &NdArray,
+    bins: &[HistogramBins],
+    range: Option<&[(f32, f32)]>,
+    weights: Option<&NdArray>,
+    density: bool,
+) -> NdArrayResult<(NdArray, NdArray, NdArray)> {
+    let (x, y) = (x.astype(Dtype::Float32), y.astype(Dtype::Float32));
+    let sample = stack(&[&x.flatten(), &y.flatten()], 1)?;
+    let (hist, mut edges) = histogramdd(&sample, bins, range, weights, density)?;
+    let y_edges = edges.pop().unwrap();
+    Ok((hist, edges.pop().unwrap(), y_edges))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bincount() {
+        let x = NdArray::from_slice([0u8, 1, 1, 3, 2, 1, 7].as_ref().into(), vec![7], None);
+        let result = bincount(&x, None, 0).unwrap();
+        assert_eq!(result.dtype, Dtype::Int32);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![1i32, 3, 1, 1, 0, 0, 0, 1].into()
+        );
+
+        let weights = NdArray::from_slice(
+            [0.5f32, 1.0, 0.25, 2.0, 1.0, 0.5, 3.0].as_ref().into(),
+            vec![7],
+            None,
+        );
+        let result = bincount(&x, Some(&weights), 10).unwrap();
+        assert_eq!(result.dtype, Dtype::Float32);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![0.5f32, 1.75, 1.0, 2.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0].into()
+        );
+
+        let x = NdArray::from_slice([1i32, -1].as_ref().into(), vec![2], None);
+        assert!(bincount(&x, None, 0).is_err());
+    }
+
+    #[test]
+    fn test_histogram() {
+        let a = NdArray::from_slice(
+            [1.0f32, 2.0, 1.0, 4.0, f32::NAN, 3.5].as_ref().into(),
+            vec![6],
+            None,
+        );
+        let edges = NdArray::from_slice([0.0f32, 1.0, 2.0, 4.0].as_ref().into(), vec![4], None);
+        let (hist, _) = histogram(&a, HistogramBins::Edges(&edges), None, None, false).unwrap();
+        assert_eq!(hist.dtype, Dtype::Int32);
+        assert_eq!(hist.data.get_raw_values(), vec![0i32, 2, 3].into());
+
+        let (hist, _) = histogram(&a, HistogramBins::Edges(&edges), None, None, true).unwrap();
+        assert_eq!(hist.data.get_raw_values(), vec![0.0f32, 0.4, 0.3].into());
+
+        let a = NdArray::from_slice([1.0f32, 2.0, 1.0, 4.0].as_ref().into(), vec![4], None);
+        let (hist, edges) = histogram(&a, HistogramBins::Count(3), None, None, false).unwrap();
+        assert_eq!(hist.data.get_raw_values(), vec![2i32, 1, 1].into());
+        assert_eq!(
+            edges.data.get_raw_values(),
+            vec![1.0f32, 2.0, 3.0, 4.0].into()
+        );
+
+        let unsorted = NdArray::from_slice([0.0f32, 2.0, 1.0].as_ref().into(), vec![3], None);
+        assert!(histogram(&a, HistogramBins::Edges(&unsorted), None, None, false).is_err());
+    }
+
+    #[test]
+    fn test_histogram2d() {
+        let x = NdArray::from_slice([0.0f32, 0.5, 1.0, 2.0].as_ref().into(), vec![4], None);
+        let y = NdArray::from_slice([0i32, 1, 1, 2].as_ref().into(), vec![4], None);
+        let range = [(0.0, 2.0), (0.0, 2.0)];
+        let (hist, x_edges, y_edges) = histogram2d(
+            &x,
+            &y,
+            &[HistogramBins::Count(2)],
+            Some(&range),
+            None,
+            false,
+        )
+        .unwrap();
+        assert_eq!(hist.shape, vec![2, 2]);
+        assert_eq!(hist.dtype, Dtype::Float32);
+        assert_eq!(
+            hist.data.get_raw_values(),
+            vec![1.0f32, 1.0, 0.0, 2.0].into()
+        );
+        assert_eq!(x_edges.data.get_raw_values(), vec![0.0f32, 1.0, 2.0].into());
+        assert_eq!(y_edges.data.get_raw_values(), vec![0.0f32, 1.0, 2.0].into());
+    }
+}
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{broadcast_op_dyn, ArrowArrayGPU, UInt32ArrayGPU},
+    gpu_utils::*,
+    kernels::{lt_op_dyn, merge_op_dyn, min_op_dyn, take_op_dyn},
+};
+use wgpu::Buffer;
+
+use crate::{full, NdArray, NdArrayError, NdArrayResult, ScalarValue};
+
+const GATHER_SHADER: &str = include_str!("../../compute_shaders/u32/gather_index.wgsl");
+
+/// How an output coordinate along an axis maps to a coordinate of the input.
+/// Offsets are the number of padded elements before the input.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum GatherMode {
+    /// `(coord + offset) % in_dim`
+    Wrap(u32),
+    Flip,
+    Constant(u32),
+    Edge(u32),
+    Reflect(u32),
+    Symmetric(u32),
+}
+
+impl GatherMode {
+    fn into_params(self) -> [u32; 2] {
+        match self {
+            GatherMode::Wrap(x) => [0, x],
+            GatherMode::Flip => [1, 0],
+            GatherMode::Constant(x) => [2, x],
+            GatherMode::Edge(x) => [3, x],
+            GatherMode::Reflect(x) => [4, x],
+            GatherMode::Symmetric(x) => [5, x],
+        }
+    }
+}
+
+/// Output axis of a gather, reading from `in_axis` of the input
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GatherAxis {
+    pub out_dim: u32,
+    pub in_axis: usize,
+    pub mode: GatherMode,
+}
+
+impl GatherAxis {
+    pub fn new(out_dim: u32, in_axis: usize, mode: GatherMode) -> Self {
+        Self {
+            out_dim,
+            in_axis,
+            mode,
+        }
+    }
+
+    /// Output axis which copies the input axis as is
+    pub fn identity(in_shape: &[u32], in_axis: usize) -> Self {
+        Self::new(in_shape[in_axis], in_axis, GatherMode::Wrap(0))
+    }
+}
+
+/// Generates the input index of every output element, elements outside of the
+/// input are set to `u32::MAX`
+pub(crate) fn gather_index_op(
+    in_shape: &[u32],
+    axes: &[GatherAxis],
+    pipeline: &mut ArrowComputePipeline,
+) -> Buffer {
+    let mut in_strides = vec![1; in_shape.len()];
+    for i in (0..in_shape.len().saturating_sub(1)).rev() {
+        in_strides[i] = in_strides[i + 1] * in_shape[i + 1];
+    }
+
+    let mut params = vec![axes.len() as u32];
+    for axis in axes {
+        params.extend([
+            axis.out_dim,
+            in_shape[axis.in_axis],
+            in_strides[axis.in_axis],
+        ]);
+        params.extend(axis.mode.into_params());
+    }
+    let params_buffer = pipeline.device.create_gpu_buffer_with_data(&params);
+
+    let count = axes.iter().map(|x| x.out_dim).product::<u32>();
+    pipeline.apply_unary_function(
+        &params_buffer,
+        count as u64 * 4,
+        GATHER_SHADER,
+        "gather_index",
+        count.div_ceil(256),
+    )
+}
+
+/// Builds a new array by gathering elements of `arr` viewed with `in_shape`.
+/// Elements outside of the input are set to `fill`, which is only needed for
+/// [`GatherMode::Constant`].
+pub(crate) fn gather(
+    arr: &NdArray,
+    in_shape: &[u32],
+    axes: &[GatherAxis],
+    fill: Option<ScalarValue>,
+) -> NdArrayResult<NdArray> {
+    let shape = axes.iter().map(|x| x.out_dim).collect::<Vec<_>>();
+    let gpu_device = arr.get_gpu_device();
+    if shape.contains(&0) {
+        return Ok(NdArray::zero_sized(shape, arr.dtype, gpu_device));
+    }
+    if arr.is_empty() {
+        let fill = fill.ok_or_else(|| {
+            NdArrayError::ShapeError(
+                "cannot gather from an empty array without a fill value".to_string(),
+            )
+        })?;
+        return full(shape, fill.cast(&arr.dtype).into(), None, Some(gpu_device));
+    }
+
+    let count = shape.iter().product::<u32>() as usize;
+    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("gather"));
+    let indexes = UInt32ArrayGPU {
+        data: Arc::new(gather_index_op(in_shape, axes, &mut pipeline)),
+        gpu_device: gpu_device.clone(),
+        phantom: std::marker::PhantomData,
+        len: count,
+        null_buffer: None,
+    };
+
+    let data = match fill {
+        None => take_op_dyn(&arr.data, &indexes, &mut pipeline),
+        Some(fill) => {
+            let indexes = indexes.into();
+            let len = broadcast_op_dyn(ScalarValue::U32(arr.len()).into(), count, &mut pipeline);
+            let last =
+                broadcast_op_dyn(ScalarValue::U32(arr.len() - 1).into(), count, &mut pipeline);
+            let mask = lt_op_dyn(&indexes, &len, &mut pipeline);
+            let clamped = match min_op_dyn(&indexes, &last, &mut pipeline) {
+                ArrowArrayGPU::UInt32ArrayGPU(x) => x,
+                _ => unreachable!(),
+            };
+            let values = take_op_dyn(&arr.data, &clamped, &mut pipeline);
+            let fill = broadcast_op_dyn(fill.cast(&arr.dtype).into(), count, &mut pipeline);
+            merge_op_dyn(&values, &fill, &mask, &mut pipeline)
+        }
+    };
+
+    pipeline.finish();
+
+    Ok(NdArray {
+        dims: shape.len() as u16,
+        shape,
+        data,
+        dtype: arr.dtype,
+    })
+}
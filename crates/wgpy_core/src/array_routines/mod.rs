@@ -1,11 +1,38 @@
 pub mod arange;
 pub mod broadcast;
+pub(crate) mod compact;
+pub mod concatenate;
+pub mod creation;
 pub mod dstack;
+pub mod flip;
+pub mod histogram;
+pub(crate) mod layout;
+pub mod pad;
 pub mod repeat;
+pub mod roll;
+pub mod search;
+pub mod set_ops;
+pub mod shape;
+pub mod sort;
+pub mod split;
+pub mod tile;
 pub mod where_routine;
 
 pub use arange::*;
 pub use broadcast::*;
+pub(crate) use compact::*;
+pub use concatenate::*;
+pub use creation::*;
 pub use dstack::*;
+pub use flip::*;
+pub use histogram::*;
+pub use pad::*;
 pub use repeat::*;
+pub use roll::*;
+pub use search::*;
+pub use set_ops::*;
+pub use shape::*;
+pub use sort::*;
+pub use split::*;
+pub use tile::*;
 pub use where_routine::*;
@@ -0,0 +1,109 @@
+use crate::{
+    array_routines::layout::{gather, GatherAxis, GatherMode},
+    NdArray, NdArrayError, NdArrayResult, ScalarValue,
+};
+
+/// Values used to fill the padded area in [`pad`]
+#[derive(Debug, Clone, Copy)]
+pub enum PadMode {
+    /// Pads with a constant value
+    Constant(ScalarValue),
+    /// Pads with the edge values of the array
+    Edge,
+    /// Pads with the reflection of the array mirrored on the first and last values
+    Reflect,
+    /// Pads with the reflection of the array mirrored along the edge
+    Symmetric,
+    /// Pads with the wrap of the array, the end values pad the beginning and the
+    /// beginning values pad the end
+    Wrap,
+}
+
+/// Pads an array with `(before, after)` elements along each axis. A single pad width
+/// is used for all the axes.
+pub fn pad(arr: &NdArray, pad_width: &[(u32, u32)], mode: PadMode) -> NdArrayResult<NdArray> {
+    let ndim = arr.shape.len();
+    if pad_width.len() != ndim && pad_width.len() != 1 {
+        return Err(NdArrayError::BroadcastError(format!(
+            "pad width of length {} cannot be broadcast to array of dimension {}",
+            pad_width.len(),
+            ndim
+        )));
+    }
+
+    let mut axes = Vec::with_capacity(ndim);
+    for (i, dim) in arr.shape.iter().enumerate() {
+        let (before, after) = pad_width[i % pad_width.len()];
+        if *dim == 0 && (before > 0 || after > 0) && !matches!(mode, PadMode::Constant(_)) {
+            return Err(NdArrayError::ShapeError(format!(
+                "can't extend empty axis {} using modes other than 'constant'",
+                i
+            )));
+        }
+
+        let mode = match mode {
+            PadMode::Constant(_) => GatherMode::Constant(before),
+            PadMode::Edge => GatherMode::Edge(before),
+            PadMode::Reflect => GatherMode::Reflect(before),
+            PadMode::Symmetric => GatherMode::Symmetric(before),
+            PadMode::Wrap if *dim == 0 => GatherMode::Wrap(0),
+            PadMode::Wrap => GatherMode::Wrap((dim - before % dim) % dim),
+        };
+        axes.push(GatherAxis::new(dim + before + after, i, mode));
+    }
+
+    let fill = match mode {
+        PadMode::Constant(x) => Some(x),
+        _ => None,
+    };
+
+    gather(arr, &arr.shape, &axes, fill)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn pad_values(mode: PadMode, pad_width: (u32, u32)) -> Vec<i32> {
+        let input = NdArray::from_slice([1i32, 2, 3].as_ref().into(), vec![3], None);
+        let result = pad(&input, &[pad_width], mode).unwrap();
+        match result.data.get_raw_values() {
+            crate::ScalarArray::I32Vec(x) => x,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_pad_1d() {
+        assert_eq!(
+            pad_values(PadMode::Constant(ScalarValue::I32(9)), (2, 1)),
+            vec![9, 9, 1, 2, 3, 9]
+        );
+        assert_eq!(pad_values(PadMode::Edge, (2, 1)), vec![1, 1, 1, 2, 3, 3]);
+        assert_eq!(
+            pad_values(PadMode::Reflect, (3, 3)),
+            vec![2, 3, 2, 1, 2, 3, 2, 1, 2]
+        );
+        assert_eq!(
+            pad_values(PadMode::Symmetric, (3, 3)),
+            vec![3, 2, 1, 1, 2, 3, 3, 2, 1]
+        );
+        assert_eq!(
+            pad_values(PadMode::Wrap, (4, 2)),
+            vec![3, 1, 2, 3, 1, 2, 3, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_pad_2d() {
+        let input = NdArray::from_slice([1.0f32, 2.0, 3.0, 4.0].as_ref().into(), vec![2, 2], None);
+        let result = pad(&input, &[(1, 0), (0, 1)], PadMode::Constant(0.5f32.into())).unwrap();
+        assert_eq!(&result.shape, &[3, 3]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![0.5f32, 0.5, 0.5, 1.0, 2.0, 0.5, 3.0, 4.0, 0.5].into()
+        );
+
+        assert!(pad(&input, &[(1, 0), (0, 1), (1, 1)], PadMode::Edge).is_err());
+    }
+}
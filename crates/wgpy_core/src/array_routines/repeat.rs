@@ -29,11 +29,12 @@ fn generate_repeat_indexes(
 ) {
     if depth as usize == shape.len() - 1 {
         for i in 0..shape[shape.len() - 1] {
-            indexes.push(*base_index + i);
-            if axis == depth {
-                (0..(repeats[i as usize % repeats.len()] - 1))
-                    .for_each(|_| indexes.push(*base_index + i));
-            }
+            let count = if axis == depth {
+                repeats[i as usize % repeats.len()]
+            } else {
+                1
+            };
+            (0..count).for_each(|_| indexes.push(*base_index + i));
         }
         *base_index += shape[shape.len() - 1];
     } else {
@@ -42,17 +43,36 @@ fn generate_repeat_indexes(
             generate_repeat_indexes(shape, repeats, axis, base_index, depth + 1, indexes);
             let new_count = indexes.len();
             if axis == depth {
-                (0..(repeats[i as usize % repeats.len()] - 1))
-                    .for_each(|_| indexes.extend_from_within(old_count..new_count));
+                match repeats[i as usize % repeats.len()] {
+                    0 => indexes.truncate(old_count),
+                    count => (0..(count - 1))
+                        .for_each(|_| indexes.extend_from_within(old_count..new_count)),
+                }
             }
         }
     }
 }
 
+fn take_repeat_indexes(arr: &NdArray, indexes: &[u32], shape: Vec<u32>) -> NdArray {
+    if indexes.is_empty() {
+        return NdArray::zero_sized(shape, arr.dtype, arr.get_gpu_device());
+    }
+
+    let indexes = UInt32ArrayGPU::from_slice(indexes, arr.data.get_gpu_device());
+    let dims = shape.len() as u16;
+    let data = take_dyn(&arr.data, &indexes);
+
+    NdArray {
+        shape,
+        dims,
+        data,
+        dtype: arr.dtype,
+    }
+}
+
 /// Broadcast an array to a new shape
 pub fn repeat(arr: &NdArray, repeats: &[u32], axis: Option<u32>) -> NdArrayResult<NdArray> {
     let array_count = arr.shape.iter().product();
-    let dtype = arr.data.get_dtype().into();
     match (repeats.len() as u32, axis) {
         (x, None) if (x != 1) && (x != array_count) => Err(NdArrayError::RepeatError(format!(
             "repeat count {} is not equal to array of count {}",
@@ -76,17 +96,8 @@ pub fn repeat(arr: &NdArray, repeats: &[u32], axis: Option<u32>) -> NdArrayResul
                     indexes.push(i);
                 }
             }
-            let indexes = UInt32ArrayGPU::from_slice(&indexes, arr.data.get_gpu_device());
-            let dims = 1u16;
             let shape = vec![final_length];
-            let data = take_dyn(&arr.data, &indexes);
-
-            Ok(NdArray {
-                shape,
-                dims,
-                data,
-                dtype,
-            })
+            Ok(take_repeat_indexes(arr, &indexes, shape))
         }
         (_, None) => {
             let final_length = repeats.iter().sum();
@@ -97,32 +108,14 @@ pub fn repeat(arr: &NdArray, repeats: &[u32], axis: Option<u32>) -> NdArrayResul
                     indexes.push(idx as u32)
                 }
             });
-            let indexes = UInt32ArrayGPU::from_slice(&indexes, arr.data.get_gpu_device());
-            let dims = 1u16;
             let shape = vec![final_length];
-            let data = take_dyn(&arr.data, &indexes);
-
-            Ok(NdArray {
-                shape,
-                dims,
-                data,
-                dtype,
-            })
+            Ok(take_repeat_indexes(arr, &indexes, shape))
         }
         (_, Some(y)) => {
             let mut indexes = vec![];
             generate_repeat_indexes(&arr.shape, repeats, y, &mut 0, 0, &mut indexes);
-            let indexes = UInt32ArrayGPU::from_slice(&indexes, arr.data.get_gpu_device());
-            let dims = 1u16;
             let shape = generate_repeat_shape(&arr.shape, repeats, y);
-            let data = take_dyn(&arr.data, &indexes);
-
-            Ok(NdArray {
-                shape,
-                dims,
-                data,
-                dtype,
-            })
+            Ok(take_repeat_indexes(arr, &indexes, shape))
         }
     }
 }
@@ -168,6 +161,14 @@ mod test {
         result_indexes = vec![];
         generate_repeat_indexes(&[3, 1], &[2, 3, 1], 0, &mut 0, 0, &mut result_indexes);
         assert_eq!(&result_indexes, &[0, 0, 1, 1, 1, 2]);
+
+        result_indexes = vec![];
+        generate_repeat_indexes(&[3, 1], &[2, 0, 1], 0, &mut 0, 0, &mut result_indexes);
+        assert_eq!(&result_indexes, &[0, 0, 2]);
+
+        result_indexes = vec![];
+        generate_repeat_indexes(&[1, 3], &[1, 0, 2], 1, &mut 0, 0, &mut result_indexes);
+        assert_eq!(&result_indexes, &[0, 2, 2]);
     }
 
     #[test]
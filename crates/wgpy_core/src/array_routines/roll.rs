@@ -0,0 +1,78 @@
+use crate::{
+    array_routines::layout::{gather, GatherAxis, GatherMode},
+    normalize_axis, NdArray, NdArrayError, NdArrayResult,
+};
+
+fn roll_mode(shift: i64, dim: u32) -> GatherMode {
+    if dim == 0 {
+        GatherMode::Wrap(0)
+    } else {
+        GatherMode::Wrap((-shift).rem_euclid(dim as i64) as u32)
+    }
+}
+
+/// Rolls elements along the given axes, elements shifted beyond the last position are
+/// re-introduced at the first. The array is flattened before rolling if axis is None.
+/// Shifts and axes are broadcast against each other.
+pub fn roll(arr: &NdArray, shift: &[i32], axis: Option<&[i32]>) -> NdArrayResult<NdArray> {
+    match axis {
+        None => {
+            let len = arr.len();
+            let shift = shift.iter().map(|x| *x as i64).sum();
+            let axes = [GatherAxis::new(len, 0, roll_mode(shift, len))];
+            let mut result = gather(arr, &[len], &axes, None)?;
+            result.dims = arr.dims;
+            result.shape = arr.shape.clone();
+            Ok(result)
+        }
+        Some(axis) => {
+            let ndim = arr.shape.len();
+            let count = shift.len().max(axis.len());
+            if (shift.len() != count && shift.len() != 1)
+                || (axis.len() != count && axis.len() != 1)
+            {
+                return Err(NdArrayError::BroadcastError(format!(
+                    "shift of length {} cannot be broadcast to axis of length {}",
+                    shift.len(),
+                    axis.len()
+                )));
+            }
+
+            let mut shifts = vec![0i64; ndim];
+            for i in 0..count {
+                let x = normalize_axis(axis[i % axis.len()], ndim)?;
+                shifts[x] += shift[i % shift.len()] as i64;
+            }
+
+            let axes = shifts
+                .iter()
+                .enumerate()
+                .map(|(i, shift)| GatherAxis::new(arr.shape[i], i, roll_mode(*shift, arr.shape[i])))
+                .collect::<Vec<_>>();
+            gather(arr, &arr.shape, &axes, None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_roll() {
+        let values = (0..6).collect::<Vec<i32>>();
+        let input = NdArray::from_slice(values.as_slice().into(), vec![2, 3], None);
+
+        let result = roll(&input, &[2], None).unwrap();
+        assert_eq!(&result.shape, &[2, 3]);
+        assert_eq!(result.data.get_raw_values(), vec![4, 5, 0, 1, 2, 3].into());
+
+        let result = roll(&input, &[-1], Some(&[1])).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![1, 2, 0, 4, 5, 3].into());
+
+        let result = roll(&input, &[1, 1], Some(&[0, 1])).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![5, 3, 4, 2, 0, 1].into());
+
+        assert!(roll(&input, &[1, 2, 3], Some(&[0, 1])).is_err());
+    }
+}
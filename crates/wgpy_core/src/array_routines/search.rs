@@ -0,0 +1,228 @@
+use arrow_gpu::{array::ArrowArrayGPU, gpu_utils::ArrowComputePipeline};
+use wgpu::Buffer;
+
+use crate::{
+    cast_if_needed, flip, full, key_type, raw_buffer,
+    utils::{into_index_array, into_int32_array, read_u32_buffer},
+    Dtype, NdArray, NdArrayError, NdArrayResult, RadixSort, ScalarValue, SortLayout,
+};
+
+pub(crate) const SEARCH_SHADER: &str = concat!(
+    include_str!("../../compute_shaders/u32/sort.wgsl"),
+    include_str!("../../compute_shaders/u32/search.wgsl")
+);
+const KEY_STATS_SHADER: &str = concat!(
+    include_str!("../../compute_shaders/u32/sort.wgsl"),
+    include_str!("../../compute_shaders/u32/key_stats.wgsl")
+);
+
+/// Which of the positions of equal elements [`searchsorted`] returns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Side {
+    /// First suitable position
+    #[default]
+    Left,
+    /// Last suitable position
+    Right,
+}
+
+/// Runs a `key_stats` kernel over `len` keys read every `stride` elements from
+/// `offset`, the data must already have the kernel type of the keys. The two u32
+/// results can be read with [`read_u32_buffer`] once the pipeline is finished.
+pub(crate) fn key_stats_op(
+    data: &ArrowArrayGPU,
+    key_type: u32,
+    [len, stride, offset]: [u32; 3],
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> Buffer {
+    let params = [len, len, stride, key_type, 0, 0, offset];
+    let params = pipeline.device.create_gpu_buffer_with_data(&params);
+    pipeline.apply_binary_function(
+        raw_buffer(data),
+        &params,
+        8,
+        KEY_STATS_SHADER,
+        entry_point,
+        len.div_ceil(256),
+    )
+}
+
+/// Value of an f32 key of the sort shaders
+pub(crate) fn f32_from_key(key: u32) -> f32 {
+    if key & 0x8000_0000 != 0 {
+        f32::from_bits(key & 0x7fff_ffff)
+    } else {
+        f32::from_bits(!key)
+    }
+}
+
+/// Value of an i32 key of the sort shaders
+pub(crate) fn i32_from_key(key: u32) -> i32 {
+    (key ^ 0x8000_0000) as i32
+}
+
+pub(crate) fn check_1d(a: &NdArray) -> NdArrayResult<()> {
+    match a.shape.len() {
+        1 => Ok(()),
+        0 => Err(NdArrayError::ValueError(
+            "object of too small depth for desired array".to_string(),
+        )),
+        _ => Err(NdArrayError::ValueError(
+            "object too deep for desired array".to_string(),
+        )),
+    }
+}
+
+/// Positions of the values in the sorted 1-D array, counted from the end with `reverse`
+fn search_sorted_op(
+    sorted: &NdArray,
+    values: &NdArray,
+    side: Side,
+    reverse: bool,
+) -> NdArrayResult<NdArray> {
+    let gpu_device = values.get_gpu_device();
+    if values.is_empty() {
+        return Ok(NdArray::zero_sized(
+            values.shape.clone(),
+            Dtype::Int32,
+            gpu_device,
+        ));
+    }
+    if sorted.is_empty() {
+        let zero = ScalarValue::I32(0).into();
+        return full(values.shape.clone(), zero, None, Some(gpu_device));
+    }
+
+    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("searchsorted"));
+    let (arrow_type, key_type) = key_type(sorted.dtype.promote(values.dtype));
+    let (mut casted_1, mut casted_2) = (None, None);
+    let sorted_data = cast_if_needed(&sorted.data, &arrow_type, &mut casted_1, &mut pipeline);
+    let values_data = cast_if_needed(&values.data, &arrow_type, &mut casted_2, &mut pipeline);
+
+    let (sorted_len, len) = (sorted.len(), values.len());
+    let layout = SortLayout::flat(sorted_len);
+    let mut radix_sort = RadixSort::new(&layout, &mut pipeline);
+    let params = radix_sort.params(key_type, 0, 0);
+    let pairs = radix_sort.apply_binary(
+        raw_buffer(sorted_data),
+        &params,
+        sorted_len as u64 * 8,
+        "sort_keys_",
+        sorted_len,
+    );
+
+    let params = [len, sorted_len, key_type, 0, u32::from(reverse)];
+    let params = pipeline.device.create_gpu_buffer_with_data(&params);
+    let entry_point = match side {
+        Side::Left => "search_left_",
+        Side::Right => "search_right_",
+    };
+    let positions = pipeline.apply_ternary_function(
+        raw_buffer(values_data),
+        &pairs,
+        &params,
+        len as u64 * 4,
+        SEARCH_SHADER,
+        entry_point,
+        len.div_ceil(256),
+    );
+    let positions = into_index_array(positions, len, gpu_device);
+    let result = into_int32_array(positions, values.shape.clone(), &mut pipeline);
+    pipeline.finish();
+    Ok(result)
+}
+
+/// Finds the indices where the values should be inserted into the sorted 1-D array `a`
+/// to keep it sorted. `sorter` holds the indices that sort `a` if it is not sorted.
+/// The indices are int32.
+pub fn searchsorted(
+    a: &NdArray,
+    v: &NdArray,
+    side: Side,
+    sorter: Option<&NdArray>,
+) -> NdArrayResult<NdArray> {
+    check_1d(a)?;
+    match sorter {
+        None => search_sorted_op(a, v, side, false),
+        Some(sorter) if sorter.shape != a.shape => Err(NdArrayError::ValueError(
+            "sorter.size must equal a.size".to_string(),
+        )),
+        Some(sorter) => {
+            let sorted = a.take(&sorter.astype(Dtype::UInt32), None);
+            search_sorted_op(&sorted, v, side, false)
+        }
+    }
+}
+
+/// Returns the indices of the bins to which every value of `x` belongs, the bins must
+/// be monotonically increasing or decreasing. `right` includes the right edge of the
+/// bins instead of the left one.
+pub fn digitize(x: &NdArray, bins: &NdArray, right: bool) -> NdArrayResult<NdArray> {
+    check_1d(bins)?;
+    let mut increasing = true;
+    if bins.len() > 1 {
+        let gpu_device = bins.get_gpu_device();
+        let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("digitize"));
+        let (arrow_type, key_type) = key_type(bins.dtype);
+        let mut casted = None;
+        let data = cast_if_needed(&bins.data, &arrow_type, &mut casted, &mut pipeline);
+        let stats = [bins.len(), 1, 0];
+        let changes = key_stats_op(data, key_type, stats, "monotonicity_", &mut pipeline);
+        pipeline.finish();
+        let changes = read_u32_buffer(&changes, &gpu_device);
+        if changes[0] > 0 && changes[1] > 0 {
+            return Err(NdArrayError::ValueError(
+                "bins must be monotonically increasing or decreasing".to_string(),
+            ));
+        }
+        increasing = changes[0] == 0;
+    }
+
+    let side = if right { Side::Left } else { Side::Right };
+    if increasing {
+        search_sorted_op(bins, x, side, false)
+    } else {
+        search_sorted_op(&flip(bins, None)?, x, side, true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_searchsorted() {
+        let a = NdArray::from_slice([1i32, 2, 2, 3, 5].as_ref().into(), vec![5], None);
+        let v = NdArray::from_slice([2.0f32, -1.0, 4.5, 6.0].as_ref().into(), vec![2, 2], None);
+        let result = searchsorted(&a, &v, Side::Left, None).unwrap();
+        assert_eq!(result.dtype, Dtype::Int32);
+        assert_eq!(result.shape, vec![2, 2]);
+        assert_eq!(result.data.get_raw_values(), vec![1i32, 0, 4, 5].into());
+
+        let result = searchsorted(&a, &v, Side::Right, None).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![3i32, 0, 4, 5].into());
+
+        let a = NdArray::from_slice([3u32, 1, 2].as_ref().into(), vec![3], None);
+        let sorter = NdArray::from_slice([1i32, 2, 0].as_ref().into(), vec![3], None);
+        let v = NdArray::from_slice([2u32, 4].as_ref().into(), vec![2], None);
+        let result = searchsorted(&a, &v, Side::Left, Some(&sorter)).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![1i32, 3].into());
+    }
+
+    #[test]
+    fn test_digitize() {
+        let x = NdArray::from_slice([0.2f32, 6.4, 3.0, 1.6].as_ref().into(), vec![4], None);
+        let bins =
+            NdArray::from_slice([0.0f32, 1.0, 2.5, 4.0, 10.0].as_ref().into(), vec![5], None);
+        let result = digitize(&x, &bins, false).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![1i32, 4, 3, 2].into());
+
+        let bins = flip(&bins, None).unwrap();
+        let result = digitize(&x, &bins, true).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![4i32, 1, 2, 3].into());
+
+        let bins = NdArray::from_slice([0.0f32, 2.0, 1.0].as_ref().into(), vec![3], None);
+        assert!(digitize(&x, &bins, false).is_err());
+    }
+}
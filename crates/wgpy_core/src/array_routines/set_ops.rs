@@ -0,0 +1,518 @@
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{ArrowArrayGPU, ArrowType},
+    gpu_utils::{ArrowComputePipeline, GpuDevice},
+    kernels::{cast_op_dyn, take_op_dyn},
+};
+use wgpu::Buffer;
+
+use crate::{
+    array_routines::layout::{gather, GatherAxis},
+    cast_if_needed, compact_op, concatenate, full, key_type, normalize_axis, raw_buffer, sort,
+    utils::{into_index_array, into_int32_array, Holder},
+    Compaction, Dtype, NdArray, NdArrayResult, RadixSort, ScalarValue, SortKey, SortLayout,
+    SEARCH_SHADER, SORT_SHADER, SORT_TERNARY_SHADER,
+};
+
+/// Unique elements of an array, with the optional outputs of [`unique`]
+pub struct UniqueResult {
+    pub values: NdArray,
+    /// Indices of the first occurrences of the unique values
+    pub indices: Option<NdArray>,
+    /// Indices of the unique values which reconstruct the input
+    pub inverse: Option<NdArray>,
+    /// Number of times every unique value appears in the input
+    pub counts: Option<NdArray>,
+}
+
+/// Common values of two arrays, with their indices if requested in [`intersect1d`]
+pub struct Intersection {
+    pub values: NdArray,
+    pub indices: Option<(NdArray, NdArray)>,
+}
+
+/// Parameters of the sort shaders for `len` rows of `inner` columns
+fn rows_params(pipeline: &ArrowComputePipeline, len: u32, inner: u32, key_type: u32) -> Buffer {
+    let params = [len, len, inner, key_type, 0, 0, 0];
+    pipeline.device.create_gpu_buffer_with_data(&params)
+}
+
+/// Flattened copy of the array with the given dtype
+fn flat_as(ar: &NdArray, dtype: Dtype) -> NdArray {
+    if ar.dtype == dtype {
+        ar.flatten()
+    } else {
+        ar.astype(dtype).flatten()
+    }
+}
+
+fn empty_unique(
+    shape: Vec<u32>,
+    dtype: Dtype,
+    options: [bool; 3],
+    gpu_device: Arc<GpuDevice>,
+) -> UniqueResult {
+    let [return_index, return_inverse, return_counts] = options;
+    let index = || NdArray::zero_sized(vec![0], Dtype::Int32, gpu_device.clone());
+    UniqueResult {
+        values: NdArray::zero_sized(shape, dtype, gpu_device.clone()),
+        indices: return_index.then(index),
+        inverse: return_inverse.then(index),
+        counts: return_counts.then(index),
+    }
+}
+
+/// Returns the sorted unique elements of an array, the array is flattened if `axis`
+/// is None. Otherwise the unique subarrays along `axis` are returned, sorted in
+/// lexicographic order. Nans are treated as equal to each other.
+///
+/// The index arrays are int32 and the inverse is 1-D, like in numpy 1.x.
+pub fn unique(
+    ar: &NdArray,
+    return_index: bool,
+    return_inverse: bool,
+    return_counts: bool,
+    axis: Option<i32>,
+) -> NdArrayResult<UniqueResult> {
+    let flat;
+    let (ar, axis) = match axis {
+        None => {
+            flat = ar.flatten();
+            (&flat, 0)
+        }
+        Some(axis) => (ar, normalize_axis(axis, ar.shape.len())?),
+    };
+
+    let ndim = ar.shape.len();
+    let rows = ar.shape[axis];
+    let others = (0..ndim).filter(|x| *x != axis).collect::<Vec<_>>();
+    let columns = others.iter().map(|x| ar.shape[*x]).product::<u32>();
+    let gpu_device = ar.get_gpu_device();
+    if ar.is_empty() {
+        let mut shape = ar.shape.clone();
+        shape[axis] = 0;
+        let options = [return_index, return_inverse, return_counts];
+        return Ok(empty_unique(shape, ar.dtype, options, gpu_device));
+    }
+
+    // Rows are the subarrays along the axis, they are sorted by their values in the
+    // column major copy and taken from the row major one
+    let moved = |order: Vec<usize>| {
+        let axes = order.iter().map(|x| GatherAxis::identity(&ar.shape, *x));
+        gather(ar, &ar.shape, &axes.collect::<Vec<_>>(), None).map(Holder::Owned)
+    };
+    let row_major = match axis {
+        0 => Holder::Borrowed(ar),
+        _ => moved([&[axis], others.as_slice()].concat())?,
+    };
+    let column_major = match ndim {
+        1 => Holder::Borrowed(ar),
+        _ => moved([others.as_slice(), &[axis]].concat())?,
+    };
+
+    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("unique"));
+    let (arrow_type, key_type) = key_type(ar.dtype);
+    let mut casted = None;
+    let keys = cast_if_needed(
+        &column_major.as_ref().data,
+        &arrow_type,
+        &mut casted,
+        &mut pipeline,
+    );
+    let layout = SortLayout::flat(rows);
+    let sort_keys = (0..columns)
+        .rev()
+        .map(|x| SortKey {
+            data: keys,
+            offset: x * rows,
+        })
+        .collect::<Vec<_>>();
+    let mut radix_sort = RadixSort::new(&layout, &mut pipeline);
+    let pairs = radix_sort.sort_pairs(&sort_keys);
+    let order = radix_sort.indices(&pairs, "flat_indices_");
+
+    let params = rows_params(&pipeline, rows, columns, key_type);
+    let flags = pipeline.apply_ternary_function(
+        raw_buffer(keys),
+        &pairs,
+        &params,
+        rows as u64 * 4,
+        SORT_TERNARY_SHADER,
+        "rows_differ_",
+        rows.div_ceil(256),
+    );
+    let Compaction {
+        indices: starts,
+        positions,
+        count,
+    } = compact_op(&flags, rows, &mut pipeline, "unique");
+
+    let first = match take_op_dyn(&order.into(), &starts, &mut pipeline) {
+        ArrowArrayGPU::UInt32ArrayGPU(x) => x,
+        _ => unreachable!(),
+    };
+    let len = count * columns;
+    let row_indices = pipeline.apply_binary_function(
+        &first.data,
+        &params,
+        len as u64 * 4,
+        SORT_SHADER,
+        "take_rows_",
+        len.div_ceil(256),
+    );
+    let row_indices = into_index_array(row_indices, len, gpu_device.clone());
+    let data = take_op_dyn(&row_major.as_ref().data, &row_indices, &mut pipeline);
+
+    let indices = return_index.then(|| into_int32_array(first, vec![count], &mut pipeline));
+    let inverse = return_inverse.then(|| {
+        let inverse = pipeline.apply_ternary_function(
+            &pairs,
+            &positions,
+            &params,
+            rows as u64 * 4,
+            SORT_TERNARY_SHADER,
+            "inverse_",
+            rows.div_ceil(256),
+        );
+        let inverse = into_index_array(inverse, rows, gpu_device.clone());
+        into_int32_array(inverse, vec![rows], &mut pipeline)
+    });
+    let counts = return_counts.then(|| {
+        let counts = pipeline.apply_binary_function(
+            &starts.data,
+            &params,
+            count as u64 * 4,
+            SORT_SHADER,
+            "group_counts_",
+            count.div_ceil(256),
+        );
+        let counts = into_index_array(counts, count, gpu_device.clone());
+        into_int32_array(counts, vec![count], &mut pipeline)
+    });
+    pipeline.finish();
+
+    let shape = [count]
+        .into_iter()
+        .chain(others.iter().map(|x| ar.shape[*x]))
+        .collect::<Vec<_>>();
+    let values = NdArray {
+        dims: shape.len() as u16,
+        shape,
+        data,
+        dtype: ar.dtype,
+    };
+    let values = match axis {
+        0 => values,
+        _ => {
+            // Moves the first axis of the unique rows back to `axis`
+            let axes = (0..ndim)
+                .map(|x| match x {
+                    x if x == axis => 0,
+                    x if x < axis => x + 1,
+                    x => x,
+                })
+                .map(|x| GatherAxis::identity(&values.shape, x))
+                .collect::<Vec<_>>();
+            gather(&values, &values.shape, &axes, None)?
+        }
+    };
+
+    Ok(UniqueResult {
+        values,
+        indices,
+        inverse,
+        counts,
+    })
+}
+
+/// u32 flags of the elements of `ar1` found in `ar2`, or not found with `invert`.
+/// Both arrays must hold elements.
+fn contains_op(
+    ar1: &NdArray,
+    ar2: &NdArray,
+    invert: bool,
+    pipeline: &mut ArrowComputePipeline,
+) -> Buffer {
+    let (arrow_type, key_type) = key_type(ar1.dtype.promote(ar2.dtype));
+    let (mut casted_1, mut casted_2) = (None, None);
+    let data_1 = cast_if_needed(&ar1.data, &arrow_type, &mut casted_1, pipeline);
+    let data_2 = cast_if_needed(&ar2.data, &arrow_type, &mut casted_2, pipeline);
+    let layout = SortLayout::flat(ar2.len());
+    let pairs = RadixSort::new(&layout, pipeline).sort_pairs(&[data_2.into()]);
+    let params = [ar1.len(), ar2.len(), key_type, u32::from(invert), 0];
+    let params = pipeline.device.create_gpu_buffer_with_data(&params);
+    pipeline.apply_ternary_function(
+        raw_buffer(data_1),
+        &pairs,
+        &params,
+        ar1.len() as u64 * 4,
+        SEARCH_SHADER,
+        "contains_",
+        ar1.len().div_ceil(256),
+    )
+}
+
+/// Elements of the flattened arrays whose flag is non zero, the pipeline is finished
+/// and replaced like in [`compact_op`]
+fn compress_op(
+    arrays: &[&NdArray],
+    flags: &Buffer,
+    pipeline: &mut ArrowComputePipeline,
+    label: &str,
+) -> Vec<NdArray> {
+    let len = arrays[0].len();
+    let compaction = compact_op(flags, len, pipeline, label);
+    arrays
+        .iter()
+        .map(|x| match compaction.count {
+            0 => NdArray::zero_sized(vec![0], x.dtype, x.get_gpu_device()),
+            count => NdArray {
+                dims: 1,
+                shape: vec![count],
+                data: take_op_dyn(&x.data, &compaction.indices, pipeline),
+                dtype: x.dtype,
+            },
+        })
+        .collect()
+}
+
+fn isin_shape(
+    ar1: &NdArray,
+    ar2: &NdArray,
+    invert: bool,
+    shape: Vec<u32>,
+) -> NdArrayResult<NdArray> {
+    let gpu_device = ar1.get_gpu_device();
+    if ar1.is_empty() {
+        return Ok(NdArray::zero_sized(shape, Dtype::Bool, gpu_device));
+    }
+    if ar2.is_empty() {
+        let value = ScalarValue::BOOL(invert).into();
+        return full(shape, value, None, Some(gpu_device));
+    }
+    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("isin"));
+    let flags = contains_op(ar1, ar2, invert, &mut pipeline);
+    let flags = into_index_array(flags, ar1.len(), gpu_device);
+    let data = cast_op_dyn(&flags.into(), &ArrowType::BooleanType, &mut pipeline);
+    pipeline.finish();
+    Ok(NdArray {
+        dims: shape.len() as u16,
+        shape,
+        data,
+        dtype: Dtype::Bool,
+    })
+}
+
+/// Tests whether every element of the flattened `ar1` is in `ar2`, `invert` tests
+/// whether it is not in `ar2`
+pub fn in1d(ar1: &NdArray, ar2: &NdArray, invert: bool) -> NdArrayResult<NdArray> {
+    isin_shape(ar1, ar2, invert, vec![ar1.len()])
+}
+
+/// Tests whether every element of `element` is in `test_elements`, the result has the
+/// shape of `element`
+pub fn isin(element: &NdArray, test_elements: &NdArray, invert: bool) -> NdArrayResult<NdArray> {
+    isin_shape(element, test_elements, invert, element.shape.clone())
+}
+
+/// Sorted unique values of the array cast to `dtype`, with the indices of their first
+/// occurrences. The values are only sorted if `assume_unique`.
+fn sorted_unique(ar: &NdArray, dtype: Dtype, assume_unique: bool) -> NdArrayResult<[NdArray; 2]> {
+    let ar = flat_as(ar, dtype);
+    if assume_unique {
+        Ok([sort(&ar, None)?, crate::argsort(&ar, None)?])
+    } else {
+        let unique = unique(&ar, true, false, false, None)?;
+        Ok([unique.values, unique.indices.unwrap()])
+    }
+}
+
+/// Returns the sorted unique values found in both arrays, and the indices of their
+/// first occurrences in the flattened arrays with `return_indices`. `assume_unique`
+/// skips the search of the unique values of the inputs.
+pub fn intersect1d(
+    ar1: &NdArray,
+    ar2: &NdArray,
+    assume_unique: bool,
+    return_indices: bool,
+) -> NdArrayResult<Intersection> {
+    let dtype = ar1.dtype.promote(ar2.dtype);
+    let [values_1, indices_1] = sorted_unique(ar1, dtype, assume_unique)?;
+    let [values_2, indices_2] = sorted_unique(ar2, dtype, assume_unique)?;
+    let gpu_device = ar1.get_gpu_device();
+    if values_1.is_empty() || values_2.is_empty() {
+        let index = || NdArray::zero_sized(vec![0], Dtype::Int32, gpu_device.clone());
+        return Ok(Intersection {
+            values: NdArray::zero_sized(vec![0], dtype, gpu_device.clone()),
+            indices: return_indices.then(|| (index(), index())),
+        });
+    }
+
+    // Both inputs are sorted, so their common values are in the same order
+    let mut pipeline = ArrowComputePipeline::new(gpu_device, Some("intersect1d"));
+    let flags_1 = contains_op(&values_1, &values_2, false, &mut pipeline);
+    let flags_2 = return_indices.then(|| contains_op(&values_2, &values_1, false, &mut pipeline));
+    let mut compressed = compress_op(
+        &[&values_1, &indices_1],
+        &flags_1,
+        &mut pipeline,
+        "intersect1d",
+    );
+    let indices = flags_2.map(|flags| {
+        let mut compressed_2 = compress_op(&[&indices_2], &flags, &mut pipeline, "intersect1d");
+        (compressed.pop().unwrap(), compressed_2.pop().unwrap())
+    });
+    pipeline.finish();
+
+    Ok(Intersection {
+        values: compressed.swap_remove(0),
+        indices,
+    })
+}
+
+/// Returns the sorted unique values found in either of the arrays
+pub fn union1d(ar1: &NdArray, ar2: &NdArray) -> NdArrayResult<NdArray> {
+    let dtype = ar1.dtype.promote(ar2.dtype);
+    let joined = concatenate(&[&flat_as(ar1, dtype), &flat_as(ar2, dtype)], None)?;
+    Ok(unique(&joined, false, false, false, None)?.values)
+}
+
+fn setdiff_op(
+    ar1: &NdArray,
+    ar2: &NdArray,
+    pipeline: &mut ArrowComputePipeline,
+    label: &str,
+) -> NdArray {
+    if ar1.is_empty() || ar2.is_empty() {
+        return ar1.flatten();
+    }
+    let flags = contains_op(ar1, ar2, true, pipeline);
+    compress_op(&[ar1], &flags, pipeline, label).swap_remove(0)
+}
+
+fn unique_values(ar: &NdArray, dtype: Dtype, assume_unique: bool) -> NdArrayResult<NdArray> {
+    let ar = flat_as(ar, dtype);
+    match assume_unique {
+        true => Ok(ar),
+        false => Ok(unique(&ar, false, false, false, None)?.values),
+    }
+}
+
+/// Returns the unique values of `ar1` which are not in `ar2`, they are sorted unless
+/// `assume_unique`, which keeps the order of `ar1`
+pub fn setdiff1d(ar1: &NdArray, ar2: &NdArray, assume_unique: bool) -> NdArrayResult<NdArray> {
+    let dtype = ar1.dtype.promote(ar2.dtype);
+    let values = unique_values(ar1, dtype, assume_unique)?;
+    let mut pipeline = ArrowComputePipeline::new(values.get_gpu_device(), Some("setdiff1d"));
+    let difference = setdiff_op(&values, ar2, &mut pipeline, "setdiff1d");
+    pipeline.finish();
+    Ok(difference)
+}
+
+/// Returns the sorted unique values which are in only one of the arrays
+pub fn setxor1d(ar1: &NdArray, ar2: &NdArray, assume_unique: bool) -> NdArrayResult<NdArray> {
+    let dtype = ar1.dtype.promote(ar2.dtype);
+    let values_1 = unique_values(ar1, dtype, assume_unique)?;
+    let values_2 = unique_values(ar2, dtype, assume_unique)?;
+    let mut pipeline = ArrowComputePipeline::new(values_1.get_gpu_device(), Some("setxor1d"));
+    let difference_1 = setdiff_op(&values_1, &values_2, &mut pipeline, "setxor1d");
+    let difference_2 = setdiff_op(&values_2, &values_1, &mut pipeline, "setxor1d");
+    pipeline.finish();
+    sort(&concatenate(&[&difference_1, &difference_2], None)?, None)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unique() {
+        let input = NdArray::from_slice(
+            [3.0f32, f32::NAN, 1.0, 3.0, -0.0, f32::NAN, 0.0]
+                .as_ref()
+                .into(),
+            vec![7],
+            None,
+        );
+        let result = unique(&input, true, true, true, None).unwrap();
+        assert_eq!(result.values.shape, vec![4]);
+        let values = match result.values.data.get_raw_values() {
+            arrow_gpu::utils::ScalarArray::F32Vec(x) => x,
+            _ => unreachable!(),
+        };
+        assert_eq!(&values[..3], &[0.0, 1.0, 3.0]);
+        assert!(values[3].is_nan());
+        assert_eq!(
+            result.indices.unwrap().data.get_raw_values(),
+            vec![4i32, 2, 0, 1].into()
+        );
+        assert_eq!(
+            result.inverse.unwrap().data.get_raw_values(),
+            vec![2i32, 3, 1, 2, 0, 3, 0].into()
+        );
+        assert_eq!(
+            result.counts.unwrap().data.get_raw_values(),
+            vec![2i32, 1, 2, 2].into()
+        );
+    }
+
+    #[test]
+    fn test_unique_axis() {
+        let input = NdArray::from_slice(
+            [1i16, 0, 1, 2, 1, 0, 1, 0, 1, 0, 1, 0].as_ref().into(),
+            vec![2, 6],
+            None,
+        )
+        .reshape(vec![2, 3, 2])
+        .unwrap();
+        let result = unique(&input, false, true, false, Some(1)).unwrap();
+        assert_eq!(result.values.shape, vec![2, 2, 2]);
+        assert_eq!(
+            result.values.data.get_raw_values(),
+            vec![1i16, 0, 1, 2, 1, 0, 1, 0].into()
+        );
+        assert_eq!(
+            result.inverse.unwrap().data.get_raw_values(),
+            vec![0i32, 1, 0].into()
+        );
+        assert!(result.indices.is_none());
+    }
+
+    #[test]
+    fn test_isin() {
+        let element = NdArray::from_slice([0i32, 2, 4, 6].as_ref().into(), vec![2, 2], None);
+        let test_elements = NdArray::from_slice([4u8, 1, 0].as_ref().into(), vec![3], None);
+        let result = isin(&element, &test_elements, false).unwrap();
+        assert_eq!(result.shape, vec![2, 2]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![true, false, true, false].into()
+        );
+        let result = in1d(&element, &test_elements, true).unwrap();
+        assert_eq!(result.shape, vec![4]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![false, true, false, true].into()
+        );
+    }
+
+    #[test]
+    fn test_set_routines() {
+        let ar1 = NdArray::from_slice([1i32, 3, 4, 3, 7].as_ref().into(), vec![5], None);
+        let ar2 = NdArray::from_slice([3i32, 1, 2, 1].as_ref().into(), vec![4], None);
+
+        let result = intersect1d(&ar1, &ar2, false, true).unwrap();
+        assert_eq!(result.values.data.get_raw_values(), vec![1i32, 3].into());
+        let (indices_1, indices_2) = result.indices.unwrap();
+        assert_eq!(indices_1.data.get_raw_values(), vec![0i32, 1].into());
+        assert_eq!(indices_2.data.get_raw_values(), vec![1i32, 0].into());
+
+        let result = union1d(&ar1, &ar2).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![1i32, 2, 3, 4, 7].into());
+        let result = setdiff1d(&ar1, &ar2, false).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![4i32, 7].into());
+        let result = setxor1d(&ar1, &ar2, false).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![2i32, 4, 7].into());
+    }
+}
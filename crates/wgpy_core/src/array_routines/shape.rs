@@ -0,0 +1,65 @@
+use crate::NdArray;
+
+pub(crate) fn atleast_1d_shape(shape: &[u32]) -> Vec<u32> {
+    match shape.len() {
+        0 => vec![1],
+        _ => shape.to_vec(),
+    }
+}
+
+pub(crate) fn atleast_2d_shape(shape: &[u32]) -> Vec<u32> {
+    match shape.len() {
+        0 => vec![1, 1],
+        1 => vec![1, shape[0]],
+        _ => shape.to_vec(),
+    }
+}
+
+pub(crate) fn atleast_3d_shape(shape: &[u32]) -> Vec<u32> {
+    match shape.len() {
+        0 => vec![1, 1, 1],
+        1 => vec![1, shape[0], 1],
+        2 => vec![shape[0], shape[1], 1],
+        _ => shape.to_vec(),
+    }
+}
+
+/// Returns the array with at least one dimension
+pub fn atleast_1d(arr: &NdArray) -> NdArray {
+    arr.with_shape(atleast_1d_shape(&arr.shape))
+}
+
+/// Returns the array with at least two dimensions, 1-D arrays of shape `(N,)`
+/// become `(1, N)`
+pub fn atleast_2d(arr: &NdArray) -> NdArray {
+    arr.with_shape(atleast_2d_shape(&arr.shape))
+}
+
+/// Returns the array with at least three dimensions, 1-D arrays of shape `(N,)`
+/// become `(1, N, 1)` and 2-D arrays of shape `(M, N)` become `(M, N, 1)`
+pub fn atleast_3d(arr: &NdArray) -> NdArray {
+    arr.with_shape(atleast_3d_shape(&arr.shape))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_atleast() {
+        let input = NdArray::from_slice([1.0f32].as_ref().into(), vec![], None);
+        assert_eq!(&atleast_1d(&input).shape, &[1]);
+        assert_eq!(&atleast_2d(&input).shape, &[1, 1]);
+        assert_eq!(&atleast_3d(&input).shape, &[1, 1, 1]);
+
+        let input = NdArray::from_slice([1.0f32, 2.0].as_ref().into(), vec![2], None);
+        let result = atleast_2d(&input);
+        assert_eq!(&result.shape, &[1, 2]);
+        assert_eq!(result.dims, 2);
+        assert_eq!(&atleast_3d(&input).shape, &[1, 2, 1]);
+
+        let input = NdArray::from_slice([1.0f32, 2.0].as_ref().into(), vec![2, 1], None);
+        assert_eq!(&atleast_1d(&input).shape, &[2, 1]);
+        assert_eq!(&atleast_3d(&input).shape, &[2, 1, 1]);
+    }
+}
@@ -0,0 +1,611 @@
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{ArrowArrayGPU, ArrowType, UInt32ArrayGPU},
+    gpu_utils::{ArrowComputePipeline, Buffer},
+    kernels::{bitcast_op_dyn, take_op_dyn},
+};
+
+use crate::{cast_if_needed, normalize_axis, scan_op, Dtype, NdArray, NdArrayError, NdArrayResult};
+
+pub(crate) const SORT_SHADER: &str = concat!(
+    include_str!("../../compute_shaders/u32/sort.wgsl"),
+    include_str!("../../compute_shaders/u32/sort_binary.wgsl")
+);
+pub(crate) const SORT_TERNARY_SHADER: &str = concat!(
+    include_str!("../../compute_shaders/u32/sort.wgsl"),
+    include_str!("../../compute_shaders/u32/sort_ternary.wgsl")
+);
+const SELECT_SHADER: &str = concat!(
+    include_str!("../../compute_shaders/u32/sort.wgsl"),
+    include_str!("../../compute_shaders/u32/select.wgsl")
+);
+
+const RADIX_BITS: u32 = 4;
+const RADIX: u32 = 1 << RADIX_BITS;
+const BLOCK_SIZE: u32 = 256;
+
+/// Input viewed as `[outer, axis_len, inner]`, every lane along the axis is sorted on its
+/// own. `axis` of None sorts the flattened array.
+pub(crate) struct SortLayout {
+    pub len: u32,
+    pub axis_len: u32,
+    pub inner: u32,
+    pub shape: Vec<u32>,
+}
+
+impl SortLayout {
+    pub fn new(shape: &[u32], axis: Option<i32>) -> NdArrayResult<Self> {
+        let len = shape.iter().product();
+        match axis {
+            None => Ok(Self::flat(len)),
+            Some(axis) => {
+                let axis = normalize_axis(axis, shape.len())?;
+                Ok(Self {
+                    len,
+                    axis_len: shape[axis],
+                    inner: shape[axis + 1..].iter().product(),
+                    shape: shape.to_vec(),
+                })
+            }
+        }
+    }
+
+    /// Layout sorting all the `len` elements as a single lane
+    pub fn flat(len: u32) -> Self {
+        Self {
+            len,
+            axis_len: len,
+            inner: 1,
+            shape: vec![len],
+        }
+    }
+
+    fn blocks(&self) -> u32 {
+        self.len.div_ceil(BLOCK_SIZE)
+    }
+
+    fn segments(&self) -> u32 {
+        self.len / self.axis_len
+    }
+}
+
+/// Kernel type of the keys and the code of their conversion to ordered keys in the shader
+pub(crate) fn key_type(dtype: Dtype) -> (ArrowType, u32) {
+    match dtype {
+        Dtype::Float32 => (ArrowType::Float32Type, 2),
+        x if x.is_signed_int() => (ArrowType::Int32Type, 1),
+        _ => (ArrowType::UInt32Type, 0),
+    }
+}
+
+pub(crate) fn raw_buffer(data: &ArrowArrayGPU) -> &Buffer {
+    match data {
+        ArrowArrayGPU::Float32ArrayGPU(x) => &x.data,
+        ArrowArrayGPU::UInt32ArrayGPU(x) => &x.data,
+        ArrowArrayGPU::UInt16ArrayGPU(x) => &x.data,
+        ArrowArrayGPU::UInt8ArrayGPU(x) => &x.data,
+        ArrowArrayGPU::Int32ArrayGPU(x) => &x.data,
+        ArrowArrayGPU::Int16ArrayGPU(x) => &x.data,
+        ArrowArrayGPU::Int8ArrayGPU(x) => &x.data,
+        ArrowArrayGPU::BooleanArrayGPU(x) => &x.data,
+    }
+}
+
+/// Sort key read from `offset` of the data, so that the columns of a single array
+/// can be used as keys
+#[derive(Clone, Copy)]
+pub(crate) struct SortKey<'a> {
+    pub data: &'a ArrowArrayGPU,
+    pub offset: u32,
+}
+
+impl<'a> From<&'a ArrowArrayGPU> for SortKey<'a> {
+    fn from(data: &'a ArrowArrayGPU) -> Self {
+        Self { data, offset: 0 }
+    }
+}
+
+/// Stable LSD radix sort of (key, index) pairs with 4 bit digits. Every pass counts the
+/// digits of blocks of 256 pairs, scans the counts into offsets and scatters the pairs.
+pub(crate) struct RadixSort<'a> {
+    layout: &'a SortLayout,
+    pipeline: &'a mut ArrowComputePipeline,
+}
+
+impl<'a> RadixSort<'a> {
+    pub fn new(layout: &'a SortLayout, pipeline: &'a mut ArrowComputePipeline) -> Self {
+        Self { layout, pipeline }
+    }
+
+    pub fn params(&mut self, key_type: u32, shift: u32, offset: u32) -> Buffer {
+        let params = [
+            self.layout.len,
+            self.layout.axis_len,
+            self.layout.inner,
+            key_type,
+            shift,
+            self.layout.blocks(),
+            offset,
+        ];
+        self.pipeline.device.create_gpu_buffer_with_data(&params)
+    }
+
+    fn pairs_size(&self) -> u64 {
+        self.layout.len as u64 * 8
+    }
+
+    /// Runs a kernel of the binary sort shader with a thread per element of `threads`
+    pub fn apply_binary(
+        &mut self,
+        input: &Buffer,
+        params: &Buffer,
+        size: u64,
+        entry_point: &str,
+        threads: u32,
+    ) -> Buffer {
+        self.pipeline.apply_binary_function(
+            input,
+            params,
+            size,
+            SORT_SHADER,
+            entry_point,
+            threads.div_ceil(256),
+        )
+    }
+
+    /// Runs a kernel of the ternary sort shader with a thread per element of `threads`
+    pub fn apply_ternary(
+        &mut self,
+        input_1: &Buffer,
+        input_2: &Buffer,
+        params: &Buffer,
+        size: u64,
+        entry_point: &str,
+        threads: u32,
+    ) -> Buffer {
+        self.pipeline.apply_ternary_function(
+            input_1,
+            input_2,
+            params,
+            size,
+            SORT_TERNARY_SHADER,
+            entry_point,
+            threads.div_ceil(256),
+        )
+    }
+
+    /// Sorts the pairs on the low `bits` bits of their keys
+    fn radix_passes(&mut self, mut pairs: Buffer, bits: u32) -> Buffer {
+        let blocks = self.layout.blocks();
+        let counts = RADIX * blocks;
+        for shift in (0..bits).step_by(RADIX_BITS as usize) {
+            let params = self.params(0, shift, 0);
+            let histogram = self.apply_binary(
+                &pairs,
+                &params,
+                counts as u64 * 4,
+                "radix_histogram_",
+                blocks,
+            );
+            let offsets = scan_op(&histogram, counts, true, self.pipeline);
+            pairs = self.pipeline.apply_ternary_function(
+                &pairs,
+                &offsets,
+                &params,
+                self.pairs_size(),
+                SORT_TERNARY_SHADER,
+                "radix_scatter_",
+                blocks,
+            );
+        }
+        pairs
+    }
+
+    /// Sorts by every key in turn, the last key is the primary one. The segments are
+    /// sorted last, so that the elements of every lane are contiguous.
+    pub fn sort_pairs(&mut self, keys: &[SortKey]) -> Buffer {
+        let mut pairs: Option<Buffer> = None;
+        for key in keys {
+            let (arrow_type, key_type) = key_type(key.data.get_dtype().into());
+            let mut casted = None;
+            let data = cast_if_needed(key.data, &arrow_type, &mut casted, self.pipeline);
+            let params = self.params(key_type, 0, key.offset);
+            let keyed = match pairs {
+                None => self.apply_binary(
+                    raw_buffer(data),
+                    &params,
+                    self.pairs_size(),
+                    "sort_keys_",
+                    self.layout.len,
+                ),
+                Some(pairs) => self.apply_ternary(
+                    raw_buffer(data),
+                    &pairs,
+                    &params,
+                    self.pairs_size(),
+                    "rekey_",
+                    self.layout.len,
+                ),
+            };
+            pairs = Some(self.radix_passes(keyed, u32::BITS));
+        }
+        self.sort_segments(pairs.unwrap())
+    }
+
+    /// Sorts the pairs by their segment, so that the elements of every lane are contiguous
+    fn sort_segments(&mut self, pairs: Buffer) -> Buffer {
+        let segments = self.layout.segments();
+        if segments == 1 {
+            return pairs;
+        }
+        let params = self.params(0, 0, 0);
+        let keyed = self.apply_binary(
+            &pairs,
+            &params,
+            self.pairs_size(),
+            "segment_keys_",
+            self.layout.len,
+        );
+        self.radix_passes(keyed, u32::BITS - (segments - 1).leading_zeros())
+    }
+
+    /// Partitions every lane around its keys at the sorted positions `kth`, which are
+    /// found with a radix select. Every pass counts the digits of the keys matching the
+    /// digits selected so far and selects the digit holding the kth key. The pairs are
+    /// then sorted on their bucket between the kth keys, which only needs the few bits
+    /// of the buckets instead of the whole keys.
+    pub fn partition_pairs(&mut self, data: &ArrowArrayGPU, kth: &[u32]) -> Buffer {
+        let (arrow_type, key_type) = key_type(data.get_dtype().into());
+        let mut casted = None;
+        let data = cast_if_needed(data, &arrow_type, &mut casted, self.pipeline);
+        let params = self.params(key_type, 0, 0);
+        let pairs = self.apply_binary(
+            raw_buffer(data),
+            &params,
+            self.pairs_size(),
+            "sort_keys_",
+            self.layout.len,
+        );
+
+        let segments = self.layout.segments();
+        let selections = segments * kth.len() as u32;
+        let state = (0..segments)
+            .flat_map(|_| kth.iter().flat_map(|k| [0, *k]))
+            .collect::<Vec<u32>>();
+        let mut state = self.pipeline.device.create_gpu_buffer_with_data(&state);
+        for shift in (0..u32::BITS).step_by(RADIX_BITS as usize).rev() {
+            let params = self.params(0, shift, 0);
+            let histogram = self.pipeline.apply_ternary_function(
+                &pairs,
+                &state,
+                &params,
+                (selections * RADIX) as u64 * 4,
+                SELECT_SHADER,
+                "select_histogram_",
+                self.layout.len.div_ceil(256),
+            );
+            state = self.apply_ternary(
+                &histogram,
+                &state,
+                &params,
+                selections as u64 * 8,
+                "select_digit_",
+                selections,
+            );
+        }
+
+        let params = self.params(0, 0, 0);
+        let keyed = self.apply_ternary(
+            &pairs,
+            &state,
+            &params,
+            self.pairs_size(),
+            "partition_keys_",
+            self.layout.len,
+        );
+        let buckets = 2 * kth.len() as u32;
+        let pairs = self.radix_passes(keyed, u32::BITS - buckets.leading_zeros());
+        self.sort_segments(pairs)
+    }
+
+    /// Flat indices of the sorted elements with `flat_indices_`, or their indices along
+    /// the axis with `axis_indices_`
+    pub fn sort(&mut self, keys: &[SortKey], entry_point: &str) -> UInt32ArrayGPU {
+        let pairs = self.sort_pairs(keys);
+        self.indices(&pairs, entry_point)
+    }
+
+    /// Indices of sorted pairs, see [`RadixSort::sort`]
+    pub fn indices(&mut self, pairs: &Buffer, entry_point: &str) -> UInt32ArrayGPU {
+        let params = self.params(0, 0, 0);
+        let len = self.layout.len;
+        let buffer = self.apply_binary(pairs, &params, len as u64 * 4, entry_point, len);
+        UInt32ArrayGPU {
+            data: Arc::new(buffer),
+            gpu_device: self.pipeline.device.clone(),
+            phantom: std::marker::PhantomData,
+            len: len as usize,
+            null_buffer: None,
+        }
+    }
+}
+
+/// Indices along the axis that sort the keys, the result is i32
+fn argsort_keys(keys: &[&NdArray], layout: SortLayout) -> NdArray {
+    let gpu_device = keys[0].get_gpu_device();
+    if layout.len == 0 {
+        return NdArray::zero_sized(layout.shape, Dtype::Int32, gpu_device);
+    }
+    let mut pipeline = ArrowComputePipeline::new(gpu_device, Some("argsort"));
+    let keys = keys.iter().map(|x| (&x.data).into()).collect::<Vec<_>>();
+    let indices = RadixSort::new(&layout, &mut pipeline).sort(&keys, "axis_indices_");
+    let data = bitcast_op_dyn(&indices.into(), &ArrowType::Int32Type, &mut pipeline);
+    pipeline.finish();
+    NdArray {
+        dims: layout.shape.len() as u16,
+        shape: layout.shape,
+        data,
+        dtype: Dtype::Int32,
+    }
+}
+
+/// Sorted positions along the axis of `kth`, without duplicates. Without any position
+/// every order is a partition, the first position is used then.
+fn normalize_kth(kth: &[i32], layout: &SortLayout) -> NdArrayResult<Vec<u32>> {
+    let axis_len = layout.axis_len as i32;
+    let mut normalized = kth
+        .iter()
+        .map(|k| {
+            if *k >= axis_len || *k < -axis_len {
+                return Err(NdArrayError::ValueError(format!(
+                    "kth(={}) out of bounds ({})",
+                    k, axis_len
+                )));
+            }
+            Ok(k.rem_euclid(axis_len) as u32)
+        })
+        .collect::<NdArrayResult<Vec<_>>>()?;
+    normalized.sort_unstable();
+    normalized.dedup();
+    if normalized.is_empty() {
+        normalized.push(0);
+    }
+    Ok(normalized)
+}
+
+/// Returns a sorted copy of an array along `axis`, `axis` of None sorts the flattened
+/// array. Nans are sorted to the end and the sort is stable.
+pub fn sort(a: &NdArray, axis: Option<i32>) -> NdArrayResult<NdArray> {
+    let layout = SortLayout::new(&a.shape, axis)?;
+    if layout.len == 0 {
+        return Ok(NdArray::zero_sized(
+            layout.shape,
+            a.dtype,
+            a.get_gpu_device(),
+        ));
+    }
+    let mut pipeline = ArrowComputePipeline::new(a.get_gpu_device(), Some("sort"));
+    let indices = RadixSort::new(&layout, &mut pipeline).sort(&[(&a.data).into()], "flat_indices_");
+    let data = take_op_dyn(&a.data, &indices, &mut pipeline);
+    pipeline.finish();
+    Ok(NdArray {
+        dims: layout.shape.len() as u16,
+        shape: layout.shape,
+        data,
+        dtype: a.dtype,
+    })
+}
+
+/// Sorts an array in place along `axis`, the sorted values are copied back into its
+/// buffer so arrays sharing the buffer see them too
+pub fn sort_inplace(a: &NdArray, axis: i32) -> NdArrayResult<()> {
+    let layout = SortLayout::new(&a.shape, Some(axis))?;
+    if layout.len == 0 {
+        return Ok(());
+    }
+    let mut pipeline = ArrowComputePipeline::new(a.get_gpu_device(), Some("sort_inplace"));
+    let indices = RadixSort::new(&layout, &mut pipeline).sort(&[(&a.data).into()], "flat_indices_");
+    let sorted = take_op_dyn(&a.data, &indices, &mut pipeline);
+    let (source, target) = (raw_buffer(&sorted), raw_buffer(&a.data));
+    pipeline.copy_buffer_to_buffer(source, 0, target, 0, source.size().min(target.size()));
+    pipeline.finish();
+    Ok(())
+}
+
+/// Returns the indices that would sort an array along `axis` with a stable sort
+pub fn argsort(a: &NdArray, axis: Option<i32>) -> NdArrayResult<NdArray> {
+    let layout = SortLayout::new(&a.shape, axis)?;
+    Ok(argsort_keys(&[a], layout))
+}
+
+/// Returns a copy of the array where the elements at the `kth` positions along `axis`
+/// are where they would be in a sorted array, with smaller elements before them and
+/// larger ones after. The order within the partitions is undefined.
+pub fn partition(a: &NdArray, kth: &[i32], axis: Option<i32>) -> NdArrayResult<NdArray> {
+    let layout = SortLayout::new(&a.shape, axis)?;
+    let kth = normalize_kth(kth, &layout)?;
+    if layout.len == 0 {
+        return Ok(NdArray::zero_sized(
+            layout.shape,
+            a.dtype,
+            a.get_gpu_device(),
+        ));
+    }
+    let mut pipeline = ArrowComputePipeline::new(a.get_gpu_device(), Some("partition"));
+    let mut radix_sort = RadixSort::new(&layout, &mut pipeline);
+    let pairs = radix_sort.partition_pairs(&a.data, &kth);
+    let indices = radix_sort.indices(&pairs, "flat_indices_");
+    let data = take_op_dyn(&a.data, &indices, &mut pipeline);
+    pipeline.finish();
+    Ok(NdArray {
+        dims: layout.shape.len() as u16,
+        shape: layout.shape,
+        data,
+        dtype: a.dtype,
+    })
+}
+
+/// Returns the indices that would partition an array along `axis`, see [`partition`]
+pub fn argpartition(a: &NdArray, kth: &[i32], axis: Option<i32>) -> NdArrayResult<NdArray> {
+    let layout = SortLayout::new(&a.shape, axis)?;
+    let kth = normalize_kth(kth, &layout)?;
+    if layout.len == 0 {
+        return Ok(NdArray::zero_sized(
+            layout.shape,
+            Dtype::Int32,
+            a.get_gpu_device(),
+        ));
+    }
+    let mut pipeline = ArrowComputePipeline::new(a.get_gpu_device(), Some("argpartition"));
+    let mut radix_sort = RadixSort::new(&layout, &mut pipeline);
+    let pairs = radix_sort.partition_pairs(&a.data, &kth);
+    let indices = radix_sort.indices(&pairs, "axis_indices_");
+    let data = bitcast_op_dyn(&indices.into(), &ArrowType::Int32Type, &mut pipeline);
+    pipeline.finish();
+    Ok(NdArray {
+        dims: layout.shape.len() as u16,
+        shape: layout.shape,
+        data,
+        dtype: Dtype::Int32,
+    })
+}
+
+/// Returns the indices that sort the keys along `axis` with a stable sort, the last key
+/// is the primary sort key
+pub fn lexsort(keys: &[&NdArray], axis: i32) -> NdArrayResult<NdArray> {
+    let first = keys.first().ok_or_else(|| {
+        NdArrayError::ValueError("need sequence of keys with len > 0 in lexsort".to_string())
+    })?;
+    if keys.iter().any(|x| x.shape != first.shape) {
+        return Err(NdArrayError::ValueError(
+            "all keys need to be the same shape".to_string(),
+        ));
+    }
+    let layout = SortLayout::new(&first.shape, Some(axis))?;
+    Ok(argsort_keys(keys, layout))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_gpu::utils::ScalarArray;
+
+    #[test]
+    fn test_sort_f32() {
+        let input = NdArray::from_slice(
+            [3.0f32, f32::NAN, -1.0, 2.5, f32::NEG_INFINITY, 0.0]
+                .as_ref()
+                .into(),
+            vec![6],
+            None,
+        );
+        let result = sort(&input, Some(-1)).unwrap();
+        let values = match result.data.get_raw_values() {
+            ScalarArray::F32Vec(x) => x,
+            _ => unreachable!(),
+        };
+        assert_eq!(&values[..5], &[f32::NEG_INFINITY, -1.0, 0.0, 2.5, 3.0]);
+        assert!(values[5].is_nan());
+
+        let result = argsort(&input, Some(0)).unwrap();
+        assert_eq!(result.dtype, Dtype::Int32);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![4i32, 2, 5, 3, 0, 1].into()
+        );
+    }
+
+    #[test]
+    fn test_sort_axis() {
+        let input = NdArray::from_slice([5i32, -2, 7, 1, 0, -3].as_ref().into(), vec![2, 3], None);
+        let result = sort(&input, Some(0)).unwrap();
+        assert_eq!(result.shape, vec![2, 3]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![1i32, -2, -3, 5, 0, 7].into()
+        );
+
+        let result = argsort(&input, Some(1)).unwrap();
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![1i32, 0, 2, 2, 1, 0].into()
+        );
+
+        let result = sort(&input, None).unwrap();
+        assert_eq!(result.shape, vec![6]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![-3i32, -2, 0, 1, 5, 7].into()
+        );
+    }
+
+    #[test]
+    fn test_sort_inplace() {
+        let input = NdArray::from_slice([3i16, -4, 2, 1].as_ref().into(), vec![2, 2], None);
+        sort_inplace(&input, -1).unwrap();
+        assert_eq!(input.data.get_raw_values(), vec![-4i16, 3, 1, 2].into());
+    }
+
+    #[test]
+    fn test_argsort_stable_many_blocks() {
+        let data = (0..1000u32).map(|x| (x * 7919) % 10).collect::<Vec<_>>();
+        let input = NdArray::from_slice(data.as_slice().into(), vec![1000], None);
+        let mut expected = (0..1000i32).collect::<Vec<_>>();
+        expected.sort_by_key(|x| data[*x as usize]);
+        let result = argsort(&input, None).unwrap();
+        assert_eq!(result.data.get_raw_values(), expected.into());
+    }
+
+    #[test]
+    fn test_partition() {
+        let input = NdArray::from_slice([3u8, 4, 2, 1].as_ref().into(), vec![4], None);
+        let result = partition(&input, &[2], Some(-1)).unwrap();
+        assert_eq!(result.dtype, Dtype::UInt8);
+        let values = match result.data.get_raw_values() {
+            ScalarArray::U8Vec(x) => x,
+            _ => unreachable!(),
+        };
+        assert_eq!(values[2], 3);
+        assert!(values[..2].iter().all(|x| *x <= 3));
+        assert!(values[2..].iter().all(|x| *x >= 3));
+        assert!(argpartition(&input, &[4], Some(0)).is_err());
+    }
+
+    #[test]
+    fn test_argpartition_axis_many_blocks() {
+        let data = (0..1200i32)
+            .map(|x| (x * 7919) % 101 - 50)
+            .collect::<Vec<_>>();
+        let input = NdArray::from_slice(data.as_slice().into(), vec![2, 600], None);
+        let kth = [5, 300, -1, 300];
+        let result = argpartition(&input, &kth, Some(1)).unwrap();
+        let indices = match result.data.get_raw_values() {
+            ScalarArray::I32Vec(x) => x,
+            _ => unreachable!(),
+        };
+        for (lane, indices) in data.chunks(600).zip(indices.chunks(600)) {
+            let values = indices
+                .iter()
+                .map(|x| lane[*x as usize])
+                .collect::<Vec<_>>();
+            let mut sorted = lane.to_vec();
+            sorted.sort();
+            for k in [5, 300, 599] {
+                assert_eq!(values[k], sorted[k]);
+                assert!(values[..k].iter().all(|x| *x <= sorted[k]));
+                assert!(values[k..].iter().all(|x| *x >= sorted[k]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_lexsort() {
+        let surnames = NdArray::from_slice([2i32, 1, 2, 0].as_ref().into(), vec![4], None);
+        let first_names =
+            NdArray::from_slice([1.0f32, 0.0, 0.0, 5.0].as_ref().into(), vec![4], None);
+        let result = lexsort(&[&first_names, &surnames], -1).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![3i32, 1, 2, 0].into());
+        assert!(lexsort(&[], -1).is_err());
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{normalize_axis, IndexSliceOp, NdArray, NdArrayError, NdArrayResult};
+
+/// Describes how [`split`] and [`array_split`] divide an axis, either into a number
+/// of sections or at the given indices
+#[derive(Debug, Clone)]
+pub enum SplitIndices {
+    Sections(u32),
+    Indices(Vec<i64>),
+}
+
+impl From<u32> for SplitIndices {
+    fn from(value: u32) -> Self {
+        SplitIndices::Sections(value)
+    }
+}
+
+impl From<Vec<i64>> for SplitIndices {
+    fn from(value: Vec<i64>) -> Self {
+        SplitIndices::Indices(value)
+    }
+}
+
+impl From<&[i64]> for SplitIndices {
+    fn from(value: &[i64]) -> Self {
+        SplitIndices::Indices(value.to_vec())
+    }
+}
+
+/// Start and stop of every sub array along an axis of the given length
+fn split_bounds(length: u32, indices: &SplitIndices) -> NdArrayResult<Vec<(i64, i64)>> {
+    let length = length as i64;
+    match indices {
+        SplitIndices::Sections(0) => Err(NdArrayError::SplitError(
+            "number sections must be larger than 0".to_string(),
+        )),
+        SplitIndices::Sections(sections) => {
+            let sections = *sections as i64;
+            let (size, extras) = (length / sections, length % sections);
+            let mut start = 0;
+            Ok((0..sections)
+                .map(|i| {
+                    let stop = start + size + if i < extras { 1 } else { 0 };
+                    let bounds = (start, stop);
+                    start = stop;
+                    bounds
+                })
+                .collect())
+        }
+        SplitIndices::Indices(indices) => {
+            let mut bounds = Vec::with_capacity(indices.len() + 1);
+            let mut start = 0;
+            for index in indices {
+                let stop = if *index < 0 { index + length } else { *index };
+                let stop = stop.clamp(0, length);
+                bounds.push((start, stop.max(start)));
+                start = stop;
+            }
+            bounds.push((start, length.max(start)));
+            Ok(bounds)
+        }
+    }
+}
+
+/// Splits an array into sub arrays along an axis, the sections need not divide
+/// the axis equally
+pub fn array_split(
+    arr: &NdArray,
+    indices_or_sections: &SplitIndices,
+    axis: i32,
+) -> NdArrayResult<Vec<NdArray>> {
+    if arr.shape.is_empty() {
+        return Err(NdArrayError::SplitError(
+            "cannot split a zero-dimensional array".to_string(),
+        ));
+    }
+    let axis = normalize_axis(axis, arr.shape.len())?;
+    let bounds = split_bounds(arr.shape[axis], indices_or_sections)?;
+
+    Ok(bounds
+        .into_iter()
+        .map(|(start, stop)| {
+            let mut slices = (0..axis)
+                .map(|_| IndexSliceOp::RangeFrom(0..))
+                .collect::<Vec<_>>();
+            slices.push((start..stop).into());
+            arr.get_items(&slices)
+        })
+        .collect())
+}
+
+/// Splits an array into sub arrays along an axis, sections must divide the axis equally
+pub fn split(
+    arr: &NdArray,
+    indices_or_sections: &SplitIndices,
+    axis: i32,
+) -> NdArrayResult<Vec<NdArray>> {
+    if let SplitIndices::Sections(sections) = indices_or_sections {
+        let length = normalize_axis(axis, arr.shape.len()).map(|x| arr.shape[x])?;
+        if *sections != 0 && length % sections != 0 {
+            return Err(NdArrayError::SplitError(
+                "array split does not result in an equal division".to_string(),
+            ));
+        }
+    }
+    array_split(arr, indices_or_sections, axis)
+}
+
+/// Splits an array column wise, 1-D arrays are split along their only axis
+pub fn hsplit(arr: &NdArray, indices_or_sections: &SplitIndices) -> NdArrayResult<Vec<NdArray>> {
+    match arr.shape.len() {
+        0 => Err(NdArrayError::SplitError(
+            "hsplit only works on arrays of 1 or more dimensions".to_string(),
+        )),
+        1 => split(arr, indices_or_sections, 0),
+        _ => split(arr, indices_or_sections, 1),
+    }
+}
+
+/// Splits an array row wise
+pub fn vsplit(arr: &NdArray, indices_or_sections: &SplitIndices) -> NdArrayResult<Vec<NdArray>> {
+    if arr.shape.len() < 2 {
+        return Err(NdArrayError::SplitError(
+            "vsplit only works on arrays of 2 or more dimensions".to_string(),
+        ));
+    }
+    split(arr, indices_or_sections, 0)
+}
+
+/// Splits an array along the third axis
+pub fn dsplit(arr: &NdArray, indices_or_sections: &SplitIndices) -> NdArrayResult<Vec<NdArray>> {
+    if arr.shape.len() < 3 {
+        return Err(NdArrayError::SplitError(
+            "dsplit only works on arrays of 3 or more dimensions".to_string(),
+        ));
+    }
+    split(arr, indices_or_sections, 2)
+}
+
+/// Splits an array into a sequence of arrays along an axis, removing that axis
+pub fn unstack(arr: &NdArray, axis: i32) -> NdArrayResult<Vec<NdArray>> {
+    if arr.shape.is_empty() {
+        return Err(NdArrayError::SplitError(
+            "input array must be at least 1-d".to_string(),
+        ));
+    }
+    let axis = normalize_axis(axis, arr.shape.len())?;
+
+    Ok((0..arr.shape[axis] as i64)
+        .map(|i| {
+            let mut slices = (0..axis)
+                .map(|_| IndexSliceOp::RangeFrom(0..))
+                .collect::<Vec<_>>();
+            slices.push(i.into());
+            arr.get_items(&slices)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_bounds() {
+        assert_eq!(
+            split_bounds(8, &3.into()).unwrap(),
+            vec![(0, 3), (3, 6), (6, 8)]
+        );
+        assert_eq!(
+            split_bounds(8, &vec![3, 5, 10].into()).unwrap(),
+            vec![(0, 3), (3, 5), (5, 8), (8, 8)]
+        );
+        assert_eq!(
+            split_bounds(8, &vec![-2, 2].into()).unwrap(),
+            vec![(0, 6), (6, 6), (2, 8)]
+        );
+        assert!(split_bounds(8, &0.into()).is_err());
+    }
+
+    #[test]
+    fn test_split() {
+        let values = (0..12).collect::<Vec<i32>>();
+        let array = NdArray::from_slice(values.as_slice().into(), vec![3, 4], None);
+
+        let result = split(&array, &2.into(), 1).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(&result[0].shape, &[3, 2]);
+        assert_eq!(
+            result[1].data.get_raw_values(),
+            vec![2, 3, 6, 7, 10, 11].into()
+        );
+
+        assert!(split(&array, &3.into(), 1).is_err());
+
+        let result = array_split(&array, &2.into(), 0).unwrap();
+        assert_eq!(&result[0].shape, &[2, 4]);
+        assert_eq!(&result[1].shape, &[1, 4]);
+        assert_eq!(result[1].data.get_raw_values(), vec![8, 9, 10, 11].into());
+    }
+
+    #[test]
+    fn test_unstack() {
+        let values = (0..6).collect::<Vec<i32>>();
+        let array = NdArray::from_slice(values.as_slice().into(), vec![2, 3], None);
+
+        let result = unstack(&array, -1).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(&result[0].shape, &[2]);
+        assert_eq!(result[2].data.get_raw_values(), vec![2, 5].into());
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{
+    array_routines::layout::{gather, GatherAxis, GatherMode},
+    NdArray, NdArrayResult,
+};
+
+/// Constructs an array by repeating `arr` the number of times given by `reps` along
+/// each axis. Shapes are padded with leading ones when the lengths differ.
+pub fn tile(arr: &NdArray, reps: &[u32]) -> NdArrayResult<NdArray> {
+    let ndim = arr.shape.len().max(reps.len());
+
+    let mut in_shape = vec![1; ndim - arr.shape.len()];
+    in_shape.extend(&arr.shape);
+    let mut full_reps = vec![1; ndim - reps.len()];
+    full_reps.extend(reps);
+
+    let axes = in_shape
+        .iter()
+        .zip(&full_reps)
+        .enumerate()
+        .map(|(i, (dim, rep))| GatherAxis::new(dim * rep, i, GatherMode::Wrap(0)))
+        .collect::<Vec<_>>();
+
+    gather(arr, &in_shape, &axes, None)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tile() {
+        let input = NdArray::from_slice([1i32, 2, 3].as_ref().into(), vec![3], None);
+        let result = tile(&input, &[2]).unwrap();
+        assert_eq!(&result.shape, &[6]);
+        assert_eq!(result.data.get_raw_values(), vec![1, 2, 3, 1, 2, 3].into());
+
+        let result = tile(&input, &[2, 2]).unwrap();
+        assert_eq!(&result.shape, &[2, 6]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3].into()
+        );
+
+        let input = NdArray::from_slice([1i32, 2, 3, 4].as_ref().into(), vec![2, 2], None);
+        let result = tile(&input, &[2]).unwrap();
+        assert_eq!(&result.shape, &[2, 4]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![1, 2, 1, 2, 3, 4, 3, 4].into()
+        );
+    }
+}
@@ -3,50 +3,54 @@ use arrow_gpu::{array::ArrowArrayGPU, kernels::merge_dyn};
 use crate::{
     broadcast::{broadcast_shape, broadcast_to},
     utils::Holder,
-    NdArray,
+    Dtype, NdArray,
 };
 
 pub fn where_(mask: &NdArray, x: &NdArray, y: &NdArray) -> NdArray {
-    //TODO broadcast mask as well
-    if let ArrowArrayGPU::BooleanArrayGPU(bool_mask) = &mask.data {
-        let broadcast_shape =
-            broadcast_shape(&mask.shape, &broadcast_shape(&x.shape, &y.shape).unwrap()).unwrap();
+    if mask.dtype != Dtype::Bool {
+        panic!("Mask is not of boolean type")
+    }
+
+    let broadcast_shape =
+        broadcast_shape(&mask.shape, &broadcast_shape(&x.shape, &y.shape).unwrap()).unwrap();
+
+    if broadcast_shape.contains(&0) {
+        return NdArray::zero_sized(broadcast_shape, x.dtype, x.get_gpu_device());
+    }
 
-        let broadcasted_x = if x.shape != broadcast_shape {
-            Holder::Owned(broadcast_to(x, &broadcast_shape))
-        } else {
-            Holder::Borrowed(x)
-        };
+    let broadcasted_x = if x.shape != broadcast_shape {
+        Holder::Owned(broadcast_to(x, &broadcast_shape))
+    } else {
+        Holder::Borrowed(x)
+    };
 
-        let broadcasted_y = if y.shape != broadcast_shape {
-            Holder::Owned(broadcast_to(y, &broadcast_shape))
-        } else {
-            Holder::Borrowed(y)
-        };
+    let broadcasted_y = if y.shape != broadcast_shape {
+        Holder::Owned(broadcast_to(y, &broadcast_shape))
+    } else {
+        Holder::Borrowed(y)
+    };
 
-        let broadcasted_mask = if mask.shape != broadcast_shape {
-            Holder::Owned(broadcast_to(mask, &broadcast_shape))
-        } else {
-            Holder::Borrowed(mask)
-        };
+    let broadcasted_mask = if mask.shape != broadcast_shape {
+        Holder::Owned(broadcast_to(mask, &broadcast_shape))
+    } else {
+        Holder::Borrowed(mask)
+    };
 
-        if let ArrowArrayGPU::BooleanArrayGPU(bool_mask) = &broadcasted_mask.as_ref().data {
-            let merged_array = merge_dyn(
-                &broadcasted_x.as_ref().data,
-                &broadcasted_y.as_ref().data,
-                bool_mask,
-            );
-            NdArray {
-                shape: x.shape.clone(),
-                dims: x.dims,
-                data: merged_array,
-                dtype: x.dtype,
-            }
-        } else {
-            unreachable!()
+    if let ArrowArrayGPU::BooleanArrayGPU(bool_mask) = &broadcasted_mask.as_ref().data {
+        let merged_array = merge_dyn(
+            &broadcasted_x.as_ref().data,
+            &broadcasted_y.as_ref().data,
+            bool_mask,
+        );
+        let dims = broadcast_shape.len() as u16;
+        NdArray {
+            shape: broadcast_shape,
+            dims,
+            data: merged_array,
+            dtype: x.dtype,
         }
     } else {
-        panic!("Mask is not of boolean type")
+        unreachable!()
     }
 }
 
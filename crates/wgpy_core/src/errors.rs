@@ -4,4 +4,10 @@ pub type NdArrayResult<T> = Result<T, NdArrayError>;
 pub enum NdArrayError {
     BroadcastError(String),
     RepeatError(String),
+    ShapeError(String),
+    DtypeError(String),
+    ConcatenateError(String),
+    AxisError(String),
+    SplitError(String),
+    ValueError(String),
 }
@@ -6,6 +6,7 @@ pub(crate) mod array_routines;
 pub(crate) mod errors;
 pub(crate) mod ndarray;
 pub(crate) mod operand;
+pub(crate) mod typed_ndarray;
 pub(crate) mod types;
 pub(crate) mod ufunc;
 pub(crate) mod utils;
@@ -15,7 +16,9 @@ pub use arrow_gpu::utils::ScalarArray;
 pub use errors::*;
 pub use ndarray::*;
 pub use operand::*;
+pub use typed_ndarray::*;
 pub use types::*;
 pub use ufunc::*;
+pub use utils::{as_f32_array, cast_if_needed, normalize_axis};
 
 pub static GPU_DEVICE: LazyLock<Arc<GpuDevice>> = LazyLock::new(|| Arc::new(GpuDevice::new()));
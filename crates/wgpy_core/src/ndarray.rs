@@ -9,7 +9,10 @@ use arrow_gpu::{
     kernels::{cast_dyn, neg_dyn, take_dyn, take_op_dyn},
 };
 
-use crate::{Dtype, IndexSlice, IndexSliceOp, Operand, ScalarArrayRef, ScalarValue, GPU_DEVICE};
+use crate::{
+    broadcast_shape, broadcast_to, normalize_axis, Dtype, IndexSlice, IndexSliceOp, NdArrayError,
+    NdArrayResult, Operand, ScalarArray, ScalarArrayRef, ScalarValue, GPU_DEVICE,
+};
 
 #[derive(Debug)]
 pub struct NdArray {
@@ -82,6 +85,9 @@ impl NdArray {
     }
 
     pub fn astype(&self, dtype: Dtype) -> Self {
+        if self.is_empty() {
+            return Self::zero_sized(self.shape.clone(), dtype, self.get_gpu_device());
+        }
         let data = cast_dyn(&self.data, (&dtype).into());
 
         Self {
@@ -92,15 +98,65 @@ impl NdArray {
         }
     }
 
+    /// Number of elements, a 0-d array holds a single element
     pub fn len(&self) -> u32 {
-        if self.shape.is_empty() {
-            0
-        } else {
-            self.shape.iter().product()
+        self.shape.iter().product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Creates an array with a zero length dimension. Kernels cant bind zero sized
+    /// buffers, so routines return this instead of dispatching.
+    pub fn zero_sized(shape: Vec<u32>, dtype: Dtype, gpu_device: Arc<GpuDevice>) -> Self {
+        let data = match dtype {
+            Dtype::Int8 => Int8ArrayGPU::from_slice(&[], gpu_device).into(),
+            Dtype::Int16 => Int16ArrayGPU::from_slice(&[], gpu_device).into(),
+            Dtype::Int32 => Int32ArrayGPU::from_slice(&[], gpu_device).into(),
+            Dtype::UInt8 => UInt8ArrayGPU::from_slice(&[], gpu_device).into(),
+            Dtype::UInt16 => UInt16ArrayGPU::from_slice(&[], gpu_device).into(),
+            Dtype::UInt32 => UInt32ArrayGPU::from_slice(&[], gpu_device).into(),
+            Dtype::Float32 => Float32ArrayGPU::from_slice(&[], gpu_device).into(),
+            Dtype::Bool => BooleanArrayGPU::from_slice(&[], gpu_device).into(),
+        };
+        let dims = shape.len() as u16;
+
+        Self {
+            shape,
+            dims,
+            data,
+            dtype,
+        }
+    }
+
+    /// Copies the only element of the array to the host
+    pub fn item(&self) -> NdArrayResult<ScalarValue> {
+        if self.len() != 1 {
+            return Err(NdArrayError::ShapeError(format!(
+                "can only convert an array of size 1 to a scalar, got shape {:?}",
+                self.shape
+            )));
         }
+
+        let value = match self.data.get_raw_values() {
+            ScalarArray::F32Vec(x) => x[0].into(),
+            ScalarArray::U32Vec(x) => x[0].into(),
+            ScalarArray::U16Vec(x) => x[0].into(),
+            ScalarArray::U8Vec(x) => x[0].into(),
+            ScalarArray::I32Vec(x) => x[0].into(),
+            ScalarArray::I16Vec(x) => x[0].into(),
+            ScalarArray::I8Vec(x) => x[0].into(),
+            ScalarArray::BOOLVec(x) => x[0].into(),
+        };
+
+        Ok(value)
     }
 
     pub fn clone_array(&self) -> Self {
+        if self.is_empty() {
+            return Self::zero_sized(self.shape.clone(), self.dtype, self.get_gpu_device());
+        }
         Self {
             shape: self.shape.clone(),
             dims: self.dims,
@@ -112,6 +168,8 @@ impl NdArray {
     pub fn take(&self, indices: &NdArray, axis: Option<u32>) -> Self {
         if axis.is_some() {
             todo!()
+        } else if indices.is_empty() {
+            Self::zero_sized(vec![0], self.dtype, self.get_gpu_device())
         } else {
             if let ArrowArrayGPU::UInt32ArrayGPU(indices_array) = &indices.data {
                 let array = take_dyn(&self.data, indices_array);
@@ -128,6 +186,9 @@ impl NdArray {
     }
 
     pub fn neg(&self) -> Self {
+        if self.is_empty() {
+            return Self::zero_sized(self.shape.clone(), self.dtype, self.get_gpu_device());
+        }
         let data = neg_dyn(&self.data);
         let dtype = data.get_dtype().into();
         Self {
@@ -143,30 +204,35 @@ impl NdArray {
             panic!("cant get items from empty slice")
         }
 
-        let mut pipeline = ArrowComputePipeline::new(self.data.get_gpu_device(), None);
-        const SHADER: &str = include_str!("../compute_shaders/u32/get_item_index.wgsl");
         let mut new_shape = Vec::with_capacity(self.shape.len());
-
-        let shape_iter = self.shape.iter();
+        let mut index_slices = Vec::with_capacity(self.shape.len());
         let mut index_slice_iter = slices.iter();
 
-        let mut initial_buffer = pipeline.device.create_empty_buffer(4);
-        let mut temp_slice;
-
-        for shape in shape_iter {
+        for shape in self.shape.iter() {
             let slice = if let Some(slice) = index_slice_iter.next() {
-                temp_slice = slice.into_index_slice(*shape);
+                let index_slice = slice.into_index_slice(*shape);
                 match slice {
                     IndexSliceOp::Index(_) => {}
-                    _ => new_shape.push(temp_slice.element_count()),
+                    _ => new_shape.push(index_slice.element_count()),
                 }
-                temp_slice
+                index_slice
             } else {
                 new_shape.push(*shape);
-                temp_slice = IndexSlice::new(0, *shape as i64, 1, *shape).unwrap();
-                temp_slice
+                IndexSlice::new(0, *shape as i64, 1, *shape).unwrap()
             };
+            index_slices.push((slice, *shape));
+        }
+
+        if new_shape.contains(&0) {
+            return Self::zero_sized(new_shape, self.dtype, self.get_gpu_device());
+        }
+
+        let mut pipeline = ArrowComputePipeline::new(self.data.get_gpu_device(), None);
+        const SHADER: &str = include_str!("../compute_shaders/u32/get_item_index.wgsl");
 
+        let mut initial_buffer = pipeline.device.create_empty_buffer(4);
+
+        for (slice, shape) in index_slices {
             let count = slice.element_count() as u64;
             let new_buffer_size = initial_buffer.size() * count;
             let dispatch_size = initial_buffer.size().div(4).div_ceil(256) as u32;
@@ -174,7 +240,7 @@ impl NdArray {
                 slice.start,
                 slice.stop,
                 slice.step as u32,
-                *shape,
+                shape,
                 count as u32,
             ]);
 
@@ -200,51 +266,212 @@ impl NdArray {
 
         pipeline.finish();
 
+        let dims = new_shape.len() as u16;
         Self {
             shape: new_shape,
-            dims: self.dims,
+            dims,
             data,
             dtype: self.dtype,
         }
     }
 
-    //TODO add flatten
+    /// Copy of the array with a new shape holding the same number of elements
+    pub(crate) fn with_shape(&self, shape: Vec<u32>) -> Self {
+        let mut new_array = self.clone_array();
+        new_array.dims = shape.len() as u16;
+        new_array.shape = shape;
+        new_array
+    }
+
+    /// Gives a new shape to the array without changing its data
+    pub fn reshape(&self, shape: Vec<u32>) -> NdArrayResult<Self> {
+        if shape.iter().product::<u32>() != self.len() {
+            return Err(NdArrayError::ShapeError(format!(
+                "cannot reshape array of shape {:?} into shape {:?}",
+                self.shape, shape
+            )));
+        }
+        Ok(self.with_shape(shape))
+    }
+
+    /// Copy of the array collapsed into one dimension
+    pub fn flatten(&self) -> Self {
+        self.with_shape(vec![self.len()])
+    }
+
+    /// Contiguous flattened array. `NdArray` has no views, so this is a copy like
+    /// [`NdArray::flatten`].
+    pub fn ravel(&self) -> Self {
+        self.flatten()
+    }
+
+    /// Removes axes of length one, all of them if axis is None
+    pub fn squeeze(&self, axis: Option<&[i32]>) -> NdArrayResult<Self> {
+        let ndim = self.shape.len();
+        let mut removed = vec![false; ndim];
+        match axis {
+            None => {
+                for (i, dim) in self.shape.iter().enumerate() {
+                    removed[i] = *dim == 1;
+                }
+            }
+            Some(axis) => {
+                for x in axis {
+                    let x = normalize_axis(*x, ndim)?;
+                    if self.shape[x] != 1 {
+                        return Err(NdArrayError::ShapeError(format!(
+                            "cannot select an axis to squeeze out which has size not equal to one, got shape {:?} and axis {}",
+                            self.shape, x
+                        )));
+                    }
+                    removed[x] = true;
+                }
+            }
+        }
+
+        let shape = self
+            .shape
+            .iter()
+            .zip(removed)
+            .filter(|(_, removed)| !removed)
+            .map(|(dim, _)| *dim)
+            .collect();
+        Ok(self.with_shape(shape))
+    }
+
+    /// Inserts axes of length one at the given positions of the expanded shape
+    pub fn expand_dims(&self, axis: &[i32]) -> NdArrayResult<Self> {
+        let ndim = self.shape.len() + axis.len();
+        let mut inserted = vec![false; ndim];
+        for x in axis {
+            let x = normalize_axis(*x, ndim)?;
+            if inserted[x] {
+                return Err(NdArrayError::AxisError(format!("repeated axis {}", x)));
+            }
+            inserted[x] = true;
+        }
+
+        let mut dims = self.shape.iter();
+        let shape = inserted
+            .iter()
+            .map(|inserted| if *inserted { 1 } else { *dims.next().unwrap() })
+            .collect();
+        Ok(self.with_shape(shape))
+    }
 }
 
+/// Broadcasts `values` into a new array of the given shape
+fn broadcast_fill(values: &NdArray, shape: Vec<u32>) -> NdArrayResult<NdArray> {
+    match broadcast_shape(&values.shape, &shape) {
+        Ok(x) if x == shape => {}
+        _ => {
+            return Err(NdArrayError::BroadcastError(format!(
+                "could not broadcast fill value of shape {:?} into shape {:?}",
+                values.shape, shape
+            )))
+        }
+    }
+    if values.shape == shape {
+        Ok(values.clone_array())
+    } else {
+        Ok(broadcast_to(values, &shape))
+    }
+}
+
+/// Creates an array filled with `value`, array values are broadcast to `shape`. Scalar
+/// values always succeed.
 pub fn full(
     shape: Vec<u32>,
     value: Operand<'_>,
     dtype: Option<Dtype>,
     gpu_device: Option<Arc<GpuDevice>>,
-) -> NdArray {
+) -> NdArrayResult<NdArray> {
+    let gpu_device = gpu_device.unwrap_or(GPU_DEVICE.clone());
     let result = match value {
         Operand::Scalar(value) => {
-            let gpu_device = gpu_device.unwrap_or(GPU_DEVICE.clone());
             let dims = shape.len() as u16;
             let len = (shape.iter().product::<u32>()) as usize;
             let dtype = (&value).into();
-            let data = broadcast_dyn(value.into(), len, gpu_device);
+            if len == 0 {
+                NdArray::zero_sized(shape, dtype, gpu_device)
+            } else {
+                let data = broadcast_dyn(value.into(), len, gpu_device);
 
-            NdArray {
-                shape,
-                dims,
-                data,
-                dtype,
+                NdArray {
+                    shape,
+                    dims,
+                    data,
+                    dtype,
+                }
             }
         }
-        Operand::ScalarArrayRef(_) => todo!(),
-        Operand::NdArrayRef(_) => todo!(),
+        Operand::ScalarArrayRef(values) => {
+            let len = values.len() as u32;
+            let values = NdArray::from_slice(values, vec![len], Some(gpu_device));
+            broadcast_fill(&values, shape)?
+        }
+        Operand::NdArrayRef(values) => broadcast_fill(values, shape)?,
     };
 
     match dtype {
-        Some(x) => {
-            if result.dtype == x {
-                result
-            } else {
-                result.astype(x)
+        Some(x) if result.dtype != x => Ok(result.astype(x)),
+        _ => Ok(result),
+    }
+}
+
+/// Creates an array without initializing its values. No kernel is dispatched, so the
+/// contents are whatever the buffer held when it was allocated.
+pub fn empty(shape: Vec<u32>, dtype: Option<Dtype>, gpu_device: Option<Arc<GpuDevice>>) -> NdArray {
+    let dtype = dtype.unwrap_or(Dtype::Float32);
+
+    let gpu_device = gpu_device.unwrap_or(GPU_DEVICE.clone());
+    let dims = shape.len() as u16;
+    let len = (shape.iter().product::<u32>()) as usize;
+    if len == 0 {
+        return NdArray::zero_sized(shape, dtype, gpu_device);
+    }
+
+    // Buffers are padded to a multiple of 4 bytes and booleans are bit packed
+    let size = match dtype {
+        Dtype::Bool => (len as u64).div_ceil(32) * 4,
+        _ => (len as u64 * dtype.item_size() as u64).div_ceil(4) * 4,
+    };
+    let buffer = Arc::new(gpu_device.create_empty_buffer(size));
+
+    macro_rules! primitive_array {
+        ($ty: ident) => {
+            $ty {
+                data: buffer,
+                gpu_device,
+                phantom: std::marker::PhantomData,
+                len,
+                null_buffer: None,
             }
+            .into()
+        };
+    }
+    let data = match dtype {
+        Dtype::Int8 => primitive_array!(Int8ArrayGPU),
+        Dtype::Int16 => primitive_array!(Int16ArrayGPU),
+        Dtype::Int32 => primitive_array!(Int32ArrayGPU),
+        Dtype::UInt8 => primitive_array!(UInt8ArrayGPU),
+        Dtype::UInt16 => primitive_array!(UInt16ArrayGPU),
+        Dtype::UInt32 => primitive_array!(UInt32ArrayGPU),
+        Dtype::Float32 => primitive_array!(Float32ArrayGPU),
+        Dtype::Bool => BooleanArrayGPU {
+            data: buffer,
+            gpu_device,
+            len,
+            null_buffer: None,
         }
-        None => result,
+        .into(),
+    };
+
+    NdArray {
+        shape,
+        dims,
+        data,
+        dtype,
     }
 }
 
@@ -254,6 +481,9 @@ pub fn zeros(shape: Vec<u32>, dtype: Option<Dtype>, gpu_device: Option<Arc<GpuDe
     let gpu_device = gpu_device.unwrap_or(GPU_DEVICE.clone());
     let dims = shape.len() as u16;
     let len = (shape.iter().product::<u32>()) as usize;
+    if len == 0 {
+        return NdArray::zero_sized(shape, dtype, gpu_device);
+    }
     let data = broadcast_dyn(ScalarValue::zero(&dtype).into(), len, gpu_device);
 
     NdArray {
@@ -270,6 +500,9 @@ pub fn ones(shape: Vec<u32>, dtype: Option<Dtype>, gpu_device: Option<Arc<GpuDev
     let gpu_device = gpu_device.unwrap_or(GPU_DEVICE.clone());
     let dims = shape.len() as u16;
     let len = (shape.iter().product::<u32>()) as usize;
+    if len == 0 {
+        return NdArray::zero_sized(shape, dtype, gpu_device);
+    }
     let data = broadcast_dyn(ScalarValue::one(&dtype).into(), len, gpu_device);
 
     NdArray {
@@ -280,6 +513,52 @@ pub fn ones(shape: Vec<u32>, dtype: Option<Dtype>, gpu_device: Option<Arc<GpuDev
     }
 }
 
+/// Uninitialized array with the same shape as `arr`, see [`empty`]
+pub fn empty_like(arr: &NdArray, dtype: Option<Dtype>) -> NdArray {
+    empty(
+        arr.shape.clone(),
+        Some(dtype.unwrap_or(arr.dtype)),
+        Some(arr.get_gpu_device()),
+    )
+}
+
+/// Array of zeros with the same shape as `arr`
+pub fn zeros_like(arr: &NdArray, dtype: Option<Dtype>) -> NdArray {
+    zeros(
+        arr.shape.clone(),
+        Some(dtype.unwrap_or(arr.dtype)),
+        Some(arr.get_gpu_device()),
+    )
+}
+
+/// Array of ones with the same shape as `arr`
+pub fn ones_like(arr: &NdArray, dtype: Option<Dtype>) -> NdArray {
+    ones(
+        arr.shape.clone(),
+        Some(dtype.unwrap_or(arr.dtype)),
+        Some(arr.get_gpu_device()),
+    )
+}
+
+/// Array filled with `value` with the same shape as `arr`, see [`full`]
+pub fn full_like(
+    arr: &NdArray,
+    value: Operand<'_>,
+    dtype: Option<Dtype>,
+) -> NdArrayResult<NdArray> {
+    full(
+        arr.shape.clone(),
+        value,
+        Some(dtype.unwrap_or(arr.dtype)),
+        Some(arr.get_gpu_device()),
+    )
+}
+
+/// Copy of an array
+pub fn copy(arr: &NdArray) -> NdArray {
+    arr.clone_array()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -357,4 +636,115 @@ mod test {
             assert_eq!(items.data.get_raw_values(), result.into());
         }
     }
+
+    #[test]
+    fn test_zero_dim() {
+        let array = NdArray::from_slice([5i32].as_ref().into(), vec![], None);
+        assert_eq!(array.len(), 1);
+        assert!(!array.is_empty());
+        assert!(matches!(array.item().unwrap(), ScalarValue::I32(5)));
+
+        let array = NdArray::from_slice([1.0f32, 2.0].as_ref().into(), vec![2], None);
+        assert!(array.item().is_err());
+    }
+
+    #[test]
+    fn test_zero_sized() {
+        let array = zeros(vec![2, 0], Some(Dtype::Float32), None);
+        assert!(array.is_empty());
+        assert_eq!(&array.neg().shape, &[2, 0]);
+
+        let values = (0..6).collect::<Vec<i32>>();
+        let array = NdArray::from_slice(values.as_slice().into(), vec![2, 3], None);
+        let items = array.get_items(&[(1..1).into()]);
+        assert_eq!(&items.shape, &[0, 3]);
+        assert!(items.is_empty());
+        assert_eq!(items.dtype, Dtype::Int32);
+    }
+
+    #[test]
+    fn test_reshape() {
+        let values = (0..6).collect::<Vec<i32>>();
+        let array = NdArray::from_slice(values.as_slice().into(), vec![2, 3], None);
+
+        let result = array.reshape(vec![3, 1, 2]).unwrap();
+        assert_eq!(&result.shape, &[3, 1, 2]);
+        assert_eq!(result.dims, 3);
+        assert!(array.reshape(vec![4]).is_err());
+
+        let result = array.flatten();
+        assert_eq!(&result.shape, &[6]);
+        assert_eq!(result.dims, 1);
+        assert_eq!(result.data.get_raw_values(), values.into());
+    }
+
+    #[test]
+    fn test_squeeze_expand_dims() {
+        let values = (0..6).collect::<Vec<i32>>();
+        let array = NdArray::from_slice(values.as_slice().into(), vec![1, 2, 1, 3], None);
+
+        assert_eq!(&array.squeeze(None).unwrap().shape, &[2, 3]);
+        assert_eq!(&array.squeeze(Some(&[-2])).unwrap().shape, &[1, 2, 3]);
+        assert!(array.squeeze(Some(&[1])).is_err());
+
+        let array = array.squeeze(None).unwrap();
+        let result = array.expand_dims(&[0, -1]).unwrap();
+        assert_eq!(&result.shape, &[1, 2, 3, 1]);
+        assert_eq!(result.dims, 4);
+        assert_eq!(&array.expand_dims(&[1]).unwrap().shape, &[2, 1, 3]);
+        assert!(array.expand_dims(&[0, 0]).is_err());
+        assert!(array.expand_dims(&[3]).is_err());
+    }
+
+    #[test]
+    fn test_full_array() {
+        let values = NdArray::from_slice([1, 2, 3].as_slice().into(), vec![3], None);
+        let result = full(vec![2, 3], (&values).into(), None, None).unwrap();
+        assert_eq!(&result.shape, &[2, 3]);
+        assert_eq!(result.data.get_raw_values(), vec![1, 2, 3, 1, 2, 3].into());
+
+        let values = [1.0f32, 2.0];
+        let result = full(
+            vec![2, 1, 2],
+            ScalarArrayRef::from(values.as_slice()).into(),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(result.dtype, Dtype::Float32);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![1.0f32, 2.0, 1.0, 2.0].into()
+        );
+    }
+
+    #[test]
+    fn test_full_array_mismatch() {
+        let values = NdArray::from_slice([1, 2, 3].as_slice().into(), vec![3], None);
+        assert!(full(vec![2, 2], (&values).into(), None, None).is_err());
+    }
+
+    #[test]
+    fn test_like() {
+        let values = NdArray::from_slice([1i16, 2, 3, 4].as_slice().into(), vec![2, 2], None);
+
+        let result = zeros_like(&values, None);
+        assert_eq!(result.dtype, Dtype::Int16);
+        assert_eq!(result.data.get_raw_values(), vec![0i16; 4].into());
+
+        let result = ones_like(&values, Some(Dtype::Float32));
+        assert_eq!(&result.shape, &[2, 2]);
+        assert_eq!(result.data.get_raw_values(), vec![1.0f32; 4].into());
+
+        let result = full_like(&values, ScalarValue::I16(7).into(), None).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![7i16; 4].into());
+
+        let result = empty_like(&values, Some(Dtype::UInt8));
+        assert_eq!(&result.shape, &[2, 2]);
+        assert_eq!(result.dtype, Dtype::UInt8);
+        assert_eq!(result.data.len(), 4);
+
+        let result = copy(&values);
+        assert_eq!(result.data.get_raw_values(), values.data.get_raw_values());
+    }
 }
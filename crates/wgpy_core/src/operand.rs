@@ -2,7 +2,7 @@ use crate::{Dtype, NdArray};
 
 use arrow_gpu::kernels::ScalarValue as ArrowScalarValue;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ScalarValue {
     F32(f32),
     U32(u32),
@@ -40,6 +40,34 @@ impl ScalarValue {
             Dtype::Bool => ScalarValue::BOOL(true),
         }
     }
+
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            ScalarValue::F32(x) => x as f64,
+            ScalarValue::U32(x) => x as f64,
+            ScalarValue::U16(x) => x as f64,
+            ScalarValue::U8(x) => x as f64,
+            ScalarValue::I32(x) => x as f64,
+            ScalarValue::I16(x) => x as f64,
+            ScalarValue::I8(x) => x as f64,
+            ScalarValue::BOOL(x) => x as u8 as f64,
+        }
+    }
+
+    /// Converts the value to the given dtype, saturating when it is out of range
+    pub fn cast(&self, dtype: &Dtype) -> Self {
+        let value = self.as_f64();
+        match *dtype {
+            Dtype::Int8 => ScalarValue::I8(value as i8),
+            Dtype::Int16 => ScalarValue::I16(value as i16),
+            Dtype::Int32 => ScalarValue::I32(value as i32),
+            Dtype::UInt8 => ScalarValue::U8(value as u8),
+            Dtype::UInt16 => ScalarValue::U16(value as u16),
+            Dtype::UInt32 => ScalarValue::U32(value as u32),
+            Dtype::Float32 => ScalarValue::F32(value as f32),
+            Dtype::Bool => ScalarValue::BOOL(value != 0.0),
+        }
+    }
 }
 
 macro_rules! impl_into_scalarvalue {
@@ -73,6 +101,25 @@ pub enum ScalarArrayRef<'a> {
     BOOLARRAY(&'a [bool]),
 }
 
+impl ScalarArrayRef<'_> {
+    pub fn len(&self) -> usize {
+        match self {
+            ScalarArrayRef::F32ARRAY(x) => x.len(),
+            ScalarArrayRef::U32ARRAY(x) => x.len(),
+            ScalarArrayRef::U16ARRAY(x) => x.len(),
+            ScalarArrayRef::U8ARRAY(x) => x.len(),
+            ScalarArrayRef::I32ARRAY(x) => x.len(),
+            ScalarArrayRef::I16ARRAY(x) => x.len(),
+            ScalarArrayRef::I8ARRAY(x) => x.len(),
+            ScalarArrayRef::BOOLARRAY(x) => x.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 macro_rules! impl_into_scalararrayref {
     ($ty: ident, $saty: ident) => {
         impl<'a> From<&'a [$ty]> for ScalarArrayRef<'a> {
@@ -0,0 +1,224 @@
+use std::{fmt::Debug, marker::PhantomData, ops::Deref, sync::Arc};
+
+use arrow_gpu::{gpu_utils::GpuDevice, utils::ScalarArray};
+
+use crate::{Dtype, NdArray, NdArrayError, NdArrayResult, ScalarArrayRef, ScalarValue};
+
+/// Rust primitive that can be stored in an [`NdArray`]
+pub trait Element: Copy + Debug + Send + Sync + Into<ScalarValue> + 'static {
+    const DTYPE: Dtype;
+
+    fn as_scalar_array_ref(values: &[Self]) -> ScalarArrayRef<'_>;
+
+    fn from_scalar_array(values: ScalarArray) -> Option<Vec<Self>>;
+}
+
+macro_rules! impl_element {
+    ($ty: ident, $dtype: ident, $saty: ident, $vecty: ident) => {
+        impl Element for $ty {
+            const DTYPE: Dtype = Dtype::$dtype;
+
+            fn as_scalar_array_ref(values: &[Self]) -> ScalarArrayRef<'_> {
+                ScalarArrayRef::$saty(values)
+            }
+
+            fn from_scalar_array(values: ScalarArray) -> Option<Vec<Self>> {
+                match values {
+                    ScalarArray::$vecty(x) => Some(x),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+impl_element!(f32, Float32, F32ARRAY, F32Vec);
+impl_element!(u32, UInt32, U32ARRAY, U32Vec);
+impl_element!(u16, UInt16, U16ARRAY, U16Vec);
+impl_element!(u8, UInt8, U8ARRAY, U8Vec);
+impl_element!(i32, Int32, I32ARRAY, I32Vec);
+impl_element!(i16, Int16, I16ARRAY, I16Vec);
+impl_element!(i8, Int8, I8ARRAY, I8Vec);
+impl_element!(bool, Bool, BOOLARRAY, BOOLVec);
+
+/// Statically typed view over an [`NdArray`] whose dtype is known to be `T`
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct TypedNdArray<T: Element> {
+    array: NdArray,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Element> TypedNdArray<T> {
+    pub fn from_slice(
+        values: &[T],
+        shape: Vec<u32>,
+        gpu_device: Option<Arc<GpuDevice>>,
+    ) -> NdArrayResult<Self> {
+        let count = shape.iter().product::<u32>() as usize;
+        if count != values.len() {
+            return Err(NdArrayError::ShapeError(format!(
+                "cannot create array of shape {:?} from {} values",
+                shape,
+                values.len()
+            )));
+        }
+
+        Ok(Self {
+            array: NdArray::from_slice(T::as_scalar_array_ref(values), shape, gpu_device),
+            phantom: PhantomData,
+        })
+    }
+
+    pub fn from_vec(values: Vec<T>, shape: Vec<u32>) -> NdArrayResult<Self> {
+        Self::from_slice(&values, shape, None)
+    }
+
+    /// Copies the values back to the host in row major order
+    pub fn to_vec(&self) -> Vec<T> {
+        T::from_scalar_array(self.array.data.get_raw_values())
+            .expect("TypedNdArray data does not match its dtype")
+    }
+
+    pub fn shape(&self) -> &[u32] {
+        &self.array.shape
+    }
+
+    pub fn as_ndarray(&self) -> &NdArray {
+        &self.array
+    }
+
+    pub fn into_ndarray(self) -> NdArray {
+        self.array
+    }
+}
+
+impl<T: Element> Deref for TypedNdArray<T> {
+    type Target = NdArray;
+
+    fn deref(&self) -> &Self::Target {
+        &self.array
+    }
+}
+
+impl<T: Element> FromIterator<T> for TypedNdArray<T> {
+    /// Creates a 1-D array from the iterator
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values = iter.into_iter().collect::<Vec<T>>();
+        let shape = vec![values.len() as u32];
+        Self {
+            array: NdArray::from_slice(T::as_scalar_array_ref(&values), shape, None),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Element> TryFrom<NdArray> for TypedNdArray<T> {
+    type Error = NdArrayError;
+
+    fn try_from(array: NdArray) -> Result<Self, Self::Error> {
+        if array.dtype == T::DTYPE {
+            Ok(Self {
+                array,
+                phantom: PhantomData,
+            })
+        } else {
+            Err(NdArrayError::DtypeError(format!(
+                "cannot view array of dtype {:?} as {:?}",
+                array.dtype,
+                T::DTYPE
+            )))
+        }
+    }
+}
+
+impl<T: Element> From<TypedNdArray<T>> for NdArray {
+    fn from(value: TypedNdArray<T>) -> Self {
+        value.array
+    }
+}
+
+#[cfg(feature = "ndarray")]
+mod ndarray_conversions {
+    use std::sync::Arc;
+
+    use arrow_gpu::gpu_utils::GpuDevice;
+    use ndarray::{ArrayBase, ArrayD, Data, Dimension, IxDyn};
+
+    use super::{Element, TypedNdArray};
+
+    impl<T: Element> TypedNdArray<T> {
+        pub fn from_ndarray<S, D>(
+            array: &ArrayBase<S, D>,
+            gpu_device: Option<Arc<GpuDevice>>,
+        ) -> Self
+        where
+            S: Data<Elem = T>,
+            D: Dimension,
+        {
+            let shape = array.shape().iter().map(|x| *x as u32).collect();
+            let values = array.iter().copied().collect::<Vec<T>>();
+            Self::from_slice(&values, shape, gpu_device).unwrap()
+        }
+
+        pub fn to_ndarray(&self) -> ArrayD<T> {
+            let shape = self.shape().iter().map(|x| *x as usize).collect::<Vec<_>>();
+            ArrayD::from_shape_vec(IxDyn(&shape), self.to_vec()).unwrap()
+        }
+    }
+
+    impl<T: Element> From<&ArrayD<T>> for TypedNdArray<T> {
+        fn from(value: &ArrayD<T>) -> Self {
+            Self::from_ndarray(value, None)
+        }
+    }
+
+    impl<T: Element> From<&TypedNdArray<T>> for ArrayD<T> {
+        fn from(value: &TypedNdArray<T>) -> Self {
+            value.to_ndarray()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_typed_from_vec() {
+        let array = TypedNdArray::from_vec(vec![1.0f32, 2.0, 3.0, 4.0], vec![2, 2]).unwrap();
+        assert_eq!(array.shape(), &[2, 2]);
+        assert_eq!(array.dtype, Dtype::Float32);
+        assert_eq!(array.to_vec(), vec![1.0f32, 2.0, 3.0, 4.0]);
+
+        assert!(TypedNdArray::from_vec(vec![1u32, 2, 3], vec![2, 2]).is_err());
+    }
+
+    #[test]
+    fn test_typed_from_iter() {
+        let array = (0..5).collect::<TypedNdArray<i32>>();
+        assert_eq!(array.shape(), &[5]);
+        assert_eq!(array.to_vec(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_typed_try_from() {
+        let array = NdArray::from_slice([1u8, 2, 3].as_ref().into(), vec![3], None);
+        let typed = TypedNdArray::<u8>::try_from(array).unwrap();
+        assert_eq!(typed.to_vec(), vec![1u8, 2, 3]);
+
+        let array = NdArray::from_slice([1u8, 2, 3].as_ref().into(), vec![3], None);
+        assert!(TypedNdArray::<f32>::try_from(array).is_err());
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_typed_ndarray_roundtrip() {
+        let host =
+            ndarray::ArrayD::from_shape_vec(ndarray::IxDyn(&[2, 3]), vec![1i16, 2, 3, 4, 5, 6])
+                .unwrap();
+        let typed = TypedNdArray::from(&host);
+        assert_eq!(typed.shape(), &[2, 3]);
+        assert_eq!(ndarray::ArrayD::from(&typed), host);
+    }
+}
@@ -89,9 +89,62 @@ impl<'a> From<&'a Dtype> for &'a ArrowType {
             Dtype::Int32 => &ArrowType::Int32Type,
             Dtype::Int16 => &ArrowType::Int16Type,
             Dtype::Int8 => &ArrowType::Int8Type,
-            _ => panic!("Unsupported type"),
+            Dtype::Bool => &ArrowType::BooleanType,
+        }
+    }
+}
+
+impl Dtype {
+    /// Size of a single element in bytes
+    pub fn item_size(&self) -> u32 {
+        match self {
+            Dtype::Int8 | Dtype::UInt8 | Dtype::Bool => 1,
+            Dtype::Int16 | Dtype::UInt16 => 2,
+            Dtype::Int32 | Dtype::UInt32 | Dtype::Float32 => 4,
+        }
+    }
+
+    pub fn is_signed_int(&self) -> bool {
+        matches!(self, Dtype::Int8 | Dtype::Int16 | Dtype::Int32)
+    }
+
+    pub fn is_unsigned_int(&self) -> bool {
+        matches!(self, Dtype::UInt8 | Dtype::UInt16 | Dtype::UInt32)
+    }
+
+    /// Smallest dtype both dtypes can be safely cast to, following numpy's promotion
+    /// rules. Types wider than 32 bits are not supported, so they fall back to Float32.
+    pub fn promote(self, other: Dtype) -> Dtype {
+        match (self, other) {
+            (x, y) if x == y => x,
+            (Dtype::Bool, x) | (x, Dtype::Bool) => x,
+            (Dtype::Float32, _) | (_, Dtype::Float32) => Dtype::Float32,
+            (x, y) if x.is_signed_int() == y.is_signed_int() => {
+                if x.item_size() > y.item_size() {
+                    x
+                } else {
+                    y
+                }
+            }
+            (x, y) => {
+                let (signed, unsigned) = if x.is_signed_int() { (x, y) } else { (y, x) };
+                if signed.item_size() > unsigned.item_size() {
+                    signed
+                } else {
+                    match unsigned {
+                        Dtype::UInt8 => Dtype::Int16,
+                        Dtype::UInt16 => Dtype::Int32,
+                        _ => Dtype::Float32,
+                    }
+                }
+            }
         }
     }
+
+    /// Promotes all the dtypes to a common dtype
+    pub fn result_type(dtypes: &[Dtype]) -> Option<Dtype> {
+        dtypes.iter().copied().reduce(Dtype::promote)
+    }
 }
 
 impl<'a> From<&'a ScalarValue> for Dtype {
@@ -153,6 +206,10 @@ impl IndexSlice {
     }
 
     pub fn element_count(&self) -> u32 {
+        if (self.step > 0 && self.start >= self.stop) || (self.step < 0 && self.start <= self.stop)
+        {
+            return 0;
+        }
         let step = (self.step).abs() as u32;
         let diff = (self.start).abs_diff(self.stop);
         let div_result = diff / step;
@@ -6,22 +6,47 @@ use arrow_gpu::{
 
 use crate::{broadcast_shape, broadcast_to_op, Dtype, NdArray, ScalarValue};
 
-pub fn ufunc_nin1_nout1<'a, F>(
+/// Dtype of the result of a ufunc given the dtypes of its inputs, used to build zero-size
+/// results without running a kernel
+pub type ResultDtype = fn(&[Dtype]) -> Dtype;
+
+/// The inputs are promoted to a common dtype, as arithmetic ufuncs do
+pub fn promoted_dtype(dtypes: &[Dtype]) -> Dtype {
+    Dtype::result_type(dtypes).unwrap()
+}
+
+/// Comparison and predicate ufuncs always return booleans
+pub fn bool_dtype(_: &[Dtype]) -> Dtype {
+    Dtype::Bool
+}
+
+/// Ufuncs computed in floating point always return Float32
+pub fn float_dtype(_: &[Dtype]) -> Dtype {
+    Dtype::Float32
+}
+
+pub fn ufunc_nin1_nout1<F>(
     dyn_function: F,
-    ndarray: &'a NdArray,
-    where_: Option<&'a NdArray>,
+    result_dtype: ResultDtype,
+    ndarray: &NdArray,
+    where_: Option<&NdArray>,
     dtype: Option<Dtype>,
 ) -> NdArray
 where
-    F: FnOnce(&'a ArrowArrayGPU, &mut ArrowComputePipeline) -> ArrowArrayGPU,
+    F: for<'b> FnOnce(&'b ArrowArrayGPU, &mut ArrowComputePipeline) -> ArrowArrayGPU,
 {
+    if ndarray.is_empty() {
+        let dtype = dtype.unwrap_or_else(|| result_dtype(&[ndarray.dtype]));
+        return NdArray::zero_sized(ndarray.shape.clone(), dtype, ndarray.get_gpu_device());
+    }
+
     let mut pipeline = ArrowComputePipeline::new(ndarray.data.get_gpu_device(), None);
     let mut new_gpu_array = dyn_function(&ndarray.data, &mut pipeline);
 
     if let Some(mask) = where_ {
         if let ArrowArrayGPU::BooleanArrayGPU(mask) = &mask.data {
             let zero_array = broadcast_op_dyn(
-                ScalarValue::zero(&ndarray.dtype).into(),
+                ScalarValue::zero(&new_gpu_array.get_dtype().into()).into(),
                 ndarray.len() as usize,
                 &mut pipeline,
             );
@@ -47,6 +72,7 @@ where
 // We have to use Pix<Box> here else the code wont compile
 pub fn ufunc_nin2_nout1<'a, F>(
     dyn_function: F,
+    result_dtype: ResultDtype,
     ndarray1: &'a NdArray,
     ndarray2: &'a NdArray,
     where_: Option<&'a NdArray>,
@@ -59,8 +85,13 @@ where
         &mut ArrowComputePipeline,
     ) -> ArrowArrayGPU,
 {
-    let mut pipeline = ArrowComputePipeline::new(ndarray1.data.get_gpu_device(), None);
     let broadcasted_shape = broadcast_shape(&ndarray1.shape, &ndarray2.shape).unwrap();
+    if broadcasted_shape.contains(&0) {
+        let dtype = dtype.unwrap_or_else(|| result_dtype(&[ndarray1.dtype, ndarray2.dtype]));
+        return NdArray::zero_sized(broadcasted_shape, dtype, ndarray1.get_gpu_device());
+    }
+
+    let mut pipeline = ArrowComputePipeline::new(ndarray1.data.get_gpu_device(), None);
     let mut in1 = ndarray1;
     let temp1;
     if ndarray1.shape != broadcasted_shape {
@@ -107,13 +138,16 @@ where
 #[macro_export]
 macro_rules! ufunc_nin2_nout1_body {
     ($name: ident, $dyn: ident) => {
+        $crate::ufunc_nin2_nout1_body!($name, $dyn, $crate::promoted_dtype);
+    };
+    ($name: ident, $dyn: ident, $result_dtype: path) => {
         pub fn $name(
             input1: &NdArray,
             input2: &NdArray,
             where_: Option<&NdArray>,
             dtype: Option<Dtype>,
         ) -> NdArray {
-            ufunc_nin2_nout1($dyn, input1, input2, where_, dtype)
+            ufunc_nin2_nout1($dyn, $result_dtype, input1, input2, where_, dtype)
         }
     };
 }
@@ -121,8 +155,63 @@ macro_rules! ufunc_nin2_nout1_body {
 #[macro_export]
 macro_rules! ufunc_nin1_nout1_body {
     ($name: ident, $dyn: ident) => {
+        $crate::ufunc_nin1_nout1_body!($name, $dyn, $crate::promoted_dtype);
+    };
+    ($name: ident, $dyn: ident, $result_dtype: path) => {
         pub fn $name(ndarray: &NdArray, where_: Option<&NdArray>, dtype: Option<Dtype>) -> NdArray {
-            ufunc_nin1_nout1($dyn, ndarray, where_, dtype)
+            ufunc_nin1_nout1($dyn, $result_dtype, ndarray, where_, dtype)
+        }
+    };
+}
+
+/// Ufunc which first calls `$check` with its name and the input dtypes, returning its
+/// error for dtypes without a kernel
+#[macro_export]
+macro_rules! ufunc_nin2_nout1_checked_body {
+    ($name: ident, $dyn: ident, $check: ident) => {
+        $crate::ufunc_nin2_nout1_checked_body!($name, $dyn, $check, $crate::promoted_dtype);
+    };
+    ($name: ident, $dyn: ident, $check: ident, $result_dtype: path) => {
+        pub fn $name(
+            input1: &NdArray,
+            input2: &NdArray,
+            where_: Option<&NdArray>,
+            dtype: Option<Dtype>,
+        ) -> NdArrayResult<NdArray> {
+            $check(stringify!($name), &[input1.dtype, input2.dtype])?;
+            Ok(ufunc_nin2_nout1(
+                $dyn,
+                $result_dtype,
+                input1,
+                input2,
+                where_,
+                dtype,
+            ))
+        }
+    };
+}
+
+/// Ufunc which first calls `$check` with its name and the input dtype, returning its
+/// error for dtypes without a kernel
+#[macro_export]
+macro_rules! ufunc_nin1_nout1_checked_body {
+    ($name: ident, $dyn: ident, $check: ident) => {
+        $crate::ufunc_nin1_nout1_checked_body!($name, $dyn, $check, $crate::promoted_dtype);
+    };
+    ($name: ident, $dyn: ident, $check: ident, $result_dtype: path) => {
+        pub fn $name(
+            ndarray: &NdArray,
+            where_: Option<&NdArray>,
+            dtype: Option<Dtype>,
+        ) -> NdArrayResult<NdArray> {
+            $check(stringify!($name), &[ndarray.dtype])?;
+            Ok(ufunc_nin1_nout1(
+                $dyn,
+                $result_dtype,
+                ndarray,
+                where_,
+                dtype,
+            ))
         }
     };
 }
@@ -1,3 +1,14 @@
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{ArrowArrayGPU, ArrowType, Float32ArrayGPU, UInt32ArrayGPU},
+    gpu_utils::{ArrowComputePipeline, GpuDevice},
+    kernels::{bitcast_op_dyn, cast_op_dyn},
+};
+use wgpu::Buffer;
+
+use crate::{Dtype, NdArray, NdArrayError, NdArrayResult};
+
 pub enum Holder<'a, B> {
     Borrowed(&'a B),
     Owned(B),
@@ -11,3 +22,83 @@ impl<'a, T> AsRef<T> for Holder<'a, T> {
         }
     }
 }
+
+/// Returns the array cast to `arrow_type`, the cast is skipped when it already has it.
+/// `casted` holds the casted array so the returned reference can outlive the call.
+pub fn cast_if_needed<'a>(
+    data: &'a ArrowArrayGPU,
+    arrow_type: &ArrowType,
+    casted: &'a mut Option<ArrowArrayGPU>,
+    pipeline: &mut ArrowComputePipeline,
+) -> &'a ArrowArrayGPU {
+    if Dtype::from(data.get_dtype()) == Dtype::from(arrow_type) {
+        return data;
+    }
+    casted.insert(cast_op_dyn(data, arrow_type, pipeline))
+}
+
+/// Returns the array as f32, casting it within the pipeline when required
+pub fn as_f32_array<'a>(
+    data: &'a ArrowArrayGPU,
+    casted: &'a mut Option<ArrowArrayGPU>,
+    pipeline: &mut ArrowComputePipeline,
+) -> &'a Float32ArrayGPU {
+    match cast_if_needed(data, &ArrowType::Float32Type, casted, pipeline) {
+        ArrowArrayGPU::Float32ArrayGPU(x) => x,
+        _ => unreachable!(),
+    }
+}
+
+/// Wraps a buffer of indexes generated by a kernel into an array
+pub(crate) fn into_index_array(
+    buffer: Buffer,
+    len: u32,
+    gpu_device: Arc<GpuDevice>,
+) -> UInt32ArrayGPU {
+    UInt32ArrayGPU {
+        data: Arc::new(buffer),
+        gpu_device,
+        phantom: std::marker::PhantomData,
+        len: len as usize,
+        null_buffer: None,
+    }
+}
+
+/// Wraps kernel generated u32 indexes into an int32 array of the given shape
+pub(crate) fn into_int32_array(
+    indices: UInt32ArrayGPU,
+    shape: Vec<u32>,
+    pipeline: &mut ArrowComputePipeline,
+) -> NdArray {
+    let data = bitcast_op_dyn(&indices.into(), &ArrowType::Int32Type, pipeline);
+    NdArray {
+        dims: shape.len() as u16,
+        shape,
+        data,
+        dtype: Dtype::Int32,
+    }
+}
+
+/// Copies a small buffer of u32 values computed by a finished pipeline to the host
+pub(crate) fn read_u32_buffer(buffer: &Buffer, gpu_device: &GpuDevice) -> Vec<u32> {
+    gpu_device
+        .retrive_data(buffer)
+        .chunks_exact(4)
+        .map(|x| u32::from_ne_bytes([x[0], x[1], x[2], x[3]]))
+        .collect()
+}
+
+/// Converts a possibly negative axis into an index in `0..ndim`
+pub fn normalize_axis(axis: i32, ndim: usize) -> NdArrayResult<usize> {
+    let ndim_i = ndim as i32;
+    if axis >= ndim_i || axis < -ndim_i {
+        Err(NdArrayError::AxisError(format!(
+            "axis {} is out of bounds for array of dimension {}",
+            axis, ndim
+        )))
+    } else if axis < 0 {
+        Ok((axis + ndim_i) as usize)
+    } else {
+        Ok(axis as usize)
+    }
+}
@@ -0,0 +1,17 @@
+// Booleans are bit packed, so every invocation handles a word of 32 values
+@group(0) @binding(0)
+var<storage, read> input_1 : array<u32>;
+
+@group(0) @binding(1)
+var<storage, read> input_2 : array<u32>;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<u32>;
+
+@compute
+@workgroup_size(256)
+fn logical_xor_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&output) {
+        output[global_id.x] = input_1[global_id.x] ^ input_2[global_id.x];
+    }
+}
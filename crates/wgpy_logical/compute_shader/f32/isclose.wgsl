@@ -0,0 +1,53 @@
+// The output is a bit packed boolean array, so every invocation handles a word of 32 values
+struct IscloseParams {
+    rtol: f32,
+    atol: f32,
+    equal_nan: u32,
+    len: u32,
+}
+
+@group(0) @binding(0)
+var<storage, read> input_1 : array<f32>;
+
+@group(0) @binding(1)
+var<storage, read> input_2 : array<f32>;
+
+@group(0) @binding(2)
+var<storage, read> params : IscloseParams;
+
+@group(0) @binding(3)
+var<storage, read_write> output : array<u32>;
+
+fn is_nan(x: f32) -> bool {
+    return (bitcast<u32>(x) & 0x7fffffffu) > 0x7f800000u;
+}
+
+fn is_inf(x: f32) -> bool {
+    return (bitcast<u32>(x) & 0x7fffffffu) == 0x7f800000u;
+}
+
+// Same as numpy, the tolerance is relative to the second input
+fn isclose(a: f32, b: f32) -> bool {
+    if is_nan(a) || is_nan(b) {
+        return params.equal_nan != 0u && is_nan(a) && is_nan(b);
+    }
+    if is_inf(a) || is_inf(b) {
+        return a == b;
+    }
+    return abs(a - b) <= params.atol + params.rtol * abs(b);
+}
+
+@compute
+@workgroup_size(256)
+fn isclose_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&output) {
+        var word = 0u;
+        for (var bit = 0u; bit < 32u; bit++) {
+            let index = global_id.x * 32u + bit;
+            if index < params.len && isclose(input_1[index], input_2[index]) {
+                word |= 1u << bit;
+            }
+        }
+        output[global_id.x] = word;
+    }
+}
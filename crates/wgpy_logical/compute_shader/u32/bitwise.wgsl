@@ -0,0 +1,55 @@
+// Signed integers are passed as their bit patterns
+@group(0) @binding(0)
+var<storage, read> input_1 : array<u32>;
+
+@group(0) @binding(1)
+var<storage, read> input_2 : array<u32>;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<u32>;
+
+@compute
+@workgroup_size(256)
+fn bitwise_xor_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        output[global_id.x] = input_1[global_id.x] ^ input_2[global_id.x];
+    }
+}
+
+// Shifting by the bit width or more gives zero, negative shifts also give zero
+@compute
+@workgroup_size(256)
+fn left_shift_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let shift = input_2[global_id.x];
+        if shift >= 32u {
+            output[global_id.x] = 0u;
+        } else {
+            output[global_id.x] = input_1[global_id.x] << shift;
+        }
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn right_shift_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let shift = input_2[global_id.x];
+        if shift >= 32u {
+            output[global_id.x] = 0u;
+        } else {
+            output[global_id.x] = input_1[global_id.x] >> shift;
+        }
+    }
+}
+
+// Arithmetic shift, large shifts leave only the sign bits
+@compute
+@workgroup_size(256)
+fn right_shift_signed_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let value = bitcast<i32>(input_1[global_id.x]);
+        let shift = min(input_2[global_id.x], 31u);
+        output[global_id.x] = bitcast<u32>(value >> shift);
+    }
+}
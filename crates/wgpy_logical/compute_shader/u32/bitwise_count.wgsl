@@ -0,0 +1,24 @@
+// Signed integers are passed as their bit patterns
+@group(0) @binding(0)
+var<storage, read> input : array<u32>;
+
+@group(0) @binding(1)
+var<storage, read_write> output : array<u32>;
+
+@compute
+@workgroup_size(256)
+fn bitwise_count_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = countOneBits(input[global_id.x]);
+    }
+}
+
+// Counts the bits of the absolute value, as numpy does
+@compute
+@workgroup_size(256)
+fn bitwise_count_signed_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        let value = bitcast<i32>(input[global_id.x]);
+        output[global_id.x] = countOneBits(bitcast<u32>(abs(value)));
+    }
+}
@@ -0,0 +1,67 @@
+@group(0) @binding(0)
+var<storage, read> input : array<u32>;
+
+// The array is viewed as [outer, axis_len, inner]
+struct PackParams {
+    axis_len: u32,
+    inner: u32,
+    out_axis_len: u32,
+    out_len: u32,
+    little: u32,
+}
+
+@group(0) @binding(1)
+var<storage, read> params : PackParams;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<u32>;
+
+// Bit `bit` of a byte, counted from the most significant bit for big bit order
+fn bit_shift(bit: u32) -> u32 {
+    if params.little == 1u {
+        return bit;
+    }
+    return 7u - bit;
+}
+
+// The input is a bit packed bool array, every invocation packs 8 values into a byte
+@compute
+@workgroup_size(256)
+fn packbits_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x >= params.out_len {
+        return;
+    }
+    let outer = global_id.x / (params.out_axis_len * params.inner);
+    let byte = (global_id.x / params.inner) % params.out_axis_len;
+    let inner = global_id.x % params.inner;
+
+    var value = 0u;
+    for (var bit = 0u; bit < 8u; bit++) {
+        let position = byte * 8u + bit;
+        if position < params.axis_len {
+            let index = (outer * params.axis_len + position) * params.inner + inner;
+            let is_set = (input[index / 32u] >> (index % 32u)) & 1u;
+            value |= is_set << bit_shift(bit);
+        }
+    }
+    output[global_id.x] = value;
+}
+
+// The input holds one byte per element, every invocation extracts a single bit
+@compute
+@workgroup_size(256)
+fn unpackbits_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x >= params.out_len {
+        return;
+    }
+    let outer = global_id.x / (params.out_axis_len * params.inner);
+    let position = (global_id.x / params.inner) % params.out_axis_len;
+    let inner = global_id.x % params.inner;
+
+    if position >= params.axis_len * 8u {
+        output[global_id.x] = 0u;
+        return;
+    }
+    let index = (outer * params.axis_len + position / 8u) * params.inner + inner;
+    output[global_id.x] = (input[index] >> bit_shift(position % 8u)) & 1u;
+}
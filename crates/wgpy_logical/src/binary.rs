@@ -1,13 +1,351 @@
-use arrow_gpu::kernels::*;
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{ArrowArrayGPU, ArrowType, Int32ArrayGPU, UInt32ArrayGPU},
+    gpu_utils::ArrowComputePipeline,
+    kernels::*,
+};
 use webgpupy_core::{
-    ufunc_nin1_nout1, ufunc_nin1_nout1_body, ufunc_nin2_nout1, ufunc_nin2_nout1_body, Dtype,
-    NdArray,
+    cast_if_needed, normalize_axis, ufunc_nin1_nout1, ufunc_nin1_nout1_body,
+    ufunc_nin1_nout1_checked_body, ufunc_nin2_nout1, ufunc_nin2_nout1_body,
+    ufunc_nin2_nout1_checked_body, Dtype, NdArray, NdArrayError, NdArrayResult,
 };
 
+use crate::logical::logical_xor_op_dyn;
+
+const BITWISE_SHADER: &str = include_str!("../compute_shader/u32/bitwise.wgsl");
+const BITWISE_COUNT_SHADER: &str = include_str!("../compute_shader/u32/bitwise_count.wgsl");
+const PACKBITS_SHADER: &str = include_str!("../compute_shader/u32/packbits.wgsl");
+
 ufunc_nin2_nout1_body!(bitwise_and, bitwise_and_op_dyn);
 ufunc_nin2_nout1_body!(bitwise_or, bitwise_or_op_dyn);
 ufunc_nin1_nout1_body!(invert, bitwise_not_op_dyn);
 
+/// Order of the bits within a byte for [`packbits`] and [`unpackbits`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BitOrder {
+    /// Most significant bit first
+    #[default]
+    Big,
+    /// Least significant bit first
+    Little,
+}
+
+/// Like numpy, the integer ufuncs have no loop for floats
+fn check_integer_dtypes(name: &str, dtypes: &[Dtype]) -> NdArrayResult<()> {
+    if Dtype::result_type(dtypes) == Some(Dtype::Float32) {
+        return Err(NdArrayError::DtypeError(format!(
+            "ufunc '{}' not supported for the input types",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Integer dtype the inputs are promoted to, floats are rejected by
+/// [`check_integer_dtypes`] before
+fn integer_dtype(dtypes: &[Dtype]) -> Dtype {
+    match Dtype::result_type(dtypes) {
+        Some(Dtype::Bool) | None => Dtype::Int8,
+        Some(x) => x,
+    }
+}
+
+/// Signed integers run the kernels as i32 and unsigned ones as u32
+fn kernel_type(dtype: Dtype) -> ArrowType {
+    if dtype.is_signed_int() {
+        ArrowType::Int32Type
+    } else {
+        ArrowType::UInt32Type
+    }
+}
+
+fn bitwise_count_dtype(_dtypes: &[Dtype]) -> Dtype {
+    Dtype::UInt8
+}
+
+/// Wraps the output of a 32 bit kernel and casts it to `dtype`
+fn integer_result(
+    buffer: arrow_gpu::gpu_utils::Buffer,
+    len: usize,
+    dtype: Dtype,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    let gpu_device = pipeline.device.clone();
+    let result: ArrowArrayGPU = if dtype.is_signed_int() {
+        Int32ArrayGPU {
+            data: Arc::new(buffer),
+            gpu_device,
+            phantom: std::marker::PhantomData,
+            len,
+            null_buffer: None,
+        }
+        .into()
+    } else {
+        UInt32ArrayGPU {
+            data: Arc::new(buffer),
+            gpu_device,
+            phantom: std::marker::PhantomData,
+            len,
+            null_buffer: None,
+        }
+        .into()
+    };
+    let mut casted = None;
+    cast_if_needed(&result, &dtype.into(), &mut casted, pipeline);
+    casted.unwrap_or(result)
+}
+
+/// Runs a kernel of the bitwise shader after promoting both inputs to a common
+/// integer dtype, narrower integers are widened to 32 bits and cast back after
+fn bitwise_op_dyn(
+    data_1: &ArrowArrayGPU,
+    data_2: &ArrowArrayGPU,
+    entry_point: &str,
+    signed_entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    let dtype = integer_dtype(&[data_1.get_dtype().into(), data_2.get_dtype().into()]);
+    let kernel_type = kernel_type(dtype);
+    let (mut casted_1, mut casted_2) = (None, None);
+    let input_1 = cast_if_needed(data_1, &kernel_type, &mut casted_1, pipeline);
+    let input_2 = cast_if_needed(data_2, &kernel_type, &mut casted_2, pipeline);
+    let (buffer_1, buffer_2, len) = match (input_1, input_2) {
+        (ArrowArrayGPU::Int32ArrayGPU(x), ArrowArrayGPU::Int32ArrayGPU(y)) => {
+            (&x.data, &y.data, x.len)
+        }
+        (ArrowArrayGPU::UInt32ArrayGPU(x), ArrowArrayGPU::UInt32ArrayGPU(y)) => {
+            (&x.data, &y.data, x.len)
+        }
+        _ => unreachable!(),
+    };
+    let buffer = pipeline.apply_binary_function(
+        buffer_1,
+        buffer_2,
+        len as u64 * 4,
+        BITWISE_SHADER,
+        if dtype.is_signed_int() {
+            signed_entry_point
+        } else {
+            entry_point
+        },
+        (len as u32).div_ceil(256),
+    );
+    integer_result(buffer, len, dtype, pipeline)
+}
+
+fn bitwise_xor_op_dyn(
+    data_1: &ArrowArrayGPU,
+    data_2: &ArrowArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    if data_1.get_dtype() == ArrowType::BooleanType && data_2.get_dtype() == ArrowType::BooleanType
+    {
+        return logical_xor_op_dyn(data_1, data_2, pipeline);
+    }
+    bitwise_op_dyn(data_1, data_2, "bitwise_xor_", "bitwise_xor_", pipeline)
+}
+
+fn left_shift_op_dyn(
+    data_1: &ArrowArrayGPU,
+    data_2: &ArrowArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    bitwise_op_dyn(data_1, data_2, "left_shift_", "left_shift_", pipeline)
+}
+
+fn right_shift_op_dyn(
+    data_1: &ArrowArrayGPU,
+    data_2: &ArrowArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    bitwise_op_dyn(
+        data_1,
+        data_2,
+        "right_shift_",
+        "right_shift_signed_",
+        pipeline,
+    )
+}
+
+/// Counts the set bits of the absolute values, the result is always uint8
+fn bitwise_count_op_dyn(
+    data: &ArrowArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    let dtype = integer_dtype(&[data.get_dtype().into()]);
+    let mut casted = None;
+    let input = cast_if_needed(data, &kernel_type(dtype), &mut casted, pipeline);
+    let (buffer, len, entry_point) = match input {
+        ArrowArrayGPU::Int32ArrayGPU(x) => (&x.data, x.len, "bitwise_count_signed_"),
+        ArrowArrayGPU::UInt32ArrayGPU(x) => (&x.data, x.len, "bitwise_count_"),
+        _ => unreachable!(),
+    };
+    let buffer = pipeline.apply_unary_function(
+        buffer,
+        len as u64 * 4,
+        BITWISE_COUNT_SHADER,
+        entry_point,
+        (len as u32).div_ceil(256),
+    );
+    integer_result(buffer, len, Dtype::UInt8, pipeline)
+}
+
+ufunc_nin2_nout1_checked_body!(bitwise_xor, bitwise_xor_op_dyn, check_integer_dtypes);
+ufunc_nin2_nout1_checked_body!(
+    left_shift,
+    left_shift_op_dyn,
+    check_integer_dtypes,
+    integer_dtype
+);
+ufunc_nin2_nout1_checked_body!(
+    right_shift,
+    right_shift_op_dyn,
+    check_integer_dtypes,
+    integer_dtype
+);
+ufunc_nin1_nout1_checked_body!(
+    bitwise_count,
+    bitwise_count_op_dyn,
+    check_integer_dtypes,
+    bitwise_count_dtype
+);
+
+/// Input viewed as `[outer, axis_len, inner]`, `axis` of None works on the flattened array
+fn pack_layout(shape: &[u32], axis: Option<i32>) -> NdArrayResult<(usize, u32, u32)> {
+    match axis {
+        None => Ok((0, shape.iter().product(), 1)),
+        Some(axis) => {
+            let axis = normalize_axis(axis, shape.len())?;
+            Ok((axis, shape[axis], shape[axis + 1..].iter().product()))
+        }
+    }
+}
+
+/// Runs a packbits shader kernel producing `out_shape`, the result is uint8
+fn pack_op(
+    data: &ArrowArrayGPU,
+    params: [u32; 5],
+    out_shape: Vec<u32>,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> NdArray {
+    let buffer = match data {
+        ArrowArrayGPU::BooleanArrayGPU(x) => &x.data,
+        ArrowArrayGPU::UInt32ArrayGPU(x) => &x.data,
+        _ => unreachable!(),
+    };
+    let out_len = params[3];
+    let params_buffer = pipeline.device.create_gpu_buffer_with_data(&params);
+    let buffer = pipeline.apply_binary_function(
+        buffer,
+        &params_buffer,
+        out_len as u64 * 4,
+        PACKBITS_SHADER,
+        entry_point,
+        out_len.div_ceil(256),
+    );
+    NdArray {
+        dims: out_shape.len() as u16,
+        shape: out_shape,
+        data: integer_result(buffer, out_len as usize, Dtype::UInt8, pipeline),
+        dtype: Dtype::UInt8,
+    }
+}
+
+/// Packs the truth values of an integer or bool array into the bits of a uint8 array,
+/// padding the end of `axis` with zeros up to a multiple of 8
+pub fn packbits(a: &NdArray, axis: Option<i32>, bitorder: BitOrder) -> NdArrayResult<NdArray> {
+    if a.dtype == Dtype::Float32 {
+        return Err(NdArrayError::DtypeError(
+            "Expected an input array of integer or boolean data type".to_string(),
+        ));
+    }
+    let (axis_index, axis_len, inner) = pack_layout(&a.shape, axis)?;
+    let out_axis_len = axis_len.div_ceil(8);
+    let out_shape = match axis {
+        None => vec![out_axis_len],
+        Some(_) => {
+            let mut out_shape = a.shape.clone();
+            out_shape[axis_index] = out_axis_len;
+            out_shape
+        }
+    };
+    let out_len = out_shape.iter().product::<u32>();
+    if out_len == 0 {
+        return Ok(NdArray::zero_sized(
+            out_shape,
+            Dtype::UInt8,
+            a.get_gpu_device(),
+        ));
+    }
+
+    let mut pipeline = ArrowComputePipeline::new(a.get_gpu_device(), Some("packbits"));
+    let mut casted = None;
+    let input = cast_if_needed(&a.data, &ArrowType::BooleanType, &mut casted, &mut pipeline);
+    let params = [
+        axis_len,
+        inner,
+        out_axis_len,
+        out_len,
+        (bitorder == BitOrder::Little) as u32,
+    ];
+    let result = pack_op(input, params, out_shape, "packbits_", &mut pipeline);
+    pipeline.finish();
+    Ok(result)
+}
+
+/// Unpacks the bits of a uint8 array along `axis`. A positive `count` keeps that many
+/// bits, padding with zeros, and a negative one drops bits from the end.
+pub fn unpackbits(
+    a: &NdArray,
+    axis: Option<i32>,
+    count: Option<i32>,
+    bitorder: BitOrder,
+) -> NdArrayResult<NdArray> {
+    if a.dtype != Dtype::UInt8 {
+        return Err(NdArrayError::DtypeError(
+            "Expected an input array of unsigned byte data type".to_string(),
+        ));
+    }
+    let (axis_index, axis_len, inner) = pack_layout(&a.shape, axis)?;
+    let out_axis_len = match count {
+        None => axis_len * 8,
+        Some(count) if count >= 0 => count as u32,
+        Some(count) => (axis_len * 8).saturating_sub(count.unsigned_abs()),
+    };
+    let out_shape = match axis {
+        None => vec![out_axis_len],
+        Some(_) => {
+            let mut out_shape = a.shape.clone();
+            out_shape[axis_index] = out_axis_len;
+            out_shape
+        }
+    };
+    let out_len = out_shape.iter().product::<u32>();
+    if out_len == 0 {
+        return Ok(NdArray::zero_sized(
+            out_shape,
+            Dtype::UInt8,
+            a.get_gpu_device(),
+        ));
+    }
+
+    let mut pipeline = ArrowComputePipeline::new(a.get_gpu_device(), Some("unpackbits"));
+    let mut casted = None;
+    let input = cast_if_needed(&a.data, &ArrowType::UInt32Type, &mut casted, &mut pipeline);
+    let params = [
+        axis_len,
+        inner,
+        out_axis_len,
+        out_len,
+        (bitorder == BitOrder::Little) as u32,
+    ];
+    let result = pack_op(input, params, out_shape, "unpackbits_", &mut pipeline);
+    pipeline.finish();
+    Ok(result)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -40,4 +378,92 @@ mod test {
         UInt32ArrayGPU,
         invert
     );
+
+    #[test]
+    fn test_i32_bitwise_xor_i32() {
+        let a = NdArray::from_slice([12i32, -1, 0].as_ref().into(), vec![3], None);
+        let b = NdArray::from_slice([10i32, 5, 7].as_ref().into(), vec![3], None);
+        let result = bitwise_xor(&a, &b, None, None).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![6i32, -6, 7].into());
+    }
+
+    #[test]
+    fn test_u32_left_shift_u32() {
+        let a = NdArray::from_slice([1u32, 3, 1].as_ref().into(), vec![3], None);
+        let b = NdArray::from_slice([4u32, 1, 32].as_ref().into(), vec![3], None);
+        let result = left_shift(&a, &b, None, None).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![16u32, 6, 0].into());
+    }
+
+    #[test]
+    fn test_i32_right_shift_i32() {
+        let a = NdArray::from_slice([16i32, -16, -1].as_ref().into(), vec![3], None);
+        let b = NdArray::from_slice([2i32, 2, 40].as_ref().into(), vec![3], None);
+        let result = right_shift(&a, &b, None, None).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![4i32, -4, -1].into());
+    }
+
+    #[test]
+    fn test_i32_bitwise_count() {
+        let a = NdArray::from_slice([0i32, 7, -8, 1023].as_ref().into(), vec![4], None);
+        let result = bitwise_count(&a, None, None).unwrap();
+        assert_eq!(result.dtype, Dtype::UInt8);
+        assert_eq!(result.data.get_raw_values(), vec![0u8, 3, 1, 10].into());
+    }
+
+    #[test]
+    fn test_integer_ufuncs_reject_floats() {
+        let a = NdArray::from_slice([1.0f32, 2.0].as_ref().into(), vec![2], None);
+        let b = NdArray::from_slice([1i32, 2].as_ref().into(), vec![2], None);
+        assert!(matches!(
+            bitwise_xor(&a, &b, None, None),
+            Err(NdArrayError::DtypeError(_))
+        ));
+        assert!(left_shift(&b, &a, None, None).is_err());
+        assert!(right_shift(&a, &a, None, None).is_err());
+        assert!(bitwise_count(&a, None, None).is_err());
+    }
+
+    #[test]
+    fn test_packbits_roundtrip() {
+        let data = [1u8, 0, 1, 1, 0, 0, 0, 1, 1, 1, 0];
+        let input = NdArray::from_slice(data.as_ref().into(), vec![11], None);
+        let packed = packbits(&input, None, BitOrder::Big).unwrap();
+        assert_eq!(packed.shape, vec![2]);
+        assert_eq!(
+            packed.data.get_raw_values(),
+            vec![0b1011_0001u8, 0b1100_0000].into()
+        );
+
+        let packed = packbits(&input, Some(0), BitOrder::Little).unwrap();
+        assert_eq!(
+            packed.data.get_raw_values(),
+            vec![0b1000_1101u8, 0b0000_0011].into()
+        );
+
+        let unpacked = unpackbits(&packed, Some(0), Some(11), BitOrder::Little).unwrap();
+        assert_eq!(unpacked.shape, vec![11]);
+        assert_eq!(unpacked.data.get_raw_values(), data.to_vec().into());
+    }
+
+    #[test]
+    fn test_packbits_axis() {
+        let input = NdArray::from_slice(
+            [true, false, true, false, true, true].as_ref().into(),
+            vec![2, 3],
+            None,
+        );
+        let packed = packbits(&input, Some(0), BitOrder::Big).unwrap();
+        assert_eq!(packed.shape, vec![1, 3]);
+        assert_eq!(packed.data.get_raw_values(), vec![128u8, 64, 192].into());
+
+        let unpacked = unpackbits(&packed, Some(1), Some(-1), BitOrder::Big).unwrap();
+        assert_eq!(unpacked.shape, vec![1, 23]);
+    }
+
+    #[test]
+    fn test_unpackbits_wrong_dtype() {
+        let input = NdArray::from_slice([1i32, 2].as_ref().into(), vec![2], None);
+        assert!(unpackbits(&input, None, None, BitOrder::Big).is_err());
+    }
 }
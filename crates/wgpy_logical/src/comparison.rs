@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{ArrowArrayGPU, BooleanArrayGPU},
+    gpu_utils::ArrowComputePipeline,
+};
+use webgpupy_core::{
+    as_f32_array, bool_dtype, broadcast_shape, ufunc_nin2_nout1, Dtype, NdArray, NdArrayResult,
+};
+
+use crate::{all, equal};
+
+const ISCLOSE_SHADER: &str = include_str!("../compute_shader/f32/isclose.wgsl");
+
+fn isclose_op_dyn(
+    data_1: &ArrowArrayGPU,
+    data_2: &ArrowArrayGPU,
+    rtol: f32,
+    atol: f32,
+    equal_nan: bool,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    let (mut casted_1, mut casted_2) = (None, None);
+    let x = as_f32_array(data_1, &mut casted_1, pipeline);
+    let y = as_f32_array(data_2, &mut casted_2, pipeline);
+    let params = [
+        rtol.to_bits(),
+        atol.to_bits(),
+        equal_nan as u32,
+        x.len as u32,
+    ];
+    let params_buffer = pipeline.device.create_gpu_buffer_with_data(&params);
+    let words = x.len.div_ceil(32) as u64;
+    let buffer = pipeline.apply_ternary_function(
+        &x.data,
+        &y.data,
+        &params_buffer,
+        words * 4,
+        ISCLOSE_SHADER,
+        "isclose_",
+        (words as u32).div_ceil(256),
+    );
+    BooleanArrayGPU {
+        data: Arc::new(buffer),
+        gpu_device: x.gpu_device.clone(),
+        len: x.len,
+        null_buffer: None,
+    }
+    .into()
+}
+
+/// Returns a bool array where `a` and `b` are equal within the tolerance
+/// `atol + rtol * abs(b)`, inputs are broadcast together and compared as f32
+pub fn isclose(
+    a: &NdArray,
+    b: &NdArray,
+    rtol: f32,
+    atol: f32,
+    equal_nan: bool,
+) -> NdArrayResult<NdArray> {
+    broadcast_shape(&a.shape, &b.shape)?;
+    Ok(ufunc_nin2_nout1(
+        |x, y, pipeline| isclose_op_dyn(x, y, rtol, atol, equal_nan, pipeline),
+        bool_dtype,
+        a,
+        b,
+        None,
+        None,
+    ))
+}
+
+/// Returns true if every element of `a` and `b` is equal within a tolerance, see [`isclose`]
+pub fn allclose(
+    a: &NdArray,
+    b: &NdArray,
+    rtol: f32,
+    atol: f32,
+    equal_nan: bool,
+) -> NdArrayResult<bool> {
+    Ok(all(&isclose(a, b, rtol, atol, equal_nan)?))
+}
+
+/// Compares `a` and `b` after casting them to their common dtype
+fn all_equal(a: &NdArray, b: &NdArray) -> bool {
+    if a.dtype == b.dtype {
+        return all(&equal(a, b, None, None));
+    }
+    let dtype = Dtype::result_type(&[a.dtype, b.dtype]).unwrap();
+    all(&equal(&a.astype(dtype), &b.astype(dtype), None, None))
+}
+
+/// Returns true if both arrays have the same shape and elements, nans are only
+/// considered equal when `equal_nan` is true
+pub fn array_equal(a: &NdArray, b: &NdArray, equal_nan: bool) -> bool {
+    if a.shape != b.shape {
+        return false;
+    }
+    if equal_nan && (a.dtype == Dtype::Float32 || b.dtype == Dtype::Float32) {
+        return allclose(a, b, 0.0, 0.0, true).unwrap_or(false);
+    }
+    all_equal(a, b)
+}
+
+/// Returns true if both arrays can be broadcast to the same shape and have equal elements
+pub fn array_equiv(a: &NdArray, b: &NdArray) -> bool {
+    if broadcast_shape(&a.shape, &b.shape).is_err() {
+        return false;
+    }
+    all_equal(a, b)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use webgpupy_core::NdArrayError;
+
+    #[test]
+    fn test_isclose() {
+        let a = NdArray::from_slice(
+            [
+                1.0f32,
+                1e10,
+                f32::NAN,
+                f32::INFINITY,
+                f32::NEG_INFINITY,
+                0.0,
+            ]
+            .as_ref()
+            .into(),
+            vec![6],
+            None,
+        );
+        let b = NdArray::from_slice(
+            [
+                1.000_001f32,
+                1.000_001e10,
+                f32::NAN,
+                f32::INFINITY,
+                f32::INFINITY,
+                1e-9,
+            ]
+            .as_ref()
+            .into(),
+            vec![6],
+            None,
+        );
+        let result = isclose(&a, &b, 1e-5, 1e-8, false).unwrap();
+        assert_eq!(result.dtype, Dtype::Bool);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![true, true, false, true, false, true].into()
+        );
+        let result = isclose(&a, &b, 0.0, 0.0, true).unwrap();
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![false, false, true, true, false, false].into()
+        );
+    }
+
+    #[test]
+    fn test_isclose_many_words() {
+        let data = (0..100).map(|x| x as f32).collect::<Vec<_>>();
+        let a = NdArray::from_slice(data.as_slice().into(), vec![100], None);
+        let b = NdArray::from_slice([50.0f32].as_ref().into(), vec![1], None);
+        let expected = (0..100).map(|x| x == 50).collect::<Vec<_>>();
+        let result = isclose(&a, &b, 1e-5, 1e-8, false).unwrap();
+        assert_eq!(result.shape, vec![100]);
+        assert_eq!(result.data.get_raw_values(), expected.into());
+    }
+
+    #[test]
+    fn test_isclose_shape_mismatch() {
+        let a = NdArray::from_slice([1.0f32, 2.0, 3.0].as_ref().into(), vec![3], None);
+        let b = NdArray::from_slice([1.0f32, 2.0].as_ref().into(), vec![2], None);
+        assert!(matches!(
+            isclose(&a, &b, 1e-5, 1e-8, false),
+            Err(NdArrayError::BroadcastError(_))
+        ));
+        assert!(allclose(&a, &b, 1e-5, 1e-8, false).is_err());
+    }
+
+    #[test]
+    fn test_array_equal() {
+        let a = NdArray::from_slice([1.0f32, f32::NAN].as_ref().into(), vec![2], None);
+        let b = NdArray::from_slice([1.0f32, f32::NAN].as_ref().into(), vec![2], None);
+        let c = NdArray::from_slice([1i32, 2].as_ref().into(), vec![2], None);
+        let d = NdArray::from_slice([1.0f32, 2.0].as_ref().into(), vec![2], None);
+        assert!(!array_equal(&a, &b, false));
+        assert!(array_equal(&a, &b, true));
+        assert!(array_equal(&c, &d, false));
+        assert!(!array_equal(&c, &d.reshape(vec![1, 2]).unwrap(), false));
+    }
+
+    #[test]
+    fn test_array_equiv() {
+        let a = NdArray::from_slice([1i32, 2].as_ref().into(), vec![2], None);
+        let b = NdArray::from_slice([1i32, 2, 1, 2].as_ref().into(), vec![2, 2], None);
+        let c = NdArray::from_slice([1i32, 2, 3].as_ref().into(), vec![3], None);
+        assert!(array_equiv(&a, &b));
+        assert!(!array_equiv(&a, &c));
+    }
+}
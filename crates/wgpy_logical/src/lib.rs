@@ -1,7 +1,9 @@
 mod binary;
+mod comparison;
 mod logical;
 mod misc;
 
 pub use binary::*;
+pub use comparison::*;
 pub use logical::*;
 pub use misc::*;
@@ -1,5 +1,16 @@
-use arrow_gpu::kernels::*;
-use webgpupy_core::{ufunc_nin2_nout1, Dtype, NdArray};
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{ArrowArrayGPU, ArrowType, BooleanArrayGPU},
+    gpu_utils::ArrowComputePipeline,
+    kernels::*,
+};
+use webgpupy_core::{
+    bool_dtype, cast_if_needed, ufunc_nin1_nout1, ufunc_nin1_nout1_body, ufunc_nin2_nout1,
+    ufunc_nin2_nout1_body, Dtype, NdArray,
+};
+
+const LOGICAL_XOR_SHADER: &str = include_str!("../compute_shader/bool/logical_xor.wgsl");
 
 #[macro_export]
 macro_rules! ufunc_compare_nin2_nout1_body {
@@ -12,6 +23,7 @@ macro_rules! ufunc_compare_nin2_nout1_body {
         ) -> NdArray {
             ufunc_nin2_nout1(
                 |x, y, z| $dyn(x, y, z).into(),
+                bool_dtype,
                 input1,
                 input2,
                 where_,
@@ -26,13 +38,97 @@ ufunc_compare_nin2_nout1_body!(greater_equal, gteq_op_dyn);
 ufunc_compare_nin2_nout1_body!(lesser, lt_op_dyn);
 ufunc_compare_nin2_nout1_body!(lesser_equal, lteq_op_dyn);
 ufunc_compare_nin2_nout1_body!(equal, eq_op_dyn);
+ufunc_compare_nin2_nout1_body!(less, lt_op_dyn);
+ufunc_compare_nin2_nout1_body!(less_equal, lteq_op_dyn);
+
+fn not_equal_op_dyn(
+    data_1: &ArrowArrayGPU,
+    data_2: &ArrowArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    let equal = eq_op_dyn(data_1, data_2, pipeline).into();
+    bitwise_not_op_dyn(&equal, pipeline)
+}
+
+/// Returns the truth value of every element, non bool arrays are cast to bool
+fn as_bool_array<'a>(
+    data: &'a ArrowArrayGPU,
+    casted: &'a mut Option<ArrowArrayGPU>,
+    pipeline: &mut ArrowComputePipeline,
+) -> &'a ArrowArrayGPU {
+    cast_if_needed(data, &ArrowType::BooleanType, casted, pipeline)
+}
+
+fn logical_and_op_dyn(
+    data_1: &ArrowArrayGPU,
+    data_2: &ArrowArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    let (mut casted_1, mut casted_2) = (None, None);
+    let input_1 = as_bool_array(data_1, &mut casted_1, pipeline);
+    let input_2 = as_bool_array(data_2, &mut casted_2, pipeline);
+    bitwise_and_op_dyn(input_1, input_2, pipeline)
+}
+
+fn logical_or_op_dyn(
+    data_1: &ArrowArrayGPU,
+    data_2: &ArrowArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    let (mut casted_1, mut casted_2) = (None, None);
+    let input_1 = as_bool_array(data_1, &mut casted_1, pipeline);
+    let input_2 = as_bool_array(data_2, &mut casted_2, pipeline);
+    bitwise_or_op_dyn(input_1, input_2, pipeline)
+}
+
+pub(crate) fn logical_xor_op_dyn(
+    data_1: &ArrowArrayGPU,
+    data_2: &ArrowArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    let (mut casted_1, mut casted_2) = (None, None);
+    let input_1 = as_bool_array(data_1, &mut casted_1, pipeline);
+    let input_2 = as_bool_array(data_2, &mut casted_2, pipeline);
+    match (input_1, input_2) {
+        (ArrowArrayGPU::BooleanArrayGPU(x), ArrowArrayGPU::BooleanArrayGPU(y)) => {
+            let words = x.len.div_ceil(32) as u64;
+            let buffer = pipeline.apply_binary_function(
+                &x.data,
+                &y.data,
+                words * 4,
+                LOGICAL_XOR_SHADER,
+                "logical_xor_",
+                (words as u32).div_ceil(256),
+            );
+            BooleanArrayGPU {
+                data: Arc::new(buffer),
+                gpu_device: x.gpu_device.clone(),
+                len: x.len,
+                null_buffer: None,
+            }
+            .into()
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn logical_not_op_dyn(data: &ArrowArrayGPU, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+    let mut casted = None;
+    bitwise_not_op_dyn(as_bool_array(data, &mut casted, pipeline), pipeline)
+}
+
+ufunc_nin2_nout1_body!(not_equal, not_equal_op_dyn, bool_dtype);
+ufunc_nin2_nout1_body!(logical_and, logical_and_op_dyn, bool_dtype);
+ufunc_nin2_nout1_body!(logical_or, logical_or_op_dyn, bool_dtype);
+ufunc_nin2_nout1_body!(logical_xor, logical_xor_op_dyn, bool_dtype);
+ufunc_nin1_nout1_body!(logical_not, logical_not_op_dyn, bool_dtype);
 
 #[cfg(test)]
 mod test {
     use super::*;
     use arrow_gpu::array::ArrowArrayGPU;
     use test_utils::*;
-    use webgpupy_core::GPU_DEVICE;
+    use webgpupy_core::{zeros, GPU_DEVICE};
 
     test_ufunc_nin2_nout1!(
         test_f32_lt_f32_mask_true,
@@ -42,4 +138,82 @@ mod test {
         BooleanArrayGPU,
         greater
     );
+
+    #[test]
+    fn test_zero_sized_comparison_dtype() {
+        let x = zeros(vec![0], Some(Dtype::Float32), None);
+        let y = zeros(vec![2, 1], Some(Dtype::Float32), None);
+        let result = greater(&x, &y, None, None);
+        assert_eq!(&result.shape, &[2, 0]);
+        assert_eq!(result.dtype, Dtype::Bool);
+    }
+
+    test_ufunc_nin2_nout1!(
+        test_f32_not_equal_f32,
+        [1.0f32, 2.0, f32::NAN],
+        [1.0f32, 3.0, f32::NAN],
+        [false, true, true],
+        BooleanArrayGPU,
+        not_equal
+    );
+
+    test_ufunc_nin2_nout1!(
+        test_u32_less_equal_u32,
+        [1u32, 2, 3],
+        [2u32, 2, 2],
+        [true, true, false],
+        BooleanArrayGPU,
+        less_equal
+    );
+
+    test_ufunc_nin2_nout1!(
+        test_bool_logical_and_bool,
+        [true, true, false, false],
+        [true, false, true, false],
+        [true, false, false, false],
+        BooleanArrayGPU,
+        logical_and
+    );
+
+    test_ufunc_nin2_nout1!(
+        test_i32_logical_or_i32,
+        [0i32, 3, 0, -1],
+        [0i32, 0, 5, 2],
+        [false, true, true, true],
+        BooleanArrayGPU,
+        logical_or
+    );
+
+    test_ufunc_nin2_nout1!(
+        test_bool_logical_xor_bool,
+        [true, true, false, false],
+        [true, false, true, false],
+        [false, true, true, false],
+        BooleanArrayGPU,
+        logical_xor
+    );
+
+    test_ufunc_nin1_nout1!(
+        test_f32_logical_not,
+        [0.0f32, 2.5, -1.0],
+        [true, false, false],
+        BooleanArrayGPU,
+        logical_not
+    );
+
+    #[test]
+    fn test_logical_xor_many_words() {
+        let data_1 = (0..100).map(|x| x % 3 == 0).collect::<Vec<_>>();
+        let data_2 = (0..100).map(|x| x % 2 == 0).collect::<Vec<_>>();
+        let input_1 = NdArray::from_slice(data_1.as_slice().into(), vec![100], None);
+        let input_2 = NdArray::from_slice(data_2.as_slice().into(), vec![100], None);
+        let expected = data_1
+            .iter()
+            .zip(data_2.iter())
+            .map(|(x, y)| x ^ y)
+            .collect::<Vec<_>>();
+        let result = logical_xor(&input_1, &input_2, None, None);
+        assert_eq!(result.dtype, Dtype::Bool);
+        assert_eq!(result.data.get_raw_values(), expected.into());
+    }
 }
@@ -3,7 +3,9 @@ use webgpupy_core::NdArray;
 
 //TODO make it like numpy api
 pub fn any(x: &NdArray) -> bool {
-    if let ArrowArrayGPU::BooleanArrayGPU(y) = &x.data {
+    if x.is_empty() {
+        false
+    } else if let ArrowArrayGPU::BooleanArrayGPU(y) = &x.data {
         y.any()
     } else {
         panic!("Cant perform any on dtype {:?}", x.dtype)
@@ -12,7 +14,9 @@ pub fn any(x: &NdArray) -> bool {
 
 //TODO make it like numpy api
 pub fn all(x: &NdArray) -> bool {
-    if let ArrowArrayGPU::BooleanArrayGPU(y) = &x.data {
+    if x.is_empty() {
+        true
+    } else if let ArrowArrayGPU::BooleanArrayGPU(y) = &x.data {
         y.all()
     } else {
         panic!("Cant perform any on dtype {:?}", x.dtype)
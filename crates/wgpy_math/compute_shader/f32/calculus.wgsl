@@ -0,0 +1,131 @@
+@group(0) @binding(0)
+var<storage, read> input : array<f32>;
+
+// A single step, the coordinates along the axis or the coordinates of every element
+@group(0) @binding(1)
+var<storage, read> spacing : array<f32>;
+
+struct CalculusParams {
+    axis_len: u32,
+    inner: u32,
+    lanes: u32,
+    // 0 for a uniform step, 1 for coordinates along the axis, 2 for every element
+    spacing_kind: u32,
+    // The edge order of the gradient, or 1 when `initial` starts the cumulative integral
+    option: u32,
+    initial: f32,
+}
+
+@group(0) @binding(2)
+var<storage, read> params : CalculusParams;
+
+@group(0) @binding(3)
+var<storage, read_write> output : array<f32>;
+
+// Distance between the elements `i` and `i + 1` of the lane starting at `base`
+fn step(base: u32, i: u32) -> f32 {
+    switch params.spacing_kind {
+        case 0u: {
+            return spacing[0];
+        }
+        case 1u: {
+            return spacing[i + 1u] - spacing[i];
+        }
+        default: {
+            return spacing[base + (i + 1u) * params.inner] - spacing[base + i * params.inner];
+        }
+    }
+}
+
+fn value(base: u32, i: u32) -> f32 {
+    return input[base + i * params.inner];
+}
+
+fn lane_base(lane: u32) -> u32 {
+    return (lane / params.inner) * params.axis_len * params.inner + lane % params.inner;
+}
+
+// Second order central differences in the interior, one sided differences of
+// `option` order at the edges
+@compute
+@workgroup_size(256)
+fn gradient_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let index = global_id.x;
+    if index >= arrayLength(&output) {
+        return;
+    }
+    let i = (index / params.inner) % params.axis_len;
+    let base = index - i * params.inner;
+    let last = params.axis_len - 1u;
+
+    var result = 0.0;
+    if i == 0u {
+        let dx1 = step(base, 0u);
+        if params.option == 1u {
+            result = (value(base, 1u) - value(base, 0u)) / dx1;
+        } else {
+            let dx2 = step(base, 1u);
+            let a = -(2.0 * dx1 + dx2) / (dx1 * (dx1 + dx2));
+            let b = (dx1 + dx2) / (dx1 * dx2);
+            let c = -dx1 / (dx2 * (dx1 + dx2));
+            result = a * value(base, 0u) + b * value(base, 1u) + c * value(base, 2u);
+        }
+    } else if i == last {
+        let dx2 = step(base, last - 1u);
+        if params.option == 1u {
+            result = (value(base, last) - value(base, last - 1u)) / dx2;
+        } else {
+            let dx1 = step(base, last - 2u);
+            let a = dx2 / (dx1 * (dx1 + dx2));
+            let b = -(dx2 + dx1) / (dx1 * dx2);
+            let c = (2.0 * dx2 + dx1) / (dx2 * (dx1 + dx2));
+            result = a * value(base, last - 2u) + b * value(base, last - 1u) + c * value(base, last);
+        }
+    } else {
+        let dx1 = step(base, i - 1u);
+        let dx2 = step(base, i);
+        let a = -dx2 / (dx1 * (dx1 + dx2));
+        let b = (dx2 - dx1) / (dx1 * dx2);
+        let c = dx1 / (dx2 * (dx1 + dx2));
+        result = a * value(base, i - 1u) + b * value(base, i) + c * value(base, i + 1u);
+    }
+    output[index] = result;
+}
+
+// Every invocation integrates one lane along the axis
+@compute
+@workgroup_size(256)
+fn trapezoid_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let lane = global_id.x;
+    if lane >= params.lanes {
+        return;
+    }
+    let base = lane_base(lane);
+    var sum = 0.0;
+    for (var i = 1u; i < params.axis_len; i++) {
+        sum += step(base, i - 1u) * (value(base, i) + value(base, i - 1u)) / 2.0;
+    }
+    output[lane] = sum;
+}
+
+// Writes the running integral of a lane, the output axis has `axis_len - 1 + option`
+// elements. Like scipy, `initial` is only prepended and not added to the integral.
+@compute
+@workgroup_size(256)
+fn cumulative_trapezoid_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let lane = global_id.x;
+    if lane >= params.lanes {
+        return;
+    }
+    let base = lane_base(lane);
+    let out_len = params.axis_len - 1u + params.option;
+    let out_base = (lane / params.inner) * out_len * params.inner + lane % params.inner;
+    if params.option == 1u {
+        output[out_base] = params.initial;
+    }
+    var sum = 0.0;
+    for (var i = 1u; i < params.axis_len; i++) {
+        sum += step(base, i - 1u) * (value(base, i) + value(base, i - 1u)) / 2.0;
+        output[out_base + (i - 1u + params.option) * params.inner] = sum;
+    }
+}
@@ -0,0 +1,58 @@
+@group(0) @binding(0)
+var<storage, read> input : array<f32>;
+
+@group(0) @binding(1)
+var<storage, read> kind : u32;
+
+// Bit packed booleans, 32 elements per word
+@group(0) @binding(2)
+var<storage, read_write> output : array<u32>;
+
+const ABS_MASK: u32 = 0x7fffffffu;
+const SIGN_MASK: u32 = 0x80000000u;
+const INF_BITS: u32 = 0x7f800000u;
+
+// Classification is done on the bit pattern, so it isn't affected by
+// compilers assuming floats are never nan or infinite
+fn classify(x: f32) -> bool {
+    let bits = bitcast<u32>(x);
+    let abs_bits = bits & ABS_MASK;
+    switch kind {
+        case 0u: {
+            return abs_bits > INF_BITS;
+        }
+        case 1u: {
+            return abs_bits == INF_BITS;
+        }
+        case 2u: {
+            return bits == INF_BITS;
+        }
+        case 3u: {
+            return bits == (INF_BITS | SIGN_MASK);
+        }
+        case 4u: {
+            return abs_bits < INF_BITS;
+        }
+        default: {
+            return (bits & SIGN_MASK) != 0u;
+        }
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn classify_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let len = arrayLength(&input);
+    let start = global_id.x * 32u;
+    if start >= len {
+        return;
+    }
+    let end = min(start + 32u, len);
+    var word = 0u;
+    for (var i = start; i < end; i++) {
+        if classify(input[i]) {
+            word |= 1u << (i - start);
+        }
+    }
+    output[global_id.x] = word;
+}
@@ -0,0 +1,60 @@
+// Padded input, the weights fit at every position of the output
+@group(0) @binding(0)
+var<storage, read> input_1 : array<f32>;
+
+@group(0) @binding(1)
+var<storage, read> input_2 : array<f32>;
+
+// flip, dims and the output, padded input and weights shapes
+@group(0) @binding(2)
+var<storage, read> input_3 : array<u32>;
+
+@group(0) @binding(3)
+var<storage, read_write> output : array<f32>;
+
+fn output_dim(d: u32) -> u32 {
+    return input_3[2u + d];
+}
+
+fn padded_dim(d: u32) -> u32 {
+    return input_3[2u + input_3[1] + d];
+}
+
+fn weights_dim(d: u32) -> u32 {
+    return input_3[2u + 2u * input_3[1] + d];
+}
+
+// Sum of the products of the weights and the window of the padded input starting at
+// the position of the output. Flipping the weights along every axis convolves them.
+@compute
+@workgroup_size(256)
+fn correlate_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let i = global_id.x;
+    if i < arrayLength(&output) {
+        let dims = input_3[1];
+        var rest = i;
+        var start = 0u;
+        var stride = 1u;
+        for (var d = dims; d > 0u; d--) {
+            start += (rest % output_dim(d - 1u)) * stride;
+            rest /= output_dim(d - 1u);
+            stride *= padded_dim(d - 1u);
+        }
+
+        let len = arrayLength(&input_2);
+        var sum = 0.0;
+        for (var j = 0u; j < len; j++) {
+            var rest_j = j;
+            var offset = 0u;
+            var stride_j = 1u;
+            for (var d = dims; d > 0u; d--) {
+                offset += (rest_j % weights_dim(d - 1u)) * stride_j;
+                rest_j /= weights_dim(d - 1u);
+                stride_j *= padded_dim(d - 1u);
+            }
+            let weight = select(input_2[j], input_2[len - 1u - j], input_3[0] != 0u);
+            sum += input_1[start + offset] * weight;
+        }
+        output[i] = sum;
+    }
+}
@@ -0,0 +1,65 @@
+// Observations of every variable, element `(v, o)` is at `v * var_stride + o * obs_stride`
+@group(0) @binding(0)
+var<storage, read> input : array<f32>;
+
+struct CovarianceParams {
+    vars: u32,
+    observations: u32,
+    var_stride: u32,
+    obs_stride: u32,
+    correlation: u32,
+    // 1 / (observations - ddof)
+    scale: f32,
+}
+
+@group(0) @binding(1)
+var<storage, read> params : CovarianceParams;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<f32>;
+
+fn is_nan(x: f32) -> bool {
+    return (bitcast<u32>(x) & 0x7fffffffu) > 0x7f800000u;
+}
+
+fn observation(v: u32, o: u32) -> f32 {
+    return input[v * params.var_stride + o * params.obs_stride];
+}
+
+fn mean(v: u32) -> f32 {
+    var sum = 0.0;
+    for (var o = 0u; o < params.observations; o++) {
+        sum += observation(v, o);
+    }
+    return sum / f32(params.observations);
+}
+
+fn covariance(v1: u32, v2: u32) -> f32 {
+    let mean_1 = mean(v1);
+    let mean_2 = mean(v2);
+    var sum = 0.0;
+    for (var o = 0u; o < params.observations; o++) {
+        sum += (observation(v1, o) - mean_1) * (observation(v2, o) - mean_2);
+    }
+    return sum * params.scale;
+}
+
+// Covariance matrix of the variables, or the correlation coefficients clipped to
+// [-1, 1] with `correlation`, nans are kept
+@compute
+@workgroup_size(256)
+fn covariance_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let i = global_id.x;
+    if i < params.vars * params.vars {
+        let v1 = i / params.vars;
+        let v2 = i % params.vars;
+        let value = covariance(v1, v2);
+        if params.correlation != 0u {
+            let stddev = sqrt(covariance(v1, v1)) * sqrt(covariance(v2, v2));
+            let coefficient = value / stddev;
+            output[i] = select(clamp(coefficient, -1.0, 1.0), coefficient, is_nan(coefficient));
+        } else {
+            output[i] = value;
+        }
+    }
+}
@@ -0,0 +1,71 @@
+@group(0) @binding(0)
+var<storage, read> input_1 : array<f32>;
+
+@group(0) @binding(1)
+var<storage, read> input_2 : array<f32>;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<f32>;
+
+fn nan() -> f32 {
+    return bitcast<f32>(0x7fc00000u);
+}
+
+// Remainder with the sign of the dividend, nan when dividing by zero
+fn fmod_impl(x: f32, y: f32) -> f32 {
+    if y == 0.0 {
+        return nan();
+    }
+    return x - y * trunc(x / y);
+}
+
+// Follows numpy's npy_divmod, which keeps floor_divide and remainder consistent
+@compute
+@workgroup_size(256)
+fn floor_divide_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let x = input_1[global_id.x];
+        let y = input_2[global_id.x];
+        if y == 0.0 {
+            output[global_id.x] = x / y;
+            return;
+        }
+        let m = fmod_impl(x, y);
+        var div = (x - m) / y;
+        if m != 0.0 && ((y < 0.0) != (m < 0.0)) {
+            div -= 1.0;
+        }
+        if div == 0.0 {
+            output[global_id.x] = 0.0 * sign(x / y);
+        } else {
+            var floor_div = floor(div);
+            if div - floor_div > 0.5 {
+                floor_div += 1.0;
+            }
+            output[global_id.x] = floor_div;
+        }
+    }
+}
+
+// Result takes the sign of the divisor
+@compute
+@workgroup_size(256)
+fn remainder_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let x = input_1[global_id.x];
+        let y = input_2[global_id.x];
+        var m = fmod_impl(x, y);
+        if m != 0.0 && ((y < 0.0) != (m < 0.0)) {
+            m += y;
+        }
+        output[global_id.x] = m;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn fmod_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        output[global_id.x] = fmod_impl(input_1[global_id.x], input_2[global_id.x]);
+    }
+}
@@ -71,7 +71,7 @@ const Ln2 = 0.693147180559945309417232121458176568;
 //	Erfinv(NaN) = NaN
 @compute
 @workgroup_size(256)
-fn erfinv(@builtin(global_invocation_id) global_id: vec3<u32>) {
+fn erfinv_(@builtin(global_invocation_id) global_id: vec3<u32>) {
     if global_id.x < arrayLength(&output_values) {
         var x = input_values[global_id.x];
 
@@ -0,0 +1,115 @@
+@group(0) @binding(0)
+var<storage, read> input : array<f32>;
+
+@group(0) @binding(1)
+var<storage, read_write> output : array<f32>;
+
+const LN_10: f32 = 2.302585092994046;
+
+fn nan() -> f32 {
+    return bitcast<f32>(0x7fc00000u);
+}
+
+fn neg_inf() -> f32 {
+    return bitcast<f32>(0xff800000u);
+}
+
+// Natural logarithm with numpy semantics for zero and negative inputs
+fn ln(x: f32) -> f32 {
+    if x < 0.0 {
+        return nan();
+    }
+    if x == 0.0 {
+        return neg_inf();
+    }
+    return log(x);
+}
+
+// exp(x) - 1 without cancellation for small x
+fn expm1_impl(x: f32) -> f32 {
+    let u = exp(x);
+    if u == 1.0 {
+        return x;
+    }
+    let um1 = u - 1.0;
+    if um1 == -1.0 {
+        return -1.0;
+    }
+    if u > 3.4028235e38 {
+        return u;
+    }
+    return um1 * x / log(u);
+}
+
+// log(1 + x) without cancellation for small x
+fn log1p_impl(x: f32) -> f32 {
+    let u = 1.0 + x;
+    if u == 1.0 {
+        return x;
+    }
+    if u <= 0.0 || u > 3.4028235e38 {
+        return ln(u);
+    }
+    return log(u) * x / (u - 1.0);
+}
+
+@compute
+@workgroup_size(256)
+fn exp_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = exp(input[global_id.x]);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn exp2_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = exp2(input[global_id.x]);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn expm1_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = expm1_impl(input[global_id.x]);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn log_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = ln(input[global_id.x]);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn log2_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        let x = input[global_id.x];
+        if x > 0.0 {
+            output[global_id.x] = log2(x);
+        } else {
+            output[global_id.x] = ln(x);
+        }
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn log10_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = ln(input[global_id.x]) / LN_10;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn log1p_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = log1p_impl(input[global_id.x]);
+    }
+}
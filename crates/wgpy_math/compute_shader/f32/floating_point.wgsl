@@ -0,0 +1,45 @@
+@group(0) @binding(0)
+var<storage, read> input : array<f32>;
+
+@group(0) @binding(1)
+var<storage, read_write> output : array<f32>;
+
+const ABS_MASK: u32 = 0x7fffffffu;
+const INF_BITS: u32 = 0x7f800000u;
+
+fn nan() -> f32 {
+    return bitcast<f32>(0x7fc00000u);
+}
+
+fn is_nan(x: f32) -> bool {
+    return (bitcast<u32>(x) & ABS_MASK) > INF_BITS;
+}
+
+@compute
+@workgroup_size(256)
+fn sign_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        let x = input[global_id.x];
+        if is_nan(x) {
+            output[global_id.x] = x;
+        } else {
+            output[global_id.x] = sign(x);
+        }
+    }
+}
+
+// Distance to the next float away from zero, negative for negative inputs
+@compute
+@workgroup_size(256)
+fn spacing_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        let x = input[global_id.x];
+        let abs_bits = bitcast<u32>(x) & ABS_MASK;
+        if abs_bits >= INF_BITS {
+            output[global_id.x] = nan();
+        } else {
+            let spacing = bitcast<f32>(abs_bits + 1u) - bitcast<f32>(abs_bits);
+            output[global_id.x] = select(spacing, -spacing, x < 0.0);
+        }
+    }
+}
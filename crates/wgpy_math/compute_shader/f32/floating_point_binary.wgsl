@@ -0,0 +1,65 @@
+@group(0) @binding(0)
+var<storage, read> input_1 : array<f32>;
+
+@group(0) @binding(1)
+var<storage, read> input_2 : array<f32>;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<f32>;
+
+const ABS_MASK: u32 = 0x7fffffffu;
+const SIGN_MASK: u32 = 0x80000000u;
+const INF_BITS: u32 = 0x7f800000u;
+
+fn is_nan(x: f32) -> bool {
+    return (bitcast<u32>(x) & ABS_MASK) > INF_BITS;
+}
+
+@compute
+@workgroup_size(256)
+fn copysign_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let magnitude = bitcast<u32>(input_1[global_id.x]) & ABS_MASK;
+        let sign = bitcast<u32>(input_2[global_id.x]) & SIGN_MASK;
+        output[global_id.x] = bitcast<f32>(magnitude | sign);
+    }
+}
+
+// Steps the bit pattern of x by one towards y
+@compute
+@workgroup_size(256)
+fn nextafter_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let x = input_1[global_id.x];
+        let y = input_2[global_id.x];
+        if is_nan(x) || is_nan(y) {
+            output[global_id.x] = x + y;
+        } else if x == y {
+            output[global_id.x] = y;
+        } else if x == 0.0 {
+            output[global_id.x] = bitcast<f32>(select(1u, SIGN_MASK | 1u, y < 0.0));
+        } else if (y > x) == (x > 0.0) {
+            output[global_id.x] = bitcast<f32>(bitcast<u32>(x) + 1u);
+        } else {
+            output[global_id.x] = bitcast<f32>(bitcast<u32>(x) - 1u);
+        }
+    }
+}
+
+// 0 for negative x1, x2 where x1 is zero and 1 for positive x1
+@compute
+@workgroup_size(256)
+fn heaviside_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let x = input_1[global_id.x];
+        if is_nan(x) {
+            output[global_id.x] = x;
+        } else if x < 0.0 {
+            output[global_id.x] = 0.0;
+        } else if x > 0.0 {
+            output[global_id.x] = 1.0;
+        } else {
+            output[global_id.x] = input_2[global_id.x];
+        }
+    }
+}
@@ -0,0 +1,97 @@
+// Gamma function helpers shared by the special function shaders, which are
+// concatenated after this file
+
+const PI: f32 = 3.141592653589793;
+const SQRT_2PI: f32 = 2.5066282746310005;
+
+fn nan() -> f32 {
+    return bitcast<f32>(0x7fc00000u);
+}
+
+fn inf() -> f32 {
+    return bitcast<f32>(0x7f800000u);
+}
+
+fn is_nan(x: f32) -> bool {
+    return (bitcast<u32>(x) & 0x7fffffffu) > 0x7f800000u;
+}
+
+fn is_inf(x: f32) -> bool {
+    return (bitcast<u32>(x) & 0x7fffffffu) == 0x7f800000u;
+}
+
+fn is_non_positive_integer(x: f32) -> bool {
+    return x <= 0.0 && floor(x) == x;
+}
+
+// sin(pi * x) reduced to [0, pi / 2] first, so that integers give exact zeros
+fn sinpi(x: f32) -> f32 {
+    var r = x - 2.0 * floor(0.5 * x);
+    var sign = 1.0;
+    if r >= 1.0 {
+        r -= 1.0;
+        sign = -1.0;
+    }
+    if r > 0.5 {
+        r = 1.0 - r;
+    }
+    return sign * sin(PI * r);
+}
+
+// Lanczos series with g = 5, see Numerical Recipes gammln
+fn lanczos_series(x: f32) -> f32 {
+    var series = 1.000000000190015;
+    series += 76.18009172947146 / (x + 1.0);
+    series += -86.50532032941677 / (x + 2.0);
+    series += 24.01409824083091 / (x + 3.0);
+    series += -1.231739572450155 / (x + 4.0);
+    series += 0.1208650973866179e-2 / (x + 5.0);
+    series += -0.5395239384953e-5 / (x + 6.0);
+    return series;
+}
+
+// Gamma function for x >= 0.5, the power is split in two to delay the overflow
+fn gamma_positive(x: f32) -> f32 {
+    if x > 36.0 {
+        return inf();
+    }
+    let t = x + 5.5;
+    let p = pow(t, 0.5 * (x + 0.5));
+    return SQRT_2PI * lanczos_series(x) / x * p * exp(-t) * p;
+}
+
+// Log gamma function for x >= 0.5
+fn lgamma_positive(x: f32) -> f32 {
+    let t = x + 5.5;
+    return (x + 0.5) * log(t) - t + log(SQRT_2PI * lanczos_series(x) / x);
+}
+
+fn gamma(x: f32) -> f32 {
+    if is_nan(x) || x == inf() {
+        return x;
+    }
+    if x == 0.0 {
+        return bitcast<f32>(bitcast<u32>(inf()) | (bitcast<u32>(x) & 0x80000000u));
+    }
+    if is_non_positive_integer(x) {
+        return nan();
+    }
+    if x < 0.5 {
+        return PI / (sinpi(x) * gamma_positive(1.0 - x));
+    }
+    return gamma_positive(x);
+}
+
+// Logarithm of the absolute value of the gamma function
+fn lgamma(x: f32) -> f32 {
+    if is_nan(x) {
+        return x;
+    }
+    if is_inf(x) || is_non_positive_integer(x) {
+        return inf();
+    }
+    if x < 0.5 {
+        return log(PI / abs(sinpi(x))) - lgamma_positive(1.0 - x);
+    }
+    return lgamma_positive(x);
+}
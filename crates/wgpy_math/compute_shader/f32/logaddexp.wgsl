@@ -0,0 +1,50 @@
+@group(0) @binding(0)
+var<storage, read> input_1 : array<f32>;
+
+@group(0) @binding(1)
+var<storage, read> input_2 : array<f32>;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<f32>;
+
+const LN_2: f32 = 0.6931471805599453;
+
+// log(1 + x) without cancellation for small x, x is in [0, 1]
+fn log1p_impl(x: f32) -> f32 {
+    let u = 1.0 + x;
+    if u == 1.0 {
+        return x;
+    }
+    return log(u) * x / (u - 1.0);
+}
+
+// log(exp(x) + exp(y)) computed as max + log1p(exp(-|x - y|))
+@compute
+@workgroup_size(256)
+fn logaddexp_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let x = input_1[global_id.x];
+        let y = input_2[global_id.x];
+        if x == y {
+            // Also handles infinities of the same sign, where x - y is nan
+            output[global_id.x] = x + LN_2;
+        } else {
+            output[global_id.x] = max(x, y) + log1p_impl(exp(-abs(x - y)));
+        }
+    }
+}
+
+// log2(2^x + 2^y) computed as max + log1p(2^-|x - y|) / ln(2)
+@compute
+@workgroup_size(256)
+fn logaddexp2_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let x = input_1[global_id.x];
+        let y = input_2[global_id.x];
+        if x == y {
+            output[global_id.x] = x + 1.0;
+        } else {
+            output[global_id.x] = max(x, y) + log1p_impl(exp2(-abs(x - y))) / LN_2;
+        }
+    }
+}
@@ -0,0 +1,36 @@
+@group(0) @binding(0)
+var<storage, read> input : array<f32>;
+
+struct NanToNumParams {
+    nan: f32,
+    posinf: f32,
+    neginf: f32,
+}
+
+@group(0) @binding(1)
+var<storage, read> params : NanToNumParams;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<f32>;
+
+const ABS_MASK: u32 = 0x7fffffffu;
+const SIGN_MASK: u32 = 0x80000000u;
+const INF_BITS: u32 = 0x7f800000u;
+
+@compute
+@workgroup_size(256)
+fn nan_to_num_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        let x = input[global_id.x];
+        let bits = bitcast<u32>(x);
+        if (bits & ABS_MASK) > INF_BITS {
+            output[global_id.x] = params.nan;
+        } else if bits == INF_BITS {
+            output[global_id.x] = params.posinf;
+        } else if bits == (INF_BITS | SIGN_MASK) {
+            output[global_id.x] = params.neginf;
+        } else {
+            output[global_id.x] = x;
+        }
+    }
+}
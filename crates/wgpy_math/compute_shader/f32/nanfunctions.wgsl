@@ -0,0 +1,261 @@
+@group(0) @binding(0)
+var<storage, read> input : array<f32>;
+
+struct ReduceParams {
+    axis_len: u32,
+    inner: u32,
+    lanes: u32,
+    op: u32,
+    ddof: f32,
+}
+
+@group(0) @binding(1)
+var<storage, read> params : ReduceParams;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<f32>;
+
+const OP_SUM: u32 = 0u;
+const OP_PROD: u32 = 1u;
+const OP_MIN: u32 = 2u;
+const OP_MAX: u32 = 3u;
+const OP_ARGMIN: u32 = 4u;
+const OP_ARGMAX: u32 = 5u;
+const OP_MEAN: u32 = 6u;
+const OP_VAR: u32 = 7u;
+const OP_STD: u32 = 8u;
+const OP_MEDIAN: u32 = 9u;
+const OP_COUNT: u32 = 10u;
+
+const WORKGROUP_SIZE: u32 = 256u;
+const RADIX: u32 = 256u;
+
+// Reduction of the non nan values seen by one invocation, or by a part of the workgroup
+struct Partial {
+    count: u32,
+    sum: f32,
+    prod: f32,
+    min_value: f32,
+    max_value: f32,
+    min_index: u32,
+    max_index: u32,
+}
+
+var<workgroup> partials : array<Partial, WORKGROUP_SIZE>;
+var<workgroup> squares : array<f32, WORKGROUP_SIZE>;
+var<workgroup> histogram : array<atomic<u32>, RADIX>;
+var<workgroup> scan : array<u32, RADIX>;
+// (digit, rank within the digit) picked by one select pass
+var<workgroup> selected : vec2<u32>;
+
+fn nan() -> f32 {
+    return bitcast<f32>(0x7fc00000u);
+}
+
+fn is_nan(x: f32) -> bool {
+    return (bitcast<u32>(x) & 0x7fffffffu) > 0x7f800000u;
+}
+
+// Maps floats to unsigned integers with the same ordering
+fn ordered_key(x: f32) -> u32 {
+    let bits = bitcast<u32>(x);
+    if (bits & 0x80000000u) != 0u {
+        return ~bits;
+    }
+    return bits | 0x80000000u;
+}
+
+fn from_ordered_key(key: u32) -> f32 {
+    if (key & 0x80000000u) != 0u {
+        return bitcast<f32>(key & 0x7fffffffu);
+    }
+    return bitcast<f32>(~key);
+}
+
+fn lane_index(base: u32, i: u32) -> u32 {
+    return base + i * params.inner;
+}
+
+fn empty_partial() -> Partial {
+    return Partial(0u, 0.0, 1.0, nan(), nan(), 0u, 0u);
+}
+
+// Combines two partials, ties of the extrema keep the first index
+fn combine(a: Partial, b: Partial) -> Partial {
+    if a.count == 0u {
+        return b;
+    }
+    if b.count == 0u {
+        return a;
+    }
+    var result = Partial(a.count + b.count, a.sum + b.sum, a.prod * b.prod, a.min_value,
+        a.max_value, a.min_index, a.max_index);
+    if b.min_value < a.min_value || (b.min_value == a.min_value && b.min_index < a.min_index) {
+        result.min_value = b.min_value;
+        result.min_index = b.min_index;
+    }
+    if b.max_value > a.max_value || (b.max_value == a.max_value && b.max_index < a.max_index) {
+        result.max_value = b.max_value;
+        result.max_index = b.max_index;
+    }
+    return result;
+}
+
+// Tree reduction of the partials of every invocation, the total is returned to all
+fn reduce_partials(local: u32, partial: Partial) -> Partial {
+    workgroupBarrier();
+    partials[local] = partial;
+    workgroupBarrier();
+    for (var stride = WORKGROUP_SIZE / 2u; stride > 0u; stride /= 2u) {
+        if local < stride {
+            partials[local] = combine(partials[local], partials[local + stride]);
+        }
+        workgroupBarrier();
+    }
+    return workgroupUniformLoad(&partials[0]);
+}
+
+// Tree reduction of a sum of every invocation, the total is returned to all
+fn reduce_sum(local: u32, value: f32) -> f32 {
+    workgroupBarrier();
+    squares[local] = value;
+    workgroupBarrier();
+    for (var stride = WORKGROUP_SIZE / 2u; stride > 0u; stride /= 2u) {
+        if local < stride {
+            squares[local] += squares[local + stride];
+        }
+        workgroupBarrier();
+    }
+    return workgroupUniformLoad(&squares[0]);
+}
+
+// k-th smallest non nan value of the lane, found one 8 bit digit of its key at a time.
+// Every pass counts the digits of the keys matching the digits found so far and picks
+// the digit holding the k-th key from the scan of the counts.
+fn select_kth(local: u32, base: u32, k_in: u32) -> f32 {
+    var k = k_in;
+    var prefix = 0u;
+    for (var digit_index = 0u; digit_index < 4u; digit_index++) {
+        let shift = 24u - 8u * digit_index;
+        atomicStore(&histogram[local], 0u);
+        workgroupBarrier();
+        for (var i = local; i < params.axis_len; i += WORKGROUP_SIZE) {
+            let x = input[lane_index(base, i)];
+            let key = ordered_key(x);
+            if !is_nan(x) && (shift == 24u || (key >> (shift + 8u)) == (prefix >> (shift + 8u))) {
+                atomicAdd(&histogram[(key >> shift) & (RADIX - 1u)], 1u);
+            }
+        }
+        workgroupBarrier();
+
+        let count = atomicLoad(&histogram[local]);
+        scan[local] = count;
+        workgroupBarrier();
+        for (var offset = 1u; offset < RADIX; offset *= 2u) {
+            var previous = 0u;
+            if local >= offset {
+                previous = scan[local - offset];
+            }
+            workgroupBarrier();
+            scan[local] += previous;
+            workgroupBarrier();
+        }
+        let end = scan[local];
+        if k < end && k >= end - count {
+            selected = vec2<u32>(local, k - (end - count));
+        }
+        let digit = workgroupUniformLoad(&selected);
+        prefix |= digit.x << shift;
+        k = digit.y;
+    }
+    return from_ordered_key(prefix);
+}
+
+fn reduce(local: u32, base: u32) -> f32 {
+    var partial = empty_partial();
+    for (var i = local; i < params.axis_len; i += WORKGROUP_SIZE) {
+        let x = input[lane_index(base, i)];
+        if !is_nan(x) {
+            partial = combine(partial, Partial(1u, x, x, x, x, i, i));
+        }
+    }
+    let total = reduce_partials(local, partial);
+    let count = total.count;
+
+    switch params.op {
+        case OP_SUM: {
+            return total.sum;
+        }
+        case OP_PROD: {
+            return total.prod;
+        }
+        case OP_MIN: {
+            return total.min_value;
+        }
+        case OP_MAX: {
+            return total.max_value;
+        }
+        case OP_ARGMIN: {
+            return bitcast<f32>(total.min_index);
+        }
+        case OP_ARGMAX: {
+            return bitcast<f32>(total.max_index);
+        }
+        case OP_MEAN: {
+            if count == 0u {
+                return nan();
+            }
+            return total.sum / f32(count);
+        }
+        case OP_VAR, OP_STD: {
+            let dof = f32(count) - params.ddof;
+            if count == 0u || dof <= 0.0 {
+                return nan();
+            }
+            let mean = total.sum / f32(count);
+            var sum_squares = 0.0;
+            for (var i = local; i < params.axis_len; i += WORKGROUP_SIZE) {
+                let x = input[lane_index(base, i)];
+                if !is_nan(x) {
+                    sum_squares += (x - mean) * (x - mean);
+                }
+            }
+            let variance = reduce_sum(local, sum_squares) / dof;
+            if params.op == OP_STD {
+                return sqrt(variance);
+            }
+            return variance;
+        }
+        case OP_MEDIAN: {
+            if count == 0u {
+                return nan();
+            }
+            let low = select_kth(local, base, (count - 1u) / 2u);
+            if count % 2u == 1u {
+                return low;
+            }
+            return (low + select_kth(local, base, count / 2u)) / 2.0;
+        }
+        default: {
+            return f32(count);
+        }
+    }
+}
+
+// Every workgroup reduces one lane along the axis at a time, ignoring nans
+@compute
+@workgroup_size(256)
+fn nan_reduce_(
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) workgroup_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>
+) {
+    for (var lane = workgroup_id.x; lane < params.lanes; lane += num_workgroups.x) {
+        let outer = lane / params.inner;
+        let base = outer * params.axis_len * params.inner + lane % params.inner;
+        let result = reduce(local_id.x, base);
+        if local_id.x == 0u {
+            output[lane] = result;
+        }
+    }
+}
@@ -0,0 +1,24 @@
+@group(0) @binding(0)
+var<storage, read> input_1 : array<f32>;
+
+// Coefficients, highest degree first
+@group(0) @binding(1)
+var<storage, read> input_2 : array<f32>;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<f32>;
+
+// Evaluates the polynomial at every value of input_1 with Horner's method
+@compute
+@workgroup_size(256)
+fn polyval_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let i = global_id.x;
+    if i < arrayLength(&output) {
+        let x = input_1[i];
+        var result = 0.0;
+        for (var j = 0u; j < arrayLength(&input_2); j++) {
+            result = result * x + input_2[j];
+        }
+        output[i] = result;
+    }
+}
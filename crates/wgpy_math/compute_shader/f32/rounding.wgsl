@@ -0,0 +1,38 @@
+@group(0) @binding(0)
+var<storage, read> input : array<f32>;
+
+@group(0) @binding(1)
+var<storage, read_write> output : array<f32>;
+
+@compute
+@workgroup_size(256)
+fn floor_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = floor(input[global_id.x]);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn ceil_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = ceil(input[global_id.x]);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn trunc_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = trunc(input[global_id.x]);
+    }
+}
+
+// WGSL's round breaks ties to even, like numpy's rint
+@compute
+@workgroup_size(256)
+fn rint_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = round(input[global_id.x]);
+    }
+}
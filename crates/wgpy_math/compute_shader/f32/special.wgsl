@@ -0,0 +1,151 @@
+// Requires gamma.wgsl to be concatenated before this file
+
+@group(0) @binding(0)
+var<storage, read> input : array<f32>;
+
+@group(0) @binding(1)
+var<storage, read_write> output : array<f32>;
+
+const TWO_OVER_SQRT_PI: f32 = 1.1283791670955126;
+
+// Complementary error function for x >= 0 with a fractional error below 1.2e-7,
+// see Numerical Recipes erfcc
+fn erfc_positive(x: f32) -> f32 {
+    let t = 1.0 / (1.0 + 0.5 * x);
+    let p = -1.26551223 + t * (1.00002368 + t * (0.37409196 + t * (0.09678418
+        + t * (-0.18628806 + t * (0.27886807 + t * (-1.13520398 + t * (1.48851587
+        + t * (-0.82215223 + t * 0.17087277))))))));
+    return t * exp(-x * x + p);
+}
+
+// Taylor series of erf, accurate for |x| < 0.5
+fn erf_series(x: f32) -> f32 {
+    let x2 = x * x;
+    return TWO_OVER_SQRT_PI * x * (1.0 - x2 * (1.0 / 3.0 - x2 * (1.0 / 10.0 - x2 * (1.0 / 42.0
+        - x2 * (1.0 / 216.0 - x2 / 1320.0)))));
+}
+
+fn erf(x: f32) -> f32 {
+    let ax = abs(x);
+    if ax < 0.5 {
+        return erf_series(x);
+    }
+    return sign(x) * (1.0 - erfc_positive(ax));
+}
+
+fn erfc(x: f32) -> f32 {
+    if abs(x) < 0.5 {
+        return 1.0 - erf_series(x);
+    }
+    if x < 0.0 {
+        return 2.0 - erfc_positive(-x);
+    }
+    return erfc_positive(x);
+}
+
+// Recurrence up to x >= 6 followed by the asymptotic expansion, negative values
+// use the reflection formula
+fn digamma(x_in: f32) -> f32 {
+    if is_nan(x_in) || x_in == inf() {
+        return x_in;
+    }
+    if x_in == 0.0 {
+        return -bitcast<f32>(bitcast<u32>(inf()) | (bitcast<u32>(x_in) & 0x80000000u));
+    }
+    if is_non_positive_integer(x_in) {
+        return nan();
+    }
+    var x = x_in;
+    var result = 0.0;
+    if x < 0.0 {
+        result = -PI * sinpi(x + 0.5) / sinpi(x);
+        x = 1.0 - x;
+    }
+    while x < 6.0 {
+        result -= 1.0 / x;
+        x += 1.0;
+    }
+    let inv = 1.0 / x;
+    let inv2 = inv * inv;
+    let tail = inv2 * (1.0 / 12.0 - inv2 * (1.0 / 120.0 - inv2 * (1.0 / 252.0
+        - inv2 * (1.0 / 240.0 - inv2 / 132.0))));
+    return result + log(x) - 0.5 * inv - tail;
+}
+
+fn sinc(x: f32) -> f32 {
+    if x == 0.0 {
+        return 1.0;
+    }
+    return sinpi(x) / (PI * x);
+}
+
+// Modified Bessel function of the first kind and order 0, with polynomial
+// approximations from Abramowitz and Stegun 9.8.1 and 9.8.2
+fn i0(x: f32) -> f32 {
+    let ax = abs(x);
+    if ax < 3.75 {
+        let y = (x / 3.75) * (x / 3.75);
+        return 1.0 + y * (3.5156229 + y * (3.0899424 + y * (1.2067492 + y * (0.2659732
+            + y * (0.0360768 + y * 0.0045813)))));
+    }
+    let y = 3.75 / ax;
+    return exp(ax) / sqrt(ax) * (0.39894228 + y * (0.01328592 + y * (0.00225319
+        + y * (-0.00157565 + y * (0.00916281 + y * (-0.02057706 + y * (0.02635537
+        + y * (-0.01647633 + y * 0.00392377))))))));
+}
+
+@compute
+@workgroup_size(256)
+fn erf_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = erf(input[global_id.x]);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn erfc_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = erfc(input[global_id.x]);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn gamma_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = gamma(input[global_id.x]);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn lgamma_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = lgamma(input[global_id.x]);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn digamma_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = digamma(input[global_id.x]);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn sinc_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = sinc(input[global_id.x]);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn i0_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        output[global_id.x] = i0(input[global_id.x]);
+    }
+}
@@ -0,0 +1,29 @@
+// Requires gamma.wgsl to be concatenated before this file
+
+@group(0) @binding(0)
+var<storage, read> input_1 : array<f32>;
+
+@group(0) @binding(1)
+var<storage, read> input_2 : array<f32>;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<f32>;
+
+// Large positive arguments go through lgamma, as the gamma values would overflow
+fn beta(a: f32, b: f32) -> f32 {
+    if is_nan(a) || is_nan(b) {
+        return nan();
+    }
+    if a > 0.0 && b > 0.0 && a + b >= 32.0 {
+        return exp(lgamma(a) + lgamma(b) - lgamma(a + b));
+    }
+    return gamma(a) * (gamma(b) / gamma(a + b));
+}
+
+@compute
+@workgroup_size(256)
+fn beta_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        output[global_id.x] = beta(input_1[global_id.x], input_2[global_id.x]);
+    }
+}
@@ -0,0 +1,188 @@
+// Input viewed as `[lanes / inner, axis_len, inner]`, reduced along the middle axis
+@group(0) @binding(0)
+var<storage, read> input_1 : array<f32>;
+
+// Weights of every element for averages, quantiles in [0, 1] for quantiles
+@group(0) @binding(1)
+var<storage, read> input_2 : array<f32>;
+
+struct StatisticsParams {
+    axis_len: u32,
+    inner: u32,
+    lanes: u32,
+    // Whether input_2 holds weights for averages, the method of quantiles
+    option: u32,
+}
+
+@group(0) @binding(2)
+var<storage, read> params : StatisticsParams;
+
+@group(0) @binding(3)
+var<storage, read_write> output : array<f32>;
+
+const INVERTED_CDF: u32 = 0u;
+const AVERAGED_INVERTED_CDF: u32 = 1u;
+const CLOSEST_OBSERVATION: u32 = 2u;
+const INTERPOLATED_INVERTED_CDF: u32 = 3u;
+const HAZEN: u32 = 4u;
+const WEIBULL: u32 = 5u;
+const LINEAR: u32 = 6u;
+const MEDIAN_UNBIASED: u32 = 7u;
+const NORMAL_UNBIASED: u32 = 8u;
+const LOWER: u32 = 9u;
+const HIGHER: u32 = 10u;
+const NEAREST: u32 = 11u;
+const MIDPOINT: u32 = 12u;
+
+fn nan() -> f32 {
+    return bitcast<f32>(0x7fc00000u);
+}
+
+fn is_nan(x: f32) -> bool {
+    return (bitcast<u32>(x) & 0x7fffffffu) > 0x7f800000u;
+}
+
+fn lane_base(lane: u32) -> u32 {
+    let outer = lane / params.inner;
+    return outer * params.axis_len * params.inner + lane % params.inner;
+}
+
+fn weight(index: u32) -> f32 {
+    if params.option != 0u {
+        return input_2[index];
+    }
+    return 1.0;
+}
+
+// Weighted mean of every lane
+@compute
+@workgroup_size(256)
+fn average_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let lane = global_id.x;
+    if lane < params.lanes {
+        let base = lane_base(lane);
+        var sum = 0.0;
+        var weights = 0.0;
+        for (var i = 0u; i < params.axis_len; i++) {
+            let index = base + i * params.inner;
+            let w = weight(index);
+            sum += input_1[index] * w;
+            weights += w;
+        }
+        output[lane] = sum / weights;
+    }
+}
+
+// Sum of the weights of every lane
+@compute
+@workgroup_size(256)
+fn weight_sum_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let lane = global_id.x;
+    if lane < params.lanes {
+        let base = lane_base(lane);
+        var weights = 0.0;
+        for (var i = 0u; i < params.axis_len; i++) {
+            weights += weight(base + i * params.inner);
+        }
+        output[lane] = weights;
+    }
+}
+
+// Index of a discontinuous method, `index - floor(index)` selects the previous or next one
+fn discrete_index(index: f32, previous_if_exact: bool) -> f32 {
+    let previous = floor(index);
+    if index == previous && previous_if_exact {
+        return max(previous, 0.0);
+    }
+    return max(previous + 1.0, 0.0);
+}
+
+// Position of the quantile among the sorted values, in numpy's terms the virtual index
+fn virtual_index(n: f32, q: f32, method: u32) -> f32 {
+    switch method {
+        case INVERTED_CDF: {
+            let index = n * q - 1.0;
+            return discrete_index(index, true);
+        }
+        case AVERAGED_INVERTED_CDF: {
+            return n * q - 1.0;
+        }
+        case CLOSEST_OBSERVATION: {
+            let index = n * q - 1.5;
+            return discrete_index(index, floor(index) % 2.0 == 0.0);
+        }
+        case INTERPOLATED_INVERTED_CDF: {
+            return n * q - 1.0;
+        }
+        case HAZEN: {
+            return n * q - 0.5;
+        }
+        case WEIBULL: {
+            return n * q + q - 1.0;
+        }
+        case MEDIAN_UNBIASED: {
+            return n * q + (1.0 + q) / 3.0 - 1.0;
+        }
+        case NORMAL_UNBIASED: {
+            return n * q + (3.0 + 2.0 * q) / 8.0 - 1.0;
+        }
+        case LOWER: {
+            return floor((n - 1.0) * q);
+        }
+        case HIGHER: {
+            return ceil((n - 1.0) * q);
+        }
+        case NEAREST: {
+            return round((n - 1.0) * q);
+        }
+        case LINEAR, MIDPOINT, default: {
+            return (n - 1.0) * q;
+        }
+    }
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    let diff = b - a;
+    if t >= 0.5 {
+        return b - diff * (1.0 - t);
+    }
+    return a + diff * t;
+}
+
+// Quantiles of the lanes sorted along the axis, one lane after the other for every
+// quantile. Lanes holding nans, which sort last, give nan.
+@compute
+@workgroup_size(256)
+fn quantile_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let i = global_id.x;
+    if i < arrayLength(&input_2) * params.lanes {
+        let q = input_2[i / params.lanes];
+        let base = lane_base(i % params.lanes);
+        let n = params.axis_len;
+        if is_nan(input_1[base + (n - 1u) * params.inner]) {
+            output[i] = nan();
+            return;
+        }
+
+        let method = params.option;
+        let index = virtual_index(f32(n), q, method);
+        var previous = u32(clamp(floor(index), 0.0, f32(n - 1u)));
+        var next = min(previous + 1u, n - 1u);
+        if index >= f32(n - 1u) {
+            previous = n - 1u;
+            next = n - 1u;
+        }
+        var gamma = index - floor(index);
+        if index < 0.0 {
+            gamma = 0.0;
+        }
+        if method == AVERAGED_INVERTED_CDF {
+            gamma = select(1.0, 0.5, gamma == 0.0);
+        } else if method == MIDPOINT {
+            gamma = select(0.5, 0.0, gamma == 0.0);
+        }
+        let a = input_1[base + previous * params.inner];
+        let b = input_1[base + next * params.inner];
+        output[i] = lerp(a, b, gamma);
+    }
+}
@@ -0,0 +1,177 @@
+@group(0) @binding(0)
+var<storage, read> input : array<f32>;
+
+@group(0) @binding(1)
+var<storage, read_write> output : array<f32>;
+
+const PI: f32 = 3.141592653589793;
+const LN_2: f32 = 0.6931471805599453;
+
+fn nan() -> f32 {
+    return bitcast<f32>(0x7fc00000u);
+}
+
+fn inf() -> f32 {
+    return bitcast<f32>(0x7f800000u);
+}
+
+// exp(x) - 1 without cancellation for small x, x is non negative
+fn expm1_impl(x: f32) -> f32 {
+    let u = exp(x);
+    if u == 1.0 {
+        return x;
+    }
+    if u > 3.4028235e38 {
+        return u;
+    }
+    return (u - 1.0) * x / log(u);
+}
+
+// log(1 + x) without cancellation for small x, x is larger than -1
+fn log1p_impl(x: f32) -> f32 {
+    let u = 1.0 + x;
+    if u == 1.0 {
+        return x;
+    }
+    if u > 3.4028235e38 {
+        return log(u);
+    }
+    return log(u) * x / (u - 1.0);
+}
+
+@compute
+@workgroup_size(256)
+fn tan_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        let x = input[global_id.x];
+        output[global_id.x] = tan(x);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn arcsin_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        let x = input[global_id.x];
+        if abs(x) > 1.0 {
+            output[global_id.x] = nan();
+        } else {
+            output[global_id.x] = asin(x);
+        }
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn arctan_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        let x = input[global_id.x];
+        output[global_id.x] = atan(x);
+    }
+}
+
+// Uses expm1 so small inputs don't lose precision to cancellation
+@compute
+@workgroup_size(256)
+fn sinh_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        let x = input[global_id.x];
+        let a = abs(x);
+        if a > 20.0 {
+            output[global_id.x] = sign(x) * exp(a - LN_2);
+        } else {
+            let e = expm1_impl(a);
+            output[global_id.x] = sign(x) * 0.5 * (e + e / (e + 1.0));
+        }
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn cosh_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        let x = input[global_id.x];
+        let e = exp(abs(x) - LN_2);
+        output[global_id.x] = e + 0.25 / e;
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn tanh_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        let x = input[global_id.x];
+        if abs(x) > 10.0 {
+            output[global_id.x] = sign(x);
+        } else {
+            let e = expm1_impl(2.0 * abs(x));
+            output[global_id.x] = sign(x) * e / (e + 2.0);
+        }
+    }
+}
+
+// asinh(|x|) = log1p(|x| + x^2 / (1 + sqrt(1 + x^2))), large inputs avoid squaring
+@compute
+@workgroup_size(256)
+fn arcsinh_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        let x = input[global_id.x];
+        let a = abs(x);
+        if a > 1e18 {
+            output[global_id.x] = sign(x) * (log(a) + LN_2);
+        } else {
+            output[global_id.x] = sign(x) * log1p_impl(a + a * a / (1.0 + sqrt(1.0 + a * a)));
+        }
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn arccosh_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        let x = input[global_id.x];
+        if x < 1.0 {
+            output[global_id.x] = nan();
+        } else if x > 1e18 {
+            output[global_id.x] = log(x) + LN_2;
+        } else {
+            let t = x - 1.0;
+            output[global_id.x] = log1p_impl(t + sqrt(2.0 * t + t * t));
+        }
+    }
+}
+
+// atanh(x) = 0.5 * log1p(2x / (1 - x))
+@compute
+@workgroup_size(256)
+fn arctanh_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        let x = input[global_id.x];
+        let a = abs(x);
+        if a > 1.0 {
+            output[global_id.x] = nan();
+        } else if a == 1.0 {
+            output[global_id.x] = sign(x) * inf();
+        } else {
+            output[global_id.x] = sign(x) * 0.5 * log1p_impl(2.0 * a / (1.0 - a));
+        }
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn deg2rad_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        let x = input[global_id.x];
+        output[global_id.x] = x * (PI / 180.0);
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn rad2deg_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input) {
+        let x = input[global_id.x];
+        output[global_id.x] = x * (180.0 / PI);
+    }
+}
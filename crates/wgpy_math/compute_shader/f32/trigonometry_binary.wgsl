@@ -0,0 +1,40 @@
+@group(0) @binding(0)
+var<storage, read> input_1 : array<f32>;
+
+@group(0) @binding(1)
+var<storage, read> input_2 : array<f32>;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<f32>;
+
+fn inf() -> f32 {
+    return bitcast<f32>(0x7f800000u);
+}
+
+@compute
+@workgroup_size(256)
+fn arctan2_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        output[global_id.x] = atan2(input_1[global_id.x], input_2[global_id.x]);
+    }
+}
+
+// sqrt(x^2 + y^2) scaled by the larger input so the squares can't overflow
+@compute
+@workgroup_size(256)
+fn hypot_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let x = abs(input_1[global_id.x]);
+        let y = abs(input_2[global_id.x]);
+        let high = max(x, y);
+        let low = min(x, y);
+        if high == inf() || low == inf() {
+            output[global_id.x] = inf();
+        } else if high == 0.0 {
+            output[global_id.x] = 0.0;
+        } else {
+            let ratio = low / high;
+            output[global_id.x] = high * sqrt(1.0 + ratio * ratio);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+@group(0) @binding(0)
+var<storage, read> input : array<f32>;
+
+struct UnwrapParams {
+    axis_len: u32,
+    inner: u32,
+    lanes: u32,
+    discont: f32,
+    period: f32,
+}
+
+@group(0) @binding(1)
+var<storage, read> params : UnwrapParams;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<f32>;
+
+// Every invocation walks one lane along the axis, accumulating the corrections
+// that bring each difference back into [-period / 2, period / 2]
+@compute
+@workgroup_size(256)
+fn unwrap_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x >= params.lanes {
+        return;
+    }
+    let outer = global_id.x / params.inner;
+    let base = outer * params.axis_len * params.inner + global_id.x % params.inner;
+    let high = params.period / 2.0;
+
+    var correction = 0.0;
+    var previous = input[base];
+    output[base] = previous;
+    for (var i = 1u; i < params.axis_len; i++) {
+        let index = base + i * params.inner;
+        let current = input[index];
+        let dd = current - previous;
+        let shifted = dd + high;
+        var dd_mod = shifted - floor(shifted / params.period) * params.period - high;
+        if dd_mod == -high && dd > 0.0 {
+            dd_mod = high;
+        }
+        if abs(dd) >= params.discont {
+            correction += dd_mod - dd;
+        }
+        output[index] = current + correction;
+        previous = current;
+    }
+}
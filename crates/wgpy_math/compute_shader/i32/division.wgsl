@@ -0,0 +1,61 @@
+@group(0) @binding(0)
+var<storage, read> input_1 : array<i32>;
+
+@group(0) @binding(1)
+var<storage, read> input_2 : array<i32>;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<i32>;
+
+// Division by zero gives zero, as numpy does for integers
+
+@compute
+@workgroup_size(256)
+fn floor_divide_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let x = input_1[global_id.x];
+        let y = input_2[global_id.x];
+        if y == 0 {
+            output[global_id.x] = 0;
+        } else {
+            var q = x / y;
+            if x % y != 0 && ((x < 0) != (y < 0)) {
+                q -= 1;
+            }
+            output[global_id.x] = q;
+        }
+    }
+}
+
+// Result takes the sign of the divisor
+@compute
+@workgroup_size(256)
+fn remainder_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let x = input_1[global_id.x];
+        let y = input_2[global_id.x];
+        if y == 0 {
+            output[global_id.x] = 0;
+        } else {
+            var r = x % y;
+            if r != 0 && ((r < 0) != (y < 0)) {
+                r += y;
+            }
+            output[global_id.x] = r;
+        }
+    }
+}
+
+// Result takes the sign of the dividend
+@compute
+@workgroup_size(256)
+fn fmod_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let y = input_2[global_id.x];
+        if y == 0 {
+            output[global_id.x] = 0;
+        } else {
+            output[global_id.x] = input_1[global_id.x] % y;
+        }
+    }
+}
@@ -0,0 +1,127 @@
+@group(0) @binding(0)
+var<storage, read> input : array<i32>;
+
+struct ReduceParams {
+    axis_len: u32,
+    inner: u32,
+    lanes: u32,
+    op: u32,
+    ddof: f32,
+}
+
+@group(0) @binding(1)
+var<storage, read> params : ReduceParams;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<i32>;
+
+const OP_SUM: u32 = 0u;
+const OP_PROD: u32 = 1u;
+const OP_MIN: u32 = 2u;
+const OP_MAX: u32 = 3u;
+const OP_ARGMIN: u32 = 4u;
+const OP_ARGMAX: u32 = 5u;
+
+const WORKGROUP_SIZE: u32 = 256u;
+
+// Reduction of the values seen by one invocation, or by a part of the workgroup
+struct Partial {
+    count: u32,
+    sum: i32,
+    prod: i32,
+    min_value: i32,
+    max_value: i32,
+    min_index: u32,
+    max_index: u32,
+}
+
+var<workgroup> partials : array<Partial, WORKGROUP_SIZE>;
+
+fn empty_partial() -> Partial {
+    return Partial(0u, 0, 1, 0, 0, 0u, 0u);
+}
+
+// Combines two partials, ties of the extrema keep the first index
+fn combine(a: Partial, b: Partial) -> Partial {
+    if a.count == 0u {
+        return b;
+    }
+    if b.count == 0u {
+        return a;
+    }
+    var result = Partial(a.count + b.count, a.sum + b.sum, a.prod * b.prod, a.min_value,
+        a.max_value, a.min_index, a.max_index);
+    if b.min_value < a.min_value || (b.min_value == a.min_value && b.min_index < a.min_index) {
+        result.min_value = b.min_value;
+        result.min_index = b.min_index;
+    }
+    if b.max_value > a.max_value || (b.max_value == a.max_value && b.max_index < a.max_index) {
+        result.max_value = b.max_value;
+        result.max_index = b.max_index;
+    }
+    return result;
+}
+
+// Tree reduction of the partials of every invocation, the total is returned to all
+fn reduce_partials(local: u32, partial: Partial) -> Partial {
+    workgroupBarrier();
+    partials[local] = partial;
+    workgroupBarrier();
+    for (var stride = WORKGROUP_SIZE / 2u; stride > 0u; stride /= 2u) {
+        if local < stride {
+            partials[local] = combine(partials[local], partials[local + stride]);
+        }
+        workgroupBarrier();
+    }
+    return workgroupUniformLoad(&partials[0]);
+}
+
+// Integers hold no nans, so only the exact reductions are computed here. Sums and
+// products wrap around on overflow.
+fn reduce(local: u32, base: u32) -> i32 {
+    var partial = empty_partial();
+    for (var i = local; i < params.axis_len; i += WORKGROUP_SIZE) {
+        let x = input[base + i * params.inner];
+        partial = combine(partial, Partial(1u, x, x, x, x, i, i));
+    }
+    let total = reduce_partials(local, partial);
+
+    switch params.op {
+        case OP_SUM: {
+            return total.sum;
+        }
+        case OP_PROD: {
+            return total.prod;
+        }
+        case OP_MIN: {
+            return total.min_value;
+        }
+        case OP_MAX: {
+            return total.max_value;
+        }
+        case OP_ARGMIN: {
+            return i32(total.min_index);
+        }
+        default: {
+            return i32(total.max_index);
+        }
+    }
+}
+
+// Every workgroup reduces one lane along the axis at a time
+@compute
+@workgroup_size(256)
+fn nan_reduce_(
+    @builtin(local_invocation_id) local_id: vec3<u32>,
+    @builtin(workgroup_id) workgroup_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>
+) {
+    for (var lane = workgroup_id.x; lane < params.lanes; lane += num_workgroups.x) {
+        let outer = lane / params.inner;
+        let base = outer * params.axis_len * params.inner + lane % params.inner;
+        let result = reduce(local_id.x, base);
+        if local_id.x == 0u {
+            output[lane] = result;
+        }
+    }
+}
@@ -0,0 +1,49 @@
+@group(0) @binding(0)
+var<storage, read> input_1 : array<u32>;
+
+@group(0) @binding(1)
+var<storage, read> input_2 : array<u32>;
+
+@group(0) @binding(2)
+var<storage, read_write> output : array<u32>;
+
+// Division by zero gives zero, as numpy does for integers
+
+@compute
+@workgroup_size(256)
+fn floor_divide_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let y = input_2[global_id.x];
+        if y == 0u {
+            output[global_id.x] = 0u;
+        } else {
+            output[global_id.x] = input_1[global_id.x] / y;
+        }
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn remainder_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let y = input_2[global_id.x];
+        if y == 0u {
+            output[global_id.x] = 0u;
+        } else {
+            output[global_id.x] = input_1[global_id.x] % y;
+        }
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn fmod_(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    if global_id.x < arrayLength(&input_1) {
+        let y = input_2[global_id.x];
+        if y == 0u {
+            output[global_id.x] = 0u;
+        } else {
+            output[global_id.x] = input_1[global_id.x] % y;
+        }
+    }
+}
@@ -1,16 +1,161 @@
-use arrow_gpu::kernels::*;
-use webgpupy_core::{ufunc_nin2_nout1, ufunc_nin2_nout1_body, Dtype, NdArray};
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{ArrayUtils, ArrowArrayGPU, ArrowPrimitiveType, ArrowType, PrimitiveArrayGpu},
+    gpu_utils::ArrowComputePipeline,
+    kernels::*,
+};
+use webgpupy_core::{
+    cast_if_needed, promoted_dtype, ufunc_nin2_nout1, ufunc_nin2_nout1_body, Dtype, NdArray,
+};
 
 ufunc_nin2_nout1_body!(multiply, mul_op_dyn);
 ufunc_nin2_nout1_body!(divide, div_op_dyn);
 ufunc_nin2_nout1_body!(add, add_op_dyn);
 ufunc_nin2_nout1_body!(subtract, sub_op_dyn);
 
+trait DivisionType: ArrowPrimitiveType {
+    const DIVISION_SHADER: &'static str;
+}
+
+impl DivisionType for f32 {
+    const DIVISION_SHADER: &'static str = include_str!("../compute_shader/f32/division.wgsl");
+}
+
+impl DivisionType for i32 {
+    const DIVISION_SHADER: &'static str = include_str!("../compute_shader/i32/division.wgsl");
+}
+
+impl DivisionType for u32 {
+    const DIVISION_SHADER: &'static str = include_str!("../compute_shader/u32/division.wgsl");
+}
+
+trait Division {
+    fn division_op(
+        &self,
+        other: &Self,
+        entry_point: &str,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self;
+}
+
+impl<T: DivisionType> Division for PrimitiveArrayGpu<T> {
+    fn division_op(
+        &self,
+        other: &Self,
+        entry_point: &str,
+        pipeline: &mut ArrowComputePipeline,
+    ) -> Self {
+        let result_buffer = pipeline.apply_binary_function(
+            &self.data,
+            &other.data,
+            self.data.size(),
+            T::DIVISION_SHADER,
+            entry_point,
+            (self.len as u32).div_ceil(256),
+        );
+
+        Self {
+            data: Arc::new(result_buffer),
+            gpu_device: self.get_gpu_device(),
+            len: self.len,
+            null_buffer: None,
+            phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Dtype of the division ufuncs, the inputs are promoted and booleans divide as Int8
+fn division_dtype(dtypes: &[Dtype]) -> Dtype {
+    match promoted_dtype(dtypes) {
+        Dtype::Bool => Dtype::Int8,
+        x => x,
+    }
+}
+
+/// Runs a division kernel after promoting both inputs to a common dtype. The kernels
+/// only exist for 32 bit types, so narrower integers are widened and cast back after.
+fn division_op_dyn(
+    data_1: &ArrowArrayGPU,
+    data_2: &ArrowArrayGPU,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    let dtype = division_dtype(&[data_1.get_dtype().into(), data_2.get_dtype().into()]);
+    let kernel_type = match dtype {
+        Dtype::Float32 => ArrowType::Float32Type,
+        x if x.is_unsigned_int() => ArrowType::UInt32Type,
+        _ => ArrowType::Int32Type,
+    };
+
+    let (mut casted_1, mut casted_2) = (None, None);
+    let input_1 = cast_if_needed(data_1, &kernel_type, &mut casted_1, pipeline);
+    let input_2 = cast_if_needed(data_2, &kernel_type, &mut casted_2, pipeline);
+    let result: ArrowArrayGPU = match (input_1, input_2) {
+        (ArrowArrayGPU::Float32ArrayGPU(x), ArrowArrayGPU::Float32ArrayGPU(y)) => {
+            x.division_op(y, entry_point, pipeline).into()
+        }
+        (ArrowArrayGPU::Int32ArrayGPU(x), ArrowArrayGPU::Int32ArrayGPU(y)) => {
+            x.division_op(y, entry_point, pipeline).into()
+        }
+        (ArrowArrayGPU::UInt32ArrayGPU(x), ArrowArrayGPU::UInt32ArrayGPU(y)) => {
+            x.division_op(y, entry_point, pipeline).into()
+        }
+        _ => unreachable!(),
+    };
+
+    let mut casted = None;
+    cast_if_needed(&result, (&dtype).into(), &mut casted, pipeline);
+    casted.unwrap_or(result)
+}
+
+fn floor_divide_op_dyn(
+    data_1: &ArrowArrayGPU,
+    data_2: &ArrowArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    division_op_dyn(data_1, data_2, "floor_divide_", pipeline)
+}
+
+fn remainder_op_dyn(
+    data_1: &ArrowArrayGPU,
+    data_2: &ArrowArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    division_op_dyn(data_1, data_2, "remainder_", pipeline)
+}
+
+fn fmod_op_dyn(
+    data_1: &ArrowArrayGPU,
+    data_2: &ArrowArrayGPU,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    division_op_dyn(data_1, data_2, "fmod_", pipeline)
+}
+
+ufunc_nin2_nout1_body!(floor_divide, floor_divide_op_dyn, division_dtype);
+ufunc_nin2_nout1_body!(remainder, remainder_op_dyn, division_dtype);
+ufunc_nin2_nout1_body!(fmod, fmod_op_dyn, division_dtype);
+
+/// Returns the floor division and the remainder of the inputs, element-wise
+pub fn divmod(
+    input1: &NdArray,
+    input2: &NdArray,
+    where_: Option<&NdArray>,
+    dtype: Option<Dtype>,
+) -> (NdArray, NdArray) {
+    (
+        floor_divide(input1, input2, where_, dtype),
+        remainder(input1, input2, where_, dtype),
+    )
+}
+
 #[cfg(test)]
 mod test {
-    use webgpupy_core::NdArray;
+    use test_utils::float_slice_eq_in_error;
+    use webgpupy_core::{Dtype, NdArray};
 
-    use crate::multiply;
+    use crate::{divmod, floor_divide, fmod, multiply, remainder};
 
     #[test]
     fn test_multiply() {
@@ -26,4 +171,61 @@ mod test {
             vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0].into()
         );
     }
+
+    #[test]
+    fn test_floor_divide_remainder_int() {
+        let arr1 = NdArray::from_slice([7i32, -7, 7, -7, 5].as_ref().into(), vec![5], None);
+        let arr2 = NdArray::from_slice([2i32, 2, -2, -2, 0].as_ref().into(), vec![5], None);
+
+        let (quotient, remainder) = divmod(&arr1, &arr2, None, None);
+        assert_eq!(quotient.dtype, Dtype::Int32);
+        assert_eq!(
+            quotient.data.get_raw_values(),
+            vec![3i32, -4, -4, 3, 0].into()
+        );
+        assert_eq!(
+            remainder.data.get_raw_values(),
+            vec![1i32, 1, -1, -1, 0].into()
+        );
+
+        let result = fmod(&arr1, &arr2, None, None);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![1i32, -1, 1, -1, 0].into()
+        );
+    }
+
+    #[test]
+    fn test_floor_divide_mixed_dtypes() {
+        let arr1 = NdArray::from_slice([7u8, 9].as_ref().into(), vec![2], None);
+        let arr2 = NdArray::from_slice([-2i8, 4].as_ref().into(), vec![2], None);
+
+        let result = floor_divide(&arr1, &arr2, None, None);
+        assert_eq!(result.dtype, Dtype::Int16);
+        assert_eq!(result.data.get_raw_values(), vec![-4i16, 2].into());
+    }
+
+    #[test]
+    fn test_floor_divide_remainder_f32() {
+        let arr1 = NdArray::from_slice([7.5f32, -7.5, 1.0].as_ref().into(), vec![3], None);
+        let arr2 = NdArray::from_slice([2.0f32, 2.0, -0.3].as_ref().into(), vec![3], None);
+
+        let result = floor_divide(&arr1, &arr2, None, None);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![3.0f32, -4.0, -4.0].into()
+        );
+
+        let result = remainder(&arr1, &arr2, None, None);
+        float_slice_eq_in_error(
+            result.data.get_raw_values(),
+            vec![1.5f32, 0.5, 1.0f32.rem_euclid(-0.3) - 0.3].into(),
+        );
+
+        let result = fmod(&arr1, &arr2, None, None);
+        float_slice_eq_in_error(
+            result.data.get_raw_values(),
+            vec![1.5f32, -1.5, 1.0 % -0.3].into(),
+        );
+    }
 }
@@ -0,0 +1,503 @@
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{ArrayUtils, Float32ArrayGPU},
+    gpu_utils::ArrowComputePipeline,
+};
+use webgpupy_core::{
+    as_f32_array, broadcast_shape, broadcast_to, concatenate, full, normalize_axis, Dtype,
+    IndexSliceOp, NdArray, NdArrayError, NdArrayResult, ScalarValue,
+};
+
+use crate::{nanfunctions::ReduceLayout, subtract};
+
+const CALCULUS_SHADER: &str = include_str!("../compute_shader/f32/calculus.wgsl");
+
+/// Distances between consecutive samples along the axis, for the calculus kernel
+enum Steps<'a> {
+    Uniform(f32),
+    /// The coordinates of the samples along the axis
+    Axis(&'a NdArray),
+    /// The coordinates of every element, with the shape of the input
+    Elements(&'a NdArray),
+}
+
+/// Runs an entry point of the calculus kernel over the lanes of `layout`, the result
+/// is f32 with the given shape. `option` and `initial` are passed to the shader.
+fn calculus_op(
+    input: &NdArray,
+    steps: Steps,
+    layout: &ReduceLayout,
+    option: u32,
+    initial: f32,
+    entry_point: &str,
+    shape: Vec<u32>,
+) -> NdArray {
+    let len = shape.iter().product::<u32>();
+    let gpu_device = input.get_gpu_device();
+    let mut pipeline = ArrowComputePipeline::new(gpu_device, Some("calculus"));
+    let (mut casted_1, mut casted_2) = (None, None);
+    let values = as_f32_array(&input.data, &mut casted_1, &mut pipeline);
+    let uniform_buffer;
+    let (spacing, spacing_kind) = match steps {
+        Steps::Uniform(dx) => {
+            uniform_buffer = pipeline.device.create_gpu_buffer_with_data(&[dx]);
+            (&uniform_buffer, 0)
+        }
+        Steps::Axis(x) => (
+            &*as_f32_array(&x.data, &mut casted_2, &mut pipeline).data,
+            1,
+        ),
+        Steps::Elements(x) => (
+            &*as_f32_array(&x.data, &mut casted_2, &mut pipeline).data,
+            2,
+        ),
+    };
+    let params = [
+        layout.axis_len,
+        layout.inner,
+        layout.lanes,
+        spacing_kind,
+        option,
+        initial.to_bits(),
+    ];
+    let params = pipeline.device.create_gpu_buffer_with_data(&params);
+    let buffer = pipeline.apply_ternary_function(
+        &values.data,
+        spacing,
+        &params,
+        len as u64 * 4,
+        CALCULUS_SHADER,
+        entry_point,
+        len.div_ceil(256),
+    );
+    let data = Float32ArrayGPU {
+        data: Arc::new(buffer),
+        gpu_device: values.get_gpu_device(),
+        phantom: std::marker::PhantomData,
+        len: len as usize,
+        null_buffer: None,
+    };
+    pipeline.finish();
+
+    NdArray {
+        dims: shape.len() as u16,
+        shape,
+        data: data.into(),
+        dtype: Dtype::Float32,
+    }
+}
+
+/// Difference of the consecutive elements along the axis, booleans differ when they
+/// are not equal
+fn diff_once(a: &NdArray, axis: usize) -> NdArray {
+    let slices = |range: IndexSliceOp| {
+        let mut range = Some(range);
+        (0..a.shape.len())
+            .map(|i| {
+                if i == axis {
+                    range.take().unwrap()
+                } else {
+                    IndexSliceOp::RangeFrom(0..)
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+    let upper = a.get_items(&slices(IndexSliceOp::RangeFrom(1..)));
+    let lower = a.get_items(&slices(IndexSliceOp::Range(0..a.shape[axis] as i64 - 1)));
+    if a.dtype == Dtype::Bool {
+        let difference = subtract(
+            &upper.astype(Dtype::Int8),
+            &lower.astype(Dtype::Int8),
+            None,
+            None,
+        );
+        return difference.astype(Dtype::Bool);
+    }
+    subtract(&upper, &lower, None, None)
+}
+
+/// Calculates the n-th discrete difference along the given axis. `prepend` and `append`
+/// are joined to the array along the axis first, 0-d values are repeated along it.
+pub fn diff(
+    a: &NdArray,
+    n: u32,
+    axis: i32,
+    prepend: Option<&NdArray>,
+    append: Option<&NdArray>,
+) -> NdArrayResult<NdArray> {
+    if n == 0 {
+        return Ok(a.clone_array());
+    }
+    if a.shape.is_empty() {
+        return Err(NdArrayError::ValueError(
+            "diff requires input that is at least one dimensional".to_string(),
+        ));
+    }
+    let axis = normalize_axis(axis, a.shape.len())?;
+
+    let mut edge_shape = a.shape.clone();
+    edge_shape[axis] = 1;
+    let edges = [prepend, append].map(|x| {
+        x.filter(|x| x.shape.is_empty())
+            .map(|x| broadcast_to(x, &edge_shape))
+    });
+    let mut arrays = Vec::with_capacity(3);
+    if let Some(x) = prepend {
+        arrays.push(edges[0].as_ref().unwrap_or(x));
+    }
+    arrays.push(a);
+    if let Some(x) = append {
+        arrays.push(edges[1].as_ref().unwrap_or(x));
+    }
+    let joined = match arrays.len() {
+        1 => None,
+        _ => Some(concatenate(&arrays, Some(axis as i32))?),
+    };
+    let joined = joined.as_ref().unwrap_or(a);
+
+    if n >= joined.shape[axis] {
+        let mut shape = joined.shape.clone();
+        shape[axis] = 0;
+        return Ok(NdArray::zero_sized(
+            shape,
+            joined.dtype,
+            joined.get_gpu_device(),
+        ));
+    }
+    let mut result = diff_once(joined, axis);
+    for _ in 1..n {
+        result = diff_once(&result, axis);
+    }
+    Ok(result)
+}
+
+/// Flattens an argument of [`ediff1d`] into `dtype`, numpy only allows casts within
+/// the same kind or to a higher kind
+fn ediff1d_edge(x: &NdArray, dtype: Dtype, name: &str) -> NdArrayResult<NdArray> {
+    let kind = |dtype: Dtype| match dtype {
+        Dtype::Bool => 0,
+        x if x.is_unsigned_int() => 1,
+        Dtype::Float32 => 3,
+        _ => 2,
+    };
+    if kind(x.dtype) > kind(dtype) {
+        return Err(NdArrayError::DtypeError(format!(
+            "dtype of `{}` must be compatible with input `ary` under the `same_kind` rule.",
+            name
+        )));
+    }
+    Ok(x.flatten().astype(dtype))
+}
+
+/// Differences between consecutive elements of the flattened array, `to_begin` and
+/// `to_end` are flattened and joined before and after them
+pub fn ediff1d(
+    ary: &NdArray,
+    to_end: Option<&NdArray>,
+    to_begin: Option<&NdArray>,
+) -> NdArrayResult<NdArray> {
+    let ary = ary.flatten();
+    let to_begin = to_begin
+        .map(|x| ediff1d_edge(x, ary.dtype, "to_begin"))
+        .transpose()?;
+    let to_end = to_end
+        .map(|x| ediff1d_edge(x, ary.dtype, "to_end"))
+        .transpose()?;
+    let differences = (ary.len() > 1).then(|| diff_once(&ary, 0));
+
+    let arrays = [to_begin.as_ref(), differences.as_ref(), to_end.as_ref()]
+        .into_iter()
+        .flatten()
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<_>>();
+    if arrays.is_empty() {
+        return Ok(NdArray::zero_sized(
+            vec![0],
+            ary.dtype,
+            ary.get_gpu_device(),
+        ));
+    }
+    concatenate(&arrays, Some(0))
+}
+
+/// Distances between the samples along an axis of [`gradient`]
+#[derive(Debug, Clone, Copy)]
+pub enum Spacing<'a> {
+    /// The same distance between all the samples
+    Uniform(f32),
+    /// The coordinates of the samples along the axis, a 0-d array is a uniform distance
+    Coordinates(&'a NdArray),
+}
+
+/// Gradient of `f` along every axis in `axis`, or all of them when None. `spacing` is
+/// empty for unit distances, a single uniform distance for every axis or one spacing
+/// per axis. The interior uses central differences and the boundaries one sided
+/// differences of `edge_order`, which is 1 or 2.
+pub fn gradient(
+    f: &NdArray,
+    spacing: &[Spacing],
+    axis: Option<&[i32]>,
+    edge_order: u32,
+) -> NdArrayResult<Vec<NdArray>> {
+    let axes = match axis {
+        None => (0..f.shape.len()).collect::<Vec<_>>(),
+        Some(axis) => axis
+            .iter()
+            .map(|x| normalize_axis(*x, f.shape.len()))
+            .collect::<NdArrayResult<Vec<_>>>()?,
+    };
+    if (1..axes.len()).any(|i| axes[..i].contains(&axes[i])) {
+        return Err(NdArrayError::ValueError("repeated axis".to_string()));
+    }
+    let spacing = match spacing.len() {
+        0 => vec![Spacing::Uniform(1.0); axes.len()],
+        1 if !matches!(spacing[0], Spacing::Coordinates(x) if !x.shape.is_empty()) => {
+            vec![spacing[0]; axes.len()]
+        }
+        n if n == axes.len() => spacing.to_vec(),
+        _ => {
+            return Err(NdArrayError::ValueError(
+                "invalid number of arguments".to_string(),
+            ))
+        }
+    };
+    if !(1..=2).contains(&edge_order) {
+        return Err(NdArrayError::ValueError(
+            "'edge_order' must be 1 or 2".to_string(),
+        ));
+    }
+
+    let mut gradients = Vec::with_capacity(axes.len());
+    for (axis, spacing) in axes.into_iter().zip(spacing) {
+        let axis_len = f.shape[axis];
+        if axis_len < edge_order + 1 {
+            return Err(NdArrayError::ValueError(
+                "Shape of array too small to calculate a numerical gradient, at least (edge_order + 1) elements are required.".to_string(),
+            ));
+        }
+        let steps = match spacing {
+            Spacing::Uniform(dx) => Steps::Uniform(dx),
+            Spacing::Coordinates(x) if x.shape.is_empty() => {
+                Steps::Uniform(x.item()?.as_f64() as f32)
+            }
+            Spacing::Coordinates(x) if x.shape.len() > 1 => {
+                return Err(NdArrayError::ValueError(
+                    "distances must be either scalars or 1d".to_string(),
+                ))
+            }
+            Spacing::Coordinates(x) if x.shape[0] != axis_len => {
+                return Err(NdArrayError::ValueError(
+                    "when 1d, distances must match the length of the corresponding dimension"
+                        .to_string(),
+                ))
+            }
+            Spacing::Coordinates(x) => Steps::Axis(x),
+        };
+        if f.is_empty() {
+            gradients.push(NdArray::zero_sized(
+                f.shape.clone(),
+                Dtype::Float32,
+                f.get_gpu_device(),
+            ));
+            continue;
+        }
+        let layout = ReduceLayout::new(&f.shape, Some(axis as i32), false)?;
+        gradients.push(calculus_op(
+            f,
+            steps,
+            &layout,
+            edge_order,
+            0.0,
+            "gradient_",
+            f.shape.clone(),
+        ));
+    }
+    Ok(gradients)
+}
+
+/// Integrates `y` along the axis with the trapezoidal rule, `cumulative` keeps the
+/// running integral, with `initial` prepended when given
+fn trapezoid_op(
+    y: &NdArray,
+    x: Option<&NdArray>,
+    dx: f32,
+    axis: i32,
+    cumulative: bool,
+    initial: Option<f32>,
+) -> NdArrayResult<NdArray> {
+    let layout = ReduceLayout::new(&y.shape, Some(axis), false)?;
+    let axis = normalize_axis(axis, y.shape.len())?;
+    let mut shape = y.shape.clone();
+    if cumulative {
+        shape[axis] = shape[axis].saturating_sub(1) + u32::from(initial.is_some());
+    } else {
+        shape.remove(axis);
+    }
+
+    let broadcasted;
+    let steps = match x {
+        None => Steps::Uniform(dx),
+        Some(x) if x.shape.len() == 1 => {
+            if x.shape[0] != y.shape[axis] {
+                return Err(NdArrayError::ValueError(format!(
+                    "x has {} samples but y has {} along the axis",
+                    x.shape[0], y.shape[axis]
+                )));
+            }
+            Steps::Axis(x)
+        }
+        Some(x) => {
+            // Like numpy, x with fewer dimensions is aligned with the last ones of y
+            if broadcast_shape(&x.shape, &y.shape)? != y.shape {
+                return Err(NdArrayError::ValueError(
+                    "x must be 1-D or broadcastable to the shape of y".to_string(),
+                ));
+            }
+            if x.shape == y.shape {
+                Steps::Elements(x)
+            } else {
+                broadcasted = broadcast_to(x, &y.shape);
+                Steps::Elements(&broadcasted)
+            }
+        }
+    };
+
+    let gpu_device = y.get_gpu_device();
+    if shape.contains(&0) {
+        return Ok(NdArray::zero_sized(shape, Dtype::Float32, gpu_device));
+    }
+    if y.is_empty() {
+        let fill = ScalarValue::F32(initial.unwrap_or(0.0));
+        return full(shape, fill.into(), None, Some(gpu_device));
+    }
+    let (entry_point, option) = if cumulative {
+        ("cumulative_trapezoid_", u32::from(initial.is_some()))
+    } else {
+        ("trapezoid_", 0)
+    };
+    Ok(calculus_op(
+        y,
+        steps,
+        &layout,
+        option,
+        initial.unwrap_or(0.0),
+        entry_point,
+        shape,
+    ))
+}
+
+/// Integrates along the given axis using the composite trapezoidal rule. The samples
+/// are `dx` apart, unless their coordinates `x` are given along the axis or for every
+/// element, broadcast to the shape of `y`.
+pub fn trapezoid(y: &NdArray, x: Option<&NdArray>, dx: f32, axis: i32) -> NdArrayResult<NdArray> {
+    trapezoid_op(y, x, dx, axis, false, None)
+}
+
+/// Cumulatively integrates along the given axis using the composite trapezoidal rule,
+/// see [`trapezoid`]. The result has one element less along the axis, unless `initial`
+/// is given to start it.
+pub fn cumulative_trapezoid(
+    y: &NdArray,
+    x: Option<&NdArray>,
+    dx: f32,
+    axis: i32,
+    initial: Option<f32>,
+) -> NdArrayResult<NdArray> {
+    trapezoid_op(y, x, dx, axis, true, initial)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::float_slice_eq_in_error;
+
+    #[test]
+    fn test_diff() {
+        let a = NdArray::from_slice([1i32, 2, 4, 7, 0, 5].as_ref().into(), vec![2, 3], None);
+
+        let result = diff(&a, 1, -1, None, None).unwrap();
+        assert_eq!(&result.shape, &[2, 2]);
+        assert_eq!(result.data.get_raw_values(), vec![1i32, 2, -7, 5].into());
+
+        let result = diff(&a, 1, 0, None, None).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![6i32, -2, 1].into());
+
+        let zero = NdArray::from_slice([0i32].as_ref().into(), vec![], None);
+        let result = diff(&a, 2, 1, Some(&zero), None).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![0i32, 1, -14, 12].into());
+
+        let result = diff(&a, 3, 1, None, None).unwrap();
+        assert_eq!(&result.shape, &[2, 0]);
+    }
+
+    #[test]
+    fn test_ediff1d() {
+        let a = NdArray::from_slice([1.0f32, 2.0, 4.0, 7.0].as_ref().into(), vec![2, 2], None);
+        let begin = NdArray::from_slice([-1i32].as_ref().into(), vec![], None);
+        let end = NdArray::from_slice([8.0f32, 9.0].as_ref().into(), vec![2], None);
+
+        let result = ediff1d(&a, Some(&end), Some(&begin)).unwrap();
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![-1.0f32, 1.0, 2.0, 3.0, 8.0, 9.0].into()
+        );
+
+        let a = a.astype(Dtype::Int32);
+        assert!(ediff1d(&a, Some(&end), None).is_err());
+    }
+
+    #[test]
+    fn test_gradient() {
+        let values = [1.0f32, 2.0, 4.0, 7.0, 11.0, 16.0];
+        let f = NdArray::from_slice(values.as_ref().into(), vec![2, 3], None);
+
+        let result = gradient(&f, &[], None, 1).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result[0].data.get_raw_values(),
+            vec![6.0f32, 9.0, 12.0, 6.0, 9.0, 12.0].into()
+        );
+        assert_eq!(
+            result[1].data.get_raw_values(),
+            vec![1.0f32, 1.5, 2.0, 4.0, 4.5, 5.0].into()
+        );
+
+        let x = NdArray::from_slice([0.0f32, 1.0, 1.5].as_ref().into(), vec![3], None);
+        let result = gradient(&f, &[Spacing::Coordinates(&x)], Some(&[1]), 2).unwrap();
+        float_slice_eq_in_error(
+            result[0].data.get_raw_values(),
+            vec![-1.0f32, 3.0, 5.0, 0.0, 8.0, 12.0].into(),
+        );
+
+        assert!(gradient(&f, &[], Some(&[0]), 2).is_err());
+    }
+
+    #[test]
+    fn test_trapezoid() {
+        let values = [1.0f32, 2.0, 4.0, 7.0, 11.0, 16.0];
+        let y = NdArray::from_slice(values.as_ref().into(), vec![2, 3], None);
+
+        let result = trapezoid(&y, None, 2.0, -1).unwrap();
+        assert_eq!(&result.shape, &[2]);
+        assert_eq!(result.data.get_raw_values(), vec![9.0f32, 45.0].into());
+
+        let x = NdArray::from_slice([0.0f32, 1.0, 1.5].as_ref().into(), vec![3], None);
+        let result = cumulative_trapezoid(&y, Some(&x), 1.0, 1, Some(0.0)).unwrap();
+        assert_eq!(&result.shape, &[2, 3]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![0.0f32, 1.5, 3.0, 0.0, 9.0, 15.75].into()
+        );
+
+        let result = cumulative_trapezoid(&y, None, 1.0, 0, None).unwrap();
+        assert_eq!(&result.shape, &[1, 3]);
+        assert_eq!(result.data.get_raw_values(), vec![4.0f32, 6.5, 10.0].into());
+
+        let y = y.reshape(vec![2, 1, 3]).unwrap();
+        let x = x.reshape(vec![1, 3]).unwrap();
+        let result = trapezoid(&y, Some(&x), 1.0, -1).unwrap();
+        assert_eq!(&result.shape, &[2, 1]);
+        assert_eq!(result.data.get_raw_values(), vec![3.0f32, 15.75].into());
+    }
+}
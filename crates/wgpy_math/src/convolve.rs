@@ -0,0 +1,270 @@
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{ArrayUtils, Float32ArrayGPU},
+    gpu_utils::ArrowComputePipeline,
+};
+use webgpupy_core::{
+    as_f32_array, pad, Dtype, NdArray, NdArrayError, NdArrayResult, PadMode, ScalarValue,
+};
+
+const CORRELATE_SHADER: &str = include_str!("../compute_shader/f32/correlate.wgsl");
+
+/// Size of the output of the 1-D [`convolve`] and [`correlate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvolveMode {
+    /// Every point of overlap of the inputs
+    Full,
+    /// The length of the longest input, centered on the full output
+    Same,
+    /// Only the points where the inputs overlap completely
+    Valid,
+}
+
+/// Correlates `weights` with the window of `padded` at every position of an output of
+/// `shape`, `flip` reverses the weights along every axis. The result is cast to `dtype`.
+fn correlate_op(
+    padded: &NdArray,
+    weights: &NdArray,
+    shape: Vec<u32>,
+    flip: bool,
+    dtype: Dtype,
+) -> NdArray {
+    let len = shape.iter().product::<u32>();
+    let gpu_device = padded.get_gpu_device();
+    let mut pipeline = ArrowComputePipeline::new(gpu_device, Some("correlate"));
+    let (mut casted_1, mut casted_2) = (None, None);
+    let input = as_f32_array(&padded.data, &mut casted_1, &mut pipeline);
+    let weights_data = as_f32_array(&weights.data, &mut casted_2, &mut pipeline);
+    let mut params = vec![u32::from(flip), shape.len() as u32];
+    params.extend(&shape);
+    params.extend(&padded.shape);
+    params.extend(&weights.shape);
+    let params = pipeline.device.create_gpu_buffer_with_data(&params);
+    let buffer = pipeline.apply_ternary_function(
+        &input.data,
+        &weights_data.data,
+        &params,
+        len as u64 * 4,
+        CORRELATE_SHADER,
+        "correlate_",
+        len.div_ceil(256),
+    );
+    let data = Float32ArrayGPU {
+        data: Arc::new(buffer),
+        gpu_device: input.get_gpu_device(),
+        phantom: std::marker::PhantomData,
+        len: len as usize,
+        null_buffer: None,
+    };
+    pipeline.finish();
+
+    let result = NdArray {
+        dims: shape.len() as u16,
+        shape,
+        data: data.into(),
+        dtype: Dtype::Float32,
+    };
+    if dtype == Dtype::Float32 {
+        result
+    } else {
+        result.astype(dtype)
+    }
+}
+
+pub(crate) fn as_1d(x: &NdArray) -> NdArrayResult<NdArray> {
+    if x.shape.len() > 1 {
+        return Err(NdArrayError::ValueError(
+            "object too deep for desired array".to_string(),
+        ));
+    }
+    x.reshape(vec![x.len()])
+}
+
+/// Cross-correlation of the 1-D arrays `z[k] = sum_j a[k + j - (len(v) - 1)] * v[j]`,
+/// trimmed to the mode. `flip` reverses `v`, which convolves the arrays.
+fn correlate_1d_op(
+    a: &NdArray,
+    v: &NdArray,
+    mode: ConvolveMode,
+    flip: bool,
+) -> NdArrayResult<NdArray> {
+    let (n, m) = (a.len(), v.len());
+    let shortest = n.min(m);
+    // numpy correlates a longer `v` with `a` and reverses the result, which moves the
+    // center of the same mode when the shortest length is even
+    let (len, start) = match mode {
+        ConvolveMode::Full => (n + m - 1, 0),
+        ConvolveMode::Same if m > n => (m, n / 2),
+        ConvolveMode::Same => (n, (m - 1) - m / 2),
+        ConvolveMode::Valid => (n.max(m) - shortest + 1, shortest - 1),
+    };
+    let before = (m - 1) - start;
+    let after = len + m - 1 - n - before;
+    let fill = PadMode::Constant(ScalarValue::I32(0));
+    let padded = pad(a, &[(before, after)], fill)?;
+    let dtype = a.dtype.promote(v.dtype);
+    Ok(correlate_op(&padded, v, vec![len], flip, dtype))
+}
+
+/// Discrete linear convolution of two 1-D arrays, 0-d arrays are one element long
+pub fn convolve(a: &NdArray, v: &NdArray, mode: ConvolveMode) -> NdArrayResult<NdArray> {
+    let (mut a, mut v) = (as_1d(a)?, as_1d(v)?);
+    if v.len() > a.len() {
+        std::mem::swap(&mut a, &mut v);
+    }
+    if a.is_empty() {
+        return Err(NdArrayError::ValueError("a cannot be empty".to_string()));
+    }
+    if v.is_empty() {
+        return Err(NdArrayError::ValueError("v cannot be empty".to_string()));
+    }
+    correlate_1d_op(&a, &v, mode, true)
+}
+
+/// Cross-correlation of two 1-D arrays, 0-d arrays are one element long
+pub fn correlate(a: &NdArray, v: &NdArray, mode: ConvolveMode) -> NdArrayResult<NdArray> {
+    let (a, v) = (as_1d(a)?, as_1d(v)?);
+    if a.is_empty() {
+        return Err(NdArrayError::ValueError(
+            "first array argument cannot be empty".to_string(),
+        ));
+    }
+    if v.is_empty() {
+        return Err(NdArrayError::ValueError(
+            "second array argument cannot be empty".to_string(),
+        ));
+    }
+    correlate_1d_op(&a, &v, mode, false)
+}
+
+fn correlate_nd_op(
+    input: &NdArray,
+    weights: &NdArray,
+    mode: PadMode,
+    origin: &[i32],
+    flip: bool,
+) -> NdArrayResult<NdArray> {
+    let ndim = input.shape.len();
+    if weights.shape.len() != ndim || weights.is_empty() {
+        return Err(NdArrayError::ValueError(
+            "filter weights array has incorrect shape.".to_string(),
+        ));
+    }
+    if origin.len() != ndim && origin.len() != 1 {
+        return Err(NdArrayError::ValueError(
+            "sequence argument must have length equal to input rank".to_string(),
+        ));
+    }
+    if input.is_empty() {
+        return Ok(NdArray::zero_sized(
+            input.shape.clone(),
+            input.dtype,
+            input.get_gpu_device(),
+        ));
+    }
+
+    let mut pad_width = Vec::with_capacity(ndim);
+    for (i, len) in weights.shape.iter().enumerate() {
+        let len = *len as i32;
+        let origin = origin[i % origin.len()];
+        if origin < -(len / 2) || origin > (len - 1) / 2 {
+            return Err(NdArrayError::ValueError("invalid origin".to_string()));
+        }
+        // The weights are centered at `len / 2 + origin`, mirrored when flipped
+        let center = len / 2 + origin;
+        let before = if flip { len - 1 - center } else { center };
+        pad_width.push((before as u32, (len - 1 - before) as u32));
+    }
+    let padded = pad(input, &pad_width, mode)?;
+    Ok(correlate_op(
+        &padded,
+        weights,
+        input.shape.clone(),
+        flip,
+        input.dtype,
+    ))
+}
+
+/// Multidimensional correlation of the input with the weights, which have as many
+/// dimensions as the input. The output has the shape and dtype of the input, `mode`
+/// extends the input beyond its boundaries and `origin` shifts the weights along every
+/// axis, a single origin applies to every axis.
+pub fn correlate_nd(
+    input: &NdArray,
+    weights: &NdArray,
+    mode: PadMode,
+    origin: &[i32],
+) -> NdArrayResult<NdArray> {
+    correlate_nd_op(input, weights, mode, origin, false)
+}
+
+/// Multidimensional convolution of the input with the weights, see [`correlate_nd`]
+pub fn convolve_nd(
+    input: &NdArray,
+    weights: &NdArray,
+    mode: PadMode,
+    origin: &[i32],
+) -> NdArrayResult<NdArray> {
+    correlate_nd_op(input, weights, mode, origin, true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_convolve() {
+        let a = NdArray::from_slice([1.0f32, 2.0, 3.0].as_ref().into(), vec![3], None);
+        let v = NdArray::from_slice([0.0f32, 1.0, 0.5].as_ref().into(), vec![3], None);
+
+        let result = convolve(&a, &v, ConvolveMode::Full).unwrap();
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![0.0f32, 1.0, 2.5, 4.0, 1.5].into()
+        );
+        let result = convolve(&a, &v, ConvolveMode::Same).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![1.0f32, 2.5, 4.0].into());
+        let result = convolve(&a, &v, ConvolveMode::Valid).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![2.5f32].into());
+    }
+
+    #[test]
+    fn test_correlate() {
+        let a = NdArray::from_slice([1i32, 2, 3].as_ref().into(), vec![3], None);
+        let v = NdArray::from_slice([0i32, 1].as_ref().into(), vec![2], None);
+
+        let result = correlate(&a, &v, ConvolveMode::Full).unwrap();
+        assert_eq!(result.dtype, Dtype::Int32);
+        assert_eq!(result.data.get_raw_values(), vec![1i32, 2, 3, 0].into());
+        let result = correlate(&a, &v, ConvolveMode::Same).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![1i32, 2, 3].into());
+        let result = correlate(&v, &a, ConvolveMode::Same).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![3i32, 2, 1].into());
+        let result = correlate(&a, &v, ConvolveMode::Valid).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![2i32, 3].into());
+    }
+
+    #[test]
+    fn test_correlate_nd() {
+        let values = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let input = NdArray::from_slice(values.as_ref().into(), vec![2, 3], None);
+        let weights = NdArray::from_slice([1.0f32, 0.0, -1.0].as_ref().into(), vec![1, 3], None);
+
+        let result = correlate_nd(&input, &weights, PadMode::Edge, &[0]).unwrap();
+        assert_eq!(&result.shape, &[2, 3]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![-1.0f32, -2.0, -1.0, -1.0, -2.0, -1.0].into()
+        );
+
+        let result = convolve_nd(&input, &weights, PadMode::Wrap, &[0]).unwrap();
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![-1.0f32, 2.0, -1.0, -1.0, 2.0, -1.0].into()
+        );
+
+        let zero = PadMode::Constant(ScalarValue::F32(0.0));
+        assert!(correlate_nd(&input, &weights, zero, &[2]).is_err());
+    }
+}
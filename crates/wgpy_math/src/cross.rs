@@ -76,6 +76,10 @@ pub fn cross(a: &NdArray, b: &NdArray) -> NdArray {
         ),
     };
 
+    if a.is_empty() || b.is_empty() {
+        return NdArray::zero_sized(shape, a.dtype, a.get_gpu_device());
+    }
+
     //TODO add broadcast support
     let data = match (&a.data, &b.data) {
         (ArrowArrayGPU::Float32ArrayGPU(x), ArrowArrayGPU::Float32ArrayGPU(y)) => {
@@ -140,7 +144,7 @@ mod test {
             vec![2, 2],
             &[1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0],
             vec![2, 3],
-            vec![-24., 21., -6., -72., 66., -7.],
+            vec![24., -21., 6., 72., -66., 7.],
         );
 
         test_cross_f32_results(
@@ -148,7 +152,7 @@ mod test {
             vec![2, 2],
             &[1.0f32, 2.0, 4.0, 5.0],
             vec![2, 2],
-            vec![-6., -7.],
+            vec![6., 7.],
         );
     }
 }
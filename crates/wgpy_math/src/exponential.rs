@@ -0,0 +1,120 @@
+use webgpupy_core::{
+    float_dtype, ufunc_nin1_nout1, ufunc_nin1_nout1_body, ufunc_nin2_nout1, ufunc_nin2_nout1_body,
+    Dtype, NdArray,
+};
+
+use crate::kernels::{f32_binary_op, f32_unary_op};
+
+const EXPONENTIAL_SHADER: &str = include_str!("../compute_shader/f32/exponential.wgsl");
+const LOGADDEXP_SHADER: &str = include_str!("../compute_shader/f32/logaddexp.wgsl");
+
+f32_unary_op!(exp_op_dyn, EXPONENTIAL_SHADER, "exp_");
+f32_unary_op!(exp2_op_dyn, EXPONENTIAL_SHADER, "exp2_");
+f32_unary_op!(expm1_op_dyn, EXPONENTIAL_SHADER, "expm1_");
+f32_unary_op!(log_op_dyn, EXPONENTIAL_SHADER, "log_");
+f32_unary_op!(log2_op_dyn, EXPONENTIAL_SHADER, "log2_");
+f32_unary_op!(log10_op_dyn, EXPONENTIAL_SHADER, "log10_");
+f32_unary_op!(log1p_op_dyn, EXPONENTIAL_SHADER, "log1p_");
+f32_binary_op!(logaddexp_op_dyn, LOGADDEXP_SHADER, "logaddexp_");
+f32_binary_op!(logaddexp2_op_dyn, LOGADDEXP_SHADER, "logaddexp2_");
+
+ufunc_nin1_nout1_body!(exp, exp_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(exp2, exp2_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(expm1, expm1_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(log, log_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(log2, log2_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(log10, log10_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(log1p, log1p_op_dyn, float_dtype);
+ufunc_nin2_nout1_body!(logaddexp, logaddexp_op_dyn, float_dtype);
+ufunc_nin2_nout1_body!(logaddexp2, logaddexp2_op_dyn, float_dtype);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_gpu::array::ArrowArrayGPU;
+    use test_utils::*;
+    use webgpupy_core::GPU_DEVICE;
+
+    test_ufunc_nin1_nout1_f32!(
+        test_exp_f32_mask_mixed,
+        [1.0, -2.0, 3.0],
+        [1.0f32.exp(), 0.0, 3.0f32.exp()],
+        [true, false, true],
+        exp
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_exp2_f32,
+        [0.5, -2.0, 10.0],
+        [0.5f32.exp2(), 0.25, 1024.0],
+        [true, true, true],
+        exp2
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_expm1_f32,
+        [1e-10, -1.0, 5.0],
+        [1e-10f32.exp_m1(), (-1.0f32).exp_m1(), 5.0f32.exp_m1()],
+        [true, true, true],
+        expm1
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_log_f32,
+        [1.0, 10.0, 0.0, -1.0],
+        [0.0, 10.0f32.ln(), f32::NEG_INFINITY, f32::NAN],
+        [true, true, true, true],
+        log
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_log2_f32,
+        [8.0, 0.3],
+        [3.0, 0.3f32.log2()],
+        [true, true],
+        log2
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_log10_f32_mask_mixed,
+        [1000.0, 0.3],
+        [3.0, 0.0],
+        [true, false],
+        log10
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_log1p_f32,
+        [1e-10, 3.0, -1.0],
+        [1e-10f32.ln_1p(), 3.0f32.ln_1p(), f32::NEG_INFINITY],
+        [true, true, true],
+        log1p
+    );
+
+    #[test]
+    fn test_log_integer_input() {
+        let input = NdArray::from_slice([1i32, 4, 16].as_ref().into(), vec![3], None);
+        let result = log2(&input, None, None);
+        assert_eq!(result.dtype, Dtype::Float32);
+        float_slice_eq_in_error(result.data.get_raw_values(), vec![0.0f32, 2.0, 4.0].into());
+    }
+
+    #[test]
+    fn test_logaddexp() {
+        let input_1 = NdArray::from_slice([1.0f32, -50.0, 3.0].as_ref().into(), vec![3], None);
+        let input_2 = NdArray::from_slice([2.0f32, -50.0, 100.0].as_ref().into(), vec![3], None);
+        let expected = [(1.0f32, 2.0f32), (-50.0, -50.0), (3.0, 100.0)]
+            .iter()
+            .map(|(x, y)| ((*x as f64).exp() + (*y as f64).exp()).ln() as f32)
+            .collect::<Vec<_>>();
+        let result = logaddexp(&input_1, &input_2, None, None);
+        float_slice_eq_in_error(result.data.get_raw_values(), expected.into());
+
+        let expected = [(1.0f32, 2.0f32), (-50.0, -50.0), (3.0, 100.0)]
+            .iter()
+            .map(|(x, y)| ((*x as f64).exp2() + (*y as f64).exp2()).log2() as f32)
+            .collect::<Vec<_>>();
+        let result = logaddexp2(&input_1, &input_2, None, None);
+        float_slice_eq_in_error(result.data.get_raw_values(), expected.into());
+    }
+}
@@ -0,0 +1,291 @@
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{broadcast_op_dyn, ArrayUtils, ArrowArrayGPU, BooleanArrayGPU, Float32ArrayGPU},
+    gpu_utils::ArrowComputePipeline,
+    kernels::*,
+};
+use webgpupy_core::{
+    as_f32_array, bool_dtype, float_dtype, ufunc_nin1_nout1, ufunc_nin1_nout1_body,
+    ufunc_nin1_nout1_checked_body, ufunc_nin2_nout1, ufunc_nin2_nout1_body, Dtype, NdArray,
+    NdArrayError, NdArrayResult, ScalarValue,
+};
+
+use crate::kernels::{apply_f32_unary, f32_binary_op, f32_unary_op};
+
+const CLASSIFY_SHADER: &str = include_str!("../compute_shader/f32/classify.wgsl");
+const FLOATING_POINT_SHADER: &str = include_str!("../compute_shader/f32/floating_point.wgsl");
+const FLOATING_POINT_BINARY_SHADER: &str =
+    include_str!("../compute_shader/f32/floating_point_binary.wgsl");
+const NAN_TO_NUM_SHADER: &str = include_str!("../compute_shader/f32/nan_to_num.wgsl");
+
+/// Predicates of the classify kernel, the discriminant is passed to the shader
+#[derive(Debug, Clone, Copy)]
+enum Classification {
+    IsNan = 0,
+    IsInf = 1,
+    IsPosInf = 2,
+    IsNegInf = 3,
+    IsFinite = 4,
+    SignBit = 5,
+}
+
+/// Classifies every element into a bit packed boolean array, other dtypes are cast to f32
+fn classify_op_dyn(
+    data: &ArrowArrayGPU,
+    classification: Classification,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    let mut casted = None;
+    let input = as_f32_array(data, &mut casted, pipeline);
+    let kind = pipeline
+        .device
+        .create_gpu_buffer_with_data(&[classification as u32]);
+    let words = input.len.div_ceil(32) as u32;
+    let buffer = pipeline.apply_binary_function(
+        &input.data,
+        &kind,
+        words as u64 * 4,
+        CLASSIFY_SHADER,
+        "classify_",
+        words.div_ceil(256),
+    );
+
+    BooleanArrayGPU {
+        data: Arc::new(buffer),
+        gpu_device: input.get_gpu_device(),
+        len: input.len,
+        null_buffer: None,
+    }
+    .into()
+}
+
+macro_rules! classify_op {
+    ($name: ident, $classification: expr) => {
+        fn $name(data: &ArrowArrayGPU, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+            classify_op_dyn(data, $classification, pipeline)
+        }
+    };
+}
+
+classify_op!(isnan_op_dyn, Classification::IsNan);
+classify_op!(isinf_op_dyn, Classification::IsInf);
+classify_op!(isposinf_op_dyn, Classification::IsPosInf);
+classify_op!(isneginf_op_dyn, Classification::IsNegInf);
+classify_op!(isfinite_op_dyn, Classification::IsFinite);
+classify_op!(signbit_op_dyn, Classification::SignBit);
+
+f32_unary_op!(spacing_op_dyn, FLOATING_POINT_SHADER, "spacing_");
+f32_binary_op!(copysign_op_dyn, FLOATING_POINT_BINARY_SHADER, "copysign_");
+f32_binary_op!(nextafter_op_dyn, FLOATING_POINT_BINARY_SHADER, "nextafter_");
+f32_binary_op!(heaviside_op_dyn, FLOATING_POINT_BINARY_SHADER, "heaviside_");
+
+/// Like numpy, sign has no loop for bools
+fn check_not_bool(name: &str, dtypes: &[Dtype]) -> NdArrayResult<()> {
+    if dtypes.contains(&Dtype::Bool) {
+        return Err(NdArrayError::DtypeError(format!(
+            "ufunc '{}' not supported for the input types",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Sign of every element, integer arrays keep their dtype. Bools are rejected by
+/// [`check_not_bool`] before.
+fn sign_op_dyn(data: &ArrowArrayGPU, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+    let dtype: Dtype = data.get_dtype().into();
+    match dtype {
+        Dtype::Float32 => apply_f32_unary(data, FLOATING_POINT_SHADER, "sign_", pipeline),
+        _ => {
+            let zero = broadcast_op_dyn(ScalarValue::zero(&dtype).into(), data.len(), pipeline);
+            let positive = gt_op_dyn(data, &zero, pipeline).into();
+            let positive = cast_op_dyn(&positive, (&dtype).into(), pipeline);
+            if dtype.is_unsigned_int() {
+                return positive;
+            }
+            let negative = lt_op_dyn(data, &zero, pipeline).into();
+            let negative = cast_op_dyn(&negative, (&dtype).into(), pipeline);
+            sub_op_dyn(&positive, &negative, pipeline)
+        }
+    }
+}
+
+ufunc_nin1_nout1_body!(isnan, isnan_op_dyn, bool_dtype);
+ufunc_nin1_nout1_body!(isinf, isinf_op_dyn, bool_dtype);
+ufunc_nin1_nout1_body!(isposinf, isposinf_op_dyn, bool_dtype);
+ufunc_nin1_nout1_body!(isneginf, isneginf_op_dyn, bool_dtype);
+ufunc_nin1_nout1_body!(isfinite, isfinite_op_dyn, bool_dtype);
+ufunc_nin1_nout1_body!(signbit, signbit_op_dyn, bool_dtype);
+ufunc_nin1_nout1_body!(spacing, spacing_op_dyn, float_dtype);
+ufunc_nin1_nout1_checked_body!(sign, sign_op_dyn, check_not_bool);
+ufunc_nin2_nout1_body!(copysign, copysign_op_dyn, float_dtype);
+ufunc_nin2_nout1_body!(nextafter, nextafter_op_dyn, float_dtype);
+ufunc_nin2_nout1_body!(heaviside, heaviside_op_dyn, float_dtype);
+
+/// Replaces nan with `nan` and infinities with `posinf` and `neginf`, which default to
+/// the largest and smallest finite f32. Integer arrays are returned unchanged.
+pub fn nan_to_num(x: &NdArray, nan: f32, posinf: Option<f32>, neginf: Option<f32>) -> NdArray {
+    if x.dtype != Dtype::Float32 {
+        return x.clone_array();
+    }
+    let gpu_device = x.get_gpu_device();
+    if x.is_empty() {
+        return NdArray::zero_sized(x.shape.clone(), x.dtype, gpu_device);
+    }
+
+    let params = [
+        nan.to_bits(),
+        posinf.unwrap_or(f32::MAX).to_bits(),
+        neginf.unwrap_or(f32::MIN).to_bits(),
+    ];
+    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("nan_to_num"));
+    let mut casted = None;
+    let input = as_f32_array(&x.data, &mut casted, &mut pipeline);
+    let params_buffer = pipeline.device.create_gpu_buffer_with_data(&params);
+    let buffer = pipeline.apply_binary_function(
+        &input.data,
+        &params_buffer,
+        x.len() as u64 * 4,
+        NAN_TO_NUM_SHADER,
+        "nan_to_num_",
+        x.len().div_ceil(256),
+    );
+    pipeline.finish();
+
+    NdArray {
+        shape: x.shape.clone(),
+        dims: x.dims,
+        data: Float32ArrayGPU {
+            data: Arc::new(buffer),
+            gpu_device,
+            phantom: std::marker::PhantomData,
+            len: x.len() as usize,
+            null_buffer: None,
+        }
+        .into(),
+        dtype: Dtype::Float32,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use webgpupy_core::GPU_DEVICE;
+
+    fn special_values() -> NdArray {
+        NdArray::from_slice(
+            [
+                1.0f32,
+                f32::NAN,
+                f32::INFINITY,
+                f32::NEG_INFINITY,
+                -0.0,
+                -2.0,
+            ]
+            .as_ref()
+            .into(),
+            vec![6],
+            Some(GPU_DEVICE.clone()),
+        )
+    }
+
+    #[test]
+    fn test_classification() {
+        let array = special_values();
+        let cases = [
+            (
+                isnan(&array, None, None),
+                [false, true, false, false, false, false],
+            ),
+            (
+                isinf(&array, None, None),
+                [false, false, true, true, false, false],
+            ),
+            (
+                isposinf(&array, None, None),
+                [false, false, true, false, false, false],
+            ),
+            (
+                isneginf(&array, None, None),
+                [false, false, false, true, false, false],
+            ),
+            (
+                isfinite(&array, None, None),
+                [true, false, false, false, true, true],
+            ),
+            (
+                signbit(&array, None, None),
+                [false, false, false, true, true, true],
+            ),
+        ];
+        for (result, expected) in cases {
+            assert_eq!(result.dtype, Dtype::Bool);
+            assert_eq!(result.data.get_raw_values(), expected.to_vec().into());
+        }
+    }
+
+    #[test]
+    fn test_classification_int_and_mask() {
+        let array = NdArray::from_slice([1i32, -2].as_ref().into(), vec![2], None);
+        let result = isfinite(&array, None, None);
+        assert_eq!(result.data.get_raw_values(), vec![true, true].into());
+
+        let mask = NdArray::from_slice([false, true].as_ref().into(), vec![2], None);
+        let result = signbit(&array, Some(&mask), None);
+        assert_eq!(result.data.get_raw_values(), vec![false, true].into());
+    }
+
+    #[test]
+    fn test_nan_to_num() {
+        let result = nan_to_num(&special_values(), 0.0, None, Some(-5.0));
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![1.0f32, 0.0, f32::MAX, -5.0, -0.0, -2.0].into()
+        );
+    }
+
+    #[test]
+    fn test_sign() {
+        let array = NdArray::from_slice([3i32, 0, -7].as_ref().into(), vec![3], None);
+        let result = sign(&array, None, None).unwrap();
+        assert_eq!(result.dtype, Dtype::Int32);
+        assert_eq!(result.data.get_raw_values(), vec![1i32, 0, -1].into());
+
+        let array = NdArray::from_slice([3.5f32, 0.0, -0.1].as_ref().into(), vec![3], None);
+        let result = sign(&array, None, None).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![1.0f32, 0.0, -1.0].into());
+
+        let array = NdArray::from_slice([true, false].as_ref().into(), vec![2], None);
+        assert!(sign(&array, None, None).is_err());
+    }
+
+    #[test]
+    fn test_copysign_nextafter_heaviside() {
+        let x1 = NdArray::from_slice([1.0f32, -2.0, 0.0].as_ref().into(), vec![3], None);
+        let x2 = NdArray::from_slice([-1.0f32, 3.0, 0.5].as_ref().into(), vec![3], None);
+
+        let result = copysign(&x1, &x2, None, None);
+        assert_eq!(result.data.get_raw_values(), vec![-1.0f32, 2.0, 0.0].into());
+
+        let result = heaviside(&x1, &x2, None, None);
+        assert_eq!(result.data.get_raw_values(), vec![1.0f32, 0.0, 0.5].into());
+
+        let result = nextafter(&x1, &x2, None, None);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![
+                f32::from_bits(1.0f32.to_bits() - 1),
+                f32::from_bits((-2.0f32).to_bits() - 1),
+                f32::from_bits(1),
+            ]
+            .into()
+        );
+
+        let result = spacing(&x2, None, None);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![-f32::EPSILON, 2.0 * f32::EPSILON, f32::EPSILON / 2.0].into()
+        );
+    }
+}
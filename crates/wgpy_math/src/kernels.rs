@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{ArrayUtils, ArrowArrayGPU, Float32ArrayGPU},
+    gpu_utils::ArrowComputePipeline,
+};
+use webgpupy_core::as_f32_array;
+
+/// Runs a f32 kernel with one input, other dtypes are cast to f32 first
+pub(crate) fn apply_f32_unary(
+    data: &ArrowArrayGPU,
+    shader: &str,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    let mut casted = None;
+    let input = as_f32_array(data, &mut casted, pipeline);
+    let buffer = pipeline.apply_unary_function(
+        &input.data,
+        input.len as u64 * 4,
+        shader,
+        entry_point,
+        (input.len as u32).div_ceil(256),
+    );
+    Float32ArrayGPU {
+        data: Arc::new(buffer),
+        gpu_device: input.get_gpu_device(),
+        phantom: std::marker::PhantomData,
+        len: input.len,
+        null_buffer: None,
+    }
+    .into()
+}
+
+/// Runs a f32 kernel with two inputs of the same length, other dtypes are cast to f32 first
+pub(crate) fn apply_f32_binary(
+    data_1: &ArrowArrayGPU,
+    data_2: &ArrowArrayGPU,
+    shader: &str,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> ArrowArrayGPU {
+    let (mut casted_1, mut casted_2) = (None, None);
+    let input_1 = as_f32_array(data_1, &mut casted_1, pipeline);
+    let input_2 = as_f32_array(data_2, &mut casted_2, pipeline);
+    let buffer = pipeline.apply_binary_function(
+        &input_1.data,
+        &input_2.data,
+        input_1.len as u64 * 4,
+        shader,
+        entry_point,
+        (input_1.len as u32).div_ceil(256),
+    );
+    Float32ArrayGPU {
+        data: Arc::new(buffer),
+        gpu_device: input_1.get_gpu_device(),
+        phantom: std::marker::PhantomData,
+        len: input_1.len,
+        null_buffer: None,
+    }
+    .into()
+}
+
+/// Defines a `fn(&ArrowArrayGPU, &mut ArrowComputePipeline)` running an entry point
+/// of a f32 shader, for use with `ufunc_nin1_nout1_body`
+macro_rules! f32_unary_op {
+    ($name: ident, $shader: ident, $entry_point: literal) => {
+        fn $name(
+            data: &arrow_gpu::array::ArrowArrayGPU,
+            pipeline: &mut arrow_gpu::gpu_utils::ArrowComputePipeline,
+        ) -> arrow_gpu::array::ArrowArrayGPU {
+            $crate::kernels::apply_f32_unary(data, $shader, $entry_point, pipeline)
+        }
+    };
+}
+
+/// Binary counterpart of [`f32_unary_op`], for use with `ufunc_nin2_nout1_body`
+macro_rules! f32_binary_op {
+    ($name: ident, $shader: ident, $entry_point: literal) => {
+        fn $name(
+            data_1: &arrow_gpu::array::ArrowArrayGPU,
+            data_2: &arrow_gpu::array::ArrowArrayGPU,
+            pipeline: &mut arrow_gpu::gpu_utils::ArrowComputePipeline,
+        ) -> arrow_gpu::array::ArrowArrayGPU {
+            $crate::kernels::apply_f32_binary(data_1, data_2, $shader, $entry_point, pipeline)
+        }
+    };
+}
+
+pub(crate) use f32_binary_op;
+pub(crate) use f32_unary_op;
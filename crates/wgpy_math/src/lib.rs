@@ -1,11 +1,30 @@
 mod arithmetic;
+mod calculus;
 mod comparison;
+mod convolve;
 mod cross;
+mod exponential;
+mod floating_point;
+mod kernels;
 mod misc;
+mod nanfunctions;
+mod polynomial;
+mod rounding;
+mod special;
+mod statistics;
 mod trigonometry;
 
 pub use arithmetic::*;
+pub use calculus::*;
 pub use comparison::*;
+pub use convolve::*;
 pub use cross::cross;
+pub use exponential::*;
+pub use floating_point::*;
 pub use misc::*;
+pub use nanfunctions::*;
+pub use polynomial::*;
+pub use rounding::*;
+pub use special::*;
+pub use statistics::*;
 pub use trigonometry::*;
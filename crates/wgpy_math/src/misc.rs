@@ -10,6 +10,15 @@ ufunc_nin1_nout1_body!(absolute, abs_op_dyn);
 ufunc_nin2_nout1_body!(power, power_op_dyn);
 
 pub fn clip(a: &NdArray, a_min: Option<&NdArray>, a_max: Option<&NdArray>) -> NdArray {
+    let zero_shape = [a_min, a_max]
+        .into_iter()
+        .flatten()
+        .try_fold(a.shape.clone(), |acc, x| broadcast_shape(&acc, &x.shape))
+        .unwrap();
+    if zero_shape.contains(&0) {
+        return NdArray::zero_sized(zero_shape, a.dtype, a.get_gpu_device());
+    }
+
     match (a_min, a_max) {
         (None, None) => panic!("Both a_min and a_max cannot be null"),
         (None, Some(max_values)) => {
@@ -33,8 +42,8 @@ pub fn clip(a: &NdArray, a_min: Option<&NdArray>, a_max: Option<&NdArray>) -> Nd
             let data = min_op_dyn(&arr.data, &max_arr.data, &mut pipeline);
             pipeline.finish();
             NdArray {
+                dims: broadcasted_shape.len() as u16,
                 shape: broadcasted_shape,
-                dims: a.dims,
                 data,
                 dtype: a.dtype,
             }
@@ -60,8 +69,8 @@ pub fn clip(a: &NdArray, a_min: Option<&NdArray>, a_max: Option<&NdArray>) -> Nd
             let data = max_op_dyn(&arr.data, &min_arr.data, &mut pipeline);
             pipeline.finish();
             NdArray {
+                dims: broadcasted_shape.len() as u16,
                 shape: broadcasted_shape,
-                dims: a.dims,
                 data,
                 dtype: a.dtype,
             }
@@ -96,8 +105,8 @@ pub fn clip(a: &NdArray, a_min: Option<&NdArray>, a_max: Option<&NdArray>) -> Nd
             data = min_op_dyn(&data, &max_arr.data, &mut pipeline);
             pipeline.finish();
             NdArray {
+                dims: broadcasted_shape.len() as u16,
                 shape: broadcasted_shape,
-                dims: a.dims,
                 data,
                 dtype: a.dtype,
             }
@@ -0,0 +1,551 @@
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{ArrayUtils, ArrowArrayGPU, ArrowType, Float32ArrayGPU, Int32ArrayGPU, UInt32ArrayGPU},
+    gpu_utils::ArrowComputePipeline,
+    kernels::bitcast_op_dyn,
+    utils::ScalarArray,
+};
+use webgpupy_core::{
+    as_f32_array, cast_if_needed, full, normalize_axis, Dtype, NdArray, NdArrayError,
+    NdArrayResult, ScalarValue,
+};
+
+const NANFUNCTIONS_SHADER: &str = include_str!("../compute_shader/f32/nanfunctions.wgsl");
+const I32_NANFUNCTIONS_SHADER: &str = include_str!("../compute_shader/i32/nanfunctions.wgsl");
+const U32_NANFUNCTIONS_SHADER: &str = include_str!("../compute_shader/u32/nanfunctions.wgsl");
+
+const ALL_NAN_SLICE: &str = "All-NaN slice encountered";
+const EMPTY_SLICE_MEAN: &str = "Mean of empty slice";
+pub(crate) const DEGREES_OF_FREEDOM: &str = "Degrees of freedom <= 0 for slice.";
+
+/// Every workgroup reduces whole lanes, so lanes past this limit are strided over
+const MAX_WORKGROUPS: u32 = 65535;
+
+/// Reductions of the nanfunctions kernel, the discriminant is passed to the shader
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NanOp {
+    Sum = 0,
+    Prod = 1,
+    Min = 2,
+    Max = 3,
+    ArgMin = 4,
+    ArgMax = 5,
+    Mean = 6,
+    Var = 7,
+    Std = 8,
+    Median = 9,
+    Count = 10,
+}
+
+/// Result of a reduction which ignores nans. `warning` holds the message numpy would
+/// warn with, when a slice had no values left to reduce.
+#[derive(Debug)]
+pub struct NanReduction {
+    pub array: NdArray,
+    pub warning: Option<&'static str>,
+}
+
+/// Input viewed as `[lanes / inner, axis_len, inner]`, reduced along the middle axis
+pub(crate) struct ReduceLayout {
+    pub axis_len: u32,
+    pub inner: u32,
+    pub lanes: u32,
+    pub shape: Vec<u32>,
+}
+
+impl ReduceLayout {
+    pub fn new(shape: &[u32], axis: Option<i32>, keepdims: bool) -> NdArrayResult<Self> {
+        match axis {
+            None => Ok(Self {
+                axis_len: shape.iter().product(),
+                inner: 1,
+                lanes: 1,
+                shape: if keepdims {
+                    vec![1; shape.len()]
+                } else {
+                    vec![]
+                },
+            }),
+            Some(axis) => {
+                let axis = normalize_axis(axis, shape.len())?;
+                let mut out_shape = shape.to_vec();
+                if keepdims {
+                    out_shape[axis] = 1;
+                } else {
+                    out_shape.remove(axis);
+                }
+                Ok(Self {
+                    axis_len: shape[axis],
+                    inner: shape[axis + 1..].iter().product(),
+                    lanes: out_shape.iter().product(),
+                    shape: out_shape,
+                })
+            }
+        }
+    }
+}
+
+/// Runs a single reduction, the result is f32 except for the arg reductions which are i32
+fn nan_reduce_op(
+    a: &NdArray,
+    layout: &ReduceLayout,
+    op: NanOp,
+    ddof: f32,
+) -> NdArrayResult<NdArray> {
+    let gpu_device = a.get_gpu_device();
+    let dtype = match op {
+        NanOp::ArgMin | NanOp::ArgMax => Dtype::Int32,
+        _ => Dtype::Float32,
+    };
+    if layout.lanes == 0 {
+        return Ok(NdArray::zero_sized(layout.shape.clone(), dtype, gpu_device));
+    }
+    if layout.axis_len == 0 {
+        let fill = match op {
+            NanOp::Sum | NanOp::Count => 0.0,
+            NanOp::Prod => 1.0,
+            _ => f32::NAN,
+        };
+        return full(
+            layout.shape.clone(),
+            ScalarValue::F32(fill).into(),
+            None,
+            Some(gpu_device),
+        );
+    }
+
+    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("nan_reduce"));
+    let mut casted = None;
+    let input = as_f32_array(&a.data, &mut casted, &mut pipeline);
+    let params = [
+        layout.axis_len,
+        layout.inner,
+        layout.lanes,
+        op as u32,
+        ddof.to_bits(),
+    ];
+    let params_buffer = pipeline.device.create_gpu_buffer_with_data(&params);
+    let buffer = pipeline.apply_binary_function(
+        &input.data,
+        &params_buffer,
+        layout.lanes as u64 * 4,
+        NANFUNCTIONS_SHADER,
+        "nan_reduce_",
+        layout.lanes.min(MAX_WORKGROUPS),
+    );
+    let mut data = Float32ArrayGPU {
+        data: Arc::new(buffer),
+        gpu_device: input.get_gpu_device(),
+        phantom: std::marker::PhantomData,
+        len: layout.lanes as usize,
+        null_buffer: None,
+    }
+    .into();
+    if dtype == Dtype::Int32 {
+        data = bitcast_op_dyn(&data, &ArrowType::Int32Type, &mut pipeline);
+    }
+    pipeline.finish();
+
+    Ok(NdArray {
+        dims: layout.shape.len() as u16,
+        shape: layout.shape.clone(),
+        data,
+        dtype,
+    })
+}
+
+/// Runs a reduction which needs no nan handling on integers with an i32 or u32 kernel,
+/// so sums and products stay exact beyond 2^24. The arg reductions are i32.
+fn integer_reduce_op(a: &NdArray, layout: &ReduceLayout, op: NanOp) -> NdArrayResult<NdArray> {
+    if layout.lanes == 0 || layout.axis_len == 0 {
+        return nan_reduce_op(a, layout, op, 0.0);
+    }
+    let gpu_device = a.get_gpu_device();
+    let kernel_dtype = match a.dtype {
+        x if x.is_unsigned_int() || x == Dtype::Bool => Dtype::UInt32,
+        _ => Dtype::Int32,
+    };
+
+    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("integer_reduce"));
+    let mut casted = None;
+    let input = cast_if_needed(&a.data, (&kernel_dtype).into(), &mut casted, &mut pipeline);
+    let params = [layout.axis_len, layout.inner, layout.lanes, op as u32, 0];
+    let params_buffer = pipeline.device.create_gpu_buffer_with_data(&params);
+    let (input, shader) = match input {
+        ArrowArrayGPU::Int32ArrayGPU(x) => (&x.data, I32_NANFUNCTIONS_SHADER),
+        ArrowArrayGPU::UInt32ArrayGPU(x) => (&x.data, U32_NANFUNCTIONS_SHADER),
+        _ => unreachable!(),
+    };
+    let buffer = Arc::new(pipeline.apply_binary_function(
+        input,
+        &params_buffer,
+        layout.lanes as u64 * 4,
+        shader,
+        "nan_reduce_",
+        layout.lanes.min(MAX_WORKGROUPS),
+    ));
+    let len = layout.lanes as usize;
+    let mut data: ArrowArrayGPU = match kernel_dtype {
+        Dtype::UInt32 => UInt32ArrayGPU {
+            data: buffer,
+            gpu_device,
+            phantom: std::marker::PhantomData,
+            len,
+            null_buffer: None,
+        }
+        .into(),
+        _ => Int32ArrayGPU {
+            data: buffer,
+            gpu_device,
+            phantom: std::marker::PhantomData,
+            len,
+            null_buffer: None,
+        }
+        .into(),
+    };
+    if matches!(op, NanOp::ArgMin | NanOp::ArgMax) && kernel_dtype == Dtype::UInt32 {
+        data = bitcast_op_dyn(&data, &ArrowType::Int32Type, &mut pipeline);
+    }
+    pipeline.finish();
+
+    let dtype = data.get_dtype().into();
+    Ok(NdArray {
+        dims: layout.shape.len() as u16,
+        shape: layout.shape.clone(),
+        data,
+        dtype,
+    })
+}
+
+/// Returns the warning numpy raises for `op`, if any slice has too few non nan values
+fn nan_reduce_warning(
+    a: &NdArray,
+    layout: &ReduceLayout,
+    op: NanOp,
+    ddof: f32,
+) -> NdArrayResult<Option<&'static str>> {
+    let (message, min_count) = match op {
+        NanOp::Sum | NanOp::Prod | NanOp::Count => return Ok(None),
+        NanOp::Mean => (EMPTY_SLICE_MEAN, 0.0),
+        NanOp::Var | NanOp::Std => (DEGREES_OF_FREEDOM, ddof),
+        _ => (ALL_NAN_SLICE, 0.0),
+    };
+    if layout.lanes == 0 {
+        return Ok(None);
+    }
+
+    // Only floats can hold nans, otherwise every slice has all of its values
+    let too_few = if a.dtype == Dtype::Float32 && layout.axis_len > 0 {
+        let counts = nan_reduce_op(a, layout, NanOp::Count, 0.0)?;
+        match counts.data.get_raw_values() {
+            ScalarArray::F32Vec(counts) => counts.iter().any(|x| *x <= min_count),
+            _ => unreachable!(),
+        }
+    } else {
+        layout.axis_len as f32 <= min_count
+    };
+
+    if too_few {
+        log::warn!("{}", message);
+        Ok(Some(message))
+    } else {
+        Ok(None)
+    }
+}
+
+fn nan_reduce(
+    a: &NdArray,
+    axis: Option<i32>,
+    keepdims: bool,
+    op: NanOp,
+    ddof: f32,
+) -> NdArrayResult<NanReduction> {
+    let layout = ReduceLayout::new(&a.shape, axis, keepdims)?;
+    let warning = nan_reduce_warning(a, &layout, op, ddof)?;
+    let exact = matches!(
+        op,
+        NanOp::Sum | NanOp::Prod | NanOp::Min | NanOp::Max | NanOp::ArgMin | NanOp::ArgMax
+    );
+    let array = if exact && a.dtype != Dtype::Float32 {
+        integer_reduce_op(a, &layout, op)?
+    } else {
+        nan_reduce_op(a, &layout, op, ddof)?
+    };
+    Ok(NanReduction { array, warning })
+}
+
+/// Casts the reduction to the requested dtype. By default bools and integers narrower
+/// than 32 bits are accumulated into Int32 or UInt32, like numpy's default integer.
+fn integer_result(array: NdArray, input_dtype: Dtype, dtype: Option<Dtype>) -> NdArray {
+    let dtype = dtype.unwrap_or(match input_dtype {
+        Dtype::Bool | Dtype::Int8 | Dtype::Int16 => Dtype::Int32,
+        Dtype::UInt8 | Dtype::UInt16 => Dtype::UInt32,
+        x => x,
+    });
+    if dtype == array.dtype {
+        array
+    } else {
+        array.astype(dtype)
+    }
+}
+
+fn float_result(reduction: NanReduction, dtype: Option<Dtype>) -> NanReduction {
+    match dtype {
+        Some(dtype) if dtype != Dtype::Float32 => NanReduction {
+            array: reduction.array.astype(dtype),
+            warning: reduction.warning,
+        },
+        _ => reduction,
+    }
+}
+
+/// Sum of array elements over an axis treating nans as zero
+pub fn nansum(
+    a: &NdArray,
+    axis: Option<i32>,
+    dtype: Option<Dtype>,
+    keepdims: bool,
+) -> NdArrayResult<NdArray> {
+    let result = nan_reduce(a, axis, keepdims, NanOp::Sum, 0.0)?;
+    Ok(integer_result(result.array, a.dtype, dtype))
+}
+
+/// Product of array elements over an axis treating nans as one
+pub fn nanprod(
+    a: &NdArray,
+    axis: Option<i32>,
+    dtype: Option<Dtype>,
+    keepdims: bool,
+) -> NdArrayResult<NdArray> {
+    let result = nan_reduce(a, axis, keepdims, NanOp::Prod, 0.0)?;
+    Ok(integer_result(result.array, a.dtype, dtype))
+}
+
+fn nan_extremum(
+    a: &NdArray,
+    axis: Option<i32>,
+    keepdims: bool,
+    op: NanOp,
+) -> NdArrayResult<NanReduction> {
+    let layout = ReduceLayout::new(&a.shape, axis, keepdims)?;
+    if layout.axis_len == 0 && layout.lanes > 0 {
+        return Err(NdArrayError::ValueError(
+            "zero-size array to reduction operation which has no identity".to_string(),
+        ));
+    }
+    let result = nan_reduce(a, axis, keepdims, op, 0.0)?;
+    Ok(NanReduction {
+        array: integer_result(result.array, a.dtype, Some(a.dtype)),
+        warning: result.warning,
+    })
+}
+
+/// Minimum of an array along an axis ignoring nans, slices of only nans give nan
+pub fn nanmin(a: &NdArray, axis: Option<i32>, keepdims: bool) -> NdArrayResult<NanReduction> {
+    nan_extremum(a, axis, keepdims, NanOp::Min)
+}
+
+/// Maximum of an array along an axis ignoring nans, slices of only nans give nan
+pub fn nanmax(a: &NdArray, axis: Option<i32>, keepdims: bool) -> NdArrayResult<NanReduction> {
+    nan_extremum(a, axis, keepdims, NanOp::Max)
+}
+
+fn nan_arg_extremum(
+    a: &NdArray,
+    axis: Option<i32>,
+    keepdims: bool,
+    op: NanOp,
+) -> NdArrayResult<NdArray> {
+    let layout = ReduceLayout::new(&a.shape, axis, keepdims)?;
+    if layout.axis_len == 0 && layout.lanes > 0 {
+        let name = match op {
+            NanOp::ArgMax => "argmax",
+            _ => "argmin",
+        };
+        return Err(NdArrayError::ValueError(format!(
+            "attempt to get {} of an empty sequence",
+            name
+        )));
+    }
+    let result = nan_reduce(a, axis, keepdims, op, 0.0)?;
+    match result.warning {
+        Some(message) => Err(NdArrayError::ValueError(message.to_string())),
+        None => Ok(result.array),
+    }
+}
+
+/// Indices of the minimum values along an axis ignoring nans, slices of only nans
+/// are an error
+pub fn nanargmin(a: &NdArray, axis: Option<i32>, keepdims: bool) -> NdArrayResult<NdArray> {
+    nan_arg_extremum(a, axis, keepdims, NanOp::ArgMin)
+}
+
+/// Indices of the maximum values along an axis ignoring nans, slices of only nans
+/// are an error
+pub fn nanargmax(a: &NdArray, axis: Option<i32>, keepdims: bool) -> NdArrayResult<NdArray> {
+    nan_arg_extremum(a, axis, keepdims, NanOp::ArgMax)
+}
+
+/// Arithmetic mean along an axis ignoring nans
+pub fn nanmean(
+    a: &NdArray,
+    axis: Option<i32>,
+    dtype: Option<Dtype>,
+    keepdims: bool,
+) -> NdArrayResult<NanReduction> {
+    let result = nan_reduce(a, axis, keepdims, NanOp::Mean, 0.0)?;
+    Ok(float_result(result, dtype))
+}
+
+/// Variance along an axis ignoring nans, the divisor is `N - ddof`
+pub fn nanvar(
+    a: &NdArray,
+    axis: Option<i32>,
+    dtype: Option<Dtype>,
+    ddof: u32,
+    keepdims: bool,
+) -> NdArrayResult<NanReduction> {
+    let result = nan_reduce(a, axis, keepdims, NanOp::Var, ddof as f32)?;
+    Ok(float_result(result, dtype))
+}
+
+/// Standard deviation along an axis ignoring nans, the divisor is `N - ddof`
+pub fn nanstd(
+    a: &NdArray,
+    axis: Option<i32>,
+    dtype: Option<Dtype>,
+    ddof: u32,
+    keepdims: bool,
+) -> NdArrayResult<NanReduction> {
+    let result = nan_reduce(a, axis, keepdims, NanOp::Std, ddof as f32)?;
+    Ok(float_result(result, dtype))
+}
+
+/// Median along an axis ignoring nans
+pub fn nanmedian(a: &NdArray, axis: Option<i32>, keepdims: bool) -> NdArrayResult<NanReduction> {
+    nan_reduce(a, axis, keepdims, NanOp::Median, 0.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::float_slice_eq_in_error;
+
+    fn input() -> NdArray {
+        NdArray::from_slice(
+            [1.0f32, f32::NAN, 3.0, 4.0, f32::NAN, f32::NAN]
+                .as_ref()
+                .into(),
+            vec![2, 3],
+            None,
+        )
+    }
+
+    #[test]
+    fn test_nansum_nanprod() {
+        let result = nansum(&input(), None, None, false).unwrap();
+        assert!(result.shape.is_empty());
+        assert_eq!(result.data.get_raw_values(), vec![8.0f32].into());
+
+        let result = nanprod(&input(), Some(1), None, true).unwrap();
+        assert_eq!(&result.shape, &[2, 1]);
+        assert_eq!(result.data.get_raw_values(), vec![3.0f32, 4.0].into());
+
+        let ints = NdArray::from_slice([1i32, 2, 3, 4].as_ref().into(), vec![2, 2], None);
+        let result = nansum(&ints, Some(0), None, false).unwrap();
+        assert_eq!(result.dtype, Dtype::Int32);
+        assert_eq!(result.data.get_raw_values(), vec![4i32, 6].into());
+
+        let ints = NdArray::from_slice([16_777_217u32, 1].as_ref().into(), vec![2], None);
+        let result = nansum(&ints, None, None, false).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![16_777_218u32].into());
+    }
+
+    #[test]
+    fn test_nansum_nanprod_narrow_integers() {
+        let ints = NdArray::from_slice([200u8, 100].as_ref().into(), vec![2], None);
+        let result = nansum(&ints, None, None, false).unwrap();
+        assert_eq!(result.dtype, Dtype::UInt32);
+        assert_eq!(result.data.get_raw_values(), vec![300u32].into());
+
+        let ints = NdArray::from_slice([-100i8, 3].as_ref().into(), vec![2], None);
+        let result = nanprod(&ints, None, None, false).unwrap();
+        assert_eq!(result.dtype, Dtype::Int32);
+        assert_eq!(result.data.get_raw_values(), vec![-300i32].into());
+
+        let result = nansum(&ints, None, Some(Dtype::Int8), false).unwrap();
+        assert_eq!(result.dtype, Dtype::Int8);
+        assert_eq!(result.data.get_raw_values(), vec![-97i8].into());
+    }
+
+    #[test]
+    fn test_nanmin_nanmax() {
+        let result = nanmax(&input(), Some(1), false).unwrap();
+        assert_eq!(result.warning, None);
+        assert_eq!(result.array.data.get_raw_values(), vec![3.0f32, 4.0].into());
+
+        let result = nanmin(&input(), Some(0), false).unwrap();
+        assert_eq!(result.warning, Some(ALL_NAN_SLICE));
+        let values = match result.array.data.get_raw_values() {
+            ScalarArray::F32Vec(x) => x,
+            _ => unreachable!(),
+        };
+        assert_eq!(values[0], 1.0);
+        assert!(values[1].is_nan());
+        assert_eq!(values[2], 3.0);
+    }
+
+    #[test]
+    fn test_nanargmin_nanargmax() {
+        let result = nanargmax(&input(), Some(1), false).unwrap();
+        assert_eq!(result.dtype, Dtype::Int32);
+        assert_eq!(result.data.get_raw_values(), vec![2i32, 0].into());
+
+        let result = nanargmin(&input(), None, false).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![0i32].into());
+
+        assert!(nanargmin(&input(), Some(0), false).is_err());
+
+        let empty = NdArray::zero_sized(vec![2, 0], Dtype::Float32, input().get_gpu_device());
+        match nanargmax(&empty, Some(1), false) {
+            Err(NdArrayError::ValueError(message)) => assert!(message.contains("argmax")),
+            _ => panic!("expected a ValueError"),
+        }
+    }
+
+    #[test]
+    fn test_nanmean_nanvar_nanstd() {
+        let result = nanmean(&input(), Some(1), None, false).unwrap();
+        assert_eq!(result.warning, None);
+        assert_eq!(result.array.data.get_raw_values(), vec![2.0f32, 4.0].into());
+
+        let result = nanvar(&input(), Some(1), None, 0, false).unwrap();
+        assert_eq!(result.array.data.get_raw_values(), vec![1.0f32, 0.0].into());
+
+        let result = nanstd(&input(), Some(1), None, 1, false).unwrap();
+        assert_eq!(result.warning, Some(DEGREES_OF_FREEDOM));
+        let values = match result.array.data.get_raw_values() {
+            ScalarArray::F32Vec(x) => x,
+            _ => unreachable!(),
+        };
+        float_slice_eq_in_error(
+            ScalarArray::F32Vec(vec![values[0]]),
+            vec![2.0f32.sqrt()].into(),
+        );
+        assert!(values[1].is_nan());
+    }
+
+    #[test]
+    fn test_nanmedian() {
+        let values = [5.0f32, f32::NAN, -1.0, 3.0, 2.0, f32::NAN, 10.0, -4.0];
+        let array = NdArray::from_slice(values.as_ref().into(), vec![2, 4], None);
+
+        let result = nanmedian(&array, Some(1), false).unwrap();
+        assert_eq!(result.array.data.get_raw_values(), vec![3.0f32, 2.0].into());
+
+        let result = nanmedian(&array, None, true).unwrap();
+        assert_eq!(&result.array.shape, &[1, 1]);
+        assert_eq!(result.array.data.get_raw_values(), vec![2.5f32].into());
+    }
+}
@@ -0,0 +1,675 @@
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{ArrayUtils, Float32ArrayGPU},
+    gpu_utils::ArrowComputePipeline,
+};
+use webgpupy_core::{
+    as_f32_array, broadcast_to, concatenate, pad, zeros, Dtype, IndexSliceOp, NdArray,
+    NdArrayError, NdArrayResult, PadMode, ScalarValue,
+};
+
+use crate::{
+    add, convolve, convolve::as_1d, divide, multiply, statistics::f32_values, ConvolveMode,
+};
+
+const POLYNOMIAL_SHADER: &str = include_str!("../compute_shader/f32/polynomial.wgsl");
+
+/// Evaluates the polynomial with the coefficients `p`, highest degree first, at every
+/// element of `x` with Horner's method. The result has the shape of `x` and is float32,
+/// integer inputs are evaluated as f32.
+pub fn polyval(p: &NdArray, x: &NdArray) -> NdArrayResult<NdArray> {
+    let p = as_1d(p)?;
+    let gpu_device = x.get_gpu_device();
+    if x.is_empty() {
+        return Ok(NdArray::zero_sized(
+            x.shape.clone(),
+            Dtype::Float32,
+            gpu_device,
+        ));
+    }
+    if p.is_empty() {
+        return Ok(zeros(
+            x.shape.clone(),
+            Some(Dtype::Float32),
+            Some(gpu_device),
+        ));
+    }
+
+    let mut pipeline = ArrowComputePipeline::new(gpu_device, Some("polyval"));
+    let (mut casted_1, mut casted_2) = (None, None);
+    let input = as_f32_array(&x.data, &mut casted_1, &mut pipeline);
+    let coefficients = as_f32_array(&p.data, &mut casted_2, &mut pipeline);
+    let buffer = pipeline.apply_binary_function(
+        &input.data,
+        &coefficients.data,
+        input.len as u64 * 4,
+        POLYNOMIAL_SHADER,
+        "polyval_",
+        (input.len as u32).div_ceil(256),
+    );
+    let data = Float32ArrayGPU {
+        data: Arc::new(buffer),
+        gpu_device: input.get_gpu_device(),
+        phantom: std::marker::PhantomData,
+        len: input.len,
+        null_buffer: None,
+    };
+    pipeline.finish();
+
+    Ok(NdArray {
+        shape: x.shape.clone(),
+        dims: x.dims,
+        data: data.into(),
+        dtype: Dtype::Float32,
+    })
+}
+
+/// Sum of two polynomials, the shorter one is padded with zeros at the front
+pub fn polyadd(a1: &NdArray, a2: &NdArray) -> NdArrayResult<NdArray> {
+    let (a1, a2) = (as_1d(a1)?, as_1d(a2)?);
+    let (long, short) = if a1.len() >= a2.len() {
+        (&a1, &a2)
+    } else {
+        (&a2, &a1)
+    };
+    if short.is_empty() {
+        return Ok(long.astype(long.dtype.promote(short.dtype)));
+    }
+    let zero = PadMode::Constant(ScalarValue::I32(0));
+    let padded = pad(short, &[(long.len() - short.len(), 0)], zero)?;
+    Ok(add(long, &padded, None, None))
+}
+
+/// Product of two polynomials
+pub fn polymul(a1: &NdArray, a2: &NdArray) -> NdArrayResult<NdArray> {
+    convolve(a1, a2, ConvolveMode::Full)
+}
+
+/// The m-th derivative of the polynomial, which has `m` coefficients less. Integer
+/// coefficients give int32 coefficients, an error is returned when the factors of the
+/// derivative do not fit in them.
+pub fn polyder(p: &NdArray, m: u32) -> NdArrayResult<NdArray> {
+    let p = as_1d(p)?;
+    if m == 0 {
+        return Ok(p);
+    }
+    let gpu_device = p.get_gpu_device();
+    let dtype = match p.dtype {
+        Dtype::Float32 => Dtype::Float32,
+        _ => Dtype::Int32,
+    };
+    let n = p.len();
+    if m >= n {
+        return Ok(NdArray::zero_sized(vec![0], dtype, gpu_device));
+    }
+
+    // The coefficient of degree d is multiplied by d * (d - 1) * .. * (d - m + 1)
+    let len = n - m;
+    let factors = (0..len)
+        .map(|i| (len - i..n - i).map(f64::from).product::<f64>())
+        .collect::<Vec<_>>();
+    let factors = match dtype {
+        Dtype::Float32 => {
+            let factors = factors.iter().map(|x| *x as f32).collect::<Vec<_>>();
+            NdArray::from_slice(factors.as_slice().into(), vec![len], Some(gpu_device))
+        }
+        _ => {
+            if factors.iter().any(|x| *x > i32::MAX as f64) {
+                return Err(NdArrayError::ValueError(format!(
+                    "the factors of the derivative of order {} overflow int32",
+                    m
+                )));
+            }
+            let factors = factors.iter().map(|x| *x as i32).collect::<Vec<_>>();
+            NdArray::from_slice(factors.as_slice().into(), vec![len], Some(gpu_device))
+        }
+    };
+    let leading = p.get_items(&[IndexSliceOp::Range(0..len as i64)]);
+    Ok(multiply(&leading.astype(dtype), &factors, None, None))
+}
+
+/// The m-th antiderivative of the polynomial. `k` holds the integration constants in
+/// the order of the integrations, a single constant is used for all of them and they
+/// are zero when None.
+pub fn polyint(p: &NdArray, m: u32, k: Option<&NdArray>) -> NdArrayResult<NdArray> {
+    let p = as_1d(p)?;
+    if m == 0 {
+        return Ok(p);
+    }
+    let gpu_device = p.get_gpu_device();
+    let k = match k {
+        None => zeros(vec![m], Some(Dtype::Float32), Some(gpu_device.clone())),
+        Some(k) if k.shape.len() > 1 || (k.len() != 1 && k.len() < m) => {
+            return Err(NdArrayError::ValueError(
+                "k must be a scalar or a rank-1 array of length 1 or >m.".to_string(),
+            ))
+        }
+        Some(k) if k.len() == 1 => broadcast_to(&k.reshape(vec![1])?, &[m]),
+        Some(k) => k.get_items(&[IndexSliceOp::Range(0..m as i64)]),
+    };
+
+    // After m integrations the coefficient of degree d is divided by
+    // (d + 1) * .. * (d + m), the constant of the j-th integration by (m - 1 - j)!
+    let n = p.len();
+    let p_factors = (0..n)
+        .map(|i| (n - i..n - i + m).map(f64::from).product::<f64>() as f32)
+        .collect::<Vec<_>>();
+    let k_factors = (0..m)
+        .map(|j| (1..m - j).map(f64::from).product::<f64>() as f32)
+        .collect::<Vec<_>>();
+    let p_factors = NdArray::from_slice(
+        p_factors.as_slice().into(),
+        vec![n],
+        Some(gpu_device.clone()),
+    );
+    let k_factors = NdArray::from_slice(k_factors.as_slice().into(), vec![m], Some(gpu_device));
+    let k = divide(&k.astype(Dtype::Float32), &k_factors, None, None);
+    if p.is_empty() {
+        return Ok(k);
+    }
+    let p = divide(&p.astype(Dtype::Float32), &p_factors, None, None);
+    concatenate(&[&p, &k], Some(0))
+}
+
+fn dot(x: &[f64], y: &[f64]) -> f64 {
+    x.iter().zip(y).map(|(x, y)| x * y).sum()
+}
+
+/// Minimum norm least squares solutions of `a x = b` for every column of `b`, from the
+/// singular value decomposition of `a` by one sided Jacobi rotations. Both are stored
+/// by columns, singular values up to `rcond` times the largest one count as zero.
+fn least_squares(mut a: Vec<Vec<f64>>, b: &[Vec<f64>], rcond: f64) -> Vec<Vec<f64>> {
+    let n = a.len();
+    let mut v = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| f64::from(u8::from(i == j)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let rotate = |columns: &mut [Vec<f64>], i: usize, j: usize, c: f64, s: f64| {
+        let (left, right) = columns.split_at_mut(j);
+        for (x, y) in left[i].iter_mut().zip(right[0].iter_mut()) {
+            (*x, *y) = (c * *x - s * *y, s * *x + c * *y);
+        }
+    };
+
+    for _ in 0..64 {
+        let mut rotated = false;
+        for i in 0..n {
+            for j in i + 1..n {
+                let alpha = dot(&a[i], &a[i]);
+                let beta = dot(&a[j], &a[j]);
+                let gamma = dot(&a[i], &a[j]);
+                if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                let c = 1.0 / (1.0 + t * t).sqrt();
+                rotate(&mut a, i, j, c, c * t);
+                rotate(&mut v, i, j, c, c * t);
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let sigma = a.iter().map(|x| dot(x, x).sqrt()).collect::<Vec<_>>();
+    let cutoff = rcond * sigma.iter().cloned().fold(0.0, f64::max);
+    b.iter()
+        .map(|b| {
+            let mut x = vec![0.0; n];
+            for j in (0..n).filter(|j| sigma[*j] > cutoff) {
+                let factor = dot(&a[j], b) / (sigma[j] * sigma[j]);
+                x.iter_mut().zip(&v[j]).for_each(|(x, v)| *x += factor * v);
+            }
+            x
+        })
+        .collect()
+}
+
+/// Least squares fit of a polynomial of degree `deg` to the points `(x, y)`, returning
+/// its coefficients highest degree first. `y` holds one data set per column when 2-D,
+/// `w` weights the residuals. Like numpy, the columns of the Vandermonde matrix are
+/// scaled to unit norm and the problem is solved on the host in f64.
+pub fn polyfit(x: &NdArray, y: &NdArray, deg: u32, w: Option<&NdArray>) -> NdArrayResult<NdArray> {
+    if x.shape.len() != 1 {
+        return Err(NdArrayError::ValueError(
+            "expected 1D vector for x".to_string(),
+        ));
+    }
+    if x.is_empty() {
+        return Err(NdArrayError::ValueError(
+            "expected non-empty vector for x".to_string(),
+        ));
+    }
+    if y.shape.is_empty() || y.shape.len() > 2 {
+        return Err(NdArrayError::ValueError(
+            "expected 1D or 2D array for y".to_string(),
+        ));
+    }
+    if x.shape[0] != y.shape[0] {
+        return Err(NdArrayError::ValueError(
+            "expected x and y to have same length".to_string(),
+        ));
+    }
+    if let Some(w) = w {
+        if w.shape.len() != 1 {
+            return Err(NdArrayError::ValueError(
+                "expected a 1-d array for weights".to_string(),
+            ));
+        }
+        if w.shape[0] != y.shape[0] {
+            return Err(NdArrayError::ValueError(
+                "expected w and y to have the same length".to_string(),
+            ));
+        }
+    }
+
+    let rows = x.len() as usize;
+    let order = deg as usize + 1;
+    let sets = y.shape.get(1).copied().unwrap_or(1) as usize;
+    let xs = f32_values(x);
+    let ys = f32_values(y);
+    let ws = w.map(f32_values);
+    let weight = |i: usize| ws.as_ref().map_or(1.0, |w| f64::from(w[i]));
+
+    let mut lhs = (0..order)
+        .map(|j| {
+            (0..rows)
+                .map(|i| weight(i) * f64::from(xs[i]).powi((order - 1 - j) as i32))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let rhs = (0..sets)
+        .map(|k| {
+            (0..rows)
+                .map(|i| weight(i) * f64::from(ys[i * sets + k]))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let scale = lhs
+        .iter_mut()
+        .map(|column| {
+            let norm = dot(column, column).sqrt();
+            let norm = if norm == 0.0 { 1.0 } else { norm };
+            column.iter_mut().for_each(|x| *x /= norm);
+            norm
+        })
+        .collect::<Vec<_>>();
+    let eps = match x.dtype {
+        Dtype::Float32 => f64::from(f32::EPSILON),
+        _ => f64::EPSILON,
+    };
+    let solutions = least_squares(lhs, &rhs, rows as f64 * eps);
+
+    let mut coefficients = vec![0.0f32; order * sets];
+    for (k, solution) in solutions.iter().enumerate() {
+        for (j, value) in solution.iter().enumerate() {
+            coefficients[j * sets + k] = (value / scale[j]) as f32;
+        }
+    }
+    let shape = match y.shape.len() {
+        1 => vec![order as u32],
+        _ => vec![order as u32, sets as u32],
+    };
+    Ok(NdArray::from_slice(
+        coefficients.as_slice().into(),
+        shape,
+        Some(x.get_gpu_device()),
+    ))
+}
+
+/// `a` with the sign of `b`
+fn sign(a: f64, b: f64) -> f64 {
+    if b >= 0.0 {
+        a.abs()
+    } else {
+        -a.abs()
+    }
+}
+
+/// Scales the rows and columns of the matrix by powers of two, so that their norms are
+/// close without changing the eigenvalues. The matrix is indexed from 1.
+fn balance(a: &mut [Vec<f64>], n: usize) {
+    const RADIX: f64 = 2.0;
+    let mut done = false;
+    while !done {
+        done = true;
+        for i in 1..=n {
+            let mut c = (1..=n)
+                .filter(|j| *j != i)
+                .map(|j| a[j][i].abs())
+                .sum::<f64>();
+            let r = (1..=n)
+                .filter(|j| *j != i)
+                .map(|j| a[i][j].abs())
+                .sum::<f64>();
+            if c == 0.0 || r == 0.0 {
+                continue;
+            }
+            let s = c + r;
+            let mut f = 1.0;
+            while c < r / RADIX {
+                f *= RADIX;
+                c *= RADIX * RADIX;
+            }
+            while c > r * RADIX {
+                f /= RADIX;
+                c /= RADIX * RADIX;
+            }
+            if (c + r) / f < 0.95 * s {
+                done = false;
+                (1..=n).for_each(|j| a[i][j] /= f);
+                (1..=n).for_each(|j| a[j][i] *= f);
+            }
+        }
+    }
+}
+
+/// Real and imaginary parts of the eigenvalues of an upper Hessenberg matrix, found by
+/// the shifted QR algorithm like `hqr` of Numerical Recipes. The matrix is indexed
+/// from 1 and is overwritten.
+fn hessenberg_eigenvalues(a: &mut [Vec<f64>], n: usize) -> NdArrayResult<(Vec<f64>, Vec<f64>)> {
+    let mut wr = vec![0.0; n + 1];
+    let mut wi = vec![0.0; n + 1];
+    let mut anorm = 0.0;
+    for i in 1..=n {
+        for j in (i - 1).max(1)..=n {
+            anorm += a[i][j].abs();
+        }
+    }
+
+    let mut nn = n;
+    let mut t = 0.0;
+    while nn >= 1 {
+        let mut its = 0;
+        loop {
+            // Look for a single small subdiagonal element
+            let mut l = 1;
+            for ll in (2..=nn).rev() {
+                let mut s = a[ll - 1][ll - 1].abs() + a[ll][ll].abs();
+                if s == 0.0 {
+                    s = anorm;
+                }
+                if a[ll][ll - 1].abs() + s == s {
+                    a[ll][ll - 1] = 0.0;
+                    l = ll;
+                    break;
+                }
+            }
+
+            let mut x = a[nn][nn];
+            if l == nn {
+                // One root found
+                wr[nn] = x + t;
+                wi[nn] = 0.0;
+                nn -= 1;
+            } else {
+                let mut y = a[nn - 1][nn - 1];
+                let mut w = a[nn][nn - 1] * a[nn - 1][nn];
+                if l == nn - 1 {
+                    // Two roots found
+                    let p = 0.5 * (y - x);
+                    let q = p * p + w;
+                    let z = q.abs().sqrt();
+                    x += t;
+                    if q >= 0.0 {
+                        let z = p + sign(z, p);
+                        wr[nn - 1] = x + z;
+                        wr[nn] = if z != 0.0 { x - w / z } else { x + z };
+                        wi[nn - 1] = 0.0;
+                        wi[nn] = 0.0;
+                    } else {
+                        wr[nn - 1] = x + p;
+                        wr[nn] = x + p;
+                        wi[nn - 1] = -z;
+                        wi[nn] = z;
+                    }
+                    nn -= 2;
+                } else {
+                    if its == 30 {
+                        return Err(NdArrayError::ValueError(
+                            "Eigenvalues did not converge".to_string(),
+                        ));
+                    }
+                    if its == 10 || its == 20 {
+                        // Exceptional shift
+                        t += x;
+                        (1..=nn).for_each(|i| a[i][i] -= x);
+                        let s = a[nn][nn - 1].abs() + a[nn - 1][nn - 2].abs();
+                        x = 0.75 * s;
+                        y = x;
+                        w = -0.4375 * s * s;
+                    }
+                    its += 1;
+
+                    // Form the shift and look for two consecutive small subdiagonal elements
+                    let mut m = nn - 2;
+                    let (mut p, mut q, mut r);
+                    loop {
+                        let z = a[m][m];
+                        r = x - z;
+                        let s = y - z;
+                        p = (r * s - w) / a[m + 1][m] + a[m][m + 1];
+                        q = a[m + 1][m + 1] - z - r - s;
+                        r = a[m + 2][m + 1];
+                        let s = p.abs() + q.abs() + r.abs();
+                        p /= s;
+                        q /= s;
+                        r /= s;
+                        if m == l {
+                            break;
+                        }
+                        let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                        let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+                        if u + v == v {
+                            break;
+                        }
+                        m -= 1;
+                    }
+                    for i in m + 2..=nn {
+                        a[i][i - 2] = 0.0;
+                        if i != m + 2 {
+                            a[i][i - 3] = 0.0;
+                        }
+                    }
+
+                    // Double QR step on the rows l to nn and the columns m to nn
+                    for k in m..nn {
+                        if k != m {
+                            p = a[k][k - 1];
+                            q = a[k + 1][k - 1];
+                            r = if k != nn - 1 { a[k + 2][k - 1] } else { 0.0 };
+                            x = p.abs() + q.abs() + r.abs();
+                            if x != 0.0 {
+                                p /= x;
+                                q /= x;
+                                r /= x;
+                            }
+                        }
+                        let s = sign((p * p + q * q + r * r).sqrt(), p);
+                        if s == 0.0 {
+                            continue;
+                        }
+                        if k != m {
+                            a[k][k - 1] = -s * x;
+                        } else if l != m {
+                            a[k][k - 1] = -a[k][k - 1];
+                        }
+                        p += s;
+                        x = p / s;
+                        y = q / s;
+                        let z = r / s;
+                        q /= p;
+                        r /= p;
+                        for j in k..=nn {
+                            p = a[k][j] + q * a[k + 1][j];
+                            if k != nn - 1 {
+                                p += r * a[k + 2][j];
+                                a[k + 2][j] -= p * z;
+                            }
+                            a[k + 1][j] -= p * y;
+                            a[k][j] -= p * x;
+                        }
+                        for i in l..=nn.min(k + 3) {
+                            p = x * a[i][k] + y * a[i][k + 1];
+                            if k != nn - 1 {
+                                p += z * a[i][k + 2];
+                                a[i][k + 2] -= p * r;
+                            }
+                            a[i][k + 1] -= p * q;
+                            a[i][k] -= p;
+                        }
+                    }
+                }
+            }
+            if l + 1 >= nn {
+                break;
+            }
+        }
+    }
+    Ok((wr.split_off(1), wi.split_off(1)))
+}
+
+/// Roots of the polynomial with the coefficients `p`, as the eigenvalues of its
+/// companion matrix computed on the host. Complex dtypes are not supported, so the
+/// real and imaginary parts are returned as two f32 arrays, sorted by the real and
+/// then the imaginary parts.
+pub fn roots(p: &NdArray) -> NdArrayResult<(NdArray, NdArray)> {
+    if p.shape.len() > 1 {
+        return Err(NdArrayError::ValueError(
+            "Input must be a rank-1 array.".to_string(),
+        ));
+    }
+    let values = f32_values(p);
+    let first = values.iter().position(|x| *x != 0.0);
+    let last = values.iter().rposition(|x| *x != 0.0);
+    let mut roots = vec![];
+    if let (Some(first), Some(last)) = (first, last) {
+        // Trailing zero coefficients are roots at zero
+        roots.resize(values.len() - 1 - last, (0.0, 0.0));
+        let p = values[first..=last]
+            .iter()
+            .map(|x| f64::from(*x))
+            .collect::<Vec<_>>();
+        let n = p.len() - 1;
+        if n > 0 {
+            let mut companion = vec![vec![0.0; n + 1]; n + 1];
+            for j in 1..=n {
+                companion[1][j] = -p[j] / p[0];
+            }
+            for i in 2..=n {
+                companion[i][i - 1] = 1.0;
+            }
+            balance(&mut companion, n);
+            let (real, imaginary) = hessenberg_eigenvalues(&mut companion, n)?;
+            roots.extend(real.into_iter().zip(imaginary));
+        }
+    }
+    roots.sort_by(|x, y| x.0.total_cmp(&y.0).then(x.1.total_cmp(&y.1)));
+
+    let gpu_device = p.get_gpu_device();
+    let len = roots.len() as u32;
+    let real = roots.iter().map(|x| x.0 as f32).collect::<Vec<_>>();
+    let imaginary = roots.iter().map(|x| x.1 as f32).collect::<Vec<_>>();
+    Ok((
+        NdArray::from_slice(real.as_slice().into(), vec![len], Some(gpu_device.clone())),
+        NdArray::from_slice(imaginary.as_slice().into(), vec![len], Some(gpu_device)),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::float_slice_eq_in_error;
+
+    #[test]
+    fn test_polyval() {
+        let p = NdArray::from_slice([1i32, 2, 3].as_ref().into(), vec![3], None);
+        let x = NdArray::from_slice([0i32, 1, 2, -3].as_ref().into(), vec![2, 2], None);
+
+        let result = polyval(&p, &x).unwrap();
+        assert_eq!(result.dtype, Dtype::Float32);
+        assert_eq!(&result.shape, &[2, 2]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![3.0f32, 6.0, 11.0, 6.0].into()
+        );
+    }
+
+    #[test]
+    fn test_polyadd_polymul() {
+        let a1 = NdArray::from_slice([1.0f32, 2.0].as_ref().into(), vec![2], None);
+        let a2 = NdArray::from_slice([1.0f32, 2.0, 3.0].as_ref().into(), vec![3], None);
+
+        let result = polyadd(&a1, &a2).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![1.0f32, 3.0, 5.0].into());
+        let result = polymul(&a1, &a2).unwrap();
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![1.0f32, 4.0, 7.0, 6.0].into()
+        );
+    }
+
+    #[test]
+    fn test_polyder_polyint() {
+        let p = NdArray::from_slice([1.0f32, 2.0, 3.0, 4.0].as_ref().into(), vec![4], None);
+
+        let result = polyder(&p, 1).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![3.0f32, 4.0, 3.0].into());
+        let result = polyder(&p, 2).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![6.0f32, 4.0].into());
+        assert!(polyder(&p, 5).unwrap().is_empty());
+        let ones = NdArray::from_slice([1i32; 13].as_ref().into(), vec![13], None);
+        let result = polyder(&ones, 12).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![479_001_600i32].into());
+        let ones = NdArray::from_slice([1i32; 14].as_ref().into(), vec![14], None);
+        assert!(polyder(&ones, 13).is_err());
+
+        let p = polyder(&p, 1).unwrap();
+        let result = polyint(&p, 1, None).unwrap();
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![1.0f32, 2.0, 3.0, 0.0].into()
+        );
+        let k = NdArray::from_slice([1.0f32, 2.0].as_ref().into(), vec![2], None);
+        let result = polyint(&p, 2, Some(&k)).unwrap();
+        float_slice_eq_in_error(
+            result.data.get_raw_values(),
+            vec![0.25f32, 2.0 / 3.0, 1.5, 1.0, 2.0].into(),
+        );
+    }
+
+    #[test]
+    fn test_polyfit() {
+        let x = NdArray::from_slice([0.0f32, 1.0, 2.0, 3.0].as_ref().into(), vec![4], None);
+        let y = NdArray::from_slice([1.0f32, 3.0, 5.0, 7.0].as_ref().into(), vec![4], None);
+
+        let result = polyfit(&x, &y, 1, None).unwrap();
+        float_slice_eq_in_error(result.data.get_raw_values(), vec![2.0f32, 1.0].into());
+
+        let result = polyfit(&x, &y, 2, None).unwrap();
+        float_slice_eq_in_error(result.data.get_raw_values(), vec![0.0f32, 2.0, 1.0].into());
+    }
+
+    #[test]
+    fn test_roots() {
+        let p = NdArray::from_slice([1.0f32, -3.0, 2.0].as_ref().into(), vec![3], None);
+        let (real, imaginary) = roots(&p).unwrap();
+        float_slice_eq_in_error(real.data.get_raw_values(), vec![1.0f32, 2.0].into());
+        assert_eq!(imaginary.data.get_raw_values(), vec![0.0f32, 0.0].into());
+
+        let p = NdArray::from_slice([0.0f32, 1.0, 0.0, 1.0, 0.0].as_ref().into(), vec![5], None);
+        let (real, imaginary) = roots(&p).unwrap();
+        float_slice_eq_in_error(real.data.get_raw_values(), vec![0.0f32, 0.0, 0.0].into());
+        float_slice_eq_in_error(
+            imaginary.data.get_raw_values(),
+            vec![-1.0f32, 0.0, 1.0].into(),
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use arrow_gpu::{
+    array::{broadcast_op_dyn, ArrowType},
+    gpu_utils::ArrowComputePipeline,
+    kernels::*,
+};
+use webgpupy_core::{
+    cast_if_needed, float_dtype, ufunc_nin1_nout1, ufunc_nin1_nout1_body, Dtype, NdArray,
+    ScalarValue,
+};
+
+use crate::kernels::{apply_f32_unary, f32_unary_op};
+
+const ROUNDING_SHADER: &str = include_str!("../compute_shader/f32/rounding.wgsl");
+
+f32_unary_op!(floor_op_dyn, ROUNDING_SHADER, "floor_");
+f32_unary_op!(ceil_op_dyn, ROUNDING_SHADER, "ceil_");
+f32_unary_op!(trunc_op_dyn, ROUNDING_SHADER, "trunc_");
+f32_unary_op!(rint_op_dyn, ROUNDING_SHADER, "rint_");
+
+ufunc_nin1_nout1_body!(floor, floor_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(ceil, ceil_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(trunc, trunc_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(rint, rint_op_dyn, float_dtype);
+
+/// Evenly rounds to the given number of decimals, negative decimals round to the
+/// left of the decimal point. Integer arrays keep their dtype.
+pub fn around(a: &NdArray, decimals: i32) -> NdArray {
+    let gpu_device = a.get_gpu_device();
+    if a.dtype != Dtype::Float32 && decimals >= 0 {
+        return a.clone_array();
+    }
+    if a.is_empty() {
+        return NdArray::zero_sized(a.shape.clone(), a.dtype, gpu_device);
+    }
+
+    let mut pipeline = ArrowComputePipeline::new(gpu_device, Some("around"));
+    let mut casted = None;
+    let data = cast_if_needed(&a.data, &ArrowType::Float32Type, &mut casted, &mut pipeline);
+
+    let scale = ScalarValue::F32(10.0f32.powi(decimals.abs()));
+    let scale = broadcast_op_dyn(scale.into(), a.len() as usize, &mut pipeline);
+    let data = if decimals >= 0 {
+        let scaled = mul_op_dyn(data, &scale, &mut pipeline);
+        let rounded = apply_f32_unary(&scaled, ROUNDING_SHADER, "rint_", &mut pipeline);
+        div_op_dyn(&rounded, &scale, &mut pipeline)
+    } else {
+        let scaled = div_op_dyn(data, &scale, &mut pipeline);
+        let rounded = apply_f32_unary(&scaled, ROUNDING_SHADER, "rint_", &mut pipeline);
+        mul_op_dyn(&rounded, &scale, &mut pipeline)
+    };
+    let data = if a.dtype == Dtype::Float32 {
+        data
+    } else {
+        cast_op_dyn(&data, &a.dtype.into(), &mut pipeline)
+    };
+    pipeline.finish();
+
+    NdArray {
+        shape: a.shape.clone(),
+        dims: a.dims,
+        data,
+        dtype: a.dtype,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_gpu::array::ArrowArrayGPU;
+    use test_utils::*;
+    use webgpupy_core::GPU_DEVICE;
+
+    test_ufunc_nin1_nout1_f32!(
+        test_floor_f32_mask_mixed,
+        [1.5, -1.5, 2.0],
+        [1.0, -2.0, 0.0],
+        [true, true, false],
+        floor
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_ceil_f32,
+        [1.5, -1.5, 2.0],
+        [2.0, -1.0, 2.0],
+        [true, true, true],
+        ceil
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_trunc_f32,
+        [1.7, -1.7],
+        [1.0, -1.0],
+        [true, true],
+        trunc
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_rint_f32,
+        [0.5, 1.5, 2.5, -0.5, 2.6],
+        [0.0, 2.0, 2.0, 0.0, 3.0],
+        [true, true, true, true, true],
+        rint
+    );
+
+    #[test]
+    fn test_around() {
+        let array = NdArray::from_slice([1.234f32, -5.678, 15.0].as_ref().into(), vec![3], None);
+        let result = around(&array, 1);
+        float_slice_eq_in_error(
+            result.data.get_raw_values(),
+            vec![1.2f32, -5.7, 15.0].into(),
+        );
+
+        let result = around(&array, -1);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![0.0f32, -10.0, 20.0].into()
+        );
+
+        let array = NdArray::from_slice([14i32, 15, -26].as_ref().into(), vec![3], None);
+        let result = around(&array, -1);
+        assert_eq!(result.dtype, Dtype::Int32);
+        assert_eq!(result.data.get_raw_values(), vec![10i32, 20, -30].into());
+        assert_eq!(
+            around(&array, 2).data.get_raw_values(),
+            vec![14i32, 15, -26].into()
+        );
+    }
+}
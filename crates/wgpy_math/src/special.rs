@@ -0,0 +1,123 @@
+use arrow_gpu::{array::ArrowArrayGPU, gpu_utils::ArrowComputePipeline};
+use webgpupy_core::{
+    float_dtype, ufunc_nin1_nout1, ufunc_nin1_nout1_body, ufunc_nin2_nout1, ufunc_nin2_nout1_body,
+    Dtype, NdArray,
+};
+
+use crate::kernels::{apply_f32_unary, f32_binary_op, f32_unary_op};
+
+const ERFINV_SHADER: &str = include_str!("../compute_shader/f32/erfinv.wgsl");
+const SPECIAL_SHADER: &str = concat!(
+    include_str!("../compute_shader/f32/gamma.wgsl"),
+    include_str!("../compute_shader/f32/special.wgsl")
+);
+const SPECIAL_BINARY_SHADER: &str = concat!(
+    include_str!("../compute_shader/f32/gamma.wgsl"),
+    include_str!("../compute_shader/f32/special_binary.wgsl")
+);
+
+/// Inverse error function of every element, other dtypes are cast to f32 first
+pub fn erfinv_op_dyn(data: &ArrowArrayGPU, pipeline: &mut ArrowComputePipeline) -> ArrowArrayGPU {
+    apply_f32_unary(data, ERFINV_SHADER, "erfinv_", pipeline)
+}
+
+f32_unary_op!(erf_op_dyn, SPECIAL_SHADER, "erf_");
+f32_unary_op!(erfc_op_dyn, SPECIAL_SHADER, "erfc_");
+f32_unary_op!(gamma_op_dyn, SPECIAL_SHADER, "gamma_");
+f32_unary_op!(lgamma_op_dyn, SPECIAL_SHADER, "lgamma_");
+f32_unary_op!(digamma_op_dyn, SPECIAL_SHADER, "digamma_");
+f32_unary_op!(sinc_op_dyn, SPECIAL_SHADER, "sinc_");
+f32_unary_op!(i0_op_dyn, SPECIAL_SHADER, "i0_");
+f32_binary_op!(beta_op_dyn, SPECIAL_BINARY_SHADER, "beta_");
+
+ufunc_nin1_nout1_body!(erf, erf_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(erfc, erfc_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(erfinv, erfinv_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(gamma, gamma_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(lgamma, lgamma_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(digamma, digamma_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(sinc, sinc_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(i0, i0_op_dyn, float_dtype);
+ufunc_nin2_nout1_body!(beta, beta_op_dyn, float_dtype);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::*;
+    use webgpupy_core::GPU_DEVICE;
+
+    test_ufunc_nin1_nout1_f32!(
+        test_erf_f32,
+        [0.0, 0.25, -1.0, 2.5],
+        [0.0, 0.276_326_4, -0.842_700_8, 0.999_593],
+        [true, true, true, true],
+        erf
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_erfc_f32_mask_mixed,
+        [-0.5, 1.0, 3.0],
+        [1.520_5, 0.0, 2.209_05e-5],
+        [true, false, true],
+        erfc
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_erfinv_f32,
+        [0.0, 0.5, -0.9],
+        [0.0, 0.476_936_28, -1.163_087_2],
+        [true, true, true],
+        erfinv
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_gamma_f32,
+        [0.5, 5.0, -1.5],
+        [std::f32::consts::PI.sqrt(), 24.0, 2.363_271_8],
+        [true, true, true],
+        gamma
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_lgamma_f32,
+        [1.0, 3.0, 0.5],
+        [0.0, 2.0f32.ln(), 0.572_364_9],
+        [true, true, true],
+        lgamma
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_digamma_f32,
+        [1.0, 0.5, 10.0],
+        [-0.577_215_7, -1.963_510_1, 2.251_752_6],
+        [true, true, true],
+        digamma
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_sinc_f32,
+        [0.0, 0.5, 2.0],
+        [1.0, 2.0 / std::f32::consts::PI, 0.0],
+        [true, true, true],
+        sinc
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_i0_f32,
+        [0.0, 1.0, 5.0],
+        [1.0, 1.266_066, 27.239_87],
+        [true, true, true],
+        i0
+    );
+
+    #[test]
+    fn test_beta_f32() {
+        let a = NdArray::from_slice([2.0f32, 0.5, 20.0].as_ref().into(), vec![3], None);
+        let b = NdArray::from_slice([3.0f32, 0.5, 15.0].as_ref().into(), vec![3], None);
+        let result = beta(&a, &b, None, None);
+        float_slice_eq_in_error(
+            result.data.get_raw_values(),
+            vec![1.0f32 / 12.0, std::f32::consts::PI, 3.592_017e-11].into(),
+        );
+    }
+}
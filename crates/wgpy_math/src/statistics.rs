@@ -0,0 +1,498 @@
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{ArrayUtils, Float32ArrayGPU},
+    gpu_utils::ArrowComputePipeline,
+    utils::ScalarArray,
+};
+use webgpupy_core::{
+    as_f32_array, broadcast_to, concatenate, full, normalize_axis, sort, Dtype, NdArray,
+    NdArrayError, NdArrayResult, ScalarValue,
+};
+
+use crate::nanfunctions::{ReduceLayout, DEGREES_OF_FREEDOM};
+
+const STATISTICS_SHADER: &str = include_str!("../compute_shader/f32/statistics.wgsl");
+const COVARIANCE_SHADER: &str = include_str!("../compute_shader/f32/covariance.wgsl");
+
+/// Methods of estimating quantiles, see numpy's `quantile`. The discriminant is passed
+/// to the shader.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuantileMethod {
+    InvertedCdf = 0,
+    AveragedInvertedCdf = 1,
+    ClosestObservation = 2,
+    InterpolatedInvertedCdf = 3,
+    Hazen = 4,
+    Weibull = 5,
+    #[default]
+    Linear = 6,
+    MedianUnbiased = 7,
+    NormalUnbiased = 8,
+    Lower = 9,
+    Higher = 10,
+    Nearest = 11,
+    Midpoint = 12,
+}
+
+impl QuantileMethod {
+    /// Methods which pick one of the values, keeping the dtype of the input
+    fn is_discrete(self) -> bool {
+        matches!(
+            self,
+            Self::InvertedCdf
+                | Self::ClosestObservation
+                | Self::Lower
+                | Self::Higher
+                | Self::Nearest
+        )
+    }
+}
+
+/// Runs a kernel of the statistics shader producing `len` f32 values
+fn statistics_op(
+    input_1: &Float32ArrayGPU,
+    input_2: &Float32ArrayGPU,
+    params: [u32; 4],
+    len: u32,
+    entry_point: &str,
+    pipeline: &mut ArrowComputePipeline,
+) -> Float32ArrayGPU {
+    let params = pipeline.device.create_gpu_buffer_with_data(&params);
+    let buffer = pipeline.apply_ternary_function(
+        &input_1.data,
+        &input_2.data,
+        &params,
+        len as u64 * 4,
+        STATISTICS_SHADER,
+        entry_point,
+        len.div_ceil(256),
+    );
+    Float32ArrayGPU {
+        data: Arc::new(buffer),
+        gpu_device: input_1.get_gpu_device(),
+        phantom: std::marker::PhantomData,
+        len: len as usize,
+        null_buffer: None,
+    }
+}
+
+pub(crate) fn f32_values(array: &NdArray) -> Vec<f32> {
+    if array.is_empty() {
+        return vec![];
+    }
+    match array.astype(Dtype::Float32).data.get_raw_values() {
+        ScalarArray::F32Vec(x) => x,
+        _ => unreachable!(),
+    }
+}
+
+/// Quantiles `q` in [0, 1] of the lanes, the shape of `q` is prepended to the result
+fn quantile_op(
+    a: &NdArray,
+    q: &[f32],
+    q_shape: &[u32],
+    axis: Option<i32>,
+    method: QuantileMethod,
+    keepdims: bool,
+) -> NdArrayResult<NdArray> {
+    let layout = ReduceLayout::new(&a.shape, axis, keepdims)?;
+    let shape = [q_shape, &layout.shape].concat();
+    let dtype = if method.is_discrete() {
+        a.dtype
+    } else {
+        Dtype::Float32
+    };
+    let gpu_device = a.get_gpu_device();
+    if layout.lanes == 0 || q.is_empty() {
+        return Ok(NdArray::zero_sized(shape, dtype, gpu_device));
+    }
+    if layout.axis_len == 0 {
+        let nan = ScalarValue::F32(f32::NAN).into();
+        return full(shape, nan, None, Some(gpu_device));
+    }
+
+    let sorted = sort(a, axis)?;
+    let q = NdArray::from_slice(q.into(), vec![q.len() as u32], Some(gpu_device.clone()));
+    let mut pipeline = ArrowComputePipeline::new(gpu_device, Some("quantile"));
+    let (mut casted_1, mut casted_2) = (None, None);
+    let input = as_f32_array(&sorted.data, &mut casted_1, &mut pipeline);
+    let q_data = as_f32_array(&q.data, &mut casted_2, &mut pipeline);
+    let params = [layout.axis_len, layout.inner, layout.lanes, method as u32];
+    let len = q.len() * layout.lanes;
+    let data = statistics_op(input, q_data, params, len, "quantile_", &mut pipeline);
+    pipeline.finish();
+
+    let result = NdArray {
+        dims: shape.len() as u16,
+        shape,
+        data: data.into(),
+        dtype: Dtype::Float32,
+    };
+    if dtype == Dtype::Float32 {
+        Ok(result)
+    } else {
+        Ok(result.astype(dtype))
+    }
+}
+
+/// Computes the `q`-th quantiles of the data along an axis, the shape of `q` is
+/// prepended to the shape of the result. Slices holding nans give nan.
+pub fn quantile(
+    a: &NdArray,
+    q: &NdArray,
+    axis: Option<i32>,
+    method: QuantileMethod,
+    keepdims: bool,
+) -> NdArrayResult<NdArray> {
+    let values = f32_values(q);
+    if !values.iter().all(|x| (0.0..=1.0).contains(x)) {
+        return Err(NdArrayError::ValueError(
+            "Quantiles must be in the range [0, 1]".to_string(),
+        ));
+    }
+    quantile_op(a, &values, &q.shape, axis, method, keepdims)
+}
+
+/// Computes the `q`-th percentiles of the data along an axis, see [`quantile`]
+pub fn percentile(
+    a: &NdArray,
+    q: &NdArray,
+    axis: Option<i32>,
+    method: QuantileMethod,
+    keepdims: bool,
+) -> NdArrayResult<NdArray> {
+    let values = f32_values(q);
+    if !values.iter().all(|x| (0.0..=100.0).contains(x)) {
+        return Err(NdArrayError::ValueError(
+            "Percentiles must be in the range [0, 100]".to_string(),
+        ));
+    }
+    let values = values.iter().map(|x| x / 100.0).collect::<Vec<_>>();
+    quantile_op(a, &values, &q.shape, axis, method, keepdims)
+}
+
+/// Median along an axis, slices holding nans give nan
+pub fn median(a: &NdArray, axis: Option<i32>, keepdims: bool) -> NdArrayResult<NdArray> {
+    quantile_op(a, &[0.5], &[], axis, QuantileMethod::Linear, keepdims)
+}
+
+/// Weighted average along an axis and the sum of the weights, which has the shape of
+/// the average. `weights` has the shape of `a`, or is 1-D with the length of the axis.
+pub fn average(
+    a: &NdArray,
+    axis: Option<i32>,
+    weights: Option<&NdArray>,
+    keepdims: bool,
+) -> NdArrayResult<(NdArray, NdArray)> {
+    let layout = ReduceLayout::new(&a.shape, axis, keepdims)?;
+    let broadcasted;
+    let weights = match weights {
+        Some(w) if w.shape != a.shape => {
+            let axis = axis.ok_or_else(|| {
+                NdArrayError::DtypeError(
+                    "Axis must be specified when shapes of a and weights differ.".to_string(),
+                )
+            })?;
+            if w.shape.len() != 1 {
+                return Err(NdArrayError::DtypeError(
+                    "1D weights expected when shapes of a and weights differ.".to_string(),
+                ));
+            }
+            let axis = normalize_axis(axis, a.shape.len())?;
+            if w.shape[0] != a.shape[axis] {
+                return Err(NdArrayError::ValueError(
+                    "Length of weights not compatible with specified axis.".to_string(),
+                ));
+            }
+            let mut shape = vec![1; a.shape.len()];
+            shape[axis] = a.shape[axis];
+            broadcasted = broadcast_to(&w.reshape(shape)?, &a.shape);
+            Some(&broadcasted)
+        }
+        x => x,
+    };
+
+    let gpu_device = a.get_gpu_device();
+    let shape = layout.shape.clone();
+    if layout.lanes == 0 {
+        let average = NdArray::zero_sized(shape.clone(), Dtype::Float32, gpu_device.clone());
+        return Ok((
+            average,
+            NdArray::zero_sized(shape, Dtype::Float32, gpu_device),
+        ));
+    }
+    if layout.axis_len == 0 && weights.is_none() {
+        let nan = ScalarValue::F32(f32::NAN).into();
+        let zero = ScalarValue::F32(0.0).into();
+        let average = full(shape.clone(), nan, None, Some(gpu_device.clone()))?;
+        return Ok((average, full(shape, zero, None, Some(gpu_device))?));
+    }
+
+    let mut pipeline = ArrowComputePipeline::new(gpu_device, Some("average"));
+    let (mut casted_1, mut casted_2) = (None, None);
+    let input = as_f32_array(&a.data, &mut casted_1, &mut pipeline);
+    let weights_data = match weights {
+        Some(w) => as_f32_array(&w.data, &mut casted_2, &mut pipeline),
+        None => input,
+    };
+    let params = [
+        layout.axis_len,
+        layout.inner,
+        layout.lanes,
+        u32::from(weights.is_some()),
+    ];
+    let lanes = layout.lanes;
+    let average = statistics_op(
+        input,
+        weights_data,
+        params,
+        lanes,
+        "average_",
+        &mut pipeline,
+    );
+    let sums = statistics_op(
+        input,
+        weights_data,
+        params,
+        lanes,
+        "weight_sum_",
+        &mut pipeline,
+    );
+    pipeline.finish();
+
+    let into_array = |data: Float32ArrayGPU| NdArray {
+        dims: shape.len() as u16,
+        shape: shape.clone(),
+        data: data.into(),
+        dtype: Dtype::Float32,
+    };
+    let (average, sums) = (into_array(average), into_array(sums));
+    if weights.is_some() {
+        let zero_sum = match sums.data.get_raw_values() {
+            ScalarArray::F32Vec(x) => x.contains(&0.0),
+            _ => unreachable!(),
+        };
+        if zero_sum {
+            return Err(NdArrayError::ValueError(
+                "Weights sum to zero, can't be normalized".to_string(),
+            ));
+        }
+    }
+    Ok((average, sums))
+}
+
+/// Variables of `x` as rows when `rowvar`, otherwise as columns
+fn variables_2d(x: &NdArray, rowvar: bool, name: &str) -> NdArrayResult<NdArray> {
+    let shape = match (x.shape.as_slice(), rowvar) {
+        ([], _) => vec![1, 1],
+        ([len], true) | ([1, len], false) => vec![1, *len],
+        ([len], false) => vec![*len, 1],
+        ([rows, cols], _) => vec![*rows, *cols],
+        _ => {
+            return Err(NdArrayError::ValueError(format!(
+                "{} has more than 2 dimensions",
+                name
+            )))
+        }
+    };
+    // A single row of observations is one variable even when variables are columns
+    match (shape.as_slice(), rowvar) {
+        ([1, len], false) => x.reshape(vec![*len, 1]),
+        _ => x.reshape(shape),
+    }
+}
+
+/// Array with the message numpy would warn with while computing it, if any
+#[derive(Debug)]
+pub struct ArrayWithWarning {
+    pub array: NdArray,
+    pub warning: Option<&'static str>,
+}
+
+fn covariance_op(
+    m: &NdArray,
+    y: Option<&NdArray>,
+    rowvar: bool,
+    correlation: bool,
+    ddof: u32,
+) -> NdArrayResult<ArrayWithWarning> {
+    let mut x = variables_2d(m, rowvar, "m")?;
+    if let Some(y) = y {
+        let y = variables_2d(y, rowvar, "y")?;
+        x = concatenate(&[&x, &y], Some(if rowvar { 0 } else { 1 }))?;
+    }
+    let (vars, observations) = if rowvar {
+        (x.shape[0], x.shape[1])
+    } else {
+        (x.shape[1], x.shape[0])
+    };
+    let (var_stride, obs_stride) = if rowvar { (observations, 1) } else { (1, vars) };
+    let shape = if vars == 1 { vec![] } else { vec![vars, vars] };
+    let gpu_device = x.get_gpu_device();
+    if vars == 0 {
+        return Ok(ArrayWithWarning {
+            array: NdArray::zero_sized(shape, Dtype::Float32, gpu_device),
+            warning: None,
+        });
+    }
+
+    // The standard deviations of corrcoef cancel the ddof of 1 numpy uses, it still
+    // warns without degrees of freedom
+    let checked_ddof = if correlation { 1 } else { ddof };
+    let warning = (observations <= checked_ddof).then_some(DEGREES_OF_FREEDOM);
+    if let Some(message) = warning {
+        log::warn!("{}", message);
+    }
+    let fact = (observations as f32 - ddof as f32).max(0.0);
+    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("covariance"));
+    let mut casted = None;
+    let input = as_f32_array(&x.data, &mut casted, &mut pipeline);
+    let params = [
+        vars,
+        observations,
+        var_stride,
+        obs_stride,
+        u32::from(correlation),
+        (1.0 / fact).to_bits(),
+    ];
+    let params = pipeline.device.create_gpu_buffer_with_data(&params);
+    let len = vars * vars;
+    let buffer = pipeline.apply_binary_function(
+        &input.data,
+        &params,
+        len as u64 * 4,
+        COVARIANCE_SHADER,
+        "covariance_",
+        len.div_ceil(256),
+    );
+    pipeline.finish();
+
+    let array = NdArray {
+        dims: shape.len() as u16,
+        shape,
+        data: Float32ArrayGPU {
+            data: Arc::new(buffer),
+            gpu_device,
+            phantom: std::marker::PhantomData,
+            len: len as usize,
+            null_buffer: None,
+        }
+        .into(),
+        dtype: Dtype::Float32,
+    };
+    Ok(ArrayWithWarning { array, warning })
+}
+
+/// Estimates the covariance matrix of the variables in `m` and `y`. `rowvar` holds the
+/// variables in rows, `ddof` defaults to 0 with `bias`, otherwise to 1. A single
+/// variable gives a 0-d array. The warning is set when there are no more observations
+/// than `ddof`.
+pub fn cov(
+    m: &NdArray,
+    y: Option<&NdArray>,
+    rowvar: bool,
+    bias: bool,
+    ddof: Option<u32>,
+) -> NdArrayResult<ArrayWithWarning> {
+    let ddof = ddof.unwrap_or(u32::from(!bias));
+    covariance_op(m, y, rowvar, false, ddof)
+}
+
+/// Pearson correlation coefficients of the variables in `x` and `y`, see [`cov`]
+pub fn corrcoef(x: &NdArray, y: Option<&NdArray>, rowvar: bool) -> NdArrayResult<ArrayWithWarning> {
+    covariance_op(x, y, rowvar, true, 0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::float_slice_eq_in_error;
+
+    #[test]
+    fn test_quantile() {
+        let values = [3.0f32, 1.0, 4.0, 1.5, 5.0, 9.0, 2.0, 6.0];
+        let array = NdArray::from_slice(values.as_ref().into(), vec![2, 4], None);
+
+        let result = median(&array, Some(1), false).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![2.25f32, 5.5].into());
+
+        let q = NdArray::from_slice([0.0f32, 0.5, 1.0].as_ref().into(), vec![3], None);
+        let result = quantile(&array, &q, None, QuantileMethod::Linear, false).unwrap();
+        assert_eq!(&result.shape, &[3]);
+        assert_eq!(result.data.get_raw_values(), vec![1.0f32, 3.5, 9.0].into());
+
+        let q = NdArray::from_slice([25.0f32].as_ref().into(), vec![], None);
+        let result = percentile(&array, &q, Some(0), QuantileMethod::Lower, true).unwrap();
+        assert_eq!(&result.shape, &[1, 4]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![3.0f32, 1.0, 2.0, 1.5].into()
+        );
+
+        let q = NdArray::from_slice([1.5f32].as_ref().into(), vec![], None);
+        assert!(quantile(&array, &q, None, QuantileMethod::Linear, false).is_err());
+    }
+
+    #[test]
+    fn test_average() {
+        let values = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let array = NdArray::from_slice(values.as_ref().into(), vec![2, 3], None);
+
+        let (result, sums) = average(&array, None, None, false).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![3.5f32].into());
+        assert_eq!(sums.data.get_raw_values(), vec![6.0f32].into());
+
+        let weights = NdArray::from_slice([1.0f32, 0.0, 3.0].as_ref().into(), vec![3], None);
+        let (result, sums) = average(&array, Some(1), Some(&weights), false).unwrap();
+        assert_eq!(result.data.get_raw_values(), vec![2.5f32, 5.5].into());
+        assert_eq!(sums.data.get_raw_values(), vec![4.0f32, 4.0].into());
+
+        assert!(average(&array, None, Some(&weights), false).is_err());
+        let weights = NdArray::from_slice([1.0f32, -1.0].as_ref().into(), vec![2], None);
+        assert!(average(&array, Some(0), Some(&weights), false).is_err());
+    }
+
+    #[test]
+    fn test_cov_corrcoef() {
+        let values = [0.0f32, 1.0, 2.0, 2.0, 1.0, 0.0];
+        let array = NdArray::from_slice(values.as_ref().into(), vec![2, 3], None);
+
+        let result = cov(&array, None, true, false, None).unwrap().array;
+        assert_eq!(&result.shape, &[2, 2]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![1.0f32, -1.0, -1.0, 1.0].into()
+        );
+
+        let result = cov(&array, None, false, true, None).unwrap().array;
+        assert_eq!(&result.shape, &[3, 3]);
+        assert_eq!(
+            result.data.get_raw_values(),
+            vec![1.0f32, 0.0, -1.0, 0.0, 0.0, 0.0, -1.0, 0.0, 1.0].into()
+        );
+
+        let x = NdArray::from_slice([1.0f32, 2.0, 4.0].as_ref().into(), vec![3], None);
+        let y = NdArray::from_slice([2.0f32, 4.0, 9.0].as_ref().into(), vec![3], None);
+        let result = corrcoef(&x, Some(&y), true).unwrap();
+        assert!(result.warning.is_none());
+        let result = result.array;
+        assert_eq!(&result.shape, &[2, 2]);
+        float_slice_eq_in_error(
+            result.data.get_raw_values(),
+            vec![1.0f32, 0.9986254, 0.9986254, 1.0].into(),
+        );
+
+        let result = cov(&x, None, true, false, None).unwrap().array;
+        assert!(result.shape.is_empty());
+
+        let single = NdArray::from_slice([1.0f32, 2.0].as_ref().into(), vec![2, 1], None);
+        let result = cov(&single, None, true, false, None).unwrap();
+        assert_eq!(result.warning, Some(DEGREES_OF_FREEDOM));
+        assert!(cov(&single, None, true, true, None)
+            .unwrap()
+            .warning
+            .is_none());
+        assert!(corrcoef(&single, None, true).unwrap().warning.is_some());
+    }
+}
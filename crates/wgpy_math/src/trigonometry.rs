@@ -1,11 +1,103 @@
-use arrow_gpu::kernels::*;
+use std::sync::Arc;
+
+use arrow_gpu::{
+    array::{ArrayUtils, Float32ArrayGPU},
+    gpu_utils::ArrowComputePipeline,
+    kernels::*,
+};
 use webgpupy_core::{
-    ufunc_nin1_nout1, ufunc_nin1_nout1_body, Dtype, NdArray, OperandType, UfuncType,
+    as_f32_array, float_dtype, normalize_axis, ufunc_nin1_nout1, ufunc_nin1_nout1_body,
+    ufunc_nin2_nout1, ufunc_nin2_nout1_body, Dtype, NdArray, NdArrayResult, OperandType, UfuncType,
 };
 
+use crate::kernels::{f32_binary_op, f32_unary_op};
+
+const TRIGONOMETRY_SHADER: &str = include_str!("../compute_shader/f32/trigonometry.wgsl");
+const TRIGONOMETRY_BINARY_SHADER: &str =
+    include_str!("../compute_shader/f32/trigonometry_binary.wgsl");
+const UNWRAP_SHADER: &str = include_str!("../compute_shader/f32/unwrap.wgsl");
+
+f32_unary_op!(tan_op_dyn, TRIGONOMETRY_SHADER, "tan_");
+f32_unary_op!(asin_op_dyn, TRIGONOMETRY_SHADER, "arcsin_");
+f32_unary_op!(atan_op_dyn, TRIGONOMETRY_SHADER, "arctan_");
+f32_unary_op!(sinh_op_dyn, TRIGONOMETRY_SHADER, "sinh_");
+f32_unary_op!(cosh_op_dyn, TRIGONOMETRY_SHADER, "cosh_");
+f32_unary_op!(tanh_op_dyn, TRIGONOMETRY_SHADER, "tanh_");
+f32_unary_op!(asinh_op_dyn, TRIGONOMETRY_SHADER, "arcsinh_");
+f32_unary_op!(acosh_op_dyn, TRIGONOMETRY_SHADER, "arccosh_");
+f32_unary_op!(atanh_op_dyn, TRIGONOMETRY_SHADER, "arctanh_");
+f32_unary_op!(deg2rad_op_dyn, TRIGONOMETRY_SHADER, "deg2rad_");
+f32_unary_op!(rad2deg_op_dyn, TRIGONOMETRY_SHADER, "rad2deg_");
+f32_binary_op!(atan2_op_dyn, TRIGONOMETRY_BINARY_SHADER, "arctan2_");
+f32_binary_op!(hypot_op_dyn, TRIGONOMETRY_BINARY_SHADER, "hypot_");
+
 ufunc_nin1_nout1_body!(sin, sin_op_dyn);
 ufunc_nin1_nout1_body!(cos, cos_op_dyn);
+ufunc_nin1_nout1_body!(tan, tan_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(arcsin, asin_op_dyn, float_dtype);
 ufunc_nin1_nout1_body!(arccos, acos_op_dyn);
+ufunc_nin1_nout1_body!(arctan, atan_op_dyn, float_dtype);
+ufunc_nin2_nout1_body!(arctan2, atan2_op_dyn, float_dtype);
+ufunc_nin2_nout1_body!(hypot, hypot_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(sinh, sinh_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(cosh, cosh_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(tanh, tanh_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(arcsinh, asinh_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(arccosh, acosh_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(arctanh, atanh_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(deg2rad, deg2rad_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(radians, deg2rad_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(rad2deg, rad2deg_op_dyn, float_dtype);
+ufunc_nin1_nout1_body!(degrees, rad2deg_op_dyn, float_dtype);
+
+/// Unwraps a signal along an axis by replacing differences larger than `discont`
+/// with their complement with respect to `period`, `discont` defaults to `period / 2`
+pub fn unwrap(p: &NdArray, discont: Option<f32>, axis: i32, period: f32) -> NdArrayResult<NdArray> {
+    let axis = normalize_axis(axis, p.shape.len())?;
+    let gpu_device = p.get_gpu_device();
+    if p.is_empty() {
+        return Ok(NdArray::zero_sized(
+            p.shape.clone(),
+            Dtype::Float32,
+            gpu_device,
+        ));
+    }
+
+    let axis_len = p.shape[axis];
+    let inner = p.shape[axis + 1..].iter().product::<u32>();
+    let lanes = p.len() / axis_len;
+    let discont = discont.unwrap_or(period / 2.0);
+
+    let mut pipeline = ArrowComputePipeline::new(gpu_device.clone(), Some("unwrap"));
+    let mut casted = None;
+    let input = as_f32_array(&p.data, &mut casted, &mut pipeline);
+    let params = [axis_len, inner, lanes, discont.to_bits(), period.to_bits()];
+    let params_buffer = pipeline.device.create_gpu_buffer_with_data(&params);
+    let buffer = pipeline.apply_binary_function(
+        &input.data,
+        &params_buffer,
+        p.len() as u64 * 4,
+        UNWRAP_SHADER,
+        "unwrap_",
+        lanes.div_ceil(256),
+    );
+    let data = Float32ArrayGPU {
+        data: Arc::new(buffer),
+        gpu_device: input.get_gpu_device(),
+        phantom: std::marker::PhantomData,
+        len: input.len,
+        null_buffer: None,
+    }
+    .into();
+    pipeline.finish();
+
+    Ok(NdArray {
+        shape: p.shape.clone(),
+        dims: p.dims,
+        data,
+        dtype: Dtype::Float32,
+    })
+}
 
 pub const COS_TYPES: [UfuncType; 5] = [
     UfuncType::UfuncNin1Nout1Type(
@@ -54,4 +146,133 @@ mod test {
         [true, true],
         sin
     );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_tan_f32,
+        [0.0, 1.0, -0.5],
+        [0.0, 1.0f32.tan(), (-0.5f32).tan()],
+        [true, true, true],
+        tan
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_arcsin_f32_mask_mixed,
+        [0.5, 1.0, 2.0],
+        [0.5f32.asin(), 0.0, f32::NAN],
+        [true, false, true],
+        arcsin
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_arctan_f32,
+        [0.0, 1.0, -20.0],
+        [0.0, 1.0f32.atan(), (-20.0f32).atan()],
+        [true, true, true],
+        arctan
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_sinh_f32,
+        [1e-8, -1.0, 30.0],
+        [1e-8, (-1.0f32).sinh(), 30.0f32.sinh()],
+        [true, true, true],
+        sinh
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_cosh_f32,
+        [0.0, -1.0, 2.0],
+        [1.0, 1.0f32.cosh(), 2.0f32.cosh()],
+        [true, true, true],
+        cosh
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_tanh_f32,
+        [0.0, 0.5, -50.0],
+        [0.0, 0.5f32.tanh(), -1.0],
+        [true, true, true],
+        tanh
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_arcsinh_f32,
+        [-2.0, 1e-8, 100.0],
+        [(-2.0f32).asinh(), 1e-8, 100.0f32.asinh()],
+        [true, true, true],
+        arcsinh
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_arccosh_f32,
+        [1.0, 2.0, 0.5],
+        [0.0, 2.0f32.acosh(), f32::NAN],
+        [true, true, true],
+        arccosh
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_arctanh_f32,
+        [0.5, -1.0, 0.0],
+        [0.5f32.atanh(), f32::NEG_INFINITY, 0.0],
+        [true, true, true],
+        arctanh
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_deg2rad_f32,
+        [180.0, -90.0],
+        [std::f32::consts::PI, -std::f32::consts::FRAC_PI_2],
+        [true, true],
+        radians
+    );
+
+    test_ufunc_nin1_nout1_f32!(
+        test_rad2deg_f32,
+        [std::f32::consts::PI, 1.0],
+        [180.0, 1.0f32.to_degrees()],
+        [true, true],
+        degrees
+    );
+
+    #[test]
+    fn test_arctan2_hypot() {
+        let y = NdArray::from_slice([1.0f32, -1.0, 3.0].as_ref().into(), vec![3], None);
+        let x = NdArray::from_slice([1.0f32, -1.0, 4.0].as_ref().into(), vec![3], None);
+
+        let result = arctan2(&y, &x, None, None);
+        float_slice_eq_in_error(
+            result.data.get_raw_values(),
+            vec![1.0f32.atan2(1.0), (-1.0f32).atan2(-1.0), 3.0f32.atan2(4.0)].into(),
+        );
+
+        let result = hypot(&y, &x, None, None);
+        float_slice_eq_in_error(
+            result.data.get_raw_values(),
+            vec![2.0f32.sqrt(), 2.0f32.sqrt(), 5.0].into(),
+        );
+    }
+
+    #[test]
+    fn test_unwrap() {
+        let values = [0.0f32, 3.0, 6.5, 3.5, 1.0, 7.5];
+        let array = NdArray::from_slice(values.as_ref().into(), vec![2, 3], None);
+        let tau = std::f32::consts::TAU;
+
+        let result = unwrap(&array, None, -1, tau).unwrap();
+        assert_eq!(&result.shape, &[2, 3]);
+        float_slice_eq_in_error(
+            result.data.get_raw_values(),
+            vec![0.0f32, 3.0, 6.5 - tau, 3.5, 1.0, 7.5 - tau].into(),
+        );
+
+        let result = unwrap(&array, None, 0, 4.0).unwrap();
+        float_slice_eq_in_error(
+            result.data.get_raw_values(),
+            vec![0.0f32, 3.0, 6.5, -0.5, 1.0, 7.5].into(),
+        );
+
+        let scalar = NdArray::from_slice([1.0f32].as_ref().into(), vec![], None);
+        assert!(unwrap(&scalar, None, 0, tau).is_err());
+    }
 }
@@ -4,7 +4,8 @@ use std::borrow::Cow;
 use pyo3::{pyfunction, Python};
 
 use crate::{
-    add_ufunc_nin2_nout1, convert_pyobj_into_operand, impl_ufunc_nin2_nout1,
+    add_ufunc_nin2_nout1, add_ufunc_nin2_nout2, convert_pyobj_into_operand, impl_ufunc_nin2_nout1,
+    impl_ufunc_nin2_nout2,
     ndarraypy::*,
     types::{into_optional_dtypepy, DtypePy},
 };
@@ -14,16 +15,31 @@ impl_ufunc_nin2_nout1!(_multiply, webgpupy::multiply);
 impl_ufunc_nin2_nout1!(_divide, webgpupy::divide);
 impl_ufunc_nin2_nout1!(_add, webgpupy::add);
 impl_ufunc_nin2_nout1!(_subtract, webgpupy::subtract);
+impl_ufunc_nin2_nout1!(_floor_divide, webgpupy::floor_divide);
+impl_ufunc_nin2_nout1!(_remainder, webgpupy::remainder);
+impl_ufunc_nin2_nout1!(_mod, webgpupy::remainder);
+impl_ufunc_nin2_nout1!(_fmod, webgpupy::fmod);
+impl_ufunc_nin2_nout2!(_divmod, webgpupy::divmod);
 
 pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(_multiply, m)?)?;
     m.add_function(wrap_pyfunction!(_divide, m)?)?;
     m.add_function(wrap_pyfunction!(_add, m)?)?;
     m.add_function(wrap_pyfunction!(_subtract, m)?)?;
+    m.add_function(wrap_pyfunction!(_floor_divide, m)?)?;
+    m.add_function(wrap_pyfunction!(_remainder, m)?)?;
+    m.add_function(wrap_pyfunction!(_mod, m)?)?;
+    m.add_function(wrap_pyfunction!(_fmod, m)?)?;
+    m.add_function(wrap_pyfunction!(_divmod, m)?)?;
 
     add_ufunc_nin2_nout1!(m, "multiply");
     add_ufunc_nin2_nout1!(m, "divide");
     add_ufunc_nin2_nout1!(m, "add");
     add_ufunc_nin2_nout1!(m, "subtract");
+    add_ufunc_nin2_nout1!(m, "floor_divide");
+    add_ufunc_nin2_nout1!(m, "remainder");
+    add_ufunc_nin2_nout1!(m, "mod");
+    add_ufunc_nin2_nout1!(m, "fmod");
+    add_ufunc_nin2_nout2!(m, "divmod");
     Ok(())
 }
@@ -1,11 +1,13 @@
 use crate::ufunc::Ufunc;
 use std::borrow::Cow;
 
-use pyo3::{pyfunction, Python};
+use pyo3::{exceptions::PyValueError, pyfunction, Python};
+use webgpupy::BitOrder;
 
 use crate::{
-    add_ufunc_nin1_nout1, add_ufunc_nin2_nout1, convert_pyobj_into_operand, impl_ufunc_nin1_nout1,
-    impl_ufunc_nin2_nout1,
+    add_ufunc_nin1_nout1, add_ufunc_nin2_nout1, convert_pyobj_into_operand,
+    impl_checked_ufunc_nin1_nout1, impl_checked_ufunc_nin2_nout1, impl_ufunc_nin1_nout1,
+    impl_ufunc_nin2_nout1, ndarray_error_into_pyerr,
     ndarraypy::*,
     types::{into_optional_dtypepy, DtypePy},
 };
@@ -13,15 +15,72 @@ use pyo3::prelude::*;
 
 impl_ufunc_nin2_nout1!(_bitwise_and, webgpupy::bitwise_and);
 impl_ufunc_nin2_nout1!(_bitwise_or, webgpupy::bitwise_or);
+impl_checked_ufunc_nin2_nout1!(_bitwise_xor, webgpupy::bitwise_xor);
+impl_checked_ufunc_nin2_nout1!(_left_shift, webgpupy::left_shift);
+impl_checked_ufunc_nin2_nout1!(_right_shift, webgpupy::right_shift);
 impl_ufunc_nin1_nout1!(_invert, webgpupy::invert);
+impl_checked_ufunc_nin1_nout1!(_bitwise_count, webgpupy::bitwise_count);
+
+fn into_bitorder(bitorder: &str) -> PyResult<BitOrder> {
+    match bitorder {
+        "big" => Ok(BitOrder::Big),
+        "little" => Ok(BitOrder::Little),
+        _ => Err(PyValueError::new_err(
+            "'order' must be either 'little' or 'big'",
+        )),
+    }
+}
+
+/// Packs the elements of a binary-valued array into bits in a uint8 array
+#[pyfunction]
+#[pyo3(signature = (a, axis=None, bitorder="big"))]
+pub fn packbits(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    axis: Option<i32>,
+    bitorder: &str,
+) -> PyResult<NdArrayPy> {
+    let bitorder = into_bitorder(bitorder)?;
+    let array = convert_pyobj_into_operand(a)?;
+    py.allow_threads(|| webgpupy::packbits(array.as_ref(), axis, bitorder))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Unpacks the elements of a uint8 array into a binary-valued output array
+#[pyfunction]
+#[pyo3(signature = (a, axis=None, count=None, bitorder="big"))]
+pub fn unpackbits(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    axis: Option<i32>,
+    count: Option<i32>,
+    bitorder: &str,
+) -> PyResult<NdArrayPy> {
+    let bitorder = into_bitorder(bitorder)?;
+    let array = convert_pyobj_into_operand(a)?;
+    py.allow_threads(|| webgpupy::unpackbits(array.as_ref(), axis, count, bitorder))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
 
 pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(_bitwise_and, m)?)?;
     m.add_function(wrap_pyfunction!(_bitwise_or, m)?)?;
+    m.add_function(wrap_pyfunction!(_bitwise_xor, m)?)?;
+    m.add_function(wrap_pyfunction!(_left_shift, m)?)?;
+    m.add_function(wrap_pyfunction!(_right_shift, m)?)?;
     m.add_function(wrap_pyfunction!(_invert, m)?)?;
+    m.add_function(wrap_pyfunction!(_bitwise_count, m)?)?;
+    m.add_function(wrap_pyfunction!(packbits, m)?)?;
+    m.add_function(wrap_pyfunction!(unpackbits, m)?)?;
 
     add_ufunc_nin2_nout1!(m, "bitwise_and");
     add_ufunc_nin2_nout1!(m, "bitwise_or");
+    add_ufunc_nin2_nout1!(m, "bitwise_xor");
+    add_ufunc_nin2_nout1!(m, "left_shift");
+    add_ufunc_nin2_nout1!(m, "right_shift");
     add_ufunc_nin1_nout1!(m, "invert");
+    add_ufunc_nin1_nout1!(m, "bitwise_count");
     Ok(())
 }
@@ -0,0 +1,153 @@
+use pyo3::{prelude::*, types::PyTuple};
+use webgpupy::Spacing;
+
+use crate::{
+    convert_pyobj_into_operand, convert_pyobj_into_option_operand,
+    convert_pyobj_into_optional_axes, ndarray_error_into_pyerr, ndarraypy::NdArrayPy,
+};
+
+/// Calculate the n-th discrete difference along the given axis
+#[pyfunction]
+#[pyo3(signature = (a, n=1, axis=-1, prepend=None, append=None))]
+pub fn diff(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    n: u32,
+    axis: i32,
+    prepend: Option<&Bound<PyAny>>,
+    append: Option<&Bound<PyAny>>,
+) -> PyResult<NdArrayPy> {
+    let a = convert_pyobj_into_operand(a)?;
+    let prepend = prepend.map(convert_pyobj_into_option_operand).transpose()?;
+    let prepend = prepend.flatten();
+    let append = append.map(convert_pyobj_into_option_operand).transpose()?;
+    let append = append.flatten();
+    py.allow_threads(|| {
+        webgpupy::diff(
+            a.as_ref(),
+            n,
+            axis,
+            prepend.as_ref().map(|x| x.as_ref()),
+            append.as_ref().map(|x| x.as_ref()),
+        )
+    })
+    .map(NdArrayPy::from)
+    .map_err(ndarray_error_into_pyerr)
+}
+
+/// The differences between consecutive elements of an array
+#[pyfunction]
+#[pyo3(signature = (ary, to_end=None, to_begin=None))]
+pub fn ediff1d(
+    py: Python<'_>,
+    ary: &Bound<PyAny>,
+    to_end: Option<&Bound<PyAny>>,
+    to_begin: Option<&Bound<PyAny>>,
+) -> PyResult<NdArrayPy> {
+    let ary = convert_pyobj_into_operand(ary)?;
+    let to_end = to_end.map(convert_pyobj_into_option_operand).transpose()?;
+    let to_end = to_end.flatten();
+    let to_begin = to_begin
+        .map(convert_pyobj_into_option_operand)
+        .transpose()?;
+    let to_begin = to_begin.flatten();
+    py.allow_threads(|| {
+        webgpupy::ediff1d(
+            ary.as_ref(),
+            to_end.as_ref().map(|x| x.as_ref()),
+            to_begin.as_ref().map(|x| x.as_ref()),
+        )
+    })
+    .map(NdArrayPy::from)
+    .map_err(ndarray_error_into_pyerr)
+}
+
+/// Return the gradient of an N-dimensional array
+#[pyfunction]
+#[pyo3(signature = (f, *varargs, axis=None, edge_order=1))]
+pub fn gradient(
+    py: Python<'_>,
+    f: &Bound<PyAny>,
+    varargs: &Bound<PyTuple>,
+    axis: Option<&Bound<PyAny>>,
+    edge_order: u32,
+) -> PyResult<PyObject> {
+    let f = convert_pyobj_into_operand(f)?;
+    let axis = axis.map(convert_pyobj_into_optional_axes).transpose()?;
+    let axis = axis.flatten();
+    let varargs = varargs.iter().collect::<Vec<_>>();
+    let spacing = varargs
+        .iter()
+        .map(convert_pyobj_into_operand)
+        .collect::<PyResult<Vec<_>>>()?;
+    let spacing = spacing
+        .iter()
+        .map(|x| Spacing::Coordinates(x.as_ref()))
+        .collect::<Vec<_>>();
+    let gradients = py
+        .allow_threads(|| webgpupy::gradient(f.as_ref(), &spacing, axis.as_deref(), edge_order))
+        .map_err(ndarray_error_into_pyerr)?;
+    let mut gradients = gradients
+        .into_iter()
+        .map(NdArrayPy::from)
+        .collect::<Vec<_>>();
+    if gradients.len() == 1 {
+        Ok(gradients.remove(0).into_py(py))
+    } else {
+        Ok(PyTuple::new_bound(py, gradients.into_iter().map(|x| x.into_py(py))).into_py(py))
+    }
+}
+
+/// Integrate along the given axis using the composite trapezoidal rule
+#[pyfunction]
+#[pyo3(signature = (y, x=None, dx=1.0, axis=-1))]
+pub fn trapezoid(
+    py: Python<'_>,
+    y: &Bound<PyAny>,
+    x: Option<&Bound<PyAny>>,
+    dx: f32,
+    axis: i32,
+) -> PyResult<NdArrayPy> {
+    let y = convert_pyobj_into_operand(y)?;
+    let x = x.map(convert_pyobj_into_option_operand).transpose()?;
+    let x = x.flatten();
+    py.allow_threads(|| webgpupy::trapezoid(y.as_ref(), x.as_ref().map(|x| x.as_ref()), dx, axis))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Cumulatively integrate along the given axis using the composite trapezoidal rule
+#[pyfunction]
+#[pyo3(signature = (y, x=None, dx=1.0, axis=-1, initial=None))]
+pub fn cumulative_trapezoid(
+    py: Python<'_>,
+    y: &Bound<PyAny>,
+    x: Option<&Bound<PyAny>>,
+    dx: f32,
+    axis: i32,
+    initial: Option<f32>,
+) -> PyResult<NdArrayPy> {
+    let y = convert_pyobj_into_operand(y)?;
+    let x = x.map(convert_pyobj_into_option_operand).transpose()?;
+    let x = x.flatten();
+    py.allow_threads(|| {
+        webgpupy::cumulative_trapezoid(
+            y.as_ref(),
+            x.as_ref().map(|x| x.as_ref()),
+            dx,
+            axis,
+            initial,
+        )
+    })
+    .map(NdArrayPy::from)
+    .map_err(ndarray_error_into_pyerr)
+}
+
+pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(diff, m)?)?;
+    m.add_function(wrap_pyfunction!(ediff1d, m)?)?;
+    m.add_function(wrap_pyfunction!(gradient, m)?)?;
+    m.add_function(wrap_pyfunction!(trapezoid, m)?)?;
+    m.add_function(wrap_pyfunction!(cumulative_trapezoid, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use pyo3::{exceptions::PyValueError, prelude::*};
+use webgpupy::{ConvolveMode, PadMode, ScalarValue};
+
+use crate::{
+    convert_pyobj_into_operand, convert_pyobj_into_optional_axes, ndarray_error_into_pyerr,
+    ndarraypy::NdArrayPy,
+};
+
+fn convolve_mode(mode: &str) -> PyResult<ConvolveMode> {
+    match mode {
+        "full" => Ok(ConvolveMode::Full),
+        "same" => Ok(ConvolveMode::Same),
+        "valid" => Ok(ConvolveMode::Valid),
+        _ => Err(PyValueError::new_err(format!(
+            "mode must be one of 'valid', 'same', or 'full' (got '{}')",
+            mode
+        ))),
+    }
+}
+
+/// Boundary modes named like `scipy.ndimage`
+fn boundary_mode(mode: &str, cval: f32) -> PyResult<PadMode> {
+    match mode {
+        "reflect" => Ok(PadMode::Symmetric),
+        "constant" => Ok(PadMode::Constant(ScalarValue::F32(cval))),
+        "nearest" => Ok(PadMode::Edge),
+        "mirror" => Ok(PadMode::Reflect),
+        "wrap" => Ok(PadMode::Wrap),
+        _ => Err(PyValueError::new_err(format!(
+            "mode '{}' is not supported",
+            mode
+        ))),
+    }
+}
+
+/// Returns the discrete, linear convolution of two one-dimensional sequences
+#[pyfunction]
+#[pyo3(signature = (a, v, mode="full"))]
+pub fn convolve(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    v: &Bound<PyAny>,
+    mode: &str,
+) -> PyResult<NdArrayPy> {
+    let mode = convolve_mode(mode)?;
+    let a = convert_pyobj_into_operand(a)?;
+    let v = convert_pyobj_into_operand(v)?;
+    py.allow_threads(|| webgpupy::convolve(a.as_ref(), v.as_ref(), mode))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Cross-correlation of two 1-dimensional sequences
+#[pyfunction]
+#[pyo3(signature = (a, v, mode="valid"))]
+pub fn correlate(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    v: &Bound<PyAny>,
+    mode: &str,
+) -> PyResult<NdArrayPy> {
+    let mode = convolve_mode(mode)?;
+    let a = convert_pyobj_into_operand(a)?;
+    let v = convert_pyobj_into_operand(v)?;
+    py.allow_threads(|| webgpupy::correlate(a.as_ref(), v.as_ref(), mode))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Multidimensional correlation, like `scipy.ndimage.correlate`
+#[pyfunction]
+#[pyo3(signature = (input, weights, mode="reflect", cval=0.0, origin=None))]
+pub fn correlate_nd(
+    py: Python<'_>,
+    input: &Bound<PyAny>,
+    weights: &Bound<PyAny>,
+    mode: &str,
+    cval: f32,
+    origin: Option<&Bound<PyAny>>,
+) -> PyResult<NdArrayPy> {
+    let mode = boundary_mode(mode, cval)?;
+    let origin = origin.map(convert_pyobj_into_optional_axes).transpose()?;
+    let origin = origin.flatten().unwrap_or(vec![0]);
+    let input = convert_pyobj_into_operand(input)?;
+    let weights = convert_pyobj_into_operand(weights)?;
+    py.allow_threads(|| webgpupy::correlate_nd(input.as_ref(), weights.as_ref(), mode, &origin))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Multidimensional convolution, like `scipy.ndimage.convolve`
+#[pyfunction]
+#[pyo3(signature = (input, weights, mode="reflect", cval=0.0, origin=None))]
+pub fn convolve_nd(
+    py: Python<'_>,
+    input: &Bound<PyAny>,
+    weights: &Bound<PyAny>,
+    mode: &str,
+    cval: f32,
+    origin: Option<&Bound<PyAny>>,
+) -> PyResult<NdArrayPy> {
+    let mode = boundary_mode(mode, cval)?;
+    let origin = origin.map(convert_pyobj_into_optional_axes).transpose()?;
+    let origin = origin.flatten().unwrap_or(vec![0]);
+    let input = convert_pyobj_into_operand(input)?;
+    let weights = convert_pyobj_into_operand(weights)?;
+    py.allow_threads(|| webgpupy::convolve_nd(input.as_ref(), weights.as_ref(), mode, &origin))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(convolve, m)?)?;
+    m.add_function(wrap_pyfunction!(correlate, m)?)?;
+    m.add_function(wrap_pyfunction!(correlate_nd, m)?)?;
+    m.add_function(wrap_pyfunction!(convolve_nd, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,225 @@
+use std::borrow::Cow;
+
+use pyo3::{exceptions::*, prelude::*, types::*};
+use webgpupy::Indexing;
+
+use crate::{
+    convert_pyobj_into_array_u32, convert_pyobj_into_operand, convert_pyobj_into_scalar,
+    ndarray_error_into_pyerr,
+    ndarraypy::NdArrayPy,
+    types::{into_optional_dtypepy, DtypePy},
+};
+
+/// Returns evenly spaced values within a given interval
+#[pyfunction]
+#[pyo3(signature = (start, stop=None, step=None, dtype=None))]
+pub fn arange(
+    py: Python<'_>,
+    start: &Bound<PyAny>,
+    stop: Option<&Bound<PyAny>>,
+    step: Option<&Bound<PyAny>>,
+    #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+) -> PyResult<NdArrayPy> {
+    let dtype = dtype.map(|x| x.dtype);
+    let start = convert_pyobj_into_scalar(start)?;
+    let (start, stop) = match stop {
+        Some(stop) => (Some(start), convert_pyobj_into_scalar(stop)?),
+        None => (None, start),
+    };
+    let step = step.map(convert_pyobj_into_scalar).transpose()?;
+    py.allow_threads(|| webgpupy::arange(start, stop, step, dtype, None))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Returns evenly spaced numbers over an interval
+#[pyfunction]
+#[pyo3(signature = (start, stop, num=50, endpoint=true, retstep=false, dtype=None))]
+pub fn linspace(
+    py: Python<'_>,
+    start: f32,
+    stop: f32,
+    num: u32,
+    endpoint: bool,
+    retstep: bool,
+    #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+) -> PyObject {
+    let dtype = dtype.map(|x| x.dtype);
+    let (array, step) =
+        py.allow_threads(|| webgpupy::linspace_retstep(start, stop, num, endpoint, dtype, None));
+    let array = NdArrayPy::from(array);
+    if retstep {
+        (array, step).into_py(py)
+    } else {
+        array.into_py(py)
+    }
+}
+
+/// Returns numbers spaced evenly on a log scale
+#[pyfunction]
+#[pyo3(signature = (start, stop, num=50, endpoint=true, base=10.0, dtype=None))]
+pub fn logspace(
+    py: Python<'_>,
+    start: f32,
+    stop: f32,
+    num: u32,
+    endpoint: bool,
+    base: f32,
+    #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+) -> NdArrayPy {
+    let dtype = dtype.map(|x| x.dtype);
+    py.allow_threads(|| webgpupy::logspace(start, stop, num, endpoint, base, dtype, None))
+        .into()
+}
+
+/// Returns numbers spaced evenly on a log scale, with the endpoints given directly
+#[pyfunction]
+#[pyo3(signature = (start, stop, num=50, endpoint=true, dtype=None))]
+pub fn geomspace(
+    py: Python<'_>,
+    start: f32,
+    stop: f32,
+    num: u32,
+    endpoint: bool,
+    #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+) -> PyResult<NdArrayPy> {
+    let dtype = dtype.map(|x| x.dtype);
+    py.allow_threads(|| webgpupy::geomspace(start, stop, num, endpoint, dtype, None))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Returns a 2-D array with ones on the diagonal and zeros elsewhere
+#[pyfunction]
+#[pyo3(signature = (N, M=None, k=0, dtype=None))]
+#[allow(non_snake_case)]
+pub fn eye(
+    py: Python<'_>,
+    N: u32,
+    M: Option<u32>,
+    k: i32,
+    #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+) -> NdArrayPy {
+    let dtype = dtype.map(|x| x.dtype);
+    py.allow_threads(|| webgpupy::eye(N, M, k, dtype, None))
+        .into()
+}
+
+/// Returns the identity array
+#[pyfunction]
+#[pyo3(signature = (n, dtype=None))]
+pub fn identity(
+    py: Python<'_>,
+    n: u32,
+    #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+) -> NdArrayPy {
+    let dtype = dtype.map(|x| x.dtype);
+    py.allow_threads(|| webgpupy::identity(n, dtype, None))
+        .into()
+}
+
+/// Returns an array with ones at and below the given diagonal and zeros elsewhere
+#[pyfunction]
+#[pyo3(signature = (N, M=None, k=0, dtype=None))]
+#[allow(non_snake_case)]
+pub fn tri(
+    py: Python<'_>,
+    N: u32,
+    M: Option<u32>,
+    k: i32,
+    #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+) -> NdArrayPy {
+    let dtype = dtype.map(|x| x.dtype);
+    py.allow_threads(|| webgpupy::tri(N, M, k, dtype, None))
+        .into()
+}
+
+/// Lower triangle of an array
+#[pyfunction]
+#[pyo3(signature = (m, k=0))]
+pub fn tril(py: Python<'_>, m: &Bound<PyAny>, k: i32) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(m)?;
+    py.allow_threads(|| webgpupy::tril(array.as_ref(), k))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Upper triangle of an array
+#[pyfunction]
+#[pyo3(signature = (m, k=0))]
+pub fn triu(py: Python<'_>, m: &Bound<PyAny>, k: i32) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(m)?;
+    py.allow_threads(|| webgpupy::triu(array.as_ref(), k))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Extracts a diagonal or constructs a diagonal array
+#[pyfunction]
+#[pyo3(signature = (v, k=0))]
+pub fn diag(py: Python<'_>, v: &Bound<PyAny>, k: i32) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(v)?;
+    py.allow_threads(|| webgpupy::diag(array.as_ref(), k))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Returns coordinate matrices from coordinate vectors
+#[pyfunction]
+#[pyo3(signature = (*xi, indexing="xy", sparse=false))]
+pub fn meshgrid(
+    py: Python<'_>,
+    xi: &Bound<PyTuple>,
+    indexing: &str,
+    sparse: bool,
+) -> PyResult<Vec<NdArrayPy>> {
+    let indexing = match indexing {
+        "xy" => Indexing::Xy,
+        "ij" => Indexing::Ij,
+        _ => {
+            return Err(PyValueError::new_err(
+                "Valid values for `indexing` are 'xy' and 'ij'.",
+            ))
+        }
+    };
+    let arrays = xi.iter().collect::<Vec<_>>();
+    let arrays = arrays
+        .iter()
+        .map(convert_pyobj_into_operand)
+        .collect::<PyResult<Vec<_>>>()?;
+    let arrays = arrays.iter().map(|x| x.as_ref()).collect::<Vec<_>>();
+    Ok(py
+        .allow_threads(|| webgpupy::meshgrid(&arrays, indexing, sparse))
+        .into_iter()
+        .map(NdArrayPy::from)
+        .collect())
+}
+
+/// Returns an array representing the indices of a grid
+#[pyfunction]
+#[pyo3(signature = (dimensions, dtype=None))]
+pub fn indices(
+    py: Python<'_>,
+    #[pyo3(from_py_with = "convert_pyobj_into_array_u32")] dimensions: Vec<u32>,
+    #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+) -> NdArrayPy {
+    let dtype = dtype.map(|x| x.dtype);
+    py.allow_threads(|| webgpupy::indices(&dimensions, dtype, None))
+        .into()
+}
+
+pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(arange, m)?)?;
+    m.add_function(wrap_pyfunction!(linspace, m)?)?;
+    m.add_function(wrap_pyfunction!(logspace, m)?)?;
+    m.add_function(wrap_pyfunction!(geomspace, m)?)?;
+    m.add_function(wrap_pyfunction!(eye, m)?)?;
+    m.add_function(wrap_pyfunction!(identity, m)?)?;
+    m.add_function(wrap_pyfunction!(tri, m)?)?;
+    m.add_function(wrap_pyfunction!(tril, m)?)?;
+    m.add_function(wrap_pyfunction!(triu, m)?)?;
+    m.add_function(wrap_pyfunction!(diag, m)?)?;
+    m.add_function(wrap_pyfunction!(meshgrid, m)?)?;
+    m.add_function(wrap_pyfunction!(indices, m)?)?;
+    Ok(())
+}
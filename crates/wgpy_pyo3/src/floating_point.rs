@@ -0,0 +1,66 @@
+use pyo3::{pyfunction, Python};
+use std::borrow::Cow;
+
+use crate::{
+    add_ufunc_nin1_nout1, add_ufunc_nin2_nout1, convert_pyobj_into_operand,
+    impl_checked_ufunc_nin1_nout1, impl_ufunc_nin1_nout1, impl_ufunc_nin2_nout1,
+    ndarraypy::*,
+    types::{into_optional_dtypepy, DtypePy},
+    ufunc::Ufunc,
+};
+use pyo3::prelude::*;
+
+impl_ufunc_nin1_nout1!(_isnan, webgpupy::isnan);
+impl_ufunc_nin1_nout1!(_isinf, webgpupy::isinf);
+impl_ufunc_nin1_nout1!(_isposinf, webgpupy::isposinf);
+impl_ufunc_nin1_nout1!(_isneginf, webgpupy::isneginf);
+impl_ufunc_nin1_nout1!(_isfinite, webgpupy::isfinite);
+impl_ufunc_nin1_nout1!(_signbit, webgpupy::signbit);
+impl_ufunc_nin1_nout1!(_spacing, webgpupy::spacing);
+impl_checked_ufunc_nin1_nout1!(_sign, webgpupy::sign);
+impl_ufunc_nin2_nout1!(_copysign, webgpupy::copysign);
+impl_ufunc_nin2_nout1!(_nextafter, webgpupy::nextafter);
+impl_ufunc_nin2_nout1!(_heaviside, webgpupy::heaviside);
+
+/// Replace nan with zero and infinity with large finite numbers
+#[pyfunction]
+#[pyo3(signature = (x, nan=0.0, posinf=None, neginf=None))]
+pub fn nan_to_num(
+    py: Python<'_>,
+    x: &Bound<PyAny>,
+    nan: f32,
+    posinf: Option<f32>,
+    neginf: Option<f32>,
+) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(x)?;
+    Ok(py
+        .allow_threads(|| webgpupy::nan_to_num(array.as_ref(), nan, posinf, neginf))
+        .into())
+}
+
+pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(_isnan, m)?)?;
+    m.add_function(wrap_pyfunction!(_isinf, m)?)?;
+    m.add_function(wrap_pyfunction!(_isposinf, m)?)?;
+    m.add_function(wrap_pyfunction!(_isneginf, m)?)?;
+    m.add_function(wrap_pyfunction!(_isfinite, m)?)?;
+    m.add_function(wrap_pyfunction!(_signbit, m)?)?;
+    m.add_function(wrap_pyfunction!(_spacing, m)?)?;
+    m.add_function(wrap_pyfunction!(_sign, m)?)?;
+    m.add_function(wrap_pyfunction!(_copysign, m)?)?;
+    m.add_function(wrap_pyfunction!(_nextafter, m)?)?;
+    m.add_function(wrap_pyfunction!(_heaviside, m)?)?;
+    m.add_function(wrap_pyfunction!(nan_to_num, m)?)?;
+    add_ufunc_nin1_nout1!(m, "isnan");
+    add_ufunc_nin1_nout1!(m, "isinf");
+    add_ufunc_nin1_nout1!(m, "isposinf");
+    add_ufunc_nin1_nout1!(m, "isneginf");
+    add_ufunc_nin1_nout1!(m, "isfinite");
+    add_ufunc_nin1_nout1!(m, "signbit");
+    add_ufunc_nin1_nout1!(m, "spacing");
+    add_ufunc_nin1_nout1!(m, "sign");
+    add_ufunc_nin2_nout1!(m, "copysign");
+    add_ufunc_nin2_nout1!(m, "nextafter");
+    add_ufunc_nin2_nout1!(m, "heaviside");
+    Ok(())
+}
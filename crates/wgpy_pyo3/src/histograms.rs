@@ -0,0 +1,208 @@
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    types::{PyInt, PyList, PyTuple},
+};
+use webgpupy::HistogramBins;
+
+use crate::{
+    convert_pyobj_into_operand, convert_pyobj_into_option_operand, ndarray_error_into_pyerr,
+    ndarraypy::NdArrayPy,
+};
+
+/// Bins of one dimension, holding the array of the edges
+enum BinsPy<'py> {
+    Count(u32),
+    Edges(Bound<'py, NdArrayPy>),
+}
+
+impl<'py> BinsPy<'py> {
+    fn as_bins(&self) -> HistogramBins<'_> {
+        match self {
+            BinsPy::Count(count) => HistogramBins::Count(*count),
+            BinsPy::Edges(edges) => HistogramBins::Edges(&edges.get().ndarray),
+        }
+    }
+}
+
+fn convert_pyobj_into_bins<'py>(data: &Bound<'py, PyAny>) -> PyResult<BinsPy<'py>> {
+    if data.is_instance_of::<PyInt>() {
+        let count = data.extract::<i64>()?;
+        if count < 1 {
+            return Err(PyValueError::new_err(
+                "`bins` must be positive, when an integer",
+            ));
+        }
+        Ok(BinsPy::Count(count as u32))
+    } else if data.is_instance_of::<NdArrayPy>() {
+        Ok(BinsPy::Edges(data.downcast::<NdArrayPy>()?.clone()))
+    } else {
+        Err(PyTypeError::new_err(
+            "Operation not supported for the given values",
+        ))
+    }
+}
+
+/// Bins of every dimension, a single int or array applies to every dimension
+fn convert_pyobj_into_vec_bins<'py>(data: &Bound<'py, PyAny>) -> PyResult<Vec<BinsPy<'py>>> {
+    if data.is_instance_of::<PyList>() || data.is_instance_of::<PyTuple>() {
+        data.iter()?.map(|x| convert_pyobj_into_bins(&x?)).collect()
+    } else {
+        Ok(vec![convert_pyobj_into_bins(data)?])
+    }
+}
+
+/// Return the indices of the bins to which each value in input array belongs
+#[pyfunction]
+#[pyo3(signature = (x, bins, right=false))]
+pub fn digitize(
+    py: Python<'_>,
+    x: &Bound<PyAny>,
+    bins: &Bound<PyAny>,
+    right: bool,
+) -> PyResult<NdArrayPy> {
+    let x = convert_pyobj_into_operand(x)?;
+    let bins = convert_pyobj_into_operand(bins)?;
+    py.allow_threads(|| webgpupy::digitize(x.as_ref(), bins.as_ref(), right))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Count number of occurrences of each value in array of non-negative ints
+#[pyfunction]
+#[pyo3(signature = (x, weights=None, minlength=0))]
+pub fn bincount(
+    py: Python<'_>,
+    x: &Bound<PyAny>,
+    weights: Option<&Bound<PyAny>>,
+    minlength: i64,
+) -> PyResult<NdArrayPy> {
+    if minlength < 0 {
+        return Err(PyValueError::new_err("'minlength' must not be negative"));
+    }
+    let x = convert_pyobj_into_operand(x)?;
+    let weights = weights.map(convert_pyobj_into_option_operand).transpose()?;
+    let weights = weights.flatten();
+    py.allow_threads(|| {
+        webgpupy::bincount(
+            x.as_ref(),
+            weights.as_ref().map(|x| x.as_ref()),
+            minlength as u32,
+        )
+    })
+    .map(NdArrayPy::from)
+    .map_err(ndarray_error_into_pyerr)
+}
+
+/// Compute the histogram of a dataset
+#[pyfunction]
+#[pyo3(signature = (a, bins=None, range=None, density=None, weights=None))]
+pub fn histogram(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    bins: Option<&Bound<PyAny>>,
+    range: Option<[f32; 2]>,
+    density: Option<bool>,
+    weights: Option<&Bound<PyAny>>,
+) -> PyResult<PyObject> {
+    let a = convert_pyobj_into_operand(a)?;
+    let bins = bins.map(convert_pyobj_into_bins).transpose()?;
+    let bins = bins.unwrap_or(BinsPy::Count(10));
+    let weights = weights.map(convert_pyobj_into_option_operand).transpose()?;
+    let weights = weights.flatten();
+    let bins = bins.as_bins();
+    let (hist, edges) = py
+        .allow_threads(|| {
+            webgpupy::histogram(
+                a.as_ref(),
+                bins,
+                range.map(|[first, last]| (first, last)),
+                weights.as_ref().map(|x| x.as_ref()),
+                density.unwrap_or(false),
+            )
+        })
+        .map_err(ndarray_error_into_pyerr)?;
+    Ok((NdArrayPy::from(hist), NdArrayPy::from(edges)).into_py(py))
+}
+
+/// Compute the bi-dimensional histogram of two data samples
+#[pyfunction]
+#[pyo3(signature = (x, y, bins=None, range=None, density=None, weights=None))]
+pub fn histogram2d(
+    py: Python<'_>,
+    x: &Bound<PyAny>,
+    y: &Bound<PyAny>,
+    bins: Option<&Bound<PyAny>>,
+    range: Option<Vec<[f32; 2]>>,
+    density: Option<bool>,
+    weights: Option<&Bound<PyAny>>,
+) -> PyResult<PyObject> {
+    let x = convert_pyobj_into_operand(x)?;
+    let y = convert_pyobj_into_operand(y)?;
+    let bins = bins.map(convert_pyobj_into_vec_bins).transpose()?;
+    let bins = bins.unwrap_or_else(|| vec![BinsPy::Count(10)]);
+    let range = range.map(|x| x.into_iter().map(|[a, b]| (a, b)).collect::<Vec<_>>());
+    let weights = weights.map(convert_pyobj_into_option_operand).transpose()?;
+    let weights = weights.flatten();
+    let bins = bins.iter().map(BinsPy::as_bins).collect::<Vec<_>>();
+    let (hist, x_edges, y_edges) = py
+        .allow_threads(|| {
+            webgpupy::histogram2d(
+                x.as_ref(),
+                y.as_ref(),
+                &bins,
+                range.as_deref(),
+                weights.as_ref().map(|x| x.as_ref()),
+                density.unwrap_or(false),
+            )
+        })
+        .map_err(ndarray_error_into_pyerr)?;
+    Ok((
+        NdArrayPy::from(hist),
+        NdArrayPy::from(x_edges),
+        NdArrayPy::from(y_edges),
+    )
+        .into_py(py))
+}
+
+/// Compute the multidimensional histogram of some data
+#[pyfunction]
+#[pyo3(signature = (sample, bins=None, range=None, density=None, weights=None))]
+pub fn histogramdd(
+    py: Python<'_>,
+    sample: &Bound<PyAny>,
+    bins: Option<&Bound<PyAny>>,
+    range: Option<Vec<[f32; 2]>>,
+    density: Option<bool>,
+    weights: Option<&Bound<PyAny>>,
+) -> PyResult<PyObject> {
+    let sample = convert_pyobj_into_operand(sample)?;
+    let bins = bins.map(convert_pyobj_into_vec_bins).transpose()?;
+    let bins = bins.unwrap_or_else(|| vec![BinsPy::Count(10)]);
+    let range = range.map(|x| x.into_iter().map(|[a, b]| (a, b)).collect::<Vec<_>>());
+    let weights = weights.map(convert_pyobj_into_option_operand).transpose()?;
+    let weights = weights.flatten();
+    let bins = bins.iter().map(BinsPy::as_bins).collect::<Vec<_>>();
+    let (hist, edges) = py
+        .allow_threads(|| {
+            webgpupy::histogramdd(
+                sample.as_ref(),
+                &bins,
+                range.as_deref(),
+                weights.as_ref().map(|x| x.as_ref()),
+                density.unwrap_or(false),
+            )
+        })
+        .map_err(ndarray_error_into_pyerr)?;
+    let edges = edges.into_iter().map(NdArrayPy::from).collect::<Vec<_>>();
+    Ok((NdArrayPy::from(hist), edges).into_py(py))
+}
+
+pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(digitize, m)?)?;
+    m.add_function(wrap_pyfunction!(bincount, m)?)?;
+    m.add_function(wrap_pyfunction!(histogram, m)?)?;
+    m.add_function(wrap_pyfunction!(histogram2d, m)?)?;
+    m.add_function(wrap_pyfunction!(histogramdd, m)?)?;
+    Ok(())
+}
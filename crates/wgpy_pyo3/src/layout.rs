@@ -0,0 +1,149 @@
+use pyo3::{exceptions::*, prelude::*, types::*};
+use webgpupy::{PadMode, ScalarValue};
+
+use crate::{
+    convert_pyobj_into_array_u32, convert_pyobj_into_operand, convert_pyobj_into_optional_axes,
+    convert_pyobj_into_scalar, ndarray_error_into_pyerr, ndarraypy::NdArrayPy,
+};
+
+/// Constructs an array by repeating A the number of times given by reps
+#[pyfunction]
+#[allow(non_snake_case)]
+pub fn tile(
+    py: Python<'_>,
+    A: &Bound<PyAny>,
+    #[pyo3(from_py_with = "convert_pyobj_into_array_u32")] reps: Vec<u32>,
+) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(A)?;
+    py.allow_threads(|| webgpupy::tile(array.as_ref(), &reps))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Reverses the order of elements along the given axes
+#[pyfunction]
+#[pyo3(signature = (m, axis=None))]
+pub fn flip(
+    py: Python<'_>,
+    m: &Bound<PyAny>,
+    #[pyo3(from_py_with = "convert_pyobj_into_optional_axes")] axis: Option<Vec<i32>>,
+) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(m)?;
+    py.allow_threads(|| webgpupy::flip(array.as_ref(), axis.as_deref()))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Reverses the order of elements along axis 1
+#[pyfunction]
+pub fn fliplr(py: Python<'_>, m: &Bound<PyAny>) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(m)?;
+    py.allow_threads(|| webgpupy::fliplr(array.as_ref()))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Reverses the order of elements along axis 0
+#[pyfunction]
+pub fn flipud(py: Python<'_>, m: &Bound<PyAny>) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(m)?;
+    py.allow_threads(|| webgpupy::flipud(array.as_ref()))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Rotates an array by 90 degrees in the plane specified by axes
+#[pyfunction]
+#[pyo3(signature = (m, k=1, axes=(0, 1)))]
+pub fn rot90(py: Python<'_>, m: &Bound<PyAny>, k: i32, axes: (i32, i32)) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(m)?;
+    py.allow_threads(|| webgpupy::rot90(array.as_ref(), k, axes))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Rolls array elements along the given axes
+#[pyfunction]
+#[pyo3(signature = (a, shift, axis=None))]
+pub fn roll(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    #[pyo3(from_py_with = "convert_pyobj_into_optional_axes")] shift: Option<Vec<i32>>,
+    #[pyo3(from_py_with = "convert_pyobj_into_optional_axes")] axis: Option<Vec<i32>>,
+) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(a)?;
+    let shift = shift.ok_or_else(|| PyTypeError::new_err("shift cannot be None"))?;
+    py.allow_threads(|| webgpupy::roll(array.as_ref(), &shift, axis.as_deref()))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+fn into_pad_width(data: &Bound<PyAny>) -> PyResult<Vec<(u32, u32)>> {
+    if data.is_instance_of::<PyInt>() {
+        let width = data.extract::<u32>()?;
+        Ok(vec![(width, width)])
+    } else if let Ok(width) = data.extract::<(u32, u32)>() {
+        Ok(vec![width])
+    } else if let Ok(widths) = data.extract::<Vec<(u32, u32)>>() {
+        Ok(widths)
+    } else if let Ok(widths) = data.extract::<Vec<Vec<u32>>>() {
+        widths
+            .into_iter()
+            .map(|x| match x.as_slice() {
+                [width] => Ok((*width, *width)),
+                [before, after] => Ok((*before, *after)),
+                _ => Err(PyValueError::new_err("invalid pad width")),
+            })
+            .collect()
+    } else {
+        Err(PyTypeError::new_err(
+            "pad_width must be an integer or a sequence of (before, after) pairs",
+        ))
+    }
+}
+
+/// Pads an array
+#[pyfunction]
+#[pyo3(signature = (array, pad_width, mode="constant", constant_values=None))]
+pub fn pad(
+    py: Python<'_>,
+    array: &Bound<PyAny>,
+    #[pyo3(from_py_with = "into_pad_width")] pad_width: Vec<(u32, u32)>,
+    mode: &str,
+    constant_values: Option<&Bound<PyAny>>,
+) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(array)?;
+    let mode = match mode {
+        "constant" => {
+            let value = match constant_values {
+                Some(x) => convert_pyobj_into_scalar(x)?,
+                None => ScalarValue::I32(0),
+            };
+            PadMode::Constant(value)
+        }
+        "edge" => PadMode::Edge,
+        "reflect" => PadMode::Reflect,
+        "symmetric" => PadMode::Symmetric,
+        "wrap" => PadMode::Wrap,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "mode '{}' is not supported",
+                mode
+            )))
+        }
+    };
+    py.allow_threads(|| webgpupy::pad(array.as_ref(), &pad_width, mode))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(tile, m)?)?;
+    m.add_function(wrap_pyfunction!(flip, m)?)?;
+    m.add_function(wrap_pyfunction!(fliplr, m)?)?;
+    m.add_function(wrap_pyfunction!(flipud, m)?)?;
+    m.add_function(wrap_pyfunction!(rot90, m)?)?;
+    m.add_function(wrap_pyfunction!(roll, m)?)?;
+    m.add_function(wrap_pyfunction!(pad, m)?)?;
+    Ok(())
+}
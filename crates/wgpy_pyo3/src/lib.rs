@@ -1,20 +1,59 @@
 pub mod arithmetic;
 pub mod binary;
+pub mod calculus;
 pub(crate) mod cast;
+pub mod convolve;
+pub mod creation;
+pub mod floating_point;
 pub mod gpu_device;
+pub mod histograms;
+pub mod layout;
 pub mod logical;
 pub mod misc;
 pub mod misc_math;
+pub mod nanfunctions;
 pub mod ndarraypy;
+pub mod polynomial;
 pub mod random;
+pub mod set_ops;
+pub mod sorting;
+pub mod special;
+pub mod statistics;
 pub mod trigonometry;
 pub mod types;
 pub mod ufunc;
 
 use ndarraypy::NdArrayPy;
-use pyo3::{exceptions::PyTypeError, prelude::*, types::*};
+use pyo3::{
+    exceptions::{PyRuntimeWarning, PyTypeError, PyValueError},
+    prelude::*,
+    types::*,
+};
 use types::OperandPy;
-use webgpupy::{NdArray, ScalarValue};
+use webgpupy::{NdArray, NdArrayError, ScalarValue};
+
+pub(crate) fn ndarray_error_into_pyerr(err: NdArrayError) -> PyErr {
+    match err {
+        NdArrayError::DtypeError(x) => PyTypeError::new_err(x),
+        NdArrayError::BroadcastError(x)
+        | NdArrayError::RepeatError(x)
+        | NdArrayError::ShapeError(x)
+        | NdArrayError::ConcatenateError(x)
+        | NdArrayError::AxisError(x)
+        | NdArrayError::SplitError(x)
+        | NdArrayError::ValueError(x) => PyValueError::new_err(x),
+    }
+}
+
+/// Raises the message as a python `RuntimeWarning`, like numpy warns about the results
+pub(crate) fn warn_runtime(py: Python<'_>, message: Option<&str>) -> PyResult<()> {
+    match message {
+        Some(message) => {
+            PyErr::warn_bound(py, &py.get_type_bound::<PyRuntimeWarning>(), message, 1)
+        }
+        None => Ok(()),
+    }
+}
 
 pub(crate) fn convert_pyobj_into_operand<'a>(
     data: &'a Bound<'a, PyAny>,
@@ -24,11 +63,11 @@ pub(crate) fn convert_pyobj_into_operand<'a>(
         PyResult::Ok((&ndarray.get().ndarray).into())
     } else if data.is_instance_of::<PyFloat>() {
         let value = data.extract::<f32>()?;
-        let ndarray = NdArray::from_slice([value].as_slice().into(), vec![1], None);
+        let ndarray = NdArray::from_slice([value].as_slice().into(), vec![], None);
         PyResult::Ok(ndarray.into())
     } else if data.is_instance_of::<PyInt>() {
         let value = data.extract::<i32>()?;
-        let ndarray = NdArray::from_slice([value].as_slice().into(), vec![1], None);
+        let ndarray = NdArray::from_slice([value].as_slice().into(), vec![], None);
         PyResult::Ok(ndarray.into())
     } else {
         //TODO implment support for pylist and pytuple
@@ -63,7 +102,7 @@ pub(crate) fn convert_pyobj_into_scalar(data: &Bound<PyAny>) -> PyResult<ScalarV
 pub(crate) fn convert_pyobj_into_array_u32(data: &Bound<PyAny>) -> PyResult<Vec<u32>> {
     if data.is_instance_of::<PyInt>() {
         PyResult::Ok(vec![data.extract::<u32>()?])
-    } else if data.is_instance_of::<PyList>() {
+    } else if data.is_instance_of::<PyList>() || data.is_instance_of::<PyTuple>() {
         PyResult::Ok(data.extract::<Vec<u32>>()?)
     } else {
         PyResult::Err(PyTypeError::new_err(
@@ -72,6 +111,20 @@ pub(crate) fn convert_pyobj_into_array_u32(data: &Bound<PyAny>) -> PyResult<Vec<
     }
 }
 
+pub(crate) fn convert_pyobj_into_optional_axes(data: &Bound<PyAny>) -> PyResult<Option<Vec<i32>>> {
+    if data.is_none() {
+        PyResult::Ok(None)
+    } else if data.is_instance_of::<PyInt>() {
+        PyResult::Ok(Some(vec![data.extract::<i32>()?]))
+    } else if data.is_instance_of::<PyList>() || data.is_instance_of::<PyTuple>() {
+        PyResult::Ok(Some(data.extract::<Vec<i32>>()?))
+    } else {
+        PyResult::Err(PyTypeError::new_err(
+            "Operation not supported for the given values",
+        ))
+    }
+}
+
 pub(crate) fn convert_pyobj_into_vec_ndarray<'a>(
     data: &'a Bound<'a, PyAny>,
 ) -> PyResult<Vec<Bound<NdArrayPy>>> {
@@ -108,9 +161,22 @@ fn webgpupy_module(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     binary::create_py_items(m)?;
     trigonometry::create_py_items(m)?;
     misc::create_py_items(m)?;
+    layout::create_py_items(m)?;
+    creation::create_py_items(m)?;
     misc_math::create_py_items(m)?;
+    floating_point::create_py_items(m)?;
+    special::create_py_items(m)?;
+    nanfunctions::create_py_items(m)?;
+    sorting::create_py_items(m)?;
+    set_ops::create_py_items(m)?;
+    histograms::create_py_items(m)?;
+    statistics::create_py_items(m)?;
+    convolve::create_py_items(m)?;
+    calculus::create_py_items(m)?;
     arithmetic::create_py_items(m)?;
     ndarraypy::create_py_items(m)?;
     random::random_module(py, m)?;
+    special::special_module(py, m)?;
+    polynomial::polynomial_module(py, m)?;
     Ok(())
 }
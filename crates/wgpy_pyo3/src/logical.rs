@@ -4,7 +4,8 @@ use std::borrow::Cow;
 use pyo3::{pyfunction, Python};
 
 use crate::{
-    add_ufunc_nin2_nout1, convert_pyobj_into_operand, impl_ufunc_nin2_nout1,
+    add_ufunc_nin1_nout1, add_ufunc_nin2_nout1, convert_pyobj_into_operand, impl_ufunc_nin1_nout1,
+    impl_ufunc_nin2_nout1, ndarray_error_into_pyerr,
     ndarraypy::*,
     types::{into_optional_dtypepy, DtypePy},
 };
@@ -13,13 +14,99 @@ use pyo3::prelude::*;
 impl_ufunc_nin2_nout1!(_greater, webgpupy::greater);
 impl_ufunc_nin2_nout1!(_lesser, webgpupy::lesser);
 impl_ufunc_nin2_nout1!(_equal, webgpupy::equal);
+impl_ufunc_nin2_nout1!(_greater_equal, webgpupy::greater_equal);
+impl_ufunc_nin2_nout1!(_less, webgpupy::less);
+impl_ufunc_nin2_nout1!(_less_equal, webgpupy::less_equal);
+impl_ufunc_nin2_nout1!(_not_equal, webgpupy::not_equal);
+impl_ufunc_nin2_nout1!(_logical_and, webgpupy::logical_and);
+impl_ufunc_nin2_nout1!(_logical_or, webgpupy::logical_or);
+impl_ufunc_nin2_nout1!(_logical_xor, webgpupy::logical_xor);
+impl_ufunc_nin1_nout1!(_logical_not, webgpupy::logical_not);
+
+/// Returns a boolean array where two arrays are element-wise equal within a tolerance
+#[pyfunction]
+#[pyo3(signature = (a, b, rtol=1e-05, atol=1e-08, equal_nan=false))]
+pub fn isclose(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    b: &Bound<PyAny>,
+    rtol: f32,
+    atol: f32,
+    equal_nan: bool,
+) -> PyResult<NdArrayPy> {
+    let a = convert_pyobj_into_operand(a)?;
+    let b = convert_pyobj_into_operand(b)?;
+    py.allow_threads(|| webgpupy::isclose(a.as_ref(), b.as_ref(), rtol, atol, equal_nan))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Returns True if two arrays are element-wise equal within a tolerance
+#[pyfunction]
+#[pyo3(signature = (a, b, rtol=1e-05, atol=1e-08, equal_nan=false))]
+pub fn allclose(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    b: &Bound<PyAny>,
+    rtol: f32,
+    atol: f32,
+    equal_nan: bool,
+) -> PyResult<bool> {
+    let a = convert_pyobj_into_operand(a)?;
+    let b = convert_pyobj_into_operand(b)?;
+    py.allow_threads(|| webgpupy::allclose(a.as_ref(), b.as_ref(), rtol, atol, equal_nan))
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// True if two arrays have the same shape and elements, False otherwise
+#[pyfunction]
+#[pyo3(signature = (a1, a2, equal_nan=false))]
+pub fn array_equal(
+    py: Python<'_>,
+    a1: &Bound<PyAny>,
+    a2: &Bound<PyAny>,
+    equal_nan: bool,
+) -> PyResult<bool> {
+    let a1 = convert_pyobj_into_operand(a1)?;
+    let a2 = convert_pyobj_into_operand(a2)?;
+    Ok(py.allow_threads(|| webgpupy::array_equal(a1.as_ref(), a2.as_ref(), equal_nan)))
+}
+
+/// Returns True if input arrays are shape consistent and all elements equal
+#[pyfunction]
+pub fn array_equiv(py: Python<'_>, a1: &Bound<PyAny>, a2: &Bound<PyAny>) -> PyResult<bool> {
+    let a1 = convert_pyobj_into_operand(a1)?;
+    let a2 = convert_pyobj_into_operand(a2)?;
+    Ok(py.allow_threads(|| webgpupy::array_equiv(a1.as_ref(), a2.as_ref())))
+}
 
 pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(_greater, m)?)?;
     m.add_function(wrap_pyfunction!(_lesser, m)?)?;
     m.add_function(wrap_pyfunction!(_equal, m)?)?;
+    m.add_function(wrap_pyfunction!(_greater_equal, m)?)?;
+    m.add_function(wrap_pyfunction!(_less, m)?)?;
+    m.add_function(wrap_pyfunction!(_less_equal, m)?)?;
+    m.add_function(wrap_pyfunction!(_not_equal, m)?)?;
+    m.add_function(wrap_pyfunction!(_logical_and, m)?)?;
+    m.add_function(wrap_pyfunction!(_logical_or, m)?)?;
+    m.add_function(wrap_pyfunction!(_logical_xor, m)?)?;
+    m.add_function(wrap_pyfunction!(_logical_not, m)?)?;
+    m.add_function(wrap_pyfunction!(isclose, m)?)?;
+    m.add_function(wrap_pyfunction!(allclose, m)?)?;
+    m.add_function(wrap_pyfunction!(array_equal, m)?)?;
+    m.add_function(wrap_pyfunction!(array_equiv, m)?)?;
 
     add_ufunc_nin2_nout1!(m, "greater");
     add_ufunc_nin2_nout1!(m, "lesser");
+    add_ufunc_nin2_nout1!(m, "equal");
+    add_ufunc_nin2_nout1!(m, "greater_equal");
+    add_ufunc_nin2_nout1!(m, "less");
+    add_ufunc_nin2_nout1!(m, "less_equal");
+    add_ufunc_nin2_nout1!(m, "not_equal");
+    add_ufunc_nin2_nout1!(m, "logical_and");
+    add_ufunc_nin2_nout1!(m, "logical_or");
+    add_ufunc_nin2_nout1!(m, "logical_xor");
+    add_ufunc_nin1_nout1!(m, "logical_not");
     Ok(())
 }
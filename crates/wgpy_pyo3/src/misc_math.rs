@@ -8,6 +8,7 @@ use crate::{
 use pyo3::prelude::*;
 use pyo3::{pyfunction, Python};
 use std::borrow::Cow;
+use webgpupy::around;
 use webgpupy::clip;
 use webgpupy::cross;
 
@@ -17,6 +18,19 @@ impl_ufunc_nin1_nout1!(_absolute, webgpupy::absolute);
 impl_ufunc_nin2_nout1!(_maximum, webgpupy::maximum);
 impl_ufunc_nin2_nout1!(_minimum, webgpupy::minimum);
 impl_ufunc_nin2_nout1!(_power, webgpupy::power);
+impl_ufunc_nin1_nout1!(_floor, webgpupy::floor);
+impl_ufunc_nin1_nout1!(_ceil, webgpupy::ceil);
+impl_ufunc_nin1_nout1!(_trunc, webgpupy::trunc);
+impl_ufunc_nin1_nout1!(_rint, webgpupy::rint);
+impl_ufunc_nin1_nout1!(_exp, webgpupy::exp);
+impl_ufunc_nin1_nout1!(_exp2, webgpupy::exp2);
+impl_ufunc_nin1_nout1!(_expm1, webgpupy::expm1);
+impl_ufunc_nin1_nout1!(_log, webgpupy::log);
+impl_ufunc_nin1_nout1!(_log2, webgpupy::log2);
+impl_ufunc_nin1_nout1!(_log10, webgpupy::log10);
+impl_ufunc_nin1_nout1!(_log1p, webgpupy::log1p);
+impl_ufunc_nin2_nout1!(_logaddexp, webgpupy::logaddexp);
+impl_ufunc_nin2_nout1!(_logaddexp2, webgpupy::logaddexp2);
 
 // TODO add ufunc kwargs support
 #[pyfunction(name = "clip")]
@@ -32,6 +46,21 @@ pub fn clip_(
     py.allow_threads(|| clip(&a.ndarray, min_arr, max_arr).into())
 }
 
+/// Evenly round to the given number of decimals
+#[pyfunction(name = "around")]
+#[pyo3(signature = (a, decimals=0))]
+pub fn around_(py: Python<'_>, a: &Bound<PyAny>, decimals: i32) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(a)?;
+    Ok(py.allow_threads(|| around(array.as_ref(), decimals).into()))
+}
+
+/// Evenly round to the given number of decimals, alias of `around`
+#[pyfunction(name = "round")]
+#[pyo3(signature = (a, decimals=0))]
+pub fn round_(py: Python<'_>, a: &Bound<PyAny>, decimals: i32) -> PyResult<NdArrayPy> {
+    around_(py, a, decimals)
+}
+
 #[pyfunction(name = "cross")]
 #[pyo3(signature = (a, b))]
 pub fn cross_<'a>(py: Python<'_>, a: &'a Bound<'a, PyAny>, b: &'a Bound<'a, PyAny>) -> NdArrayPy {
@@ -47,6 +76,21 @@ pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(_minimum, m)?)?;
     m.add_function(wrap_pyfunction!(_absolute, m)?)?;
     m.add_function(wrap_pyfunction!(_power, m)?)?;
+    m.add_function(wrap_pyfunction!(_floor, m)?)?;
+    m.add_function(wrap_pyfunction!(_ceil, m)?)?;
+    m.add_function(wrap_pyfunction!(_trunc, m)?)?;
+    m.add_function(wrap_pyfunction!(_rint, m)?)?;
+    m.add_function(wrap_pyfunction!(around_, m)?)?;
+    m.add_function(wrap_pyfunction!(round_, m)?)?;
+    m.add_function(wrap_pyfunction!(_exp, m)?)?;
+    m.add_function(wrap_pyfunction!(_exp2, m)?)?;
+    m.add_function(wrap_pyfunction!(_expm1, m)?)?;
+    m.add_function(wrap_pyfunction!(_log, m)?)?;
+    m.add_function(wrap_pyfunction!(_log2, m)?)?;
+    m.add_function(wrap_pyfunction!(_log10, m)?)?;
+    m.add_function(wrap_pyfunction!(_log1p, m)?)?;
+    m.add_function(wrap_pyfunction!(_logaddexp, m)?)?;
+    m.add_function(wrap_pyfunction!(_logaddexp2, m)?)?;
     m.add_function(wrap_pyfunction!(clip_, m)?)?;
 
     m.add_function(wrap_pyfunction!(cross_, m)?)?;
@@ -57,5 +101,18 @@ pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
     add_ufunc_nin2_nout1!(m, "minimum");
     add_ufunc_nin2_nout1!(m, "absolute");
     add_ufunc_nin2_nout1!(m, "power");
+    add_ufunc_nin1_nout1!(m, "floor");
+    add_ufunc_nin1_nout1!(m, "ceil");
+    add_ufunc_nin1_nout1!(m, "trunc");
+    add_ufunc_nin1_nout1!(m, "rint");
+    add_ufunc_nin1_nout1!(m, "exp");
+    add_ufunc_nin1_nout1!(m, "exp2");
+    add_ufunc_nin1_nout1!(m, "expm1");
+    add_ufunc_nin1_nout1!(m, "log");
+    add_ufunc_nin1_nout1!(m, "log2");
+    add_ufunc_nin1_nout1!(m, "log10");
+    add_ufunc_nin1_nout1!(m, "log1p");
+    add_ufunc_nin2_nout1!(m, "logaddexp");
+    add_ufunc_nin2_nout1!(m, "logaddexp2");
     Ok(())
 }
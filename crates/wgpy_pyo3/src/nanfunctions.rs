@@ -0,0 +1,199 @@
+use std::borrow::Cow;
+
+use pyo3::prelude::*;
+use webgpupy::NanReduction;
+
+use crate::{
+    convert_pyobj_into_operand, ndarray_error_into_pyerr,
+    ndarraypy::NdArrayPy,
+    types::{into_optional_dtypepy, DtypePy},
+    warn_runtime,
+};
+
+/// Raises the warning of the reduction as a python `RuntimeWarning`
+fn warn_nan_reduction(py: Python<'_>, reduction: NanReduction) -> PyResult<NdArrayPy> {
+    warn_runtime(py, reduction.warning)?;
+    Ok(reduction.array.into())
+}
+
+/// Sum of array elements over a given axis treating nans as zero
+#[pyfunction]
+#[pyo3(signature = (a, axis=None, dtype=None, keepdims=false))]
+pub fn nansum(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    axis: Option<i32>,
+    #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+    keepdims: bool,
+) -> PyResult<NdArrayPy> {
+    let dtype = dtype.map(|x| x.dtype);
+    let array = convert_pyobj_into_operand(a)?;
+    py.allow_threads(|| webgpupy::nansum(array.as_ref(), axis, dtype, keepdims))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Product of array elements over a given axis treating nans as one
+#[pyfunction]
+#[pyo3(signature = (a, axis=None, dtype=None, keepdims=false))]
+pub fn nanprod(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    axis: Option<i32>,
+    #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+    keepdims: bool,
+) -> PyResult<NdArrayPy> {
+    let dtype = dtype.map(|x| x.dtype);
+    let array = convert_pyobj_into_operand(a)?;
+    py.allow_threads(|| webgpupy::nanprod(array.as_ref(), axis, dtype, keepdims))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Minimum of an array along an axis, ignoring any nans
+#[pyfunction]
+#[pyo3(signature = (a, axis=None, keepdims=false))]
+pub fn nanmin(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    axis: Option<i32>,
+    keepdims: bool,
+) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(a)?;
+    let reduction = py
+        .allow_threads(|| webgpupy::nanmin(array.as_ref(), axis, keepdims))
+        .map_err(ndarray_error_into_pyerr)?;
+    warn_nan_reduction(py, reduction)
+}
+
+/// Maximum of an array along an axis, ignoring any nans
+#[pyfunction]
+#[pyo3(signature = (a, axis=None, keepdims=false))]
+pub fn nanmax(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    axis: Option<i32>,
+    keepdims: bool,
+) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(a)?;
+    let reduction = py
+        .allow_threads(|| webgpupy::nanmax(array.as_ref(), axis, keepdims))
+        .map_err(ndarray_error_into_pyerr)?;
+    warn_nan_reduction(py, reduction)
+}
+
+/// Indices of the minimum values along an axis, ignoring any nans
+#[pyfunction]
+#[pyo3(signature = (a, axis=None, keepdims=false))]
+pub fn nanargmin(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    axis: Option<i32>,
+    keepdims: bool,
+) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(a)?;
+    py.allow_threads(|| webgpupy::nanargmin(array.as_ref(), axis, keepdims))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Indices of the maximum values along an axis, ignoring any nans
+#[pyfunction]
+#[pyo3(signature = (a, axis=None, keepdims=false))]
+pub fn nanargmax(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    axis: Option<i32>,
+    keepdims: bool,
+) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(a)?;
+    py.allow_threads(|| webgpupy::nanargmax(array.as_ref(), axis, keepdims))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Arithmetic mean along an axis, ignoring any nans
+#[pyfunction]
+#[pyo3(signature = (a, axis=None, dtype=None, keepdims=false))]
+pub fn nanmean(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    axis: Option<i32>,
+    #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+    keepdims: bool,
+) -> PyResult<NdArrayPy> {
+    let dtype = dtype.map(|x| x.dtype);
+    let array = convert_pyobj_into_operand(a)?;
+    let reduction = py
+        .allow_threads(|| webgpupy::nanmean(array.as_ref(), axis, dtype, keepdims))
+        .map_err(ndarray_error_into_pyerr)?;
+    warn_nan_reduction(py, reduction)
+}
+
+/// Variance along an axis, ignoring any nans
+#[pyfunction]
+#[pyo3(signature = (a, axis=None, dtype=None, ddof=0, keepdims=false))]
+pub fn nanvar(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    axis: Option<i32>,
+    #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+    ddof: u32,
+    keepdims: bool,
+) -> PyResult<NdArrayPy> {
+    let dtype = dtype.map(|x| x.dtype);
+    let array = convert_pyobj_into_operand(a)?;
+    let reduction = py
+        .allow_threads(|| webgpupy::nanvar(array.as_ref(), axis, dtype, ddof, keepdims))
+        .map_err(ndarray_error_into_pyerr)?;
+    warn_nan_reduction(py, reduction)
+}
+
+/// Standard deviation along an axis, ignoring any nans
+#[pyfunction]
+#[pyo3(signature = (a, axis=None, dtype=None, ddof=0, keepdims=false))]
+pub fn nanstd(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    axis: Option<i32>,
+    #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+    ddof: u32,
+    keepdims: bool,
+) -> PyResult<NdArrayPy> {
+    let dtype = dtype.map(|x| x.dtype);
+    let array = convert_pyobj_into_operand(a)?;
+    let reduction = py
+        .allow_threads(|| webgpupy::nanstd(array.as_ref(), axis, dtype, ddof, keepdims))
+        .map_err(ndarray_error_into_pyerr)?;
+    warn_nan_reduction(py, reduction)
+}
+
+/// Median along an axis, ignoring any nans
+#[pyfunction]
+#[pyo3(signature = (a, axis=None, keepdims=false))]
+pub fn nanmedian(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    axis: Option<i32>,
+    keepdims: bool,
+) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(a)?;
+    let reduction = py
+        .allow_threads(|| webgpupy::nanmedian(array.as_ref(), axis, keepdims))
+        .map_err(ndarray_error_into_pyerr)?;
+    warn_nan_reduction(py, reduction)
+}
+
+pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(nansum, m)?)?;
+    m.add_function(wrap_pyfunction!(nanprod, m)?)?;
+    m.add_function(wrap_pyfunction!(nanmin, m)?)?;
+    m.add_function(wrap_pyfunction!(nanmax, m)?)?;
+    m.add_function(wrap_pyfunction!(nanargmin, m)?)?;
+    m.add_function(wrap_pyfunction!(nanargmax, m)?)?;
+    m.add_function(wrap_pyfunction!(nanmean, m)?)?;
+    m.add_function(wrap_pyfunction!(nanvar, m)?)?;
+    m.add_function(wrap_pyfunction!(nanstd, m)?)?;
+    m.add_function(wrap_pyfunction!(nanmedian, m)?)?;
+    Ok(())
+}
@@ -5,12 +5,13 @@ use webgpupy::*;
 
 use crate::{
     arithmetic::*,
-    binary::{_bitwise_and, _bitwise_or, _invert},
+    binary::{_bitwise_and, _bitwise_or, _bitwise_xor, _invert, _left_shift, _right_shift},
     cast::PyObectToRustPrimitive,
-    convert_pyobj_into_array_u32, convert_pyobj_into_operand, convert_pyobj_into_scalar,
-    convert_pyobj_into_vec_ndarray,
-    logical::{_equal, _greater, _lesser},
+    convert_pyobj_into_array_u32, convert_pyobj_into_operand, convert_pyobj_into_optional_axes,
+    convert_pyobj_into_scalar, convert_pyobj_into_vec_ndarray,
+    logical::{_equal, _greater, _greater_equal, _less_equal, _lesser, _not_equal},
     misc_math::_absolute,
+    ndarray_error_into_pyerr,
     types::{into_dtypepy, into_optional_dtypepy, DtypePy},
 };
 
@@ -40,16 +41,62 @@ impl NdArrayPy {
     }
 
     #[doc = include_str!("../python/webgpupy/python_doc/ndarray.tolist.rst")]
-    pub fn tolist(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+    pub fn tolist(&self, py: Python<'_>) -> PyResult<PyObject> {
+        if self.ndarray.shape.is_empty() {
+            return self.item(py);
+        }
         let values = self.ndarray.data.get_raw_values();
-        to_list(py, &values, 0, &self.ndarray.shape, &mut 0)
+        Ok(to_list(py, &values, 0, &self.ndarray.shape, &mut 0)?.into_any())
+    }
+
+    /// Copies an element of size 1 array to a python scalar
+    pub fn item(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let value = self.ndarray.item().map_err(ndarray_error_into_pyerr)?;
+        Ok(scalar_into_py(py, value))
+    }
+
+    pub fn __float__(&self, py: Python<'_>) -> PyResult<f64> {
+        self.item(py)?.extract::<f64>(py)
     }
 
-    pub fn reshape(&self, py: Python<'_>, shape: Vec<u32>) -> Py<PyAny> {
-        let mut new_array = self.ndarray.clone_array();
-        //TODO add validation
-        new_array.shape = shape;
-        NdArrayPy { ndarray: new_array }.into_py(py)
+    pub fn __int__(&self, py: Python<'_>) -> PyResult<i64> {
+        let value = self.item(py)?;
+        let value = value.bind(py);
+        if value.is_instance_of::<PyFloat>() {
+            Ok(value.extract::<f64>()?.trunc() as i64)
+        } else {
+            value.extract::<i64>()
+        }
+    }
+
+    pub fn __bool__(&self, py: Python<'_>) -> PyResult<bool> {
+        if self.ndarray.len() > 1 {
+            return Err(PyValueError::new_err(
+                "The truth value of an array with more than one element is ambiguous",
+            ));
+        }
+        self.item(py)?.bind(py).is_truthy()
+    }
+
+    pub fn reshape(
+        &self,
+        py: Python<'_>,
+        #[pyo3(from_py_with = "convert_pyobj_into_array_u32")] shape: Vec<u32>,
+    ) -> PyResult<Self> {
+        py.allow_threads(|| self.ndarray.reshape(shape))
+            .map(NdArrayPy::from)
+            .map_err(ndarray_error_into_pyerr)
+    }
+
+    #[pyo3(signature = (axis=None))]
+    pub fn squeeze(
+        &self,
+        py: Python<'_>,
+        #[pyo3(from_py_with = "convert_pyobj_into_optional_axes")] axis: Option<Vec<i32>>,
+    ) -> PyResult<Self> {
+        py.allow_threads(|| self.ndarray.squeeze(axis.as_deref()))
+            .map(NdArrayPy::from)
+            .map_err(ndarray_error_into_pyerr)
     }
 
     /// Shape of ndarry
@@ -58,6 +105,18 @@ impl NdArrayPy {
         Ok(self.ndarray.shape.clone())
     }
 
+    /// Number of dimensions of ndarray
+    #[getter]
+    pub fn ndim(&self) -> usize {
+        self.ndarray.shape.len()
+    }
+
+    /// Number of elements in ndarray
+    #[getter]
+    pub fn size(&self) -> u32 {
+        self.ndarray.len()
+    }
+
     /// type of ndarry
     #[getter]
     pub fn dtype(&self) -> PyResult<DtypePy> {
@@ -96,6 +155,42 @@ impl NdArrayPy {
         Ok(_subtract(py, other, slf, None, None))
     }
 
+    pub fn __floordiv__(slf: &Bound<Self>, py: Python<'_>, other: &Bound<PyAny>) -> PyResult<Self> {
+        Ok(_floor_divide(py, slf, other, None, None))
+    }
+
+    pub fn __rfloordiv__(
+        slf: &Bound<Self>,
+        py: Python<'_>,
+        other: &Bound<PyAny>,
+    ) -> PyResult<Self> {
+        Ok(_floor_divide(py, other, slf, None, None))
+    }
+
+    pub fn __mod__(slf: &Bound<Self>, py: Python<'_>, other: &Bound<PyAny>) -> PyResult<Self> {
+        Ok(_remainder(py, slf, other, None, None))
+    }
+
+    pub fn __rmod__(slf: &Bound<Self>, py: Python<'_>, other: &Bound<PyAny>) -> PyResult<Self> {
+        Ok(_remainder(py, other, slf, None, None))
+    }
+
+    pub fn __divmod__(
+        slf: &Bound<Self>,
+        py: Python<'_>,
+        other: &Bound<PyAny>,
+    ) -> PyResult<(Self, Self)> {
+        Ok(_divmod(py, slf, other, None, None))
+    }
+
+    pub fn __rdivmod__(
+        slf: &Bound<Self>,
+        py: Python<'_>,
+        other: &Bound<PyAny>,
+    ) -> PyResult<(Self, Self)> {
+        Ok(_divmod(py, other, slf, None, None))
+    }
+
     pub fn __lt__(slf: &Bound<Self>, py: Python<'_>, other: &Bound<PyAny>) -> PyResult<Self> {
         Ok(_lesser(py, slf, other, None, None))
     }
@@ -104,6 +199,14 @@ impl NdArrayPy {
         Ok(_greater(py, slf, other, None, None))
     }
 
+    pub fn __le__(slf: &Bound<Self>, py: Python<'_>, other: &Bound<PyAny>) -> PyResult<Self> {
+        Ok(_less_equal(py, slf, other, None, None))
+    }
+
+    pub fn __ge__(slf: &Bound<Self>, py: Python<'_>, other: &Bound<PyAny>) -> PyResult<Self> {
+        Ok(_greater_equal(py, slf, other, None, None))
+    }
+
     pub fn __and__(slf: &Bound<Self>, py: Python<'_>, other: &Bound<PyAny>) -> PyResult<Self> {
         Ok(_bitwise_and(py, slf, other, None, None))
     }
@@ -112,6 +215,22 @@ impl NdArrayPy {
         Ok(_bitwise_or(py, slf, other, None, None))
     }
 
+    pub fn __xor__(slf: &Bound<Self>, py: Python<'_>, other: &Bound<PyAny>) -> PyResult<Self> {
+        _bitwise_xor(py, slf, other, None, None)
+    }
+
+    pub fn __lshift__(slf: &Bound<Self>, py: Python<'_>, other: &Bound<PyAny>) -> PyResult<Self> {
+        _left_shift(py, slf, other, None, None)
+    }
+
+    pub fn __rshift__(slf: &Bound<Self>, py: Python<'_>, other: &Bound<PyAny>) -> PyResult<Self> {
+        _right_shift(py, slf, other, None, None)
+    }
+
+    pub fn __invert__(slf: &Bound<Self>, py: Python<'_>) -> PyResult<Self> {
+        Ok(_invert(py, slf, None, None))
+    }
+
     pub fn __neg__(&self) -> PyResult<Self> {
         Ok(NdArrayPy {
             ndarray: self.ndarray.neg(),
@@ -127,9 +246,14 @@ impl NdArrayPy {
     }
 
     pub fn __ne__(slf: &Bound<Self>, py: Python<'_>, other: &Bound<PyAny>) -> PyResult<Self> {
-        let binding = _equal(py, slf, other, None, None).into_py(py);
-        let eq = binding.bind(py);
-        Ok(_invert(py, eq, None, None))
+        Ok(_not_equal(py, slf, other, None, None))
+    }
+
+    /// Sort an array in-place along an axis
+    #[pyo3(signature = (axis=-1))]
+    pub fn sort(&self, py: Python<'_>, axis: i32) -> PyResult<()> {
+        py.allow_threads(|| webgpupy::sort_inplace(&self.ndarray, axis))
+            .map_err(ndarray_error_into_pyerr)
     }
 
     pub fn astype(&self, #[pyo3(from_py_with = "into_dtypepy")] dtype: Dtype) -> PyResult<Self> {
@@ -139,11 +263,15 @@ impl NdArrayPy {
     }
 
     pub fn flatten(&self, py: Python<'_>) -> PyResult<Self> {
-        py.allow_threads(|| {
-            let mut new_array = self.ndarray.clone_array();
-            new_array.shape = vec![(new_array.shape).iter().copied().product()];
-            Ok(NdArrayPy { ndarray: new_array })
-        })
+        Ok(py.allow_threads(|| self.ndarray.flatten()).into())
+    }
+
+    pub fn copy(&self, py: Python<'_>) -> PyResult<Self> {
+        Ok(py.allow_threads(|| self.ndarray.clone_array()).into())
+    }
+
+    pub fn ravel(&self, py: Python<'_>) -> PyResult<Self> {
+        Ok(py.allow_threads(|| self.ndarray.ravel()).into())
     }
 
     pub fn __getitem__(&self, py: Python<'_>, other: &Bound<PyAny>) -> PyResult<Self> {
@@ -188,6 +316,35 @@ pub fn array_zeros(
     })
 }
 
+/// Creates an array of the given shape filled from a python scalar, list or ndarray.
+/// Scalars fall back to `scalar_dtype`, arrays keep their dtype unless one is given.
+fn full_from_pyobj(
+    py: Python<'_>,
+    shape: Vec<u32>,
+    data: &Bound<PyAny>,
+    dtype: Option<Dtype>,
+    scalar_dtype: Dtype,
+) -> PyResult<NdArrayPy> {
+    let owned;
+    let values = if let Ok(values) = data.downcast::<NdArrayPy>() {
+        &values.get().ndarray
+    } else if data.is_instance_of::<PyList>() || data.is_instance_of::<PyTuple>() {
+        owned = into_scalar_array(data, get_type(data)?)?;
+        &owned.ndarray
+    } else {
+        let operand = convert_pyobj_into_scalar(data)?;
+        let dtype = dtype.unwrap_or(scalar_dtype);
+        return py
+            .allow_threads(|| full(shape, operand.into(), Some(dtype), None))
+            .map(NdArrayPy::from)
+            .map_err(ndarray_error_into_pyerr);
+    };
+
+    py.allow_threads(|| full(shape, values.into(), dtype, None))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
 /// Fills array with value
 #[pyfunction(name = "full")]
 #[pyo3(signature = (shape, data, dtype=None))]
@@ -197,15 +354,89 @@ pub fn array_full(
     data: &Bound<PyAny>,
     #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
 ) -> PyResult<NdArrayPy> {
-    let operand = convert_pyobj_into_scalar(data)?;
-    py.allow_threads(|| {
-        let dtype: &Dtype = match dtype.as_ref() {
-            Some(x) => &x.as_ref().dtype,
-            None => &Dtype::Float32,
-        };
-        // TODO remove unwrap
-        PyResult::Ok(full(shape, operand.into(), Some(*dtype), None).into())
-    })
+    full_from_pyobj(py, shape, data, dtype.map(|x| x.dtype), Dtype::Float32)
+}
+
+/// Creates a new array without initializing its values
+#[pyfunction(name = "empty")]
+#[pyo3(signature = (shape, dtype=None))]
+pub fn array_empty(
+    py: Python<'_>,
+    shape: Vec<u32>,
+    #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+) -> NdArrayPy {
+    let dtype = dtype.map(|x| x.dtype);
+    py.allow_threads(|| empty(shape, dtype, None)).into()
+}
+
+macro_rules! impl_like_fn {
+    ($name: ident, $fn: ident, $doc: literal) => {
+        #[doc = $doc]
+        #[pyfunction]
+        #[pyo3(signature = (a, dtype=None))]
+        pub fn $name(
+            py: Python<'_>,
+            a: &Bound<PyAny>,
+            #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+        ) -> PyResult<NdArrayPy> {
+            let array = convert_pyobj_into_operand(a)?;
+            let dtype = dtype.map(|x| x.dtype);
+            Ok(py
+                .allow_threads(|| webgpupy::$fn(array.as_ref(), dtype))
+                .into())
+        }
+    };
+}
+
+impl_like_fn!(
+    zeros_like,
+    zeros_like,
+    "Returns an array of zeros with the same shape as a given array"
+);
+impl_like_fn!(
+    ones_like,
+    ones_like,
+    "Returns an array of ones with the same shape as a given array"
+);
+impl_like_fn!(
+    empty_like,
+    empty_like,
+    "Returns an uninitialized array with the same shape as a given array"
+);
+
+/// Returns an array filled with a value with the same shape as a given array
+#[pyfunction]
+#[pyo3(signature = (a, fill_value, dtype=None))]
+pub fn full_like(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    fill_value: &Bound<PyAny>,
+    #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(a)?;
+    let array = array.as_ref();
+    let dtype = dtype.map_or(array.dtype, |x| x.dtype);
+    full_from_pyobj(py, array.shape.clone(), fill_value, Some(dtype), dtype)
+}
+
+/// Returns a copy of an array
+#[pyfunction(name = "copy")]
+pub fn array_copy(py: Python<'_>, a: &Bound<PyAny>) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(a)?;
+    Ok(py.allow_threads(|| copy(array.as_ref())).into())
+}
+
+fn scalar_into_py(py: Python<'_>, value: ScalarValue) -> PyObject {
+    match value {
+        ScalarValue::F32(x) => x.into_py(py),
+        ScalarValue::U32(x) => x.into_py(py),
+        ScalarValue::U16(x) => x.into_py(py),
+        ScalarValue::U8(x) => x.into_py(py),
+        ScalarValue::I32(x) => x.into_py(py),
+        ScalarValue::I16(x) => x.into_py(py),
+        ScalarValue::I8(x) => x.into_py(py),
+        ScalarValue::BOOL(x) => x.into_py(py),
+    }
 }
 
 fn to_list(
@@ -289,7 +520,9 @@ pub fn get_shape(data: &Bound<PyAny>) -> PyResult<Vec<u32>> {
     if data.is_instance_of::<PyList>() {
         let mut shape = vec![];
         shape.push(data.len()? as u32);
-        shape.extend(get_shape(&data.get_item(0)?)?);
+        if !data.is_empty()? {
+            shape.extend(get_shape(&data.get_item(0)?)?);
+        }
         Ok(shape)
     } else if data.is_instance_of::<PyFloat>()
         || data.is_instance_of::<PyInt>()
@@ -305,7 +538,11 @@ pub fn get_shape(data: &Bound<PyAny>) -> PyResult<Vec<u32>> {
 
 pub fn get_type(data: &Bound<PyAny>) -> PyResult<Dtype> {
     if data.is_instance_of::<PyList>() {
-        get_type(&data.get_item(0)?)
+        if data.is_empty()? {
+            Ok(Dtype::Float32)
+        } else {
+            get_type(&data.get_item(0)?)
+        }
     } else if data.is_instance_of::<PyBool>() {
         Ok(Dtype::Bool)
     } else if data.is_instance_of::<PyFloat>() {
@@ -428,33 +665,300 @@ pub fn repeat(
     })
 }
 
-#[pyfunction(name = "dstack")]
-pub fn dstack(
-    #[pyo3(from_py_with = "convert_pyobj_into_vec_ndarray")] refs: Vec<Bound<NdArrayPy>>,
+fn vec_ndarray_refs<'a>(refs: &'a [Bound<NdArrayPy>]) -> Vec<&'a NdArray> {
+    refs.iter().map(|x| &x.get().ndarray).collect()
+}
+
+/// Joins a sequence of arrays along an existing axis
+#[pyfunction(name = "concatenate")]
+#[pyo3(signature = (arrays, axis=Some(0)))]
+pub fn concatenate(
+    py: Python<'_>,
+    #[pyo3(from_py_with = "convert_pyobj_into_vec_ndarray")] arrays: Vec<Bound<NdArrayPy>>,
+    axis: Option<i32>,
 ) -> PyResult<NdArrayPy> {
-    // TODO add array size validation
+    let arrays = vec_ndarray_refs(&arrays);
+    py.allow_threads(|| webgpupy::concatenate(&arrays, axis))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Joins a sequence of arrays along a new axis
+#[pyfunction(name = "stack")]
+#[pyo3(signature = (arrays, axis=0))]
+pub fn stack(
+    py: Python<'_>,
+    #[pyo3(from_py_with = "convert_pyobj_into_vec_ndarray")] arrays: Vec<Bound<NdArrayPy>>,
+    axis: i32,
+) -> PyResult<NdArrayPy> {
+    let arrays = vec_ndarray_refs(&arrays);
+    py.allow_threads(|| webgpupy::stack(&arrays, axis))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+macro_rules! impl_stack_fn {
+    ($name: ident, $doc: literal) => {
+        #[doc = $doc]
+        #[pyfunction]
+        pub fn $name(
+            py: Python<'_>,
+            #[pyo3(from_py_with = "convert_pyobj_into_vec_ndarray")] tup: Vec<Bound<NdArrayPy>>,
+        ) -> PyResult<NdArrayPy> {
+            let tup = vec_ndarray_refs(&tup);
+            py.allow_threads(|| webgpupy::$name(&tup))
+                .map(NdArrayPy::from)
+                .map_err(ndarray_error_into_pyerr)
+        }
+    };
+}
+
+impl_stack_fn!(hstack, "Stacks arrays in sequence horizontally");
+impl_stack_fn!(vstack, "Stacks arrays in sequence vertically");
+impl_stack_fn!(dstack, "Stacks arrays in sequence depth wise");
+impl_stack_fn!(
+    column_stack,
+    "Stacks 1-D arrays as columns into a 2-D array"
+);
+
+/// Appends values to the end of an array
+#[pyfunction(name = "append")]
+#[pyo3(signature = (arr, values, axis=None))]
+pub fn append(
+    py: Python<'_>,
+    arr: &Bound<PyAny>,
+    values: &Bound<PyAny>,
+    axis: Option<i32>,
+) -> PyResult<NdArrayPy> {
+    let arr = convert_pyobj_into_operand(arr)?;
+    let values = convert_pyobj_into_operand(values)?;
+    py.allow_threads(|| webgpupy::append(arr.as_ref(), values.as_ref(), axis))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+enum BlockPy<'py> {
+    Array(Bound<'py, NdArrayPy>),
+    List(Vec<BlockPy<'py>>),
+}
+
+impl<'py> BlockPy<'py> {
+    fn extract(data: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if data.is_instance_of::<NdArrayPy>() {
+            Ok(BlockPy::Array(data.downcast::<NdArrayPy>()?.clone()))
+        } else if data.is_instance_of::<PyList>() {
+            let blocks = data
+                .iter()?
+                .map(|x| BlockPy::extract(&x?))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(BlockPy::List(blocks))
+        } else {
+            Err(PyTypeError::new_err(format!(
+                "Operation not supported for the given type {:?}",
+                data.get_type()
+            )))
+        }
+    }
+
+    fn as_block(&self) -> Block<'_> {
+        match self {
+            BlockPy::Array(x) => Block::Array(&x.get().ndarray),
+            BlockPy::List(x) => Block::List(x.iter().map(|x| x.as_block()).collect()),
+        }
+    }
+}
 
-    let mut tup = vec![];
+/// Assembles an array from nested lists of blocks
+#[pyfunction(name = "block")]
+pub fn block(py: Python<'_>, arrays: &Bound<PyAny>) -> PyResult<NdArrayPy> {
+    let blocks = BlockPy::extract(arrays)?;
+    let blocks = blocks.as_block();
+    py.allow_threads(|| webgpupy::block(&blocks))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
 
-    for rf in &refs {
-        tup.push(&rf.get().ndarray)
+fn into_split_indices(data: &Bound<PyAny>) -> PyResult<SplitIndices> {
+    if data.is_instance_of::<PyInt>() {
+        Ok(SplitIndices::Sections(data.extract::<u32>()?))
+    } else if data.is_instance_of::<PyList>() || data.is_instance_of::<PyTuple>() {
+        Ok(SplitIndices::Indices(data.extract::<Vec<i64>>()?))
+    } else {
+        Err(PyTypeError::new_err(
+            "indices_or_sections must be an integer or a sequence of integers",
+        ))
     }
+}
 
-    let result = NdArrayPy {
-        ndarray: webgpupy::dstack(&tup),
+fn into_vec_ndarraypy(result: NdArrayResult<Vec<NdArray>>) -> PyResult<Vec<NdArrayPy>> {
+    result
+        .map(|x| x.into_iter().map(NdArrayPy::from).collect())
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Splits an array into equal sub arrays
+#[pyfunction(name = "split")]
+#[pyo3(signature = (ary, indices_or_sections, axis=0))]
+pub fn split(
+    py: Python<'_>,
+    ary: &Bound<PyAny>,
+    #[pyo3(from_py_with = "into_split_indices")] indices_or_sections: SplitIndices,
+    axis: i32,
+) -> PyResult<Vec<NdArrayPy>> {
+    let array = convert_pyobj_into_operand(ary)?;
+    into_vec_ndarraypy(
+        py.allow_threads(|| webgpupy::split(array.as_ref(), &indices_or_sections, axis)),
+    )
+}
+
+/// Splits an array into sub arrays which need not be equal
+#[pyfunction(name = "array_split")]
+#[pyo3(signature = (ary, indices_or_sections, axis=0))]
+pub fn array_split(
+    py: Python<'_>,
+    ary: &Bound<PyAny>,
+    #[pyo3(from_py_with = "into_split_indices")] indices_or_sections: SplitIndices,
+    axis: i32,
+) -> PyResult<Vec<NdArrayPy>> {
+    let array = convert_pyobj_into_operand(ary)?;
+    into_vec_ndarraypy(
+        py.allow_threads(|| webgpupy::array_split(array.as_ref(), &indices_or_sections, axis)),
+    )
+}
+
+macro_rules! impl_split_fn {
+    ($name: ident, $doc: literal) => {
+        #[doc = $doc]
+        #[pyfunction]
+        pub fn $name(
+            py: Python<'_>,
+            ary: &Bound<PyAny>,
+            #[pyo3(from_py_with = "into_split_indices")] indices_or_sections: SplitIndices,
+        ) -> PyResult<Vec<NdArrayPy>> {
+            let array = convert_pyobj_into_operand(ary)?;
+            into_vec_ndarraypy(
+                py.allow_threads(|| webgpupy::$name(array.as_ref(), &indices_or_sections)),
+            )
+        }
     };
+}
 
-    Ok(result)
+impl_split_fn!(hsplit, "Splits an array into sub arrays horizontally");
+impl_split_fn!(vsplit, "Splits an array into sub arrays vertically");
+impl_split_fn!(dsplit, "Splits an array into sub arrays depth wise");
+
+/// Splits an array into a sequence of arrays along an axis
+#[pyfunction(name = "unstack")]
+#[pyo3(signature = (x, axis=0))]
+pub fn unstack(py: Python<'_>, x: &Bound<PyAny>, axis: i32) -> PyResult<Vec<NdArrayPy>> {
+    let array = convert_pyobj_into_operand(x)?;
+    into_vec_ndarraypy(py.allow_threads(|| webgpupy::unstack(array.as_ref(), axis)))
+}
+
+/// Removes axes of length one
+#[pyfunction(name = "squeeze")]
+#[pyo3(signature = (a, axis=None))]
+pub fn squeeze(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    #[pyo3(from_py_with = "convert_pyobj_into_optional_axes")] axis: Option<Vec<i32>>,
+) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(a)?;
+    py.allow_threads(|| array.as_ref().squeeze(axis.as_deref()))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Inserts new axes of length one at the given positions
+#[pyfunction(name = "expand_dims")]
+#[pyo3(signature = (a, axis))]
+pub fn expand_dims(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    #[pyo3(from_py_with = "convert_pyobj_into_optional_axes")] axis: Option<Vec<i32>>,
+) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(a)?;
+    let axis = axis.ok_or_else(|| PyTypeError::new_err("axis must be an int or a sequence"))?;
+    py.allow_threads(|| array.as_ref().expand_dims(&axis))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
 }
 
+/// Returns a flattened array
+#[pyfunction(name = "ravel")]
+pub fn ravel(py: Python<'_>, a: &Bound<PyAny>) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(a)?;
+    Ok(py.allow_threads(|| array.as_ref().ravel()).into())
+}
+
+macro_rules! impl_atleast_fn {
+    ($name: ident, $doc: literal) => {
+        #[doc = $doc]
+        #[pyfunction]
+        #[pyo3(signature = (*arys))]
+        pub fn $name(py: Python<'_>, arys: &Bound<PyTuple>) -> PyResult<PyObject> {
+            let mut results = arys
+                .iter()
+                .map(|x| {
+                    let array = convert_pyobj_into_operand(&x)?;
+                    Ok(NdArrayPy::from(webgpupy::$name(array.as_ref())))
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+            if results.len() == 1 {
+                Ok(results.remove(0).into_py(py))
+            } else {
+                Ok(results.into_py(py))
+            }
+        }
+    };
+}
+
+impl_atleast_fn!(
+    atleast_1d,
+    "Views inputs as arrays with at least one dimension"
+);
+impl_atleast_fn!(
+    atleast_2d,
+    "Views inputs as arrays with at least two dimensions"
+);
+impl_atleast_fn!(
+    atleast_3d,
+    "Views inputs as arrays with at least three dimensions"
+);
+
 pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(array_zeros, m)?)?;
     m.add_function(wrap_pyfunction!(array_ones, m)?)?;
     m.add_function(wrap_pyfunction!(array_full, m)?)?;
+    m.add_function(wrap_pyfunction!(array_empty, m)?)?;
+    m.add_function(wrap_pyfunction!(array_copy, m)?)?;
+    m.add_function(wrap_pyfunction!(self::zeros_like, m)?)?;
+    m.add_function(wrap_pyfunction!(self::ones_like, m)?)?;
+    m.add_function(wrap_pyfunction!(self::empty_like, m)?)?;
+    m.add_function(wrap_pyfunction!(self::full_like, m)?)?;
     m.add_function(wrap_pyfunction!(array, m)?)?;
     m.add_function(wrap_pyfunction!(self::broadcast_to, m)?)?;
     m.add_function(wrap_pyfunction!(self::repeat, m)?)?;
     m.add_function(wrap_pyfunction!(self::dstack, m)?)?;
+    m.add_function(wrap_pyfunction!(self::hstack, m)?)?;
+    m.add_function(wrap_pyfunction!(self::vstack, m)?)?;
+    m.add_function(wrap_pyfunction!(self::column_stack, m)?)?;
+    m.add_function(wrap_pyfunction!(self::concatenate, m)?)?;
+    m.add_function(wrap_pyfunction!(self::stack, m)?)?;
+    m.add_function(wrap_pyfunction!(self::append, m)?)?;
+    m.add_function(wrap_pyfunction!(self::block, m)?)?;
+    m.add_function(wrap_pyfunction!(self::split, m)?)?;
+    m.add_function(wrap_pyfunction!(self::array_split, m)?)?;
+    m.add_function(wrap_pyfunction!(self::hsplit, m)?)?;
+    m.add_function(wrap_pyfunction!(self::vsplit, m)?)?;
+    m.add_function(wrap_pyfunction!(self::dsplit, m)?)?;
+    m.add_function(wrap_pyfunction!(self::unstack, m)?)?;
+    m.add_function(wrap_pyfunction!(self::squeeze, m)?)?;
+    m.add_function(wrap_pyfunction!(self::expand_dims, m)?)?;
+    m.add_function(wrap_pyfunction!(self::ravel, m)?)?;
+    m.add_function(wrap_pyfunction!(self::atleast_1d, m)?)?;
+    m.add_function(wrap_pyfunction!(self::atleast_2d, m)?)?;
+    m.add_function(wrap_pyfunction!(self::atleast_3d, m)?)?;
     m.add_class::<NdArrayPy>()?;
     m.add_class::<DtypePy>()?;
     Ok(())
@@ -0,0 +1,110 @@
+use pyo3::prelude::*;
+
+use crate::{
+    convert_pyobj_into_operand, convert_pyobj_into_option_operand, ndarray_error_into_pyerr,
+    ndarraypy::NdArrayPy,
+};
+
+/// Evaluate a polynomial at specific values
+#[pyfunction]
+#[pyo3(signature = (p, x))]
+pub fn polyval(py: Python<'_>, p: &Bound<PyAny>, x: &Bound<PyAny>) -> PyResult<NdArrayPy> {
+    let p = convert_pyobj_into_operand(p)?;
+    let x = convert_pyobj_into_operand(x)?;
+    py.allow_threads(|| webgpupy::polyval(p.as_ref(), x.as_ref()))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Least squares polynomial fit
+#[pyfunction]
+#[pyo3(signature = (x, y, deg, w=None))]
+pub fn polyfit(
+    py: Python<'_>,
+    x: &Bound<PyAny>,
+    y: &Bound<PyAny>,
+    deg: u32,
+    w: Option<&Bound<PyAny>>,
+) -> PyResult<NdArrayPy> {
+    let x = convert_pyobj_into_operand(x)?;
+    let y = convert_pyobj_into_operand(y)?;
+    let w = w.map(convert_pyobj_into_option_operand).transpose()?;
+    let w = w.flatten();
+    py.allow_threads(|| {
+        webgpupy::polyfit(x.as_ref(), y.as_ref(), deg, w.as_ref().map(|x| x.as_ref()))
+    })
+    .map(NdArrayPy::from)
+    .map_err(ndarray_error_into_pyerr)
+}
+
+/// Return the derivative of the specified order of a polynomial
+#[pyfunction]
+#[pyo3(signature = (p, m=1))]
+pub fn polyder(py: Python<'_>, p: &Bound<PyAny>, m: u32) -> PyResult<NdArrayPy> {
+    let p = convert_pyobj_into_operand(p)?;
+    py.allow_threads(|| webgpupy::polyder(p.as_ref(), m))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Return an antiderivative (indefinite integral) of a polynomial
+#[pyfunction]
+#[pyo3(signature = (p, m=1, k=None))]
+pub fn polyint(
+    py: Python<'_>,
+    p: &Bound<PyAny>,
+    m: u32,
+    k: Option<&Bound<PyAny>>,
+) -> PyResult<NdArrayPy> {
+    let p = convert_pyobj_into_operand(p)?;
+    let k = k.map(convert_pyobj_into_option_operand).transpose()?;
+    let k = k.flatten();
+    py.allow_threads(|| webgpupy::polyint(p.as_ref(), m, k.as_ref().map(|x| x.as_ref())))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Find the sum of two polynomials
+#[pyfunction]
+#[pyo3(signature = (a1, a2))]
+pub fn polyadd(py: Python<'_>, a1: &Bound<PyAny>, a2: &Bound<PyAny>) -> PyResult<NdArrayPy> {
+    let a1 = convert_pyobj_into_operand(a1)?;
+    let a2 = convert_pyobj_into_operand(a2)?;
+    py.allow_threads(|| webgpupy::polyadd(a1.as_ref(), a2.as_ref()))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Find the product of two polynomials
+#[pyfunction]
+#[pyo3(signature = (a1, a2))]
+pub fn polymul(py: Python<'_>, a1: &Bound<PyAny>, a2: &Bound<PyAny>) -> PyResult<NdArrayPy> {
+    let a1 = convert_pyobj_into_operand(a1)?;
+    let a2 = convert_pyobj_into_operand(a2)?;
+    py.allow_threads(|| webgpupy::polymul(a1.as_ref(), a2.as_ref()))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Return the roots of a polynomial, as arrays of their real and imaginary parts
+#[pyfunction]
+#[pyo3(signature = (p))]
+pub fn roots(py: Python<'_>, p: &Bound<PyAny>) -> PyResult<(NdArrayPy, NdArrayPy)> {
+    let p = convert_pyobj_into_operand(p)?;
+    py.allow_threads(|| webgpupy::roots(p.as_ref()))
+        .map(|(real, imaginary)| (NdArrayPy::from(real), NdArrayPy::from(imaginary)))
+        .map_err(ndarray_error_into_pyerr)
+}
+
+pub fn polynomial_module(py: Python, parent_module: &Bound<PyModule>) -> PyResult<()> {
+    let child_module = PyModule::new_bound(py, "polynomial")?;
+    child_module.add_function(wrap_pyfunction_bound!(polyval, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction_bound!(polyfit, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction_bound!(polyder, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction_bound!(polyint, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction_bound!(polyadd, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction_bound!(polymul, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction_bound!(roots, &child_module)?)?;
+    parent_module.add_submodule(&child_module)?;
+    Ok(())
+}
@@ -0,0 +1,163 @@
+use pyo3::{prelude::*, types::PyTuple};
+
+use crate::{convert_pyobj_into_operand, ndarray_error_into_pyerr, ndarraypy::NdArrayPy};
+
+/// Find the unique elements of an array
+#[pyfunction]
+#[pyo3(signature = (ar, return_index=false, return_inverse=false, return_counts=false, axis=None))]
+pub fn unique(
+    py: Python<'_>,
+    ar: &Bound<PyAny>,
+    return_index: bool,
+    return_inverse: bool,
+    return_counts: bool,
+    axis: Option<i32>,
+) -> PyResult<PyObject> {
+    let array = convert_pyobj_into_operand(ar)?;
+    let result = py
+        .allow_threads(|| {
+            webgpupy::unique(
+                array.as_ref(),
+                return_index,
+                return_inverse,
+                return_counts,
+                axis,
+            )
+        })
+        .map_err(ndarray_error_into_pyerr)?;
+    let outputs = [
+        Some(result.values),
+        result.indices,
+        result.inverse,
+        result.counts,
+    ]
+    .into_iter()
+    .flatten()
+    .map(|x| NdArrayPy::from(x).into_py(py))
+    .collect::<Vec<_>>();
+    if outputs.len() == 1 {
+        Ok(outputs.into_iter().next().unwrap())
+    } else {
+        Ok(PyTuple::new_bound(py, outputs).into_py(py))
+    }
+}
+
+/// Test whether each element of a 1-D array is also present in a second array.
+/// `assume_unique` is accepted for compatibility and has no effect.
+#[pyfunction]
+#[pyo3(signature = (ar1, ar2, assume_unique=false, invert=false))]
+pub fn in1d(
+    py: Python<'_>,
+    ar1: &Bound<PyAny>,
+    ar2: &Bound<PyAny>,
+    assume_unique: bool,
+    invert: bool,
+) -> PyResult<NdArrayPy> {
+    let _ = assume_unique;
+    let ar1 = convert_pyobj_into_operand(ar1)?;
+    let ar2 = convert_pyobj_into_operand(ar2)?;
+    py.allow_threads(|| webgpupy::in1d(ar1.as_ref(), ar2.as_ref(), invert))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Calculates `element in test_elements`, broadcasting over `element` only.
+/// `assume_unique` is accepted for compatibility and has no effect.
+#[pyfunction]
+#[pyo3(signature = (element, test_elements, assume_unique=false, invert=false))]
+pub fn isin(
+    py: Python<'_>,
+    element: &Bound<PyAny>,
+    test_elements: &Bound<PyAny>,
+    assume_unique: bool,
+    invert: bool,
+) -> PyResult<NdArrayPy> {
+    let _ = assume_unique;
+    let element = convert_pyobj_into_operand(element)?;
+    let test_elements = convert_pyobj_into_operand(test_elements)?;
+    py.allow_threads(|| webgpupy::isin(element.as_ref(), test_elements.as_ref(), invert))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Find the intersection of two arrays
+#[pyfunction]
+#[pyo3(signature = (ar1, ar2, assume_unique=false, return_indices=false))]
+pub fn intersect1d(
+    py: Python<'_>,
+    ar1: &Bound<PyAny>,
+    ar2: &Bound<PyAny>,
+    assume_unique: bool,
+    return_indices: bool,
+) -> PyResult<PyObject> {
+    let ar1 = convert_pyobj_into_operand(ar1)?;
+    let ar2 = convert_pyobj_into_operand(ar2)?;
+    let result = py
+        .allow_threads(|| {
+            webgpupy::intersect1d(ar1.as_ref(), ar2.as_ref(), assume_unique, return_indices)
+        })
+        .map_err(ndarray_error_into_pyerr)?;
+    let values = NdArrayPy::from(result.values);
+    Ok(match result.indices {
+        Some((indices_1, indices_2)) => (
+            values,
+            NdArrayPy::from(indices_1),
+            NdArrayPy::from(indices_2),
+        )
+            .into_py(py),
+        None => values.into_py(py),
+    })
+}
+
+/// Find the union of two arrays
+#[pyfunction]
+pub fn union1d(py: Python<'_>, ar1: &Bound<PyAny>, ar2: &Bound<PyAny>) -> PyResult<NdArrayPy> {
+    let ar1 = convert_pyobj_into_operand(ar1)?;
+    let ar2 = convert_pyobj_into_operand(ar2)?;
+    py.allow_threads(|| webgpupy::union1d(ar1.as_ref(), ar2.as_ref()))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Find the set difference of two arrays
+#[pyfunction]
+#[pyo3(signature = (ar1, ar2, assume_unique=false))]
+pub fn setdiff1d(
+    py: Python<'_>,
+    ar1: &Bound<PyAny>,
+    ar2: &Bound<PyAny>,
+    assume_unique: bool,
+) -> PyResult<NdArrayPy> {
+    let ar1 = convert_pyobj_into_operand(ar1)?;
+    let ar2 = convert_pyobj_into_operand(ar2)?;
+    py.allow_threads(|| webgpupy::setdiff1d(ar1.as_ref(), ar2.as_ref(), assume_unique))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Find the set exclusive-or of two arrays
+#[pyfunction]
+#[pyo3(signature = (ar1, ar2, assume_unique=false))]
+pub fn setxor1d(
+    py: Python<'_>,
+    ar1: &Bound<PyAny>,
+    ar2: &Bound<PyAny>,
+    assume_unique: bool,
+) -> PyResult<NdArrayPy> {
+    let ar1 = convert_pyobj_into_operand(ar1)?;
+    let ar2 = convert_pyobj_into_operand(ar2)?;
+    py.allow_threads(|| webgpupy::setxor1d(ar1.as_ref(), ar2.as_ref(), assume_unique))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(unique, m)?)?;
+    m.add_function(wrap_pyfunction!(in1d, m)?)?;
+    m.add_function(wrap_pyfunction!(isin, m)?)?;
+    m.add_function(wrap_pyfunction!(intersect1d, m)?)?;
+    m.add_function(wrap_pyfunction!(union1d, m)?)?;
+    m.add_function(wrap_pyfunction!(setdiff1d, m)?)?;
+    m.add_function(wrap_pyfunction!(setxor1d, m)?)?;
+    Ok(())
+}
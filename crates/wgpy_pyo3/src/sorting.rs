@@ -0,0 +1,125 @@
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+};
+
+use crate::{
+    convert_pyobj_into_operand, convert_pyobj_into_optional_axes, convert_pyobj_into_vec_ndarray,
+    ndarray_error_into_pyerr, ndarraypy::NdArrayPy,
+};
+
+fn convert_pyobj_into_kth(kth: &Bound<PyAny>) -> PyResult<Vec<i32>> {
+    convert_pyobj_into_optional_axes(kth)?
+        .ok_or_else(|| PyTypeError::new_err("kth must be an integer or a sequence of integers"))
+}
+
+/// Return a sorted copy of an array
+#[pyfunction]
+#[pyo3(signature = (a, axis=Some(-1)))]
+pub fn sort(py: Python<'_>, a: &Bound<PyAny>, axis: Option<i32>) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(a)?;
+    py.allow_threads(|| webgpupy::sort(array.as_ref(), axis))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Returns the indices that would sort an array
+#[pyfunction]
+#[pyo3(signature = (a, axis=Some(-1)))]
+pub fn argsort(py: Python<'_>, a: &Bound<PyAny>, axis: Option<i32>) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(a)?;
+    py.allow_threads(|| webgpupy::argsort(array.as_ref(), axis))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Return a partitioned copy of an array
+#[pyfunction]
+#[pyo3(signature = (a, kth, axis=Some(-1)))]
+pub fn partition(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    kth: &Bound<PyAny>,
+    axis: Option<i32>,
+) -> PyResult<NdArrayPy> {
+    let kth = convert_pyobj_into_kth(kth)?;
+    let array = convert_pyobj_into_operand(a)?;
+    py.allow_threads(|| webgpupy::partition(array.as_ref(), &kth, axis))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Returns the indices that would partition an array
+#[pyfunction]
+#[pyo3(signature = (a, kth, axis=Some(-1)))]
+pub fn argpartition(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    kth: &Bound<PyAny>,
+    axis: Option<i32>,
+) -> PyResult<NdArrayPy> {
+    let kth = convert_pyobj_into_kth(kth)?;
+    let array = convert_pyobj_into_operand(a)?;
+    py.allow_threads(|| webgpupy::argpartition(array.as_ref(), &kth, axis))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Perform an indirect stable sort using a sequence of keys
+#[pyfunction]
+#[pyo3(signature = (keys, axis=-1))]
+pub fn lexsort(
+    py: Python<'_>,
+    #[pyo3(from_py_with = "convert_pyobj_into_vec_ndarray")] keys: Vec<Bound<NdArrayPy>>,
+    axis: i32,
+) -> PyResult<NdArrayPy> {
+    let keys = keys.iter().map(|x| &x.get().ndarray).collect::<Vec<_>>();
+    py.allow_threads(|| webgpupy::lexsort(&keys, axis))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Find indices where elements should be inserted to maintain order
+#[pyfunction]
+#[pyo3(signature = (a, v, side="left", sorter=None))]
+pub fn searchsorted(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    v: &Bound<PyAny>,
+    side: &str,
+    sorter: Option<&Bound<PyAny>>,
+) -> PyResult<NdArrayPy> {
+    let side = match side {
+        "left" => webgpupy::Side::Left,
+        "right" => webgpupy::Side::Right,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "side must be 'left' or 'right' (got '{}')",
+                side
+            )))
+        }
+    };
+    let array = convert_pyobj_into_operand(a)?;
+    let values = convert_pyobj_into_operand(v)?;
+    let sorter = sorter.map(convert_pyobj_into_operand).transpose()?;
+    py.allow_threads(|| {
+        webgpupy::searchsorted(
+            array.as_ref(),
+            values.as_ref(),
+            side,
+            sorter.as_ref().map(|x| x.as_ref()),
+        )
+    })
+    .map(NdArrayPy::from)
+    .map_err(ndarray_error_into_pyerr)
+}
+
+pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(sort, m)?)?;
+    m.add_function(wrap_pyfunction!(argsort, m)?)?;
+    m.add_function(wrap_pyfunction!(partition, m)?)?;
+    m.add_function(wrap_pyfunction!(argpartition, m)?)?;
+    m.add_function(wrap_pyfunction!(lexsort, m)?)?;
+    m.add_function(wrap_pyfunction!(searchsorted, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use pyo3::{pyfunction, Python};
+use std::borrow::Cow;
+
+use crate::{
+    add_ufunc_nin1_nout1, add_ufunc_nin2_nout1, convert_pyobj_into_operand, impl_ufunc_nin1_nout1,
+    impl_ufunc_nin2_nout1,
+    ndarraypy::*,
+    types::{into_optional_dtypepy, DtypePy},
+    ufunc::Ufunc,
+};
+use pyo3::prelude::*;
+
+impl_ufunc_nin1_nout1!(_erf, webgpupy::erf);
+impl_ufunc_nin1_nout1!(_erfc, webgpupy::erfc);
+impl_ufunc_nin1_nout1!(_erfinv, webgpupy::erfinv);
+impl_ufunc_nin1_nout1!(_gamma, webgpupy::gamma);
+impl_ufunc_nin1_nout1!(_lgamma, webgpupy::lgamma);
+impl_ufunc_nin1_nout1!(_digamma, webgpupy::digamma);
+impl_ufunc_nin1_nout1!(_sinc, webgpupy::sinc);
+impl_ufunc_nin1_nout1!(_i0, webgpupy::i0);
+impl_ufunc_nin2_nout1!(_beta, webgpupy::beta);
+
+/// Registers `sinc` and `i0`, which numpy has in its main namespace
+pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(_sinc, m)?)?;
+    m.add_function(wrap_pyfunction!(_i0, m)?)?;
+    add_ufunc_nin1_nout1!(m, "sinc");
+    add_ufunc_nin1_nout1!(m, "i0");
+    Ok(())
+}
+
+pub fn special_module(py: Python, parent_module: &Bound<PyModule>) -> PyResult<()> {
+    let child_module = PyModule::new_bound(py, "special")?;
+    child_module.add_function(wrap_pyfunction_bound!(_erf, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction_bound!(_erfc, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction_bound!(_erfinv, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction_bound!(_gamma, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction_bound!(_lgamma, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction_bound!(_digamma, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction_bound!(_beta, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction_bound!(_sinc, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction_bound!(_i0, &child_module)?)?;
+    add_ufunc_nin1_nout1!(child_module, "erf");
+    add_ufunc_nin1_nout1!(child_module, "erfc");
+    add_ufunc_nin1_nout1!(child_module, "erfinv");
+    add_ufunc_nin1_nout1!(child_module, "gamma");
+    add_ufunc_nin1_nout1!(child_module, "lgamma");
+    add_ufunc_nin1_nout1!(child_module, "digamma");
+    add_ufunc_nin2_nout1!(child_module, "beta");
+    add_ufunc_nin1_nout1!(child_module, "sinc");
+    add_ufunc_nin1_nout1!(child_module, "i0");
+    parent_module.add_submodule(&child_module)?;
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use pyo3::{exceptions::PyValueError, prelude::*};
+use webgpupy::QuantileMethod;
+
+use crate::{
+    convert_pyobj_into_operand, convert_pyobj_into_option_operand, ndarray_error_into_pyerr,
+    ndarraypy::NdArrayPy, warn_runtime,
+};
+
+fn quantile_method(method: &str) -> PyResult<QuantileMethod> {
+    Ok(match method {
+        "inverted_cdf" => QuantileMethod::InvertedCdf,
+        "averaged_inverted_cdf" => QuantileMethod::AveragedInvertedCdf,
+        "closest_observation" => QuantileMethod::ClosestObservation,
+        "interpolated_inverted_cdf" => QuantileMethod::InterpolatedInvertedCdf,
+        "hazen" => QuantileMethod::Hazen,
+        "weibull" => QuantileMethod::Weibull,
+        "linear" => QuantileMethod::Linear,
+        "median_unbiased" => QuantileMethod::MedianUnbiased,
+        "normal_unbiased" => QuantileMethod::NormalUnbiased,
+        "lower" => QuantileMethod::Lower,
+        "higher" => QuantileMethod::Higher,
+        "nearest" => QuantileMethod::Nearest,
+        "midpoint" => QuantileMethod::Midpoint,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "'{}' is not a valid method",
+                method
+            )))
+        }
+    })
+}
+
+/// Compute the median along the specified axis
+#[pyfunction]
+#[pyo3(signature = (a, axis=None, keepdims=false))]
+pub fn median(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    axis: Option<i32>,
+    keepdims: bool,
+) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(a)?;
+    py.allow_threads(|| webgpupy::median(array.as_ref(), axis, keepdims))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Compute the q-th quantile of the data along the specified axis
+#[pyfunction]
+#[pyo3(signature = (a, q, axis=None, method="linear", keepdims=false))]
+pub fn quantile(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    q: &Bound<PyAny>,
+    axis: Option<i32>,
+    method: &str,
+    keepdims: bool,
+) -> PyResult<NdArrayPy> {
+    let method = quantile_method(method)?;
+    let array = convert_pyobj_into_operand(a)?;
+    let q = convert_pyobj_into_operand(q)?;
+    py.allow_threads(|| webgpupy::quantile(array.as_ref(), q.as_ref(), axis, method, keepdims))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Compute the q-th percentile of the data along the specified axis
+#[pyfunction]
+#[pyo3(signature = (a, q, axis=None, method="linear", keepdims=false))]
+pub fn percentile(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    q: &Bound<PyAny>,
+    axis: Option<i32>,
+    method: &str,
+    keepdims: bool,
+) -> PyResult<NdArrayPy> {
+    let method = quantile_method(method)?;
+    let array = convert_pyobj_into_operand(a)?;
+    let q = convert_pyobj_into_operand(q)?;
+    py.allow_threads(|| webgpupy::percentile(array.as_ref(), q.as_ref(), axis, method, keepdims))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
+
+/// Compute the weighted average along the specified axis
+#[pyfunction]
+#[pyo3(signature = (a, axis=None, weights=None, returned=false, keepdims=false))]
+pub fn average(
+    py: Python<'_>,
+    a: &Bound<PyAny>,
+    axis: Option<i32>,
+    weights: Option<&Bound<PyAny>>,
+    returned: bool,
+    keepdims: bool,
+) -> PyResult<PyObject> {
+    let array = convert_pyobj_into_operand(a)?;
+    let weights = weights.map(convert_pyobj_into_option_operand).transpose()?;
+    let weights = weights.flatten();
+    let (average, weight_sum) = py
+        .allow_threads(|| {
+            webgpupy::average(
+                array.as_ref(),
+                axis,
+                weights.as_ref().map(|x| x.as_ref()),
+                keepdims,
+            )
+        })
+        .map_err(ndarray_error_into_pyerr)?;
+    let average = NdArrayPy::from(average);
+    if returned {
+        Ok((average, NdArrayPy::from(weight_sum)).into_py(py))
+    } else {
+        Ok(average.into_py(py))
+    }
+}
+
+/// Estimate a covariance matrix, given data and weights
+#[pyfunction]
+#[pyo3(signature = (m, y=None, rowvar=true, bias=false, ddof=None))]
+pub fn cov(
+    py: Python<'_>,
+    m: &Bound<PyAny>,
+    y: Option<&Bound<PyAny>>,
+    rowvar: bool,
+    bias: bool,
+    ddof: Option<u32>,
+) -> PyResult<NdArrayPy> {
+    let m = convert_pyobj_into_operand(m)?;
+    let y = y.map(convert_pyobj_into_option_operand).transpose()?;
+    let y = y.flatten();
+    let covariance = py
+        .allow_threads(|| {
+            webgpupy::cov(
+                m.as_ref(),
+                y.as_ref().map(|x| x.as_ref()),
+                rowvar,
+                bias,
+                ddof,
+            )
+        })
+        .map_err(ndarray_error_into_pyerr)?;
+    warn_runtime(py, covariance.warning)?;
+    Ok(covariance.array.into())
+}
+
+/// Return Pearson product-moment correlation coefficients
+#[pyfunction]
+#[pyo3(signature = (x, y=None, rowvar=true))]
+pub fn corrcoef(
+    py: Python<'_>,
+    x: &Bound<PyAny>,
+    y: Option<&Bound<PyAny>>,
+    rowvar: bool,
+) -> PyResult<NdArrayPy> {
+    let x = convert_pyobj_into_operand(x)?;
+    let y = y.map(convert_pyobj_into_option_operand).transpose()?;
+    let y = y.flatten();
+    let correlation = py
+        .allow_threads(|| webgpupy::corrcoef(x.as_ref(), y.as_ref().map(|x| x.as_ref()), rowvar))
+        .map_err(ndarray_error_into_pyerr)?;
+    warn_runtime(py, correlation.warning)?;
+    Ok(correlation.array.into())
+}
+
+pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(median, m)?)?;
+    m.add_function(wrap_pyfunction!(quantile, m)?)?;
+    m.add_function(wrap_pyfunction!(percentile, m)?)?;
+    m.add_function(wrap_pyfunction!(average, m)?)?;
+    m.add_function(wrap_pyfunction!(cov, m)?)?;
+    m.add_function(wrap_pyfunction!(corrcoef, m)?)?;
+    Ok(())
+}
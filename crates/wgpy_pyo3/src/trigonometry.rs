@@ -2,7 +2,8 @@ use pyo3::{pyfunction, Python};
 use std::borrow::Cow;
 
 use crate::{
-    add_ufunc_nin1_nout1, convert_pyobj_into_operand, impl_ufunc_nin1_nout1,
+    add_ufunc_nin1_nout1, add_ufunc_nin2_nout1, convert_pyobj_into_operand, impl_ufunc_nin1_nout1,
+    impl_ufunc_nin2_nout1, ndarray_error_into_pyerr,
     ndarraypy::*,
     types::{into_optional_dtypepy, DtypePy},
     ufunc::Ufunc,
@@ -11,14 +12,76 @@ use pyo3::prelude::*;
 
 impl_ufunc_nin1_nout1!(_cos, webgpupy::cos);
 impl_ufunc_nin1_nout1!(_sin, webgpupy::sin);
+impl_ufunc_nin1_nout1!(_tan, webgpupy::tan);
 impl_ufunc_nin1_nout1!(_arccos, webgpupy::arccos);
+impl_ufunc_nin1_nout1!(_arcsin, webgpupy::arcsin);
+impl_ufunc_nin1_nout1!(_arctan, webgpupy::arctan);
+impl_ufunc_nin2_nout1!(_arctan2, webgpupy::arctan2);
+impl_ufunc_nin2_nout1!(_hypot, webgpupy::hypot);
+impl_ufunc_nin1_nout1!(_sinh, webgpupy::sinh);
+impl_ufunc_nin1_nout1!(_cosh, webgpupy::cosh);
+impl_ufunc_nin1_nout1!(_tanh, webgpupy::tanh);
+impl_ufunc_nin1_nout1!(_arcsinh, webgpupy::arcsinh);
+impl_ufunc_nin1_nout1!(_arccosh, webgpupy::arccosh);
+impl_ufunc_nin1_nout1!(_arctanh, webgpupy::arctanh);
+impl_ufunc_nin1_nout1!(_deg2rad, webgpupy::deg2rad);
+impl_ufunc_nin1_nout1!(_radians, webgpupy::radians);
+impl_ufunc_nin1_nout1!(_rad2deg, webgpupy::rad2deg);
+impl_ufunc_nin1_nout1!(_degrees, webgpupy::degrees);
+
+/// Unwraps by taking the complement of large deltas with respect to the period
+#[pyfunction]
+#[pyo3(signature = (p, discont=None, axis=-1, *, period=std::f32::consts::TAU))]
+pub fn unwrap(
+    py: Python<'_>,
+    p: &Bound<PyAny>,
+    discont: Option<f32>,
+    axis: i32,
+    period: f32,
+) -> PyResult<NdArrayPy> {
+    let array = convert_pyobj_into_operand(p)?;
+    py.allow_threads(|| webgpupy::unwrap(array.as_ref(), discont, axis, period))
+        .map(NdArrayPy::from)
+        .map_err(ndarray_error_into_pyerr)
+}
 
 pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(_sin, m)?)?;
     m.add_function(wrap_pyfunction!(_cos, m)?)?;
+    m.add_function(wrap_pyfunction!(_tan, m)?)?;
     m.add_function(wrap_pyfunction!(_arccos, m)?)?;
+    m.add_function(wrap_pyfunction!(_arcsin, m)?)?;
+    m.add_function(wrap_pyfunction!(_arctan, m)?)?;
+    m.add_function(wrap_pyfunction!(_arctan2, m)?)?;
+    m.add_function(wrap_pyfunction!(_hypot, m)?)?;
+    m.add_function(wrap_pyfunction!(_sinh, m)?)?;
+    m.add_function(wrap_pyfunction!(_cosh, m)?)?;
+    m.add_function(wrap_pyfunction!(_tanh, m)?)?;
+    m.add_function(wrap_pyfunction!(_arcsinh, m)?)?;
+    m.add_function(wrap_pyfunction!(_arccosh, m)?)?;
+    m.add_function(wrap_pyfunction!(_arctanh, m)?)?;
+    m.add_function(wrap_pyfunction!(_deg2rad, m)?)?;
+    m.add_function(wrap_pyfunction!(_radians, m)?)?;
+    m.add_function(wrap_pyfunction!(_rad2deg, m)?)?;
+    m.add_function(wrap_pyfunction!(_degrees, m)?)?;
+    m.add_function(wrap_pyfunction!(unwrap, m)?)?;
     add_ufunc_nin1_nout1!(m, "sin");
     add_ufunc_nin1_nout1!(m, "cos");
+    add_ufunc_nin1_nout1!(m, "tan");
     add_ufunc_nin1_nout1!(m, "arccos");
+    add_ufunc_nin1_nout1!(m, "arcsin");
+    add_ufunc_nin1_nout1!(m, "arctan");
+    add_ufunc_nin2_nout1!(m, "arctan2");
+    add_ufunc_nin2_nout1!(m, "hypot");
+    add_ufunc_nin1_nout1!(m, "sinh");
+    add_ufunc_nin1_nout1!(m, "cosh");
+    add_ufunc_nin1_nout1!(m, "tanh");
+    add_ufunc_nin1_nout1!(m, "arcsinh");
+    add_ufunc_nin1_nout1!(m, "arccosh");
+    add_ufunc_nin1_nout1!(m, "arctanh");
+    add_ufunc_nin1_nout1!(m, "deg2rad");
+    add_ufunc_nin1_nout1!(m, "radians");
+    add_ufunc_nin1_nout1!(m, "rad2deg");
+    add_ufunc_nin1_nout1!(m, "degrees");
     Ok(())
 }
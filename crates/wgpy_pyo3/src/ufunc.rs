@@ -72,6 +72,28 @@ macro_rules! impl_ufunc_nin2_nout1 {
     };
 }
 
+#[macro_export]
+macro_rules! impl_ufunc_nin2_nout2 {
+    ($name: ident, $fn_name: expr) => {
+        #[pyfunction]
+        #[pyo3(signature = (x, y, /, *, r#where = None, dtype=None))]
+        pub fn $name<'a>(
+            py: Python<'_>,
+            x: &'a Bound<'a, PyAny>,
+            y: &'a Bound<'a, PyAny>,
+            r#where: Option<&NdArrayPy>,
+            #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+        ) -> (NdArrayPy, NdArrayPy) {
+            let x = convert_pyobj_into_operand(x).unwrap();
+            let y = convert_pyobj_into_operand(y).unwrap();
+            let where_ = r#where.map(|x| x.into());
+            let dtype = dtype.map(|x| x.as_ref().dtype);
+            let (out1, out2) = py.allow_threads(|| $fn_name(x.as_ref(), y.as_ref(), where_, dtype));
+            (out1.into(), out2.into())
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! impl_ufunc_nin1_nout1 {
     ($name: ident, $fn_name: expr) => {
@@ -91,6 +113,52 @@ macro_rules! impl_ufunc_nin1_nout1 {
     };
 }
 
+/// Binding of a ufunc returning a result, its errors are raised as python exceptions
+#[macro_export]
+macro_rules! impl_checked_ufunc_nin2_nout1 {
+    ($name: ident, $fn_name: expr) => {
+        #[pyfunction]
+        #[pyo3(signature = (x, y, /, *, r#where = None, dtype=None))]
+        pub fn $name<'a>(
+            py: Python<'_>,
+            x: &'a Bound<'a, PyAny>,
+            y: &'a Bound<'a, PyAny>,
+            r#where: Option<&NdArrayPy>,
+            #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+        ) -> PyResult<NdArrayPy> {
+            let x = convert_pyobj_into_operand(x)?;
+            let y = convert_pyobj_into_operand(y)?;
+            let where_ = r#where.map(|x| x.into());
+            let dtype = dtype.map(|x| x.as_ref().dtype);
+            py.allow_threads(|| $fn_name(x.as_ref(), y.as_ref(), where_, dtype))
+                .map(NdArrayPy::from)
+                .map_err($crate::ndarray_error_into_pyerr)
+        }
+    };
+}
+
+/// Binding of a ufunc returning a result, its errors are raised as python exceptions
+#[macro_export]
+macro_rules! impl_checked_ufunc_nin1_nout1 {
+    ($name: ident, $fn_name: expr) => {
+        #[pyfunction]
+        #[pyo3(signature = (x, /, *, r#where = None, dtype=None))]
+        pub fn $name(
+            py: Python<'_>,
+            x: &Bound<PyAny>,
+            r#where: Option<&NdArrayPy>,
+            #[pyo3(from_py_with = "into_optional_dtypepy")] dtype: Option<Cow<DtypePy>>,
+        ) -> PyResult<NdArrayPy> {
+            let data = convert_pyobj_into_operand(x)?;
+            let where_ = r#where.map(|x| x.into());
+            let dtype = dtype.map(|x| x.as_ref().dtype);
+            py.allow_threads(|| $fn_name(data.as_ref(), where_, dtype))
+                .map(NdArrayPy::from)
+                .map_err($crate::ndarray_error_into_pyerr)
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! add_ufunc_nin1_nout1 {
     ($m: ident, $name: literal) => {
@@ -117,7 +185,7 @@ macro_rules! add_ufunc_nin2_nout1 {
         let py_fn = $m.getattr(concat!("_", $name))?.into();
         let ufunc_function = Ufunc {
             function_name: $name,
-            doc_string_path: "",
+            doc_string_path: concat!("python_doc/", $name, ".rst"),
             nin: 2,
             nout: 1,
             ntypes: 1,
@@ -131,6 +199,26 @@ macro_rules! add_ufunc_nin2_nout1 {
     };
 }
 
+#[macro_export]
+macro_rules! add_ufunc_nin2_nout2 {
+    ($m: ident, $name: literal) => {
+        let py_fn = $m.getattr(concat!("_", $name))?.into();
+        let ufunc_function = Ufunc {
+            function_name: $name,
+            doc_string_path: concat!("python_doc/", $name, ".rst"),
+            nin: 2,
+            nout: 2,
+            ntypes: 1,
+            py_func: py_fn,
+        };
+        let ufunc_py = Python::with_gil(|py| {
+            let ufunc_py_function = Py::new(py, ufunc_function)?;
+            PyResult::Ok(ufunc_py_function)
+        })?;
+        $m.add($name, ufunc_py)?;
+    };
+}
+
 pub fn create_py_items(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<Ufunc>()?;
     Ok(())
@@ -45,3 +45,44 @@ def test_sub_f32(wp_array, np_array):
 
 def test_rsub_f32(wp_array, np_array):
     almost_equals(10.0 - wp_array, 10 - np_array)
+
+
+@pytest.fixture
+def int_operands():
+    return [7, -7, 7, -7, 0], [2, 2, -2, -2, 3]
+
+
+@pytest.mark.parametrize("fn", ["floor_divide", "remainder", "mod", "fmod"])
+def test_integer_division(int_operands, fn):
+    x1, x2 = int_operands
+    result = getattr(wp, fn)(wp.array(x1), wp.array(x2))
+    assert result.tolist() == getattr(np, fn)(np.array(x1), np.array(x2)).tolist()
+
+
+@pytest.mark.parametrize("fn", ["floor_divide", "remainder", "fmod"])
+def test_float_division(fn):
+    x1 = [7.5, -7.5, 1.0, -3.0]
+    x2 = [2.0, 2.0, -0.25, 0.5]
+    almost_equals(
+        getattr(wp, fn)(wp.array(x1), wp.array(x2)),
+        getattr(np, fn)(np.array(x1), np.array(x2)),
+        decimal=5,
+    )
+
+
+def test_divmod(int_operands):
+    x1, x2 = int_operands
+    quotient, remainder = wp.divmod(wp.array(x1), wp.array(x2))
+    np_quotient, np_remainder = np.divmod(np.array(x1), np.array(x2))
+    assert quotient.tolist() == np_quotient.tolist()
+    assert remainder.tolist() == np_remainder.tolist()
+
+
+def test_floordiv_mod_operators(wp_array, np_array):
+    almost_equals(wp_array // 3.0, np_array // 3)
+    almost_equals(10.0 // wp_array, 10 // np_array)
+    almost_equals(wp_array % 3.0, np_array % 3)
+    almost_equals(10.0 % wp_array, 10 % np_array)
+    quotient, remainder = divmod(wp_array, 3.0)
+    almost_equals(quotient, np_array // 3)
+    almost_equals(remainder, np_array % 3)
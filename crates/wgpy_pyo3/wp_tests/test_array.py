@@ -56,3 +56,29 @@ def test_braodcast_to():
     np_array = np.broadcast_to(bool_array, new_shape)
     wp_array = wp.broadcast_to(wp.array(bool_array), new_shape)
     almost_equals(wp_array, np_array)
+
+
+def test_zero_dim():
+    arr = wp.array(2.5)
+    assert arr.shape == []
+    assert arr.ndim == 0
+    assert arr.size == 1
+    assert arr.tolist() == 2.5
+    assert arr.item() == 2.5
+    assert float(arr) == 2.5
+    assert int(arr) == 2
+    assert bool(arr)
+    assert (arr + 1.0).tolist() == 3.5
+    with pytest.raises(ValueError):
+        wp.array([1.0, 2.0]).item()
+
+
+def test_zero_size(wp_array):
+    empty = wp.array([])
+    assert empty.shape == [0]
+    assert empty.size == 0
+    assert empty.tolist() == []
+    assert (empty + 1.0).tolist() == []
+    assert wp.zeros([2, 0]).tolist() == [[], []]
+    assert wp_array[2:2].shape == [0, 1]
+    assert wp.repeat(wp_array, 0).tolist() == []
@@ -44,3 +44,91 @@ def test_bitwise_or_u32(wp_array_1, wp_array_2, np_array_1, np_array_2):
 
 def test_invert(wp_array_1, np_array_1):
     assert_values_nin1(wp_array_1, np_array_1, "invert")
+
+
+def test_bitwise_xor(wp_array_1, wp_array_2, np_array_1, np_array_2):
+    assert_values_nin2(wp_array_1, wp_array_2, np_array_1, np_array_2, "bitwise_xor")
+
+
+def test_left_shift(wp_array_1, wp_array_2, np_array_1, np_array_2):
+    assert_values_nin2(wp_array_1, wp_array_2, np_array_1, np_array_2, "left_shift")
+
+
+def test_right_shift(wp_array_1, wp_array_2, np_array_1, np_array_2):
+    assert_values_nin2(wp_array_1, wp_array_2, np_array_1, np_array_2, "right_shift")
+
+
+@pytest.mark.parametrize("dtype", ["int8", "uint8", "int16", "uint16", "int32", "uint32"])
+def test_shift_operators(dtype):
+    data = [1, 5, 10, 7]
+    shifts = [0, 1, 3, 2]
+    wp_array = wp.array(data, dtype=dtype)
+    wp_shifts = wp.array(shifts, dtype=dtype)
+    np_array = np.array(data, dtype=dtype)
+    np_shifts = np.array(shifts, dtype=dtype)
+    assert (wp_array << wp_shifts).tolist() == (np_array << np_shifts).tolist()
+    assert (wp_array >> wp_shifts).tolist() == (np_array >> np_shifts).tolist()
+    assert (wp_array ^ wp_shifts).tolist() == (np_array ^ np_shifts).tolist()
+
+
+def test_right_shift_negative():
+    data = [-1, -16, -7, 9]
+    assert (wp.array(data) >> wp.array([1, 2, 1, 40])).tolist() == [-1, -4, -4, 0]
+
+
+def test_xor_bool():
+    x1 = [True, True, False, False]
+    x2 = [True, False, True, False]
+    assert (wp.array(x1) ^ wp.array(x2)).tolist() == [False, True, True, False]
+
+
+def test_bitwise_count():
+    data = [0, 1, -8, 255, -2147483647]
+    result = wp.bitwise_count(wp.array(data, dtype="int32"))
+    assert result.tolist() == [bin(abs(x)).count("1") for x in data]
+
+
+def test_integer_ufuncs_reject_floats():
+    floats = wp.array([1.5, 2.0])
+    integers = wp.array([1, 2], dtype="int32")
+    with pytest.raises(TypeError):
+        wp.bitwise_xor(floats, integers)
+    with pytest.raises(TypeError):
+        wp.left_shift(integers, floats)
+    with pytest.raises(TypeError):
+        floats >> integers
+    with pytest.raises(TypeError):
+        wp.bitwise_count(floats)
+
+
+@pytest.mark.parametrize("bitorder", ["big", "little"])
+@pytest.mark.parametrize("axis", [None, 0, 1, -1])
+def test_packbits(axis, bitorder):
+    data = [[1, 0, 1, 1, 0, 0, 0, 1, 1, 1], [0, 0, 4, 0, 1, 0, 1, 0, 0, 1]]
+    expected = np.packbits(np.array(data), axis=axis, bitorder=bitorder)
+    result = wp.packbits(wp.array(data), axis=axis, bitorder=bitorder)
+    assert result.shape == expected.shape
+    assert result.tolist() == expected.tolist()
+
+
+@pytest.mark.parametrize("bitorder", ["big", "little"])
+@pytest.mark.parametrize("count", [None, 3, -3, 20])
+def test_unpackbits(count, bitorder):
+    data = [[177, 3], [64, 255]]
+    expected = np.unpackbits(np.array(data, dtype=np.uint8), axis=1, count=count, bitorder=bitorder)
+    result = wp.unpackbits(wp.array(data, dtype="uint8"), axis=1, count=count, bitorder=bitorder)
+    assert result.tolist() == expected.tolist()
+
+
+def test_unpackbits_flattened():
+    data = [[177, 3], [64, 255]]
+    expected = np.unpackbits(np.array(data, dtype=np.uint8))
+    result = wp.unpackbits(wp.array(data, dtype="uint8"))
+    assert result.tolist() == expected.tolist()
+
+
+def test_unpackbits_invalid():
+    with pytest.raises(TypeError):
+        wp.unpackbits(wp.array([1, 2]))
+    with pytest.raises(ValueError):
+        wp.packbits(wp.array([1, 2]), bitorder="middle")
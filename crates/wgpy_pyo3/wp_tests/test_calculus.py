@@ -0,0 +1,119 @@
+import numpy as np
+import webgpupy as wp
+import pytest
+
+
+@pytest.fixture
+def input_array():
+    return [[1.0, 2.0, 4.0, 7.0, 11.0], [16.0, 3.0, 5.0, 0.5, 2.0], [9.0, 8.0, 1.0, 6.0, 4.0]]
+
+
+@pytest.mark.parametrize("n", [0, 1, 2, 4, 6])
+@pytest.mark.parametrize("axis", [0, 1, -1])
+def test_diff(input_array, n, axis):
+    wp_result = wp.diff(wp.array(input_array), n=n, axis=axis)
+    np_result = np.diff(np.array(input_array, dtype=np.float32), n=n, axis=axis)
+    assert wp_result.shape == list(np_result.shape)
+    assert np.allclose(wp_result.tolist(), np_result)
+
+
+def test_diff_prepend_append(input_array):
+    wp_result = wp.diff(wp.array(input_array), prepend=0.0, append=wp.array([[1.0], [2.0], [3.0]]))
+    np_result = np.diff(np.array(input_array), prepend=0.0, append=[[1.0], [2.0], [3.0]])
+    assert np.allclose(wp_result.tolist(), np_result)
+
+    wp_result = wp.diff(wp.array([1, 3, 2, 8]), n=2)
+    assert repr(wp_result.dtype) == repr(wp.DtypePy("int32"))
+    assert wp_result.tolist() == np.diff([1, 3, 2, 8], n=2).tolist()
+
+    with pytest.raises(ValueError):
+        wp.diff(wp.array(1.0))
+
+
+def test_ediff1d(input_array):
+    wp_result = wp.ediff1d(wp.array(input_array), to_end=wp.array([8.0, 9.0]), to_begin=-1.0)
+    np_result = np.ediff1d(np.array(input_array), to_end=[8.0, 9.0], to_begin=-1.0)
+    assert np.allclose(wp_result.tolist(), np_result)
+
+    with pytest.raises(TypeError):
+        wp.ediff1d(wp.array([1, 2, 4]), to_begin=0.5)
+
+
+@pytest.mark.parametrize("edge_order", [1, 2])
+def test_gradient(input_array, edge_order):
+    wp_result = wp.gradient(wp.array(input_array), edge_order=edge_order)
+    np_result = np.gradient(np.array(input_array), edge_order=edge_order)
+    assert len(wp_result) == 2
+    for wp_gradient, np_gradient in zip(wp_result, np_result):
+        assert np.allclose(wp_gradient.tolist(), np_gradient)
+
+
+@pytest.mark.parametrize("edge_order", [1, 2])
+def test_gradient_spacing(input_array, edge_order):
+    x = [0.0, 0.5, 2.0, 2.5, 4.0]
+    wp_result = wp.gradient(wp.array(input_array), 2.0, wp.array(x), edge_order=edge_order)
+    np_result = np.gradient(np.array(input_array), 2.0, x, edge_order=edge_order)
+    for wp_gradient, np_gradient in zip(wp_result, np_result):
+        assert np.allclose(wp_gradient.tolist(), np_gradient, rtol=1e-4)
+
+    wp_result = wp.gradient(wp.array(input_array), wp.array(x), axis=1)
+    np_result = np.gradient(np.array(input_array), x, axis=1)
+    assert np.allclose(wp_result.tolist(), np_result)
+
+    with pytest.raises(ValueError):
+        wp.gradient(wp.array(input_array), wp.array([0.0, 1.0]), axis=1)
+    with pytest.raises(ValueError):
+        wp.gradient(wp.array([1.0, 2.0]), edge_order=2)
+
+
+@pytest.mark.parametrize("axis", [0, 1, -1])
+def test_trapezoid(input_array, axis):
+    wp_result = wp.trapezoid(wp.array(input_array), dx=0.5, axis=axis)
+    np_result = np.trapezoid(np.array(input_array), dx=0.5, axis=axis)
+    assert wp_result.shape == list(np_result.shape)
+    assert np.allclose(wp_result.tolist(), np_result)
+
+
+def test_trapezoid_x(input_array):
+    x = [0.0, 0.5, 2.0, 2.5, 4.0]
+    wp_result = wp.trapezoid(wp.array(input_array), wp.array(x))
+    np_result = np.trapezoid(np.array(input_array), x)
+    assert np.allclose(wp_result.tolist(), np_result)
+
+    x = [[0.0, 1.0, 2.0, 3.0, 5.0], [0.0, 0.5, 2.0, 2.5, 4.0], [1.0, 2.0, 3.0, 3.5, 4.5]]
+    wp_result = wp.trapezoid(wp.array(input_array), wp.array(x))
+    np_result = np.trapezoid(np.array(input_array), x)
+    assert np.allclose(wp_result.tolist(), np_result)
+
+    wp_result = wp.trapezoid(wp.array([input_array, input_array]), wp.array(x))
+    np_result = np.trapezoid(np.array([input_array, input_array]), x)
+    assert wp_result.shape == list(np_result.shape)
+    assert np.allclose(wp_result.tolist(), np_result)
+
+    with pytest.raises(ValueError):
+        wp.trapezoid(wp.array(input_array), wp.array([0.0, 1.0]))
+
+
+def cumulative_trapezoid_reference(y, x=None, dx=1.0, axis=-1, initial=None):
+    # Same as scipy.integrate.cumulative_trapezoid, which is not a test dependency
+    y = np.moveaxis(np.array(y), axis, -1)
+    d = np.diff(x) if x is not None else dx
+    result = np.cumsum(d * (y[..., 1:] + y[..., :-1]) / 2.0, axis=-1)
+    if initial is not None:
+        start = np.full(result.shape[:-1] + (1,), initial)
+        result = np.concatenate([start, result], axis=-1)
+    return np.moveaxis(result, -1, axis)
+
+
+@pytest.mark.parametrize("axis", [0, 1])
+@pytest.mark.parametrize("initial", [None, 0.0])
+def test_cumulative_trapezoid(input_array, axis, initial):
+    wp_result = wp.cumulative_trapezoid(wp.array(input_array), dx=2.0, axis=axis, initial=initial)
+    np_result = cumulative_trapezoid_reference(input_array, dx=2.0, axis=axis, initial=initial)
+    assert wp_result.shape == list(np_result.shape)
+    assert np.allclose(wp_result.tolist(), np_result)
+
+    x = [0.0, 0.5, 2.0, 2.5, 4.0]
+    wp_result = wp.cumulative_trapezoid(wp.array(input_array), wp.array(x), initial=initial)
+    np_result = cumulative_trapezoid_reference(input_array, x, initial=initial)
+    assert np.allclose(wp_result.tolist(), np_result)
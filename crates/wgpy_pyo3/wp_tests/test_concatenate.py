@@ -0,0 +1,66 @@
+import webgpupy as wp
+import pytest
+import numpy as np
+from test_utils import almost_equals
+
+
+@pytest.fixture
+def input_arrays():
+    return [[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]]
+
+
+@pytest.mark.parametrize("axis", [0, 1, -1, None])
+def test_concatenate(input_arrays, axis):
+    wp_arrays = [wp.array(x) for x in input_arrays]
+    np_arrays = [np.array(x) for x in input_arrays]
+    almost_equals(
+        wp.concatenate(wp_arrays, axis=axis), np.concatenate(np_arrays, axis=axis)
+    )
+
+
+def test_concatenate_errors():
+    with pytest.raises(ValueError):
+        wp.concatenate([wp.array([[1.0, 2.0]]), wp.array([[1.0]])])
+    with pytest.raises(ValueError):
+        wp.concatenate([wp.array([1.0]), wp.array([2.0])], axis=1)
+
+
+@pytest.mark.parametrize("axis", [0, 1, 2, -1])
+def test_stack(input_arrays, axis):
+    wp_arrays = [wp.array(x) for x in input_arrays]
+    np_arrays = [np.array(x) for x in input_arrays]
+    almost_equals(wp.stack(wp_arrays, axis=axis), np.stack(np_arrays, axis=axis))
+
+
+@pytest.mark.parametrize(
+    "fn", ["hstack", "vstack", "dstack", "column_stack"]
+)
+@pytest.mark.parametrize(
+    "inputs", [[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], [[[1.0], [2.0]], [[3.0], [4.0]]]]
+)
+def test_stack_helpers(fn, inputs):
+    wp_result = getattr(wp, fn)([wp.array(x) for x in inputs])
+    np_result = getattr(np, fn)([np.array(x) for x in inputs])
+    assert wp_result.shape == list(np_result.shape)
+    almost_equals(wp_result, np_result)
+
+
+def test_append():
+    almost_equals(
+        wp.append(wp.array([[1, 2], [3, 4]]), wp.array([[5, 6]]), axis=0),
+        np.append(np.array([[1, 2], [3, 4]]), np.array([[5, 6]]), axis=0),
+    )
+    almost_equals(
+        wp.append(wp.array([[1, 2], [3, 4]]), wp.array([5.5])),
+        np.append(np.array([[1, 2], [3, 4]]), np.array([5.5])),
+    )
+
+
+def test_block():
+    a = [[1.0, 2.0], [3.0, 4.0]]
+    b = [[5.0], [6.0]]
+    c = [7.0, 8.0, 9.0]
+    wp_result = wp.block([[wp.array(a), wp.array(b)], [wp.array(c)]])
+    np_result = np.block([[np.array(a), np.array(b)], [np.array(c)]])
+    assert wp_result.shape == list(np_result.shape)
+    almost_equals(wp_result, np_result)
@@ -0,0 +1,78 @@
+import numpy as np
+import webgpupy as wp
+import pytest
+
+
+@pytest.mark.parametrize("mode", ["full", "same", "valid"])
+@pytest.mark.parametrize("lengths", [(7, 3), (6, 4), (3, 6)])
+def test_convolve(mode, lengths):
+    a = [float(x * x % 5) - 1.5 for x in range(lengths[0])]
+    v = [0.5, -1.0, 2.0, 0.25, 1.0, -0.5][: lengths[1]]
+    wp_result = wp.convolve(wp.array(a), wp.array(v), mode=mode)
+    np_result = np.convolve(a, v, mode=mode)
+    assert np.allclose(wp_result.tolist(), np_result)
+
+
+@pytest.mark.parametrize("mode", ["full", "same", "valid"])
+@pytest.mark.parametrize("lengths", [(7, 3), (6, 4), (3, 6), (2, 5)])
+def test_correlate(mode, lengths):
+    a = [float(x * x % 5) - 1.5 for x in range(lengths[0])]
+    v = [0.5, -1.0, 2.0, 0.25, 1.0, -0.5][: lengths[1]]
+    wp_result = wp.correlate(wp.array(a), wp.array(v), mode=mode)
+    np_result = np.correlate(a, v, mode=mode)
+    assert np.allclose(wp_result.tolist(), np_result)
+
+
+def test_convolve_ints():
+    wp_result = wp.convolve(wp.array([1, 2, 3]), wp.array([0, 1, 2]))
+    assert repr(wp_result.dtype) == repr(wp.DtypePy("int32"))
+    assert wp_result.tolist() == np.convolve([1, 2, 3], [0, 1, 2]).tolist()
+
+    with pytest.raises(ValueError):
+        wp.convolve(wp.array([1, 2, 3]), wp.array([0, 1]), mode="middle")
+
+
+def correlate_nd_reference(data, weights, mode, cval, origin, flip):
+    # Same as scipy.ndimage.correlate and convolve, which are not a test dependency
+    data = np.array(data, dtype=np.float64)
+    weights = np.array(weights, dtype=np.float64)
+    if flip:
+        weights = weights[::-1, ::-1]
+    pad_width = []
+    for length in weights.shape:
+        center = length // 2 + origin
+        before = length - 1 - center if flip else center
+        pad_width.append((before, length - 1 - before))
+    np_mode = {"reflect": "symmetric", "nearest": "edge", "mirror": "reflect", "wrap": "wrap"}
+    if mode == "constant":
+        padded = np.pad(data, pad_width, mode="constant", constant_values=cval)
+    else:
+        padded = np.pad(data, pad_width, mode=np_mode[mode])
+    result = np.zeros_like(data)
+    for i in range(data.shape[0]):
+        for j in range(data.shape[1]):
+            window = padded[i : i + weights.shape[0], j : j + weights.shape[1]]
+            result[i, j] = (window * weights).sum()
+    return result
+
+
+@pytest.mark.parametrize("mode", ["reflect", "constant", "nearest", "mirror", "wrap"])
+@pytest.mark.parametrize("flip", [False, True])
+def test_correlate_nd(mode, flip):
+    data = [[float((i * 7 + j * 3) % 11) for j in range(6)] for i in range(5)]
+    weights = [[0.0, 1.0, 0.5], [1.0, -4.0, 1.0], [0.25, 1.0, 0.0], [0.5, 0.0, 0.5]]
+    function = wp.convolve_nd if flip else wp.correlate_nd
+    wp_result = function(wp.array(data), wp.array(weights), mode=mode, cval=2.0, origin=1)
+    np_result = correlate_nd_reference(data, weights, mode, 2.0, 1, flip)
+    assert wp_result.shape == list(np_result.shape)
+    assert np.allclose(wp_result.tolist(), np_result)
+
+
+def test_correlate_nd_errors():
+    data = wp.array([[1.0, 2.0], [3.0, 4.0]])
+    with pytest.raises(ValueError):
+        wp.correlate_nd(data, wp.array([1.0, 2.0]))
+    with pytest.raises(ValueError):
+        wp.correlate_nd(data, wp.array([[1.0, 2.0, 3.0]]), origin=[0, 2])
+    with pytest.raises(ValueError):
+        wp.correlate_nd(data, data, mode="grid-wrap")
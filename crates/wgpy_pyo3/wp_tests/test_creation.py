@@ -0,0 +1,172 @@
+import webgpupy as wp
+import pytest
+import numpy as np
+from test_utils import almost_equals
+
+
+@pytest.mark.parametrize(
+    "start,stop,num,endpoint",
+    [(0, 1, 5, True), (0, 1, 5, False), (-3.5, 10, 50, True), (2, 3, 1, True), (1, 0, 7, True)],
+)
+def test_linspace(start, stop, num, endpoint):
+    almost_equals(
+        wp.linspace(start, stop, num, endpoint=endpoint),
+        np.linspace(start, stop, num, endpoint=endpoint, dtype=np.float32),
+    )
+
+
+def test_linspace_retstep():
+    wp_result, wp_step = wp.linspace(2.0, 3.0, 5, retstep=True)
+    np_result, np_step = np.linspace(2.0, 3.0, 5, retstep=True)
+    almost_equals(wp_result, np_result)
+    assert wp_step == pytest.approx(np_step)
+
+
+@pytest.mark.parametrize(
+    "start,stop,num,endpoint,base",
+    [(0, 3, 4, True, 10.0), (0, 3, 4, False, 10.0), (1, 5, 5, True, 2.0)],
+)
+def test_logspace(start, stop, num, endpoint, base):
+    almost_equals(
+        wp.logspace(start, stop, num, endpoint=endpoint, base=base),
+        np.logspace(start, stop, num, endpoint=endpoint, base=base, dtype=np.float32),
+    )
+
+
+@pytest.mark.parametrize(
+    "start,stop,num,endpoint", [(1, 1000, 4, True), (-1, -256, 9, False), (8, 2, 3, True)]
+)
+def test_geomspace(start, stop, num, endpoint):
+    almost_equals(
+        wp.geomspace(start, stop, num, endpoint=endpoint),
+        np.geomspace(start, stop, num, endpoint=endpoint, dtype=np.float32),
+    )
+
+
+def test_geomspace_error():
+    with pytest.raises(ValueError):
+        wp.geomspace(0, 10)
+
+
+@pytest.mark.parametrize("N,M,k", [(3, None, 0), (2, 4, 1), (4, 3, -2), (3, 3, 5)])
+def test_eye_tri(N, M, k):
+    almost_equals(wp.eye(N, M, k), np.eye(N, M, k, dtype=np.float32))
+    almost_equals(wp.tri(N, M, k), np.tri(N, M, k, dtype=np.float32))
+
+
+def test_identity():
+    almost_equals(wp.identity(4), np.identity(4, dtype=np.float32))
+
+
+@pytest.mark.parametrize("k", [-1, 0, 2])
+@pytest.mark.parametrize("shape", [[4], [3, 4], [2, 4, 3]])
+def test_tril_triu(shape, k):
+    data = np.arange(np.prod(shape), dtype=np.float32).reshape(shape)
+    almost_equals(wp.tril(wp.array(data.tolist()), k), np.tril(data, k))
+    almost_equals(wp.triu(wp.array(data.tolist()), k), np.triu(data, k))
+
+
+@pytest.mark.parametrize("k", [-2, 0, 1, 4])
+@pytest.mark.parametrize("shape", [[3], [3, 4], [4, 2]])
+def test_diag(shape, k):
+    data = np.arange(np.prod(shape), dtype=np.float32).reshape(shape)
+    wp_result = wp.diag(wp.array(data.tolist()), k)
+    np_result = np.diag(data, k)
+    assert wp_result.shape == list(np_result.shape)
+    almost_equals(wp_result, np_result)
+
+
+@pytest.mark.parametrize("indexing", ["xy", "ij"])
+@pytest.mark.parametrize("sparse", [False, True])
+def test_meshgrid(indexing, sparse):
+    x = np.arange(3, dtype=np.float32)
+    y = np.arange(4, dtype=np.float32) * 2
+    z = np.arange(2, dtype=np.float32) - 1
+    wp_result = wp.meshgrid(
+        wp.array(x.tolist()),
+        wp.array(y.tolist()),
+        wp.array(z.tolist()),
+        indexing=indexing,
+        sparse=sparse,
+    )
+    np_result = np.meshgrid(x, y, z, indexing=indexing, sparse=sparse)
+    for wp_arr, np_arr in zip(wp_result, np_result):
+        assert wp_arr.shape == list(np_arr.shape)
+        almost_equals(wp_arr, np_arr)
+
+
+@pytest.mark.parametrize("dimensions", [(2, 3), (3,), (2, 1, 4)])
+def test_indices(dimensions):
+    wp_result = wp.indices(dimensions)
+    np_result = np.indices(dimensions)
+    assert wp_result.shape == list(np_result.shape)
+    almost_equals(wp_result, np_result)
+
+
+@pytest.mark.parametrize(
+    "args",
+    [(10,), (2, 10), (3, 10, 2), (10, 0, -1), (10, 0, -3), (5, 1), (-1.0, 1.0, 0.25), (0, 1, 0.3)],
+)
+def test_arange(args):
+    wp_result = wp.arange(*args)
+    np_result = np.arange(*args)
+    assert wp_result.shape == list(np_result.shape)
+    almost_equals(wp_result, np_result)
+
+
+@pytest.mark.parametrize("dtype", ["float32", "int8", "uint32", "int16"])
+def test_arange_dtype(dtype):
+    wp_result = wp.arange(0, 6, 2, dtype=dtype)
+    np_result = np.arange(0, 6, 2, dtype=dtype)
+    assert repr(wp_result.dtype) == repr(wp.DtypePy(dtype))
+    almost_equals(wp_result, np_result)
+
+
+def test_arange_zero_step():
+    with pytest.raises(ValueError):
+        wp.arange(0, 10, 0)
+
+
+@pytest.mark.parametrize("fill", [[1.0, 2.0, 3.0], [[1.0], [2.0]], [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]])
+def test_full_array_fill(fill):
+    almost_equals(wp.full([2, 3], fill), np.full([2, 3], fill, dtype=np.float32))
+    almost_equals(wp.full([2, 3], wp.array(fill)), np.full([2, 3], fill, dtype=np.float32))
+
+
+def test_full_array_fill_error():
+    with pytest.raises(ValueError):
+        wp.full([2, 2], wp.array([1.0, 2.0, 3.0]))
+
+
+@pytest.mark.parametrize("func", ["zeros_like", "ones_like"])
+@pytest.mark.parametrize("dtype", [None, "float32", "int16"])
+def test_like(func, dtype):
+    data = np.arange(6, dtype=np.int32).reshape([2, 3])
+    wp_result = getattr(wp, func)(wp.array(data.tolist()), dtype=dtype)
+    np_result = getattr(np, func)(data, dtype=dtype)
+    assert wp_result.shape == list(np_result.shape)
+    almost_equals(wp_result, np_result)
+
+
+def test_full_like():
+    data = np.arange(6, dtype=np.int32).reshape([2, 3])
+    almost_equals(wp.full_like(wp.array(data.tolist()), 7), np.full_like(data, 7))
+    almost_equals(
+        wp.full_like(wp.array(data.tolist()), wp.array([1, 2, 3])),
+        np.full_like(data, [1, 2, 3]),
+    )
+
+
+def test_empty():
+    wp_result = wp.empty([3, 4], dtype="int32")
+    assert wp_result.shape == [3, 4]
+    assert repr(wp_result.dtype) == repr(wp.DtypePy("int32"))
+    wp_result = wp.empty_like(wp.array([[1.0, 2.0]]))
+    assert wp_result.shape == [1, 2]
+    assert wp.empty([0, 2]).shape == [0, 2]
+
+
+def test_copy():
+    wp_array = wp.array([[1.0, 2.0], [3.0, 4.0]])
+    almost_equals(wp.copy(wp_array), np.array([[1.0, 2.0], [3.0, 4.0]]))
+    almost_equals(wp_array.copy(), np.array([[1.0, 2.0], [3.0, 4.0]]))
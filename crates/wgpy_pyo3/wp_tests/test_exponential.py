@@ -0,0 +1,68 @@
+import numpy as np
+import webgpupy as wp
+import pytest
+from test_utils import almost_equals
+
+
+@pytest.fixture
+def input_array_1():
+    return [0.0, 1e-6, 0.5, 1.0, 2.5]
+
+
+@pytest.fixture
+def input_array_2():
+    return [0.0, -1.0, 2.0, 1.0, 30.0]
+
+
+@pytest.fixture
+def wp_array_1(input_array_1):
+    return wp.array(input_array_1)
+
+
+@pytest.fixture
+def wp_array_2(input_array_2):
+    return wp.array(input_array_2)
+
+
+@pytest.fixture
+def np_array_1(input_array_1):
+    return np.array(input_array_1, dtype=np.float32)
+
+
+@pytest.fixture
+def np_array_2(input_array_2):
+    return np.array(input_array_2, dtype=np.float32)
+
+
+@pytest.mark.parametrize("fn", ["exp", "exp2", "expm1"])
+def test_exponents(wp_array_1, np_array_1, fn):
+    almost_equals(getattr(wp, fn)(wp_array_1), getattr(np, fn)(np_array_1), decimal=4)
+
+
+@pytest.mark.parametrize("fn", ["log", "log2", "log10", "log1p"])
+def test_logarithms(wp_array_1, np_array_1, fn):
+    # zero is left out as the logarithm of it is -inf
+    almost_equals(
+        getattr(wp, fn)(wp_array_1[1:]), getattr(np, fn)(np_array_1[1:]), decimal=4
+    )
+
+
+def test_log_special_values():
+    result = wp.log(wp.array([0.0, -1.0])).tolist()
+    assert result[0] == -np.inf
+    assert np.isnan(result[1])
+
+
+@pytest.mark.parametrize("fn", ["logaddexp", "logaddexp2"])
+def test_logaddexp(wp_array_1, wp_array_2, np_array_1, np_array_2, fn):
+    almost_equals(
+        getattr(wp, fn)(wp_array_1, wp_array_2),
+        getattr(np, fn)(np_array_1, np_array_2),
+        decimal=4,
+    )
+
+
+def test_exp_where():
+    x = wp.array([1.0, 2.0])
+    mask = wp.array([True, False])
+    assert wp.exp(x, where=mask).tolist()[1] == 0.0
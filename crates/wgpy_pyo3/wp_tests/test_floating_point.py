@@ -0,0 +1,52 @@
+import numpy as np
+import webgpupy as wp
+import pytest
+from test_utils import almost_equals
+
+
+@pytest.fixture
+def input_array():
+    return [1.0, float("nan"), float("inf"), float("-inf"), -0.0, -2.5, 0.0]
+
+
+@pytest.mark.parametrize(
+    "fn", ["isnan", "isinf", "isposinf", "isneginf", "isfinite", "signbit"]
+)
+def test_classification(input_array, fn):
+    wp_result = getattr(wp, fn)(wp.array(input_array))
+    assert repr(wp_result.dtype) == repr(wp.DtypePy("bool"))
+    assert wp_result.tolist() == getattr(np, fn)(np.array(input_array)).tolist()
+
+
+def test_classification_where(input_array):
+    mask = [True, True, False, True, True, True, True]
+    result = wp.isinf(wp.array(input_array), where=wp.array(mask))
+    assert result.tolist() == [False, False, False, True, False, False, False]
+
+
+def test_nan_to_num(input_array):
+    result = wp.nan_to_num(wp.array(input_array), nan=-1.0, posinf=100.0)
+    expected = np.nan_to_num(
+        np.array(input_array, dtype=np.float32), nan=-1.0, posinf=100.0
+    )
+    assert result.tolist() == expected.tolist()
+
+
+def test_sign():
+    assert wp.sign(wp.array([-5, 0, 4])).tolist() == [-1, 0, 1]
+    almost_equals(wp.sign(wp.array([-2.5, 0.0, 3.0])), np.sign(np.array([-2.5, 0.0, 3.0])))
+    with pytest.raises(TypeError):
+        wp.sign(wp.array([True, False]))
+
+
+@pytest.mark.parametrize("fn", ["copysign", "heaviside", "nextafter"])
+def test_binary(fn):
+    x1 = np.array([1.0, -2.0, 0.0, 3.0], dtype=np.float32)
+    x2 = np.array([-1.0, 3.0, 0.5, 3.0], dtype=np.float32)
+    result = getattr(wp, fn)(wp.array(x1.tolist()), wp.array(x2.tolist()))
+    assert result.tolist() == getattr(np, fn)(x1, x2).tolist()
+
+
+def test_spacing():
+    values = np.array([1.0, -3.0, 100.0], dtype=np.float32)
+    assert wp.spacing(wp.array(values.tolist())).tolist() == np.spacing(values).tolist()
@@ -0,0 +1,112 @@
+import numpy as np
+import webgpupy as wp
+import pytest
+
+
+@pytest.mark.parametrize("side", ["left", "right"])
+def test_searchsorted(side):
+    a = [1, 2, 2, 3, 5]
+    v = [[2.0, -1.0], [4.5, 6.0]]
+    wp_result = wp.searchsorted(wp.array(a), wp.array(v), side=side)
+    np_result = np.searchsorted(np.array(a), np.array(v), side=side)
+    assert wp_result.tolist() == np_result.tolist()
+
+
+def test_searchsorted_sorter():
+    a = [3.0, 1.0, 2.0, 0.5]
+    sorter = np.argsort(np.array(a)).tolist()
+    wp_result = wp.searchsorted(wp.array(a), wp.array([2.0, 4.0]), sorter=wp.array(sorter))
+    np_result = np.searchsorted(np.array(a), np.array([2.0, 4.0]), sorter=sorter)
+    assert wp_result.tolist() == np_result.tolist()
+
+    with pytest.raises(ValueError):
+        wp.searchsorted(wp.array(a), wp.array([2.0]), side="middle")
+
+
+@pytest.mark.parametrize("right", [False, True])
+def test_digitize(right):
+    x = [0.2, 6.4, 3.0, 1.6, 10.0]
+    bins = [0.0, 1.0, 2.5, 4.0, 10.0]
+    wp_result = wp.digitize(wp.array(x), wp.array(bins), right=right)
+    np_result = np.digitize(np.array(x), np.array(bins), right=right)
+    assert wp_result.tolist() == np_result.tolist()
+
+    wp_result = wp.digitize(wp.array(x), wp.array(bins[::-1]), right=right)
+    np_result = np.digitize(np.array(x), np.array(bins[::-1]), right=right)
+    assert wp_result.tolist() == np_result.tolist()
+
+
+def test_digitize_not_monotonic():
+    with pytest.raises(ValueError):
+        wp.digitize(wp.array([1.0]), wp.array([0.0, 2.0, 1.0]))
+
+
+def test_bincount():
+    x = [0, 1, 1, 3, 2, 1, 7]
+    weights = [0.5, 1.0, 0.25, 2.0, 1.0, 0.5, 3.0]
+    assert wp.bincount(wp.array(x)).tolist() == np.bincount(np.array(x)).tolist()
+    wp_result = wp.bincount(wp.array(x), weights=wp.array(weights), minlength=10)
+    np_result = np.bincount(np.array(x), weights=np.array(weights), minlength=10)
+    assert wp_result.tolist() == np_result.tolist()
+
+    with pytest.raises(ValueError):
+        wp.bincount(wp.array([1, -1]))
+    with pytest.raises(TypeError):
+        wp.bincount(wp.array([1.0, 2.0]))
+
+
+@pytest.mark.parametrize("density", [False, True])
+def test_histogram(density):
+    a = [1.0, 2.0, 1.0, 4.0, 3.5, 0.5]
+    wp_hist, wp_edges = wp.histogram(wp.array(a), bins=3, density=density)
+    np_hist, np_edges = np.histogram(np.array(a, dtype=np.float32), bins=3, density=density)
+    assert np.allclose(wp_hist.tolist(), np_hist)
+    assert np.allclose(wp_edges.tolist(), np_edges)
+
+    edges = [0.0, 1.0, 2.0, 4.0]
+    wp_hist, _ = wp.histogram(wp.array(a), bins=wp.array(edges), density=density)
+    np_hist, _ = np.histogram(np.array(a), bins=np.array(edges), density=density)
+    assert np.allclose(wp_hist.tolist(), np_hist)
+
+
+def test_histogram_range_weights():
+    a = [1.0, 2.0, 1.0, 4.0, 3.5, 0.5]
+    weights = [1.0, 0.5, 2.0, 1.0, 0.25, 4.0]
+    wp_hist, wp_edges = wp.histogram(
+        wp.array(a), bins=4, range=(0.0, 2.0), weights=wp.array(weights)
+    )
+    np_hist, np_edges = np.histogram(np.array(a), bins=4, range=(0.0, 2.0), weights=weights)
+    assert wp_hist.tolist() == np_hist.tolist()
+    assert wp_edges.tolist() == np_edges.tolist()
+
+    with pytest.raises(ValueError):
+        wp.histogram(wp.array(a), bins=0)
+    with pytest.raises(ValueError):
+        wp.histogram(wp.array(a), bins=wp.array([0.0, 2.0, 1.0]))
+
+
+def test_histogram2d():
+    x = [0.0, 0.5, 1.0, 2.0, 1.5]
+    y = [0.0, 1.0, 1.0, 2.0, 0.25]
+    wp_hist, wp_x_edges, wp_y_edges = wp.histogram2d(
+        wp.array(x), wp.array(y), bins=[2, wp.array([0.0, 0.5, 2.0])]
+    )
+    np_hist, np_x_edges, np_y_edges = np.histogram2d(x, y, bins=[2, [0.0, 0.5, 2.0]])
+    assert wp_hist.tolist() == np_hist.tolist()
+    assert wp_x_edges.tolist() == np_x_edges.tolist()
+    assert wp_y_edges.tolist() == np_y_edges.tolist()
+
+
+def test_histogramdd():
+    sample = [[0.0, 0.5, 1.0], [1.0, 1.5, 0.0], [2.0, 0.5, 0.5], [0.5, 1.0, 1.0]]
+    wp_hist, wp_edges = wp.histogramdd(
+        wp.array(sample), bins=2, range=[[0.0, 2.0], [0.0, 2.0], [0.0, 1.0]], density=True
+    )
+    np_hist, np_edges = np.histogramdd(
+        np.array(sample), bins=2, range=[[0.0, 2.0], [0.0, 2.0], [0.0, 1.0]], density=True
+    )
+    assert wp_hist.shape == list(np_hist.shape)
+    assert np.allclose(wp_hist.tolist(), np_hist)
+    assert len(wp_edges) == 3
+    for wp_edge, np_edge in zip(wp_edges, np_edges):
+        assert wp_edge.tolist() == np_edge.tolist()
@@ -0,0 +1,61 @@
+import webgpupy as wp
+import pytest
+import numpy as np
+from test_utils import almost_equals
+
+
+@pytest.fixture
+def input_array():
+    return np.arange(24, dtype=np.float32).reshape([2, 3, 4]).tolist()
+
+
+@pytest.mark.parametrize("reps", [2, [2, 1], [1, 2, 3], [2, 1, 1, 2]])
+def test_tile(input_array, reps):
+    almost_equals(wp.tile(wp.array(input_array), reps), np.tile(np.array(input_array), reps))
+
+
+@pytest.mark.parametrize("axis", [None, 0, 1, -1, (0, 2)])
+def test_flip(input_array, axis):
+    almost_equals(
+        wp.flip(wp.array(input_array), axis=axis), np.flip(np.array(input_array), axis=axis)
+    )
+
+
+def test_fliplr_flipud(input_array):
+    almost_equals(wp.fliplr(wp.array(input_array)), np.fliplr(np.array(input_array)))
+    almost_equals(wp.flipud(wp.array(input_array)), np.flipud(np.array(input_array)))
+
+
+@pytest.mark.parametrize("k", [-1, 0, 1, 2, 3, 5])
+@pytest.mark.parametrize("axes", [(0, 1), (1, 2), (2, 0)])
+def test_rot90(input_array, k, axes):
+    wp_result = wp.rot90(wp.array(input_array), k=k, axes=axes)
+    np_result = np.rot90(np.array(input_array), k=k, axes=axes)
+    assert wp_result.shape == list(np_result.shape)
+    almost_equals(wp_result, np_result)
+
+
+@pytest.mark.parametrize(
+    "shift,axis", [(3, None), (-5, None), (1, 0), (-2, 2), ((1, 2), (1, 2)), (2, (0, 1))]
+)
+def test_roll(input_array, shift, axis):
+    almost_equals(
+        wp.roll(wp.array(input_array), shift, axis=axis),
+        np.roll(np.array(input_array), shift, axis=axis),
+    )
+
+
+@pytest.mark.parametrize("mode", ["constant", "edge", "reflect", "symmetric", "wrap"])
+@pytest.mark.parametrize("pad_width", [1, (2, 1), [(0, 1), (3, 2), (5, 0)]])
+def test_pad(input_array, mode, pad_width):
+    wp_result = wp.pad(wp.array(input_array), pad_width, mode=mode)
+    np_result = np.pad(np.array(input_array), pad_width, mode=mode)
+    assert wp_result.shape == list(np_result.shape)
+    almost_equals(wp_result, np_result)
+
+
+def test_pad_constant_value():
+    almost_equals(
+        wp.pad(wp.array([[1, 2], [3, 4]]), 1, constant_values=7),
+        np.pad(np.array([[1, 2], [3, 4]]), 1, constant_values=7),
+    )
@@ -44,3 +44,103 @@ def test_bitwise_or_u32(input_array_1, input_array_2, wp_array_1, wp_array_2):
     np_where = np.where([True, True, False, False], input_array_1, input_array_2)
     wp_where = wp.where([True, True, False, False], wp_array_1, wp_array_2)
     np.testing.assert_array_almost_equal(np_where, wp_where.tolist(), decimal=4)
+
+
+def test_le_ge_f32(wp_array_1, wp_array_2, input_array_1, input_array_2):
+    np_1, np_2 = np.array(input_array_1), np.array(input_array_2)
+    assert (wp_array_1 <= wp_array_2).tolist() == (np_1 <= np_2).tolist()
+    assert (wp_array_1 >= wp_array_2).tolist() == (np_1 >= np_2).tolist()
+
+
+@pytest.mark.parametrize(
+    "fn", ["less", "less_equal", "greater", "greater_equal", "equal", "not_equal"]
+)
+def test_comparison_ufuncs(input_array_1, input_array_2, fn):
+    wp_result = getattr(wp, fn)(wp.array(input_array_1), wp.array(input_array_2))
+    np_result = getattr(np, fn)(np.array(input_array_1), np.array(input_array_2))
+    assert wp_result.tolist() == np_result.tolist()
+
+
+@pytest.mark.parametrize("fn", ["logical_and", "logical_or", "logical_xor"])
+@pytest.mark.parametrize(
+    "x1, x2",
+    [
+        ([True, True, False, False], [True, False, True, False]),
+        ([0, 3, 0, -1], [2, 0, 0, 5]),
+        ([0.0, 0.5, -2.0, 0.0], [1.0, 0.0, 3.0, 0.0]),
+    ],
+)
+def test_logical_binary(fn, x1, x2):
+    wp_result = getattr(wp, fn)(wp.array(x1), wp.array(x2))
+    assert repr(wp_result.dtype) == repr(wp.DtypePy("bool"))
+    assert wp_result.tolist() == getattr(np, fn)(np.array(x1), np.array(x2)).tolist()
+
+
+def test_logical_xor_broadcast():
+    x1 = np.arange(70).reshape(2, 35) % 3 == 0
+    x2 = np.arange(35) % 2 == 0
+    wp_result = wp.logical_xor(wp.array(x1.tolist()), wp.array(x2.tolist()))
+    assert wp_result.tolist() == np.logical_xor(x1, x2).tolist()
+
+
+@pytest.mark.parametrize("x", [[True, False], [0, 7, -1], [0.0, 1.5]])
+def test_logical_not(x):
+    assert wp.logical_not(wp.array(x)).tolist() == np.logical_not(np.array(x)).tolist()
+
+
+def test_bool_operators():
+    x1 = wp.array([True, True, False, False])
+    x2 = wp.array([True, False, True, False])
+    assert (~x1).tolist() == [False, False, True, True]
+    assert (~wp.array([1, 2])).tolist() == [-2, -3]
+
+
+def test_isclose():
+    a = [1.0, 1e10, np.nan, np.inf, -np.inf, 0.0]
+    b = [1.000001, 1.000001e10, np.nan, np.inf, np.inf, 1e-9]
+    assert wp.isclose(wp.array(a), wp.array(b)).tolist() == np.isclose(a, b).tolist()
+    assert (
+        wp.isclose(wp.array(a), wp.array(b), rtol=0, atol=0, equal_nan=True).tolist()
+        == np.isclose(a, b, rtol=0, atol=0, equal_nan=True).tolist()
+    )
+
+
+def test_isclose_broadcast():
+    a = [[1.0, 2.0, 3.0], [1.1, 2.0, 2.9]]
+    b = [1.0, 2.05, 3.0]
+    assert (
+        wp.isclose(wp.array(a), wp.array(b), atol=0.1).tolist()
+        == np.isclose(a, b, atol=0.1).tolist()
+    )
+
+
+def test_allclose():
+    a = wp.array([1e10, 1e-7])
+    assert not wp.allclose(a, wp.array([1.00001e10, 1e-8]))
+    assert wp.allclose(a, wp.array([1.000001e10, 1.05e-7]))
+    assert not wp.allclose(wp.array([1.0, np.nan]), wp.array([1.0, np.nan]))
+    assert wp.allclose(wp.array([1.0, np.nan]), wp.array([1.0, np.nan]), equal_nan=True)
+
+
+def test_isclose_shape_mismatch():
+    with pytest.raises(ValueError):
+        wp.isclose(wp.array([1.0, 2.0, 3.0]), wp.array([1.0, 2.0]))
+    with pytest.raises(ValueError):
+        wp.allclose(wp.array([1.0, 2.0, 3.0]), wp.array([1.0, 2.0]))
+
+
+def test_array_equal():
+    assert wp.array_equal(wp.array([1, 2]), wp.array([1, 2]))
+    assert not wp.array_equal(wp.array([1, 2]), wp.array([1, 2, 3]))
+    assert not wp.array_equal(wp.array([1, 2]), wp.array([1, 4]))
+    assert wp.array_equal(wp.array([1, 2]), wp.array([1.0, 2.0]))
+    a = wp.array([1.0, np.nan])
+    assert not wp.array_equal(a, a)
+    assert wp.array_equal(a, a, equal_nan=True)
+
+
+def test_array_equiv():
+    assert wp.array_equiv(wp.array([1, 2]), wp.array([1, 2]))
+    assert wp.array_equiv(wp.array([1, 2]), wp.array([[1, 2], [1, 2]]))
+    assert not wp.array_equiv(wp.array([1, 2]), wp.array([[1, 2, 1, 2], [1, 2, 1, 2]]))
+    assert not wp.array_equiv(wp.array([1, 2]), wp.array([[1, 2], [1, 3]]))
@@ -0,0 +1,82 @@
+import numpy as np
+import webgpupy as wp
+import pytest
+from test_utils import almost_equals
+
+nan = float("nan")
+
+
+@pytest.fixture
+def input_array():
+    return [[1.0, nan, 3.0, -2.0], [nan, 5.0, 0.5, nan], [4.0, 2.0, nan, 7.0]]
+
+
+@pytest.mark.parametrize(
+    "fn",
+    ["nansum", "nanprod", "nanmin", "nanmax", "nanmean", "nanvar", "nanstd", "nanmedian"],
+)
+@pytest.mark.parametrize("axis", [None, 0, 1, -1])
+@pytest.mark.parametrize("keepdims", [False, True])
+def test_nan_reductions(input_array, fn, axis, keepdims):
+    wp_result = getattr(wp, fn)(wp.array(input_array), axis=axis, keepdims=keepdims)
+    np_result = getattr(np, fn)(
+        np.array(input_array, dtype=np.float32), axis=axis, keepdims=keepdims
+    )
+    assert wp_result.shape == list(np_result.shape)
+    almost_equals(wp_result, np_result, decimal=5)
+
+
+@pytest.mark.parametrize("fn", ["nanargmin", "nanargmax"])
+@pytest.mark.parametrize("axis", [None, 1])
+def test_nan_arg_reductions(input_array, fn, axis):
+    wp_result = getattr(wp, fn)(wp.array(input_array), axis=axis)
+    np_result = getattr(np, fn)(np.array(input_array), axis=axis)
+    assert repr(wp_result.dtype) == repr(wp.DtypePy("int32"))
+    assert wp_result.tolist() == np_result.tolist()
+
+
+def test_nan_arg_reductions_all_nan():
+    with pytest.raises(ValueError):
+        wp.nanargmax(wp.array([[nan, 1.0], [nan, 2.0]]), axis=0)
+
+
+def test_nanvar_ddof(input_array):
+    wp_result = wp.nanstd(wp.array(input_array), axis=1, ddof=1)
+    np_result = np.nanstd(np.array(input_array), axis=1, ddof=1)
+    almost_equals(wp_result, np_result, decimal=5)
+
+
+def test_integer_inputs():
+    values = [[1, 2, 3], [4, 5, 6]]
+    wp_result = wp.nansum(wp.array(values), axis=0)
+    assert repr(wp_result.dtype) == repr(wp.DtypePy("int32"))
+    assert wp_result.tolist() == np.nansum(np.array(values), axis=0).tolist()
+    assert wp.nanmax(wp.array(values)).tolist() == 6
+    almost_equals(wp.nanmean(wp.array(values), axis=1), np.array([2.0, 5.0]))
+
+
+def test_large_integer_inputs():
+    values = wp.array([16_777_217, 1, 3], dtype="int32")
+    assert wp.nansum(values).tolist() == 16_777_221
+    assert wp.nanprod(values).tolist() == 50_331_651
+    assert wp.nanmax(values).tolist() == 16_777_217
+
+
+@pytest.mark.parametrize(
+    "fn, message",
+    [
+        ("nanmin", "All-NaN slice encountered"),
+        ("nanmax", "All-NaN slice encountered"),
+        ("nanmedian", "All-NaN slice encountered"),
+        ("nanmean", "Mean of empty slice"),
+        ("nanvar", "Degrees of freedom <= 0 for slice."),
+        ("nanstd", "Degrees of freedom <= 0 for slice."),
+    ],
+)
+def test_all_nan_warning(fn, message):
+    values = [[nan, 1.0], [nan, 2.0]]
+    with pytest.warns(RuntimeWarning, match=message):
+        wp_result = getattr(wp, fn)(wp.array(values), axis=0)
+    with pytest.warns(RuntimeWarning):
+        np_result = getattr(np, fn)(np.array(values, dtype=np.float32), axis=0)
+    almost_equals(wp_result, np_result)
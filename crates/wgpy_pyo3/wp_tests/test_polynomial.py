@@ -0,0 +1,101 @@
+import numpy as np
+import webgpupy as wp
+import pytest
+
+
+@pytest.mark.parametrize("p", [[3.0], [1.0, -2.0], [0.5, -1.0, 2.0, 3.0], [2.0, 0.0, -1.5, 0.25, 1.0]])
+def test_polyval(p):
+    x = [[-2.0, -0.5, 0.0], [0.75, 1.0, 3.0]]
+    wp_result = wp.polynomial.polyval(wp.array(p), wp.array(x))
+    np_result = np.polyval(p, np.array(x, dtype=np.float32))
+    assert wp_result.shape == list(np_result.shape)
+    assert np.allclose(wp_result.tolist(), np_result)
+
+
+def test_polyval_ints():
+    wp_result = wp.polynomial.polyval(wp.array([1, 2, 3]), wp.array([0, 1, 2, -3]))
+    assert repr(wp_result.dtype) == repr(wp.DtypePy("float32"))
+    assert wp_result.tolist() == np.polyval([1, 2, 3], [0, 1, 2, -3]).tolist()
+
+
+@pytest.mark.parametrize("a2", [[1.0], [1.0, 2.0, 3.0], [4.0, -1.0]])
+def test_polyadd_polymul(a2):
+    a1 = [2.0, 0.5]
+    wp_result = wp.polynomial.polyadd(wp.array(a1), wp.array(a2))
+    assert np.allclose(wp_result.tolist(), np.polyadd(a1, a2))
+    wp_result = wp.polynomial.polymul(wp.array(a1), wp.array(a2))
+    assert np.allclose(wp_result.tolist(), np.polymul(a1, a2))
+
+
+@pytest.mark.parametrize("m", [0, 1, 2, 3, 5])
+def test_polyder(m):
+    p = [1.0, -2.0, 3.0, 0.5]
+    wp_result = wp.polynomial.polyder(wp.array(p), m=m)
+    assert np.allclose(wp_result.tolist(), np.polyder(p, m=m))
+
+    wp_result = wp.polynomial.polyder(wp.array([4, 3, 2, 1]), m=m)
+    assert wp_result.tolist() == np.polyder([4, 3, 2, 1], m=m).tolist()
+
+
+def test_polyder_overflow():
+    with pytest.raises(ValueError):
+        wp.polynomial.polyder(wp.array([1] * 14), m=13)
+
+
+@pytest.mark.parametrize("m", [0, 1, 2, 3])
+def test_polyint(m):
+    p = [1.0, -2.0, 3.0, 0.5]
+    wp_result = wp.polynomial.polyint(wp.array(p), m=m)
+    assert np.allclose(wp_result.tolist(), np.polyint(p, m=m))
+
+    wp_result = wp.polynomial.polyint(wp.array(p), m=m, k=2.0)
+    assert np.allclose(wp_result.tolist(), np.polyint(p, m=m, k=2.0))
+
+    k = [1.0, -1.0, 0.5, 2.0]
+    wp_result = wp.polynomial.polyint(wp.array(p), m=m, k=wp.array(k))
+    assert np.allclose(wp_result.tolist(), np.polyint(p, m=m, k=k))
+
+
+def test_polyint_errors():
+    with pytest.raises(ValueError):
+        wp.polynomial.polyint(wp.array([1.0, 2.0]), m=3, k=wp.array([1.0, 2.0]))
+
+
+@pytest.mark.parametrize("deg", [0, 1, 2, 3, 5])
+def test_polyfit(deg):
+    x = [0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 5.5]
+    y = [1.0, 1.8, 2.1, 3.5, 3.9, 6.2, 7.7, 12.0]
+    wp_result = wp.polynomial.polyfit(wp.array(x), wp.array(y), deg)
+    np_result = np.polyfit(np.array(x, dtype=np.float32), np.array(y, dtype=np.float32), deg)
+    assert np.allclose(wp_result.tolist(), np_result, rtol=1e-3, atol=1e-4)
+
+
+def test_polyfit_weights_2d():
+    x = [0.0, 1.0, 2.0, 3.0, 4.0]
+    y = [[1.0, 0.0], [2.5, 1.0], [2.9, 4.0], [4.2, 9.0], [5.1, 16.5]]
+    w = [1.0, 2.0, 0.5, 1.0, 3.0]
+    wp_result = wp.polynomial.polyfit(wp.array(x), wp.array(y), 2, w=wp.array(w))
+    np_result = np.polyfit(x, y, 2, w=w)
+    assert wp_result.shape == list(np_result.shape)
+    assert np.allclose(wp_result.tolist(), np_result, rtol=1e-3, atol=1e-4)
+
+    with pytest.raises(ValueError):
+        wp.polynomial.polyfit(wp.array(x), wp.array([1.0, 2.0]), 1)
+
+
+@pytest.mark.parametrize(
+    "p",
+    [
+        [1.0, -3.0, 2.0],
+        [1.0, 0.0, 1.0],
+        [0.0, 2.0, -1.0, 0.0, 0.0],
+        [1.0, -6.0, 11.0, -6.0],
+        [3.0, 1.0, -2.0, 5.0, 0.5, -1.0],
+        [4.0],
+    ],
+)
+def test_roots(p):
+    wp_real, wp_imaginary = wp.polynomial.roots(wp.array(p))
+    np_result = np.sort_complex(np.roots(p).astype(complex))
+    assert np.allclose(wp_real.tolist(), np_result.real, atol=1e-4)
+    assert np.allclose(wp_imaginary.tolist(), np_result.imag, atol=1e-4)
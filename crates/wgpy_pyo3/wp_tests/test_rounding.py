@@ -0,0 +1,30 @@
+import numpy as np
+import webgpupy as wp
+import pytest
+from test_utils import almost_equals
+
+
+@pytest.fixture
+def input_array():
+    return [-2.5, -1.7, -0.5, 0.2, 0.5, 1.5, 2.5, 3.7]
+
+
+@pytest.mark.parametrize("fn", ["floor", "ceil", "trunc", "rint"])
+def test_rounding(input_array, fn):
+    almost_equals(getattr(wp, fn)(wp.array(input_array)), getattr(np, fn)(np.array(input_array)))
+
+
+@pytest.mark.parametrize("decimals", [0, 1, -1])
+def test_around(decimals):
+    values = [1.234, -5.678, 15.0, 0.25]
+    almost_equals(
+        wp.around(wp.array(values), decimals),
+        np.around(np.array(values, dtype=np.float32), decimals),
+        decimal=5,
+    )
+
+
+def test_round_integers():
+    values = [14, 15, -26]
+    assert wp.round(wp.array(values), decimals=-1).tolist() == [10, 20, -30]
+    assert wp.around(wp.array(values)).tolist() == values
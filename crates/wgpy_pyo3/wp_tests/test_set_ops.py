@@ -0,0 +1,117 @@
+import numpy as np
+import webgpupy as wp
+import pytest
+
+nan = float("nan")
+
+
+def nan_list(values):
+    return [None if x != x else x for x in values]
+
+
+def test_unique():
+    data = [3.0, nan, 1.0, 3.0, -0.0, nan, 0.0, 1.0]
+    wp_result = wp.unique(
+        wp.array(data), return_index=True, return_inverse=True, return_counts=True
+    )
+    np_result = np.unique(
+        np.array(data, dtype=np.float32),
+        return_index=True,
+        return_inverse=True,
+        return_counts=True,
+    )
+    assert len(wp_result) == 4
+    assert nan_list(wp_result[0].tolist()) == nan_list(np_result[0].tolist())
+    for wp_array, np_array in zip(wp_result[1:], np_result[1:]):
+        assert repr(wp_array.dtype) == repr(wp.DtypePy("int32"))
+        assert wp_array.tolist() == np_array.tolist()
+
+
+@pytest.mark.parametrize("dtype", ["int8", "uint16", "int32", "uint32", "bool"])
+def test_unique_dtypes(dtype):
+    data = np.random.default_rng(0).integers(0, 100, size=3000).astype(dtype)
+    wp_values, wp_counts = wp.unique(wp.array(data.tolist(), dtype=dtype), return_counts=True)
+    np_values, np_counts = np.unique(data, return_counts=True)
+    assert wp_values.tolist() == np_values.tolist()
+    assert wp_counts.tolist() == np_counts.tolist()
+
+
+@pytest.mark.parametrize("axis", [0, 1, -1])
+def test_unique_axis(axis):
+    data = [[[1, 0], [1, 2], [1, 0]], [[1, 0], [1, 0], [1, 0]], [[1, 0], [1, 2], [1, 0]]]
+    wp_values, wp_index, wp_inverse = wp.unique(
+        wp.array(data, dtype="int32"), return_index=True, return_inverse=True, axis=axis
+    )
+    np_values, np_index, np_inverse = np.unique(
+        np.array(data, dtype=np.int32), return_index=True, return_inverse=True, axis=axis
+    )
+    assert wp_values.shape == list(np_values.shape)
+    assert wp_values.tolist() == np_values.tolist()
+    assert wp_index.tolist() == np_index.tolist()
+    assert wp_inverse.tolist() == np_inverse.tolist()
+
+
+def test_unique_empty():
+    assert wp.unique(wp.array([])).tolist() == []
+
+
+@pytest.mark.parametrize("invert", [False, True])
+def test_isin(invert):
+    element = [[0, 2, 4], [6, 8, 1]]
+    test_elements = [4.0, 1.0, 0.0, nan]
+    wp_result = wp.isin(wp.array(element), wp.array(test_elements), invert=invert)
+    np_result = np.isin(np.array(element), np.array(test_elements), invert=invert)
+    assert wp_result.shape == list(np_result.shape)
+    assert wp_result.tolist() == np_result.tolist()
+
+    wp_result = wp.in1d(wp.array(element), wp.array(test_elements), invert=invert)
+    np_result = np.in1d(np.array(element), np.array(test_elements), invert=invert)
+    assert wp_result.tolist() == np_result.tolist()
+
+
+def test_isin_nan():
+    wp_result = wp.isin(wp.array([nan, 1.0]), wp.array([nan, 1.0]))
+    assert wp_result.tolist() == [False, True]
+
+
+@pytest.mark.parametrize("assume_unique", [False, True])
+def test_intersect1d(assume_unique):
+    ar1 = [1, 3, 4, 7, -2] if assume_unique else [1, 3, 4, 3, 7, 1, -2]
+    ar2 = [3, 1, 2, -2, 9]
+    wp_values, wp_index_1, wp_index_2 = wp.intersect1d(
+        wp.array(ar1, dtype="int32"),
+        wp.array(ar2, dtype="int32"),
+        assume_unique=assume_unique,
+        return_indices=True,
+    )
+    np_values, np_index_1, np_index_2 = np.intersect1d(
+        np.array(ar1), np.array(ar2), assume_unique=assume_unique, return_indices=True
+    )
+    assert wp_values.tolist() == np_values.tolist()
+    assert wp_index_1.tolist() == np_index_1.tolist()
+    assert wp_index_2.tolist() == np_index_2.tolist()
+
+
+def test_set_routines():
+    ar1 = [5.0, 1.0, 3.0, 3.0, 2.0, 5.0]
+    ar2 = [3.0, 6.0, 1.0, 8.0]
+    wp_1, wp_2 = wp.array(ar1), wp.array(ar2)
+    np_1, np_2 = np.array(ar1, dtype=np.float32), np.array(ar2, dtype=np.float32)
+    assert wp.intersect1d(wp_1, wp_2).tolist() == np.intersect1d(np_1, np_2).tolist()
+    assert wp.union1d(wp_1, wp_2).tolist() == np.union1d(np_1, np_2).tolist()
+    assert wp.setdiff1d(wp_1, wp_2).tolist() == np.setdiff1d(np_1, np_2).tolist()
+    assert wp.setxor1d(wp_1, wp_2).tolist() == np.setxor1d(np_1, np_2).tolist()
+
+
+def test_setdiff1d_assume_unique():
+    ar1 = [7, 1, 5, 3]
+    ar2 = [5, 0]
+    wp_result = wp.setdiff1d(wp.array(ar1), wp.array(ar2), assume_unique=True)
+    np_result = np.setdiff1d(np.array(ar1), np.array(ar2), assume_unique=True)
+    assert wp_result.tolist() == np_result.tolist()
+
+
+def test_set_routines_mixed_dtypes():
+    wp_result = wp.union1d(wp.array([1, 2], dtype="uint8"), wp.array([-1, 2], dtype="int8"))
+    assert repr(wp_result.dtype) == repr(wp.DtypePy("int16"))
+    assert wp_result.tolist() == [-1, 1, 2]
@@ -0,0 +1,78 @@
+import webgpupy as wp
+import pytest
+import numpy as np
+from test_utils import almost_equals
+
+
+@pytest.fixture
+def input_array():
+    return np.arange(6, dtype=np.float32).reshape([1, 2, 1, 3]).tolist()
+
+
+@pytest.mark.parametrize("axis", [None, 0, -2, (0, 2)])
+def test_squeeze(input_array, axis):
+    wp_result = wp.squeeze(wp.array(input_array), axis=axis)
+    np_result = np.squeeze(np.array(input_array), axis=axis)
+    assert wp_result.shape == list(np_result.shape)
+    assert wp_result.ndim == np_result.ndim
+    almost_equals(wp_result, np_result)
+
+    wp_result = wp.array(input_array).squeeze(axis)
+    assert wp_result.shape == list(np_result.shape)
+
+
+def test_squeeze_error(input_array):
+    with pytest.raises(ValueError):
+        wp.squeeze(wp.array(input_array), axis=1)
+
+
+@pytest.mark.parametrize("axis", [0, -1, 2, (0, 1), (0, -1)])
+def test_expand_dims(axis):
+    data = np.arange(6, dtype=np.float32).reshape([2, 3])
+    wp_result = wp.expand_dims(wp.array(data.tolist()), axis)
+    np_result = np.expand_dims(data, axis)
+    assert wp_result.shape == list(np_result.shape)
+    assert wp_result.ndim == np_result.ndim
+    almost_equals(wp_result, np_result)
+
+
+@pytest.mark.parametrize("axis", [3, (0, 0)])
+def test_expand_dims_error(axis):
+    with pytest.raises(ValueError):
+        wp.expand_dims(wp.array([[1, 2, 3]]), axis)
+
+
+@pytest.mark.parametrize("func", ["atleast_1d", "atleast_2d", "atleast_3d"])
+@pytest.mark.parametrize("data", [5.0, [1.0, 2.0], [[1.0, 2.0]], [[[1.0], [2.0]]]])
+def test_atleast(func, data):
+    wp_result = getattr(wp, func)(wp.array(data))
+    np_result = getattr(np, func)(np.array(data, dtype=np.float32))
+    assert wp_result.shape == list(np_result.shape)
+    almost_equals(wp_result, np_result)
+
+
+def test_atleast_multiple():
+    wp_result = wp.atleast_2d(wp.array([1.0]), wp.array([[1.0, 2.0]]))
+    assert len(wp_result) == 2
+    assert wp_result[0].shape == [1, 1]
+    assert wp_result[1].shape == [1, 2]
+
+
+def test_ravel_flatten(input_array):
+    np_result = np.array(input_array).ravel()
+    for wp_result in [
+        wp.ravel(wp.array(input_array)),
+        wp.array(input_array).ravel(),
+        wp.array(input_array).flatten(),
+    ]:
+        assert wp_result.shape == list(np_result.shape)
+        assert wp_result.ndim == 1
+        almost_equals(wp_result, np_result)
+
+
+def test_reshape():
+    wp_result = wp.array([1, 2, 3, 4, 5, 6]).reshape((3, 2))
+    assert wp_result.shape == [3, 2]
+    assert wp_result.ndim == 2
+    with pytest.raises(ValueError):
+        wp.array([1, 2, 3, 4, 5, 6]).reshape([4])
@@ -0,0 +1,104 @@
+import numpy as np
+import webgpupy as wp
+import pytest
+
+nan = float("nan")
+
+
+@pytest.fixture
+def input_array():
+    return [[3.0, nan, -1.0, 2.5], [0.0, -0.0, 7.0, -np.inf], [2.0, 2.0, nan, 1.0]]
+
+
+def nan_list(values):
+    return [[None if x != x else x for x in row] for row in values]
+
+
+@pytest.mark.parametrize("axis", [0, 1, -1])
+def test_sort(input_array, axis):
+    wp_result = wp.sort(wp.array(input_array), axis=axis)
+    np_result = np.sort(np.array(input_array, dtype=np.float32), axis=axis)
+    assert nan_list(wp_result.tolist()) == nan_list(np_result.tolist())
+
+
+def test_sort_flattened(input_array):
+    wp_result = wp.sort(wp.array(input_array), axis=None)
+    np_result = np.sort(np.array(input_array, dtype=np.float32), axis=None)
+    assert wp_result.shape == list(np_result.shape)
+    assert nan_list([wp_result.tolist()]) == nan_list([np_result.tolist()])
+
+
+@pytest.mark.parametrize("axis", [None, 0, 1])
+def test_argsort(input_array, axis):
+    wp_result = wp.argsort(wp.array(input_array), axis=axis)
+    np_result = np.argsort(np.array(input_array, dtype=np.float32), axis=axis, kind="stable")
+    assert wp_result.tolist() == np_result.tolist()
+
+
+@pytest.mark.parametrize("dtype", ["int8", "uint8", "int16", "uint16", "int32", "uint32", "bool"])
+def test_sort_dtypes(dtype):
+    data = [5, 3, 0, 127, 1, 3, 100, 9] * 40
+    wp_array = wp.array(data, dtype=dtype)
+    np_array = np.array(data, dtype=dtype)
+    assert wp.sort(wp_array).tolist() == np.sort(np_array).tolist()
+    assert wp.argsort(wp_array).tolist() == np.argsort(np_array, kind="stable").tolist()
+
+
+def test_sort_negative_ints():
+    data = np.random.default_rng(0).integers(-(2**31), 2**31 - 1, size=2000, dtype=np.int32)
+    assert wp.sort(wp.array(data.tolist(), dtype="int32")).tolist() == np.sort(data).tolist()
+
+
+def test_ndarray_sort():
+    a = wp.array([[1, 4], [3, 1]])
+    assert a.sort() is None
+    assert a.tolist() == [[1, 4], [1, 3]]
+    a.sort(axis=0)
+    assert a.tolist() == [[1, 3], [1, 4]]
+
+
+@pytest.mark.parametrize("kth", [0, 3, -1, [1, 4]])
+def test_partition(kth):
+    data = [3.0, 4.0, 2.0, 1.0, 9.0, -5.0]
+    wp_result = wp.partition(wp.array(data), kth).tolist()
+    for k in np.atleast_1d(kth):
+        expected = np.partition(np.array(data), kth)[k]
+        assert wp_result[k] == expected
+        assert all(x <= expected for x in wp_result[:k])
+        assert all(x >= expected for x in wp_result[k:])
+
+
+def test_partition_axis():
+    data = np.random.default_rng(2).integers(-50, 50, size=(3, 700)).astype(np.int32)
+    kth = [10, 350, -2]
+    wp_result = np.array(wp.partition(wp.array(data.tolist(), dtype="int32"), kth, axis=1).tolist())
+    expected = np.sort(data, axis=1)
+    for k in np.array(kth) % 700:
+        assert wp_result[:, k].tolist() == expected[:, k].tolist()
+        assert (wp_result[:, :k] <= expected[:, k : k + 1]).all()
+        assert (wp_result[:, k:] >= expected[:, k : k + 1]).all()
+
+
+def test_argpartition_top_k():
+    scores = np.random.default_rng(1).random(5000, dtype=np.float32)
+    top = wp.argpartition(wp.array(scores.tolist()), -10).tolist()[-10:]
+    assert sorted(top) == sorted(np.argsort(scores)[-10:].tolist())
+
+
+def test_partition_out_of_bounds():
+    with pytest.raises(ValueError):
+        wp.partition(wp.array([1, 2, 3]), 3)
+
+
+def test_lexsort():
+    surnames = [2, 1, 2, 0, 1]
+    first_names = [1.0, 0.0, 0.0, 5.0, 0.0]
+    wp_result = wp.lexsort((wp.array(first_names), wp.array(surnames)))
+    assert wp_result.tolist() == np.lexsort((first_names, surnames)).tolist()
+
+
+def test_lexsort_axis():
+    a = [[1, 5, 1], [4, 2, 4]]
+    b = [[3, 3, 1], [0, 0, 1]]
+    wp_result = wp.lexsort((wp.array(a), wp.array(b)), axis=0)
+    assert wp_result.tolist() == np.lexsort((np.array(a), np.array(b)), axis=0).tolist()
@@ -0,0 +1,77 @@
+import math
+import numpy as np
+import webgpupy as wp
+import pytest
+from test_utils import almost_equals
+
+
+def assert_close(wp_result, expected, rtol=1e-5, atol=1e-6):
+    np.testing.assert_allclose(wp_result.tolist(), expected, rtol=rtol, atol=atol)
+
+
+@pytest.mark.parametrize(
+    "fn, reference",
+    [("erf", math.erf), ("erfc", math.erfc)],
+)
+def test_error_functions(fn, reference):
+    values = np.linspace(-4.0, 4.0, 81, dtype=np.float32).tolist()
+    wp_result = getattr(wp.special, fn)(wp.array(values))
+    assert_close(wp_result, [reference(v) for v in values])
+
+
+def test_erfinv():
+    values = np.linspace(-0.99, 0.99, 67, dtype=np.float32).tolist()
+    wp_result = wp.special.erfinv(wp.array(values))
+    assert_close(wp.special.erf(wp_result), values)
+    assert wp.special.erfinv(wp.array([1.0, -1.0])).tolist() == [math.inf, -math.inf]
+    assert math.isnan(wp.special.erfinv(wp.array([2.0])).tolist()[0])
+
+
+def test_gamma():
+    values = [0.1, 0.5, 1.0, 2.5, 7.0, 20.0, -0.5, -2.5, 30.5]
+    wp_result = wp.special.gamma(wp.array(values))
+    assert_close(wp_result, [math.gamma(v) for v in values], rtol=1e-4)
+    result = wp.special.gamma(wp.array([0.0, -3.0, 40.0])).tolist()
+    assert result[0] == math.inf and math.isnan(result[1]) and result[2] == math.inf
+
+
+def test_lgamma():
+    values = [0.1, 0.5, 1.0, 2.0, 2.5, 7.0, 100.0, 1e4, -0.5, -2.5]
+    wp_result = wp.special.lgamma(wp.array(values))
+    assert_close(wp_result, [math.lgamma(v) for v in values], atol=1e-5)
+
+
+def test_digamma():
+    values = [1.0, 0.5, 2.0, 10.0, -0.5, 0.01]
+    expected = [
+        -0.5772156649,
+        -1.9635100260,
+        0.4227843351,
+        2.2517525891,
+        0.0364899740,
+        -100.5608179,
+    ]
+    wp_result = wp.special.digamma(wp.array(values))
+    assert_close(wp_result, expected, atol=1e-5)
+
+
+def test_beta():
+    a = [2.0, 0.5, 20.0, 1.5]
+    b = [3.0, 0.5, 15.0, 4.0]
+    expected = [math.gamma(x) * math.gamma(y) / math.gamma(x + y) for x, y in zip(a, b)]
+    wp_result = wp.special.beta(wp.array(a), wp.array(b))
+    assert_close(wp_result, expected, rtol=1e-4)
+
+
+@pytest.mark.parametrize("fn", ["sinc", "i0"])
+def test_numpy_special_functions(fn):
+    values = np.linspace(-6.0, 6.0, 49, dtype=np.float32)
+    wp_result = getattr(wp, fn)(wp.array(values.tolist()))
+    assert_close(wp_result, getattr(np, fn)(values.astype(np.float64)), rtol=1e-5)
+    almost_equals(getattr(wp.special, fn)(wp.array(values.tolist())), wp_result)
+
+
+def test_integer_input():
+    wp_result = wp.special.gamma(wp.array([1, 2, 3, 4]))
+    assert repr(wp_result.dtype) == repr(wp.DtypePy("float32"))
+    assert_close(wp_result, [1.0, 1.0, 2.0, 6.0])
@@ -0,0 +1,53 @@
+import webgpupy as wp
+import pytest
+import numpy as np
+from test_utils import almost_equals
+
+
+@pytest.fixture
+def input_array():
+    return np.arange(24, dtype=np.float32).reshape([2, 3, 4]).tolist()
+
+
+def assert_splits_equal(wp_arrays, np_arrays):
+    assert len(wp_arrays) == len(np_arrays)
+    for wp_arr, np_arr in zip(wp_arrays, np_arrays):
+        assert wp_arr.shape == list(np_arr.shape)
+        almost_equals(wp_arr, np_arr)
+
+
+@pytest.mark.parametrize(
+    "indices_or_sections,axis", [(2, 0), (2, 2), (3, 1), ([1, 3], 2), ([-1, 5], -1)]
+)
+def test_split(input_array, indices_or_sections, axis):
+    assert_splits_equal(
+        wp.split(wp.array(input_array), indices_or_sections, axis=axis),
+        np.split(np.array(input_array), indices_or_sections, axis=axis),
+    )
+
+
+@pytest.mark.parametrize("indices_or_sections,axis", [(3, 2), (2, 1), ([2], 0)])
+def test_array_split(input_array, indices_or_sections, axis):
+    assert_splits_equal(
+        wp.array_split(wp.array(input_array), indices_or_sections, axis=axis),
+        np.array_split(np.array(input_array), indices_or_sections, axis=axis),
+    )
+
+
+def test_split_uneven(input_array):
+    with pytest.raises(ValueError):
+        wp.split(wp.array(input_array), 3, axis=2)
+
+
+@pytest.mark.parametrize("fn", ["hsplit", "vsplit", "dsplit"])
+def test_hvd_split(input_array, fn):
+    assert_splits_equal(
+        getattr(wp, fn)(wp.array(input_array), [1]),
+        getattr(np, fn)(np.array(input_array), [1]),
+    )
+
+
+def test_unstack(input_array):
+    np_array = np.array(input_array)
+    wp_arrays = wp.unstack(wp.array(input_array), axis=1)
+    assert_splits_equal(wp_arrays, [np_array[:, i] for i in range(3)])
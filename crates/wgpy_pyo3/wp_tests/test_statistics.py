@@ -0,0 +1,118 @@
+import numpy as np
+import webgpupy as wp
+import pytest
+
+nan = float("nan")
+
+
+@pytest.fixture
+def input_array():
+    return [[3.0, 1.0, 4.0, 1.5, 5.0], [9.0, 2.0, 6.0, 5.0, 3.0], [5.0, 8.0, 9.0, 7.0, 9.0]]
+
+
+@pytest.mark.parametrize("axis", [None, 0, 1])
+def test_median(input_array, axis):
+    wp_result = wp.median(wp.array(input_array), axis=axis)
+    np_result = np.median(np.array(input_array, dtype=np.float32), axis=axis)
+    assert np.allclose(wp_result.tolist(), np_result)
+
+
+def test_median_nan():
+    wp_result = wp.median(wp.array([[1.0, nan, 2.0], [1.0, 3.0, 2.0]]), axis=1)
+    assert wp_result.tolist()[0] != wp_result.tolist()[0]
+    assert wp_result.tolist()[1] == 2.0
+
+
+@pytest.mark.parametrize(
+    "method",
+    [
+        "inverted_cdf",
+        "averaged_inverted_cdf",
+        "closest_observation",
+        "interpolated_inverted_cdf",
+        "hazen",
+        "weibull",
+        "linear",
+        "median_unbiased",
+        "normal_unbiased",
+        "lower",
+        "higher",
+        "nearest",
+        "midpoint",
+    ],
+)
+def test_quantile_methods(input_array, method):
+    q = [0.0, 0.1, 0.25, 0.5, 0.8, 1.0]
+    wp_result = wp.quantile(wp.array(input_array), wp.array(q), axis=1, method=method)
+    np_result = np.quantile(np.array(input_array, dtype=np.float32), q, axis=1, method=method)
+    assert wp_result.shape == list(np_result.shape)
+    assert np.allclose(wp_result.tolist(), np_result)
+
+
+def test_percentile(input_array):
+    wp_result = wp.percentile(wp.array(input_array), 40.0, axis=0, keepdims=True)
+    np_result = np.percentile(np.array(input_array, dtype=np.float32), 40.0, axis=0, keepdims=True)
+    assert wp_result.shape == list(np_result.shape)
+    assert np.allclose(wp_result.tolist(), np_result)
+
+    with pytest.raises(ValueError):
+        wp.percentile(wp.array(input_array), 101.0)
+    with pytest.raises(ValueError):
+        wp.quantile(wp.array(input_array), 0.5, method="unknown")
+
+
+@pytest.mark.parametrize("axis", [None, 0, 1])
+def test_average(input_array, axis):
+    wp_result = wp.average(wp.array(input_array), axis=axis)
+    np_result = np.average(np.array(input_array, dtype=np.float32), axis=axis)
+    assert np.allclose(wp_result.tolist(), np_result)
+
+
+def test_average_weights(input_array):
+    weights = [1.0, 0.5, 2.0, 0.0, 1.5]
+    wp_result, wp_sum = wp.average(
+        wp.array(input_array), axis=1, weights=wp.array(weights), returned=True
+    )
+    np_result, np_sum = np.average(np.array(input_array), axis=1, weights=weights, returned=True)
+    assert np.allclose(wp_result.tolist(), np_result)
+    assert wp_sum.tolist() == np_sum.tolist()
+
+    with pytest.raises(TypeError):
+        wp.average(wp.array(input_array), weights=wp.array(weights))
+    with pytest.raises(ValueError):
+        wp.average(wp.array(input_array), axis=0, weights=wp.array([1.0, -1.0, 0.0]))
+
+
+@pytest.mark.parametrize("rowvar", [True, False])
+@pytest.mark.parametrize("bias", [True, False])
+def test_cov(input_array, rowvar, bias):
+    wp_result = wp.cov(wp.array(input_array), rowvar=rowvar, bias=bias)
+    np_result = np.cov(np.array(input_array), rowvar=rowvar, bias=bias)
+    assert wp_result.shape == list(np_result.shape)
+    assert np.allclose(wp_result.tolist(), np_result, rtol=1e-4)
+
+
+def test_cov_y_ddof():
+    x = [1.0, 2.0, 4.0, 7.0]
+    y = [2.0, 4.0, 9.0, 1.0]
+    wp_result = wp.cov(wp.array(x), wp.array(y), ddof=2)
+    np_result = np.cov(x, y, ddof=2)
+    assert np.allclose(wp_result.tolist(), np_result, rtol=1e-4)
+
+    wp_result = wp.cov(wp.array(x))
+    assert wp_result.shape == []
+    assert np.isclose(wp_result.tolist(), np.cov(x))
+
+
+def test_cov_degrees_of_freedom():
+    with pytest.warns(RuntimeWarning):
+        wp.cov(wp.array([[1.0], [2.0]]))
+    with pytest.warns(RuntimeWarning):
+        wp.corrcoef(wp.array([[1.0], [2.0]]))
+
+
+@pytest.mark.parametrize("rowvar", [True, False])
+def test_corrcoef(input_array, rowvar):
+    wp_result = wp.corrcoef(wp.array(input_array), rowvar=rowvar)
+    np_result = np.corrcoef(np.array(input_array), rowvar=rowvar)
+    assert np.allclose(wp_result.tolist(), np_result, atol=1e-5)
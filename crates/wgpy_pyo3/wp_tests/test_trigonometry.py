@@ -1,6 +1,6 @@
 import numpy as np
 import webgpupy as wp
-from test_utils import assert_values_nin1
+from test_utils import assert_values_nin1, almost_equals
 import pytest
 
 
@@ -25,3 +25,45 @@ def test_cos(wp_array, np_array):
 
 def test_sin(wp_array, np_array):
     assert_values_nin1(wp_array, np_array, "sin")
+
+
+@pytest.mark.parametrize(
+    "fn", ["tan", "arctan", "sinh", "cosh", "tanh", "arcsinh", "deg2rad", "rad2deg"]
+)
+def test_unary(wp_array, np_array, fn):
+    almost_equals(getattr(wp, fn)(wp_array), getattr(np, fn)(np_array), decimal=4)
+
+
+@pytest.mark.parametrize("fn", ["arcsin", "arccos", "arctanh"])
+def test_unit_domain(fn):
+    values = [-0.9, -0.5, 0.0, 0.25, 0.9]
+    almost_equals(getattr(wp, fn)(wp.array(values)), getattr(np, fn)(np.array(values)))
+
+
+def test_arccosh():
+    values = [1.0, 1.5, 10.0]
+    almost_equals(wp.arccosh(wp.array(values)), np.arccosh(np.array(values)))
+
+
+def test_aliases(wp_array):
+    assert wp.radians(wp_array).tolist() == wp.deg2rad(wp_array).tolist()
+    assert wp.degrees(wp_array).tolist() == wp.rad2deg(wp_array).tolist()
+
+
+@pytest.mark.parametrize("fn", ["arctan2", "hypot"])
+def test_binary(fn):
+    x1 = [1.0, -1.0, 3.0, 0.0]
+    x2 = [1.0, -2.0, 4.0, -1.0]
+    almost_equals(
+        getattr(wp, fn)(wp.array(x1), wp.array(x2)),
+        getattr(np, fn)(np.array(x1), np.array(x2)),
+    )
+
+
+def test_unwrap():
+    values = [[0.0, 3.0, 6.5], [3.5, 1.0, 7.5]]
+    almost_equals(wp.unwrap(wp.array(values)), np.unwrap(np.array(values)), decimal=5)
+    almost_equals(
+        wp.unwrap(wp.array(values), axis=0, period=4.0),
+        np.unwrap(np.array(values), axis=0, period=4.0),
+    )
@@ -1,5 +1,5 @@
 use arrow_gpu::{
-    array::{ArrayUtils, ArrowArrayGPU, ArrowType, Float32ArrayGPU, UInt32ArrayGPU},
+    array::{ArrowArrayGPU, ArrowType, Float32ArrayGPU, UInt32ArrayGPU},
     gpu_utils::*,
     kernels::{
         add_op_dyn, bitcast_op_dyn, broadcast::Broadcast, max_op_dyn, mul_op_dyn, sub_op_dyn,
@@ -7,7 +7,8 @@ use arrow_gpu::{
     },
 };
 use std::{f32::consts::SQRT_2, fmt::Debug, sync::Arc};
-use webgpupy_core::{full, ones, zeros, Dtype, NdArray, ScalarValue, GPU_DEVICE};
+use webgpupy_core::{ones, zeros, Dtype, NdArray, GPU_DEVICE};
+use webgpupy_math::erfinv_op_dyn;
 use wgpu::Buffer;
 
 use crate::{iota::*, threefry::*};
@@ -136,6 +137,10 @@ impl Generator for ThreeFry2x32 {
         max_value: &NdArray,
         pipeline: &mut ArrowComputePipeline,
     ) -> NdArray {
+        if shape.contains(&0) {
+            return NdArray::zero_sized(shape.to_vec(), Dtype::Float32, self.get_gpu_device());
+        }
+
         let rand_buffer = self.random_bit_op(shape, pipeline);
         let count: u32 = shape.iter().product();
         let data = Arc::new(rand_buffer);
@@ -182,13 +187,19 @@ impl Generator for ThreeFry2x32 {
     }
 
     fn normal(&mut self, shape: &[u32]) -> NdArray {
+        if shape.contains(&0) {
+            return NdArray::zero_sized(shape.to_vec(), Dtype::Float32, self.get_gpu_device());
+        }
+
         let mut pipeline = ArrowComputePipeline::new(self.get_gpu_device(), Some("normal"));
-        let lo = full(
-            shape.to_vec(),
-            ScalarValue::F32(-1.0f32 + f32::EPSILON).into(),
-            Some(Dtype::Float32),
-            Some(self.get_gpu_device()),
-        );
+        let count = shape.iter().product::<u32>() as usize;
+        let lo = NdArray {
+            shape: shape.to_vec(),
+            dims: shape.len() as u16,
+            data: Float32ArrayGPU::broadcast_op(-1.0f32 + f32::EPSILON, count, &mut pipeline)
+                .into(),
+            dtype: Dtype::Float32,
+        };
         let hi = ones(
             shape.to_vec(),
             Some(Dtype::Float32),
@@ -197,22 +208,7 @@ impl Generator for ThreeFry2x32 {
         let u = self.uniform_op(shape, &lo, &hi, &mut pipeline);
         let sqrt_ans = Float32ArrayGPU::broadcast_op(SQRT_2, 1, &mut pipeline);
 
-        if let ArrowArrayGPU::Float32ArrayGPU(x) = u.data {
-            let data = pipeline.apply_unary_function(
-                &x.data,
-                x.data.size(),
-                include_str!("../compute_shader/erfinv.wgsl"),
-                "erfinv",
-                x.len.div_ceil(256) as u32,
-            );
-            let result_arr = Float32ArrayGPU {
-                data: Arc::new(data),
-                gpu_device: x.get_gpu_device(),
-                phantom: std::marker::PhantomData,
-                len: x.len,
-                null_buffer: None,
-            };
-
+        if let ArrowArrayGPU::Float32ArrayGPU(result_arr) = erfinv_op_dyn(&u.data, &mut pipeline) {
             let data = result_arr.mul_scalar_op(&sqrt_ans, &mut pipeline).into();
 
             pipeline.finish();